@@ -1,3 +1,3 @@
 #!/bin/bash
 
-cargo build --release
+cargo build --release "$@"
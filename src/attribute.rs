@@ -13,6 +13,11 @@ use crate::node_type::NodeType;
 #[cfg(feature = "use_cstr")]
 use std::ffi::CStr;
 
+#[cfg(not(feature = "use_cstr"))]
+use crate::defs::XmlIdx;
+#[cfg(not(feature = "use_cstr"))]
+use std::ops::Range;
+
 /// Information about an XML attribute, storing name and value ranges within the document.
 ///
 /// This struct holds references to positions in the source document where the attribute
@@ -42,18 +47,28 @@ impl AttributeInfo {
 /// This struct provides access to an attribute's name and value by combining
 /// a reference to the document with attribute information.
 pub struct Attribute<'a> {
-    doc: &'a Document,
+    doc: &'a Document<'a>,
     data: &'a AttributeInfo,
 }
 
 impl<'xml> Attribute<'xml> {
+    /// Creates a new `Attribute` from its information.
+    ///
+    /// # Arguments
+    /// * `doc` - The document containing the attribute
+    /// * `data` - The information about the attribute
+    #[inline]
+    pub(crate) fn new(doc: &'xml Document<'xml>, data: &'xml AttributeInfo) -> Self {
+        Attribute { doc, data }
+    }
+
     /// Returns the name of the attribute as a string slice.
     ///
     /// # Returns
     /// A string slice containing the attribute name
     #[inline]
     #[must_use]
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> &'xml str {
         #[cfg(feature = "use_cstr")]
         {
             self.doc.get_str_from_location(self.data.name)
@@ -64,18 +79,19 @@ impl<'xml> Attribute<'xml> {
 
     /// Returns the name of the attribute as a byte slice.
     ///
+    /// Unlike `name()`, the actual name is returned, even if it is not valid UTF-8.
+    ///
     /// # Returns
     /// A byte slice containing the attribute name
     #[inline]
     #[must_use]
-    pub fn name_bytes(&self) -> &[u8] {
+    pub fn name_bytes(&self) -> &'xml [u8] {
         #[cfg(feature = "use_cstr")]
         {
-            self.doc.get_cstr_from_location(self.data.name).to_bytes()
+            self.doc.get_bytes_from_location(self.data.name)
         }
-
         #[cfg(not(feature = "use_cstr"))]
-        &self.doc.xml[self.data.name.start as usize..self.data.name.end as usize]
+        self.doc.get_bytes_from_location(self.data.name.clone())
     }
 
     #[cfg(feature = "use_cstr")]
@@ -88,7 +104,7 @@ impl<'xml> Attribute<'xml> {
     /// This method is only available when the `use_cstr` feature is enabled
     #[inline]
     #[must_use]
-    pub fn name_cstr(&self) -> &CStr {
+    pub fn name_cstr(&self) -> &'xml CStr {
         self.doc.get_cstr_from_location(self.data.name)
     }
 
@@ -135,6 +151,13 @@ impl<'xml> Attribute<'xml> {
         self.name_cstr() == name
     }
 
+    /// Returns true if the value comes from the parsed XML content, and false if it was
+    /// stored by the mutable DOM methods or expanded out of place.
+    #[inline]
+    pub(crate) fn is_parsed(&self) -> bool {
+        self.doc.is_parsed_location(&self.data.value)
+    }
+
     /// Returns the value of the attribute as a string slice.
     ///
     /// # Returns  
@@ -150,20 +173,106 @@ impl<'xml> Attribute<'xml> {
         self.doc.get_str_from_location(self.data.value.clone())
     }
 
-    /// Returns the vale of the attribute as a byte slice.
+    /// Returns the value of the attribute as a byte slice.
+    ///
+    /// Unlike `value()`, the actual value is returned, even if it is not valid UTF-8.
     ///
     /// # Returns
     /// A byte slice containing the attribute value
     #[inline]
     #[must_use]
-    pub fn value_bytes(&self) -> &[u8] {
+    pub fn value_bytes(&self) -> &'xml [u8] {
         #[cfg(feature = "use_cstr")]
         {
-            self.doc.get_cstr_from_location(self.data.value).to_bytes()
+            self.doc.get_bytes_from_location(self.data.value)
         }
-
         #[cfg(not(feature = "use_cstr"))]
-        &self.doc.xml[self.data.name.start as usize..self.data.name.end as usize]
+        self.doc.get_bytes_from_location(self.data.value.clone())
+    }
+
+    #[cfg(not(feature = "use_cstr"))]
+    /// Returns the byte range of the attribute name in the XML content.
+    ///
+    /// As with `Node::position()`, the offsets locate the attribute in the parsed content,
+    /// such that linters and editors can map their findings back to positions in the source
+    /// file (see `Document::line_col()`). When the `namespace_removal` option is enabled, the
+    /// range starts after the removed prefix. The attributes created after parsing are
+    /// located after the parsed content.
+    ///
+    /// # Returns
+    /// The range of the attribute name
+    ///
+    /// # Note
+    /// This method is only available when the `use_cstr` feature is disabled
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root id=\"main\"/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let attr = document.root().unwrap().attributes().next().unwrap();
+    ///
+    /// assert_eq!(attr.name_range(), 6..8);
+    /// assert_eq!(attr.value_range(), 10..14);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn name_range(&self) -> Range<XmlIdx> {
+        self.data.name.clone()
+    }
+
+    #[cfg(not(feature = "use_cstr"))]
+    /// Returns the byte range of the attribute value in the XML content, quotes excluded.
+    ///
+    /// The range starts at the beginning of the value in the source. As the escape sequences
+    /// and whitespace of the value are translated in place, the range ends before the end of
+    /// the value in the source when the value has been modified by the parsing.
+    ///
+    /// # Returns
+    /// The range of the attribute value
+    ///
+    /// # Note
+    /// This method is only available when the `use_cstr` feature is disabled
+    #[inline]
+    #[must_use]
+    pub fn value_range(&self) -> Range<XmlIdx> {
+        self.data.value.clone()
+    }
+
+    /// Appends the entity-decoded value of the attribute to the given buffer.
+    ///
+    /// Attribute values are usually decoded in place during parsing, the value being then
+    /// copied as-is. When the `decode_entities` parsing option is disabled, the character
+    /// and entity references of the value are translated while appending it. Reusing the
+    /// same buffer avoids an allocation per attribute when reading many values.
+    ///
+    /// # Arguments
+    /// * `buf` - The buffer receiving the attribute value
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root a=\"x &amp; y\" b=\"&#65;\"/>".to_vec();
+    /// let options = ParserOptions::new().decode_entities(false);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let mut buf = String::new();
+    ///
+    /// for attr in document.root().unwrap().attributes() {
+    ///     attr.decoded_into(&mut buf);
+    /// }
+    ///
+    /// assert_eq!(buf, "x & yA");
+    /// ```
+    #[inline]
+    pub fn decoded_into(&self, buf: &mut String) {
+        if self.doc.options.decode_entities || !self.is_parsed() {
+            buf.push_str(self.value());
+        } else {
+            self.doc.decode_references_into(self.value(), buf);
+        }
     }
 
     #[cfg(feature = "use_cstr")]
@@ -199,7 +308,7 @@ impl fmt::Debug for Attribute<'_> {
 /// yielding `Attribute` instances for each attribute found.
 #[derive(Clone)]
 pub struct Attributes<'a> {
-    doc: &'a Document,
+    doc: &'a Document<'a>,
     attrs: core::slice::Iter<'a, AttributeInfo>,
 }
 
@@ -0,0 +1,189 @@
+//! Construction of documents from scratch.
+//!
+//! This module provides the `DocumentBuilder`, which describes an element tree and produces a
+//! `Document` without any XML source. The produced document uses the same nodes and attributes
+//! vectors as a parsed one, its strings being stored in the text store area of an owned XML
+//! buffer. It is useful to generate XHTML output, or to construct test fixtures.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::builder::DocumentBuilder;
+//!
+//! let document = DocumentBuilder::element("html")
+//!     .attr("xmlns", "http://www.w3.org/1999/xhtml")
+//!     .child(DocumentBuilder::element("body").child(DocumentBuilder::element("p").text("Fish & Chips")))
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(document.find_first("p").unwrap().first_child().unwrap().text(), Some("Fish & Chips"));
+//! assert_eq!(
+//!     document.to_xml(),
+//!     "<html xmlns=\"http://www.w3.org/1999/xhtml\"><body><p>Fish &amp; Chips</p></body></html>"
+//! );
+//! ```
+
+use crate::defs::{NodeIdx, ParseXmlError};
+use crate::document::Document;
+use crate::node_info::NodeInfo;
+use crate::node_type::NodeType;
+use crate::xml_buffer::XmlBuffer;
+
+/// A child of an element under construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Content {
+    Element(DocumentBuilder),
+    Text(String),
+}
+
+/// Describes an element, with its attributes and children, to be built into a `Document`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct DocumentBuilder {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Content>,
+}
+
+impl DocumentBuilder {
+    /// Starts the description of an element.
+    ///
+    /// # Arguments
+    /// * `name` - The tag name of the element
+    pub fn element(name: impl Into<String>) -> Self {
+        DocumentBuilder {
+            name: name.into(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds an attribute to the element.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the attribute
+    /// * `value` - The value of the attribute, unescaped
+    pub fn attr(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Adds a child element, after the current children.
+    ///
+    /// # Arguments
+    /// * `child` - The description of the child element
+    pub fn child(mut self, child: DocumentBuilder) -> Self {
+        self.children.push(Content::Element(child));
+        self
+    }
+
+    /// Adds a text node, after the current children.
+    ///
+    /// # Arguments
+    /// * `text` - The content of the text node, unescaped
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.children.push(Content::Text(text.into()));
+        self
+    }
+
+    /// Builds the document, the described element being its root.
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: The built document.
+    /// - `Err(ParseXmlError)`: If the document cannot be built.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If an element or attribute name is not a valid XML name.
+    /// - `ParseXmlError::NoMoreSpace`: If the nodes, attributes or XML buffer would become too large for the selected size features.
+    pub fn build(&self) -> Result<Document<'static>, ParseXmlError> {
+        let (node_count, attr_count, text_size) = self.sizes();
+        let mut document =
+            Document::with_buffer(XmlBuffer::Owned(Vec::new()), node_count + 1, attr_count);
+        document
+            .xml
+            .try_reserve(text_size)
+            .map_err(|_| ParseXmlError::NotEnoughMemory)?;
+
+        #[cfg(not(feature = "forward_only"))]
+        document.nodes.push(NodeInfo::new(0, 0, NodeType::Head));
+        #[cfg(feature = "forward_only")]
+        document.nodes.push(NodeInfo::new(NodeType::Head));
+
+        // For each open element: its remaining children, its index and its last child index
+        let root_idx = self.add_element(&mut document, 0, 0)?;
+        let mut stack = vec![(self.children.iter(), root_idx, 0)];
+
+        while let Some((children, parent_idx, last_child_idx)) = stack.last_mut() {
+            let Some(child) = children.next() else {
+                stack.pop();
+                continue;
+            };
+            let (parent_idx, previous_idx) = (*parent_idx, *last_child_idx);
+
+            match child {
+                Content::Element(element) => {
+                    let node_idx = element.add_element(&mut document, parent_idx, previous_idx)?;
+                    *last_child_idx = node_idx;
+                    stack.push((element.children.iter(), node_idx, 0));
+                }
+                Content::Text(text) => {
+                    let location = document.store_str(text)?;
+                    *last_child_idx =
+                        document.add_node(parent_idx, previous_idx, NodeType::Text(location))?;
+                }
+            }
+        }
+
+        Ok(document)
+    }
+
+    /// Adds the element and its attributes to the document.
+    fn add_element(
+        &self,
+        document: &mut Document,
+        parent_idx: NodeIdx,
+        last_child_idx: NodeIdx,
+    ) -> Result<NodeIdx, ParseXmlError> {
+        Document::check_name(&self.name, "element")?;
+        for (attr_name, _) in &self.attributes {
+            Document::check_name(attr_name, "attribute")?;
+        }
+
+        let name = document.store_str(&self.name)?;
+        let node_idx = document.add_node(
+            parent_idx,
+            last_child_idx,
+            NodeType::Element {
+                name,
+                attributes: 0..0, // Set by add_node()
+            },
+        )?;
+
+        for (attr_name, attr_value) in &self.attributes {
+            let attr_name = document.store_str(attr_name)?;
+            let attr_value = document.store_str(attr_value)?;
+            document.add_attribute(node_idx, attr_name, attr_value)?;
+        }
+
+        Ok(node_idx)
+    }
+
+    /// Returns the number of nodes, the number of attributes and the size of the strings of
+    /// the element and its descendants.
+    fn sizes(&self) -> (usize, usize, usize) {
+        let mut sizes = (1, self.attributes.len(), self.name.len() + 1);
+        for (name, value) in &self.attributes {
+            sizes.2 += name.len() + value.len() + 2;
+        }
+
+        for child in &self.children {
+            let (nodes, attributes, text) = match child {
+                Content::Element(element) => element.sizes(),
+                Content::Text(text) => (1, 0, text.len() + 1),
+            };
+            sizes = (sizes.0 + nodes, sizes.1 + attributes, sizes.2 + text);
+        }
+
+        sizes
+    }
+}
@@ -0,0 +1,583 @@
+//! Node selection with CSS selectors.
+//!
+//! This module is only available when the `css` feature is enabled. It matches the elements
+//! of a document against a list of CSS selectors, such as `div.note > p, h1#title`. The
+//! supported selectors are:
+//!
+//! - The type (`p`) and universal (`*`) selectors.
+//! - The class (`.note`) and id (`#title`) selectors.
+//! - The attribute selectors: `[lang]`, `[lang=en]`, `[class~=note]`, `[lang|=en]`,
+//!   `[href^="http"]`, `[href$=".png"]` and `[href*=example]`.
+//! - The descendant (` `), child (`>`), next-sibling (`+`) and subsequent-sibling (`~`)
+//!   combinators.
+//!
+//! Pseudo-classes and pseudo-elements are not supported. The names are compared with the tag
+//! and attribute names kept by the parser, case-sensitively as in XHTML. A namespace prefix
+//! kept by the parser can be matched by escaping the colon (`svg\:rect`).
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::Document;
+//!
+//! let xml_data = b"<body><div class=\"note big\"><p>A</p><span><p>B</p></span></div><p>C</p></body>".to_vec();
+//! let document = Document::new(xml_data).unwrap();
+//! let body = document.root().unwrap();
+//! let texts: Vec<String> = body.select_css("div.note > p").unwrap().map(|p| p.text_content()).collect();
+//!
+//! assert_eq!(texts, ["A"]);
+//! ```
+
+use std::borrow::Cow;
+
+use crate::defs::{NodeIdx, ParseXmlError};
+use crate::document::Nodes;
+use crate::node::Node;
+
+/// A compiled list of CSS selectors, matching the elements matched by any of them.
+///
+/// Compiling the selectors once with `Selector::new()` avoids parsing them again when they
+/// are used many times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    alternatives: Vec<ComplexSelector>,
+}
+
+/// Compound selectors separated by combinators, `combinators[i]` being located between
+/// `compounds[i]` and `compounds[i + 1]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComplexSelector {
+    compounds: Vec<CompoundSelector>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+/// The simple selectors applying to a single element. `name` is `None` for `*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CompoundSelector {
+    name: Option<String>,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Id(String),
+    Class(String),
+    Attribute {
+        name: String,
+        test: Option<(AttributeOperator, String)>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttributeOperator {
+    Equal,     // =
+    Includes,  // ~=
+    DashMatch, // |=
+    Prefix,    // ^=
+    Suffix,    // $=
+    Substring, // *=
+}
+
+impl Selector {
+    /// Compiles a comma-separated list of CSS selectors.
+    ///
+    /// # Arguments
+    /// * `selectors` - The selectors, in the subset described in the module documentation
+    ///
+    /// # Returns
+    /// - `Ok(Selector)`: The compiled selectors.
+    /// - `Err(ParseXmlError)`: If the selectors are not valid or not supported.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidSelector`: If the selectors are not valid or not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::css::Selector;
+    ///
+    /// assert!(Selector::new("ul > li.item, a[href^='http']").is_ok());
+    /// assert!(Selector::new("ul >").is_err());
+    /// assert!(Selector::new("a:hover").is_err());
+    /// ```
+    pub fn new(selectors: &str) -> Result<Self, ParseXmlError> {
+        let mut parser = SelectorParser {
+            input: selectors,
+            pos: 0,
+        };
+        let mut alternatives = vec![parser.complex_selector()?];
+        while parser.eat(',') {
+            alternatives.push(parser.complex_selector()?);
+        }
+        if parser.pos < selectors.len() {
+            return Err(parser.error("Unexpected character"));
+        }
+        Ok(Selector { alternatives })
+    }
+
+    /// Returns true if the node is an element matched by the selectors.
+    ///
+    /// # Arguments
+    /// * `node` - The node to check
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::css::Selector;
+    ///
+    /// let document = Document::new(b"<ul><li id=\"a\"/><li id=\"b\"/></ul>".to_vec()).unwrap();
+    /// let selector = Selector::new("li + li").unwrap();
+    ///
+    /// assert!(!selector.matches(&document.find_first("li").unwrap()));
+    /// assert!(selector.matches(&document.select("//li[@id='b']").unwrap()[0]));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, node: &Node) -> bool {
+        node.is_element() && self.matches_in(node, &ancestors(node))
+    }
+
+    /// Returns an iterator over the descendants of the node matched by the selectors, in
+    /// document order.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose descendants are searched
+    pub fn select<'xml>(&self, node: &Node<'xml>) -> CssMatches<'xml, '_> {
+        CssMatches::new(Cow::Borrowed(self), node)
+    }
+
+    /// Returns true if the element, whose ancestors are given from the root element, is
+    /// matched by the selectors.
+    fn matches_in(&self, node: &Node, ancestors: &[Node]) -> bool {
+        self.alternatives
+            .iter()
+            .any(|complex| complex.matches_from(complex.compounds.len() - 1, node, ancestors))
+    }
+}
+
+impl ComplexSelector {
+    /// Returns true if the element is matched by the compound selector at index `i`, the
+    /// compound selectors on its left being matched by the related elements.
+    fn matches_from(&self, i: usize, node: &Node, ancestors: &[Node]) -> bool {
+        if !self.compounds[i].matches(node) {
+            return false;
+        }
+        if i == 0 {
+            return true;
+        }
+
+        match self.combinators[i - 1] {
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|j| self.matches_from(i - 1, &ancestors[j], &ancestors[..j])),
+            Combinator::Child => ancestors
+                .split_last()
+                .is_some_and(|(parent, ancestors)| self.matches_from(i - 1, parent, ancestors)),
+            Combinator::NextSibling => previous_siblings(node, ancestors)
+                .last()
+                .is_some_and(|sibling| self.matches_from(i - 1, sibling, ancestors)),
+            Combinator::SubsequentSibling => previous_siblings(node, ancestors)
+                .iter()
+                .any(|sibling| self.matches_from(i - 1, sibling, ancestors)),
+        }
+    }
+}
+
+impl CompoundSelector {
+    fn matches(&self, node: &Node) -> bool {
+        self.name.as_ref().is_none_or(|name| node.is(name))
+            && self
+                .conditions
+                .iter()
+                .all(|condition| condition.matches(node))
+    }
+}
+
+impl Condition {
+    fn matches(&self, node: &Node) -> bool {
+        match self {
+            Condition::Id(id) => node.get_attribute("id") == Some(id),
+            Condition::Class(class) => node
+                .get_attribute("class")
+                .is_some_and(|classes| classes.split_ascii_whitespace().any(|c| c == class)),
+            Condition::Attribute { name, test } => {
+                let Some(value) = node.get_attribute(name) else {
+                    return false;
+                };
+                let Some((operator, expected)) = test else {
+                    return true;
+                };
+                let expected = expected.as_str();
+                match operator {
+                    AttributeOperator::Equal => value == expected,
+                    AttributeOperator::Includes => {
+                        value.split_ascii_whitespace().any(|word| word == expected)
+                    }
+                    AttributeOperator::DashMatch => {
+                        value == expected
+                            || value
+                                .strip_prefix(expected)
+                                .is_some_and(|rest| rest.starts_with('-'))
+                    }
+                    // An empty value matches nothing with these operators
+                    AttributeOperator::Prefix => {
+                        !expected.is_empty() && value.starts_with(expected)
+                    }
+                    AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(expected),
+                    AttributeOperator::Substring => {
+                        !expected.is_empty() && value.contains(expected)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the ancestors of the node, from the root element to its parent.
+fn ancestors<'xml>(node: &Node<'xml>) -> Vec<Node<'xml>> {
+    let mut ancestors: Vec<Node> = node.ancestors().collect();
+    ancestors.reverse();
+    ancestors
+}
+
+/// Returns the elements preceding the node under its parent, the last of the ancestors.
+fn previous_siblings<'xml>(node: &Node, ancestors: &[Node<'xml>]) -> Vec<Node<'xml>> {
+    ancestors.last().map_or_else(Vec::new, |parent| {
+        parent
+            .children()
+            .take_while(|sibling| sibling.idx() < node.idx())
+            .filter(Node::is_element)
+            .collect()
+    })
+}
+
+/// An iterator over the descendants of a node matched by CSS selectors.
+///
+/// Returned by `Node::select_css()` and `Selector::select()`.
+#[must_use]
+pub struct CssMatches<'xml, 's> {
+    selector: Cow<'s, Selector>,
+    nodes: Nodes<'xml>,
+    ancestors: Vec<Node<'xml>>,
+    ends: Vec<NodeIdx>, // Index of the last descendant of each ancestor
+}
+
+impl<'xml, 's> CssMatches<'xml, 's> {
+    fn new(selector: Cow<'s, Selector>, node: &Node<'xml>) -> Self {
+        let mut ancestors = ancestors(node);
+        ancestors.push(node.clone());
+        // The ancestors of the node contain all its descendants
+        let ends = vec![NodeIdx::MAX; ancestors.len()];
+
+        CssMatches {
+            selector,
+            nodes: node.descendants(),
+            ancestors,
+            ends,
+        }
+    }
+}
+
+impl<'xml> Iterator for CssMatches<'xml, '_> {
+    type Item = Node<'xml>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for node in self.nodes.by_ref() {
+            while self.ends.last().is_some_and(|&end| end < node.idx()) {
+                self.ancestors.pop();
+                self.ends.pop();
+            }
+            if !node.is_element() {
+                continue;
+            }
+
+            let matched = self.selector.matches_in(&node, &self.ancestors);
+            if let Some(end) = node.doc.last_descendant(node.idx()) {
+                self.ancestors.push(node.clone());
+                self.ends.push(end);
+            }
+            if matched {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// A recursive descent parser of CSS selectors.
+struct SelectorParser<'s> {
+    input: &'s str,
+    pos: usize,
+}
+
+impl SelectorParser<'_> {
+    fn error(&self, msg: &str) -> ParseXmlError {
+        ParseXmlError::InvalidSelector(format!(
+            "{msg} at position {} in `{}`",
+            self.pos, self.input
+        ))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Skips the whitespaces, returning true if there were some.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.input[self.pos..];
+        let len = rest.len() - rest.trim_start().len();
+        self.pos += len;
+        len > 0
+    }
+
+    /// Consumes the character if the input continues with it, after whitespaces.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes an identifier, with its backslash escapes resolved.
+    fn identifier(&mut self) -> Result<String, ParseXmlError> {
+        let mut identifier = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.pos += 1;
+                let Some(escaped) = self.peek() else {
+                    return Err(self.error("Unterminated escape sequence"));
+                };
+                identifier.push(escaped);
+                self.pos += escaped.len_utf8();
+            } else if c.is_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii() {
+                identifier.push(c);
+                self.pos += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+
+        if identifier.is_empty() {
+            Err(self.error("Expected an identifier"))
+        } else {
+            Ok(identifier)
+        }
+    }
+
+    fn complex_selector(&mut self) -> Result<ComplexSelector, ParseXmlError> {
+        self.skip_whitespace();
+        let mut complex = ComplexSelector {
+            compounds: vec![self.compound_selector()?],
+            combinators: Vec::new(),
+        };
+
+        loop {
+            let whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::NextSibling,
+                Some('~') => Combinator::SubsequentSibling,
+                Some(',') | None => return Ok(complex),
+                Some(_) if whitespace => {
+                    complex.combinators.push(Combinator::Descendant);
+                    complex.compounds.push(self.compound_selector()?);
+                    continue;
+                }
+                Some(_) => return Err(self.error("Unexpected character")),
+            };
+            self.pos += 1;
+            self.skip_whitespace();
+            complex.combinators.push(combinator);
+            complex.compounds.push(self.compound_selector()?);
+        }
+    }
+
+    fn compound_selector(&mut self) -> Result<CompoundSelector, ParseXmlError> {
+        let mut compound = CompoundSelector::default();
+        let start = self.pos;
+        if self.peek() == Some('*') {
+            self.pos += 1;
+        } else if self
+            .peek()
+            .is_some_and(|c| c != '#' && c != '.' && c != '[')
+        {
+            compound.name = Some(self.identifier()?);
+        }
+
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.pos += 1;
+                    compound.conditions.push(Condition::Id(self.identifier()?));
+                }
+                Some('.') => {
+                    self.pos += 1;
+                    compound
+                        .conditions
+                        .push(Condition::Class(self.identifier()?));
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    compound.conditions.push(self.attribute_selector()?);
+                }
+                Some(':') => return Err(self.error("Pseudo-classes are not supported")),
+                _ => break,
+            }
+        }
+
+        if self.pos == start {
+            Err(self.error("Expected a selector"))
+        } else {
+            Ok(compound)
+        }
+    }
+
+    fn attribute_selector(&mut self) -> Result<Condition, ParseXmlError> {
+        self.skip_whitespace();
+        let name = self.identifier()?;
+        self.skip_whitespace();
+
+        let operator = match self.peek() {
+            Some(']') => {
+                self.pos += 1;
+                return Ok(Condition::Attribute { name, test: None });
+            }
+            Some('=') => AttributeOperator::Equal,
+            Some('~') => AttributeOperator::Includes,
+            Some('|') => AttributeOperator::DashMatch,
+            Some('^') => AttributeOperator::Prefix,
+            Some('$') => AttributeOperator::Suffix,
+            Some('*') => AttributeOperator::Substring,
+            _ => return Err(self.error("Expected an attribute operator")),
+        };
+        self.pos += 1;
+        if operator != AttributeOperator::Equal && !self.input[self.pos..].starts_with('=') {
+            return Err(self.error("Expected `=`"));
+        }
+        if operator != AttributeOperator::Equal {
+            self.pos += 1;
+        }
+
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let Some(len) = self.input[self.pos..].find(quote) else {
+                    return Err(self.error("Unterminated string"));
+                };
+                let value = self.input[self.pos..self.pos + len].to_string();
+                self.pos += len + 1;
+                value
+            }
+            _ => self.identifier()?,
+        };
+
+        if self.eat(']') {
+            Ok(Condition::Attribute {
+                name,
+                test: Some((operator, value)),
+            })
+        } else {
+            Err(self.error("Expected `]`"))
+        }
+    }
+}
+
+impl<'xml> Node<'xml> {
+    /// Returns an iterator over the descendants of the node matched by a list of CSS
+    /// selectors, in document order.
+    ///
+    /// This method is only available when the `css` feature is enabled. The selectors are
+    /// compiled at each call: use `Selector::new()` and `Selector::select()` to use them
+    /// several times.
+    ///
+    /// # Arguments
+    /// * `selectors` - The comma-separated selectors, in the subset described in the `css` module
+    ///
+    /// # Returns
+    /// - `Ok(CssMatches)`: The iterator over the matched elements.
+    /// - `Err(ParseXmlError)`: If the selectors are not valid or not supported.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidSelector`: If the selectors are not valid or not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<ul><li id=\"a\">1</li><li class=\"x\">2</li><li>3</li></ul>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let ul = document.root().unwrap();
+    ///
+    /// assert_eq!(ul.select_css("li.x ~ li").unwrap().count(), 1);
+    /// assert_eq!(ul.select_css("#a, .x").unwrap().count(), 2);
+    /// ```
+    pub fn select_css(&self, selectors: &str) -> Result<CssMatches<'xml, 'static>, ParseXmlError> {
+        Ok(CssMatches::new(Cow::Owned(Selector::new(selectors)?), self))
+    }
+
+    /// Returns true if the node is an element matched by a list of CSS selectors, as does the
+    /// DOM `Element.matches()` method. The combinators are checked against the ancestors and
+    /// preceding siblings of the node.
+    ///
+    /// This method is only available when the `css` feature is enabled. The selectors are
+    /// compiled at each call: use `Selector::new()` and `Node::matches_compiled_selector()` to
+    /// use them several times.
+    ///
+    /// # Arguments
+    /// * `selectors` - The comma-separated selectors, in the subset described in the `css` module
+    ///
+    /// # Returns
+    /// True if the node is matched, false if it is not, or if the selectors are not valid or
+    /// not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<body><div class=\"note\"><p>Text</p></div></body>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let p = document.find_first("p").unwrap();
+    ///
+    /// assert!(p.matches_selector("div.note > p"));
+    /// assert!(!p.matches_selector("body > p"));
+    /// assert!(!p.matches_selector("p >"));
+    /// ```
+    #[must_use]
+    pub fn matches_selector(&self, selectors: &str) -> bool {
+        Selector::new(selectors).is_ok_and(|selector| selector.matches(self))
+    }
+
+    /// Returns true if the node is an element matched by compiled CSS selectors.
+    ///
+    /// This method is only available when the `css` feature is enabled.
+    ///
+    /// # Arguments
+    /// * `selector` - The compiled selectors
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::css::Selector;
+    ///
+    /// let document = Document::new(b"<ul><li/><li class=\"x\"/></ul>".to_vec()).unwrap();
+    /// let selector = Selector::new("li.x").unwrap();
+    /// let ul = document.root().unwrap();
+    ///
+    /// assert_eq!(ul.children().filter(|li| li.matches_compiled_selector(&selector)).count(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn matches_compiled_selector(&self, selector: &Selector) -> bool {
+        selector.matches(self)
+    }
+}
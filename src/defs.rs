@@ -101,12 +101,33 @@ pub type XmlLocation = Range<XmlIdx>;
 pub type NodeRange = Range<NodeIdx>;
 pub type AttributeRange = Range<AttrIdx>;
 
+// ----- DOM storage definitions -----
+
+/// The allocator of the nodes and attributes vectors of a document, selected with
+/// `Document::new_in()` when the `allocator_api` feature is enabled. It must be `Sync`, for
+/// the document to remain `Send` and `Sync`.
+#[cfg(feature = "allocator_api")]
+pub type DomAllocator<'a> = &'a (dyn std::alloc::Allocator + Sync);
+
+#[cfg(feature = "allocator_api")]
+pub type DomVec<'a, T> = Vec<T, DomAllocator<'a>>;
+
+#[cfg(not(feature = "allocator_api"))]
+pub type DomVec<'a, T> = Vec<T>;
+
 #[derive(Debug)]
 pub enum ParseXmlError {
     InvalidXml(String),
     NoMoreSpace,
     InternalError,
     NotEnoughMemory,
+    MultipleRoots,
+    Io(std::io::Error),
+    InvalidXPath(String),
+    InvalidSelector(String),
+    EntityLimitExceeded,
+    LimitExceeded(String),
+    InvalidEncoding(String),
 }
 
 impl std::fmt::Display for ParseXmlError {
@@ -118,6 +139,145 @@ impl std::fmt::Display for ParseXmlError {
             ParseXmlError::NotEnoughMemory => {
                 write!(f, "Not enough memory to complete the operation")
             }
+            ParseXmlError::MultipleRoots => {
+                write!(f, "Content found after the root element")
+            }
+            ParseXmlError::Io(err) => write!(f, "I/O error while reading the XML content: {err}"),
+            ParseXmlError::InvalidXPath(msg) => write!(f, "Invalid XPath expression: {msg}"),
+            ParseXmlError::InvalidSelector(msg) => write!(f, "Invalid CSS selector: {msg}"),
+            ParseXmlError::EntityLimitExceeded => {
+                write!(f, "Entity expansion limits exceeded")
+            }
+            ParseXmlError::LimitExceeded(msg) => write!(f, "Parsing limit exceeded: {msg}"),
+            ParseXmlError::InvalidEncoding(msg) => write!(f, "Invalid encoding: {msg}"),
+        }
+    }
+}
+
+/// A violation reported by `Document::validate_against_allowed()`.
+///
+/// Each violation carries the index of the offending element node, so that the caller
+/// can locate it in the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// An element whose tag name is not part of the allowed tags.
+    Element { node_idx: NodeIdx, tag_name: String },
+    /// An attribute whose name is not part of the allowed attributes.
+    Attribute { node_idx: NodeIdx, name: String },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Element { node_idx, tag_name } => {
+                write!(f, "Element '{tag_name}' not allowed (node {node_idx})")
+            }
+            Violation::Attribute { node_idx, name } => {
+                write!(f, "Attribute '{name}' not allowed (node {node_idx})")
+            }
+        }
+    }
+}
+
+/// The kind of problem reported by a `Diagnostic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A `<` not followed by a valid tag name. The `<` is ignored.
+    InvalidTagName,
+    /// A malformed tag, such as a `/` not followed by `>` in a self-closing tag.
+    MalformedTag,
+    /// A malformed attribute (invalid name, missing `=` or unquoted value). The attribute is dropped.
+    InvalidAttribute,
+    /// A malformed DOCTYPE declaration.
+    MalformedDeclaration,
+    /// A closing tag that does not match the element being closed. If an enclosing element
+    /// has the same name, the elements opened since are implicitly closed, otherwise the
+    /// closing tag is ignored.
+    MismatchedClosingTag,
+    /// A closing tag without a matching opening tag. The closing tag is ignored.
+    UnmatchedClosingTag,
+    /// The end of the document reached before the end of the root element or of a construct.
+    UnexpectedEnd,
+}
+
+/// A problem found by `Document::new_recovering()` while parsing a malformed document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The kind of problem
+    pub kind: DiagnosticKind,
+    /// A description of the problem
+    pub message: String,
+    /// The byte offset of the problem in the XML content
+    pub position: XmlIdx,
+    /// The line of the problem, starting at 1
+    pub line: usize,
+    /// The column of the problem, in characters, starting at 1
+    pub column: usize,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {} (position {})",
+            self.message, self.line, self.column, self.position
+        )
+    }
+}
+
+/// The pseudo-attributes of the XML declaration (`<?xml ...?>`), returned by
+/// `Document::declaration()`.
+///
+/// The values are kept as written, without verification, so validators can check them. The
+/// encoding is the declared one: the content of a document transcoded to UTF-8 by the parser
+/// still declares its original encoding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XmlDeclaration {
+    /// The value of the `version` pseudo-attribute
+    pub version: Option<String>,
+    /// The value of the `encoding` pseudo-attribute
+    pub encoding: Option<String>,
+    /// The value of the `standalone` pseudo-attribute
+    pub standalone: Option<String>,
+}
+
+impl XmlDeclaration {
+    /// Reads the pseudo-attributes of an XML declaration. The reading stops at the first
+    /// malformed pseudo-attribute, and the unknown ones are ignored.
+    ///
+    /// # Arguments
+    /// * `data` - The content of the declaration, following the `xml` target
+    pub(crate) fn from_data(data: &[u8]) -> Self {
+        let mut declaration = XmlDeclaration::default();
+        let mut rest = data.trim_ascii_start();
+
+        while let Some(name_end) = rest.iter().position(|&b| b == b'=') {
+            let name = rest[..name_end].trim_ascii();
+            let quoted = rest[name_end + 1..].trim_ascii_start();
+            let Some(&quote) = quoted.first().filter(|&&q| q == b'"' || q == b'\'') else {
+                break;
+            };
+            let Some(value_len) = quoted[1..].iter().position(|&b| b == quote) else {
+                break;
+            };
+            let value = Some(String::from_utf8_lossy(&quoted[1..=value_len]).into_owned());
+
+            match name {
+                b"version" => declaration.version = value,
+                b"encoding" => declaration.encoding = value,
+                b"standalone" => declaration.standalone = value,
+                _ => {}
+            }
+            rest = quoted[value_len + 2..].trim_ascii_start();
         }
+
+        declaration
+    }
+
+    /// Returns true if the document is declared standalone (`standalone="yes"`).
+    #[inline]
+    #[must_use]
+    pub fn is_standalone(&self) -> bool {
+        self.standalone.as_deref() == Some("yes")
     }
 }
@@ -0,0 +1,671 @@
+//! Structural comparison of two documents.
+//!
+//! This module provides the `diff()` function, which compares the element trees of two
+//! documents and reports their differences: added, removed and renamed elements, added,
+//! removed and changed attributes, and changed text. Unlike a textual diff of their
+//! serializations, the whitespace of the text is normalized by default, and the spacing and
+//! order of the attributes is not considered, which suits the comparison of generated
+//! documents against golden files. When only the equality of the documents matters,
+//! `Document::content_eq()` does the same comparison, stopping at the first difference.
+//!
+//! The children of two matching elements are aligned on their longest common subsequence
+//! of element names and text nodes, so an inserted element is reported once instead of
+//! shifting all its following siblings. An element replaced by another one with a different
+//! name is reported as renamed, and its content is compared. Comments and processing
+//! instructions are not compared.
+//!
+//! Each difference is located by the path of its node, made of the tag names of its
+//! ancestors with their position among the siblings of the same name, as in
+//! `/html[1]/body[1]/p[2]`, text nodes being designated by `text()[n]`. The path of an added
+//! node is its path in the second document, the other paths are the ones of the first
+//! document.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::Document;
+//! use xhtml_parser::diff::{diff, Difference};
+//!
+//! let golden = Document::new(b"<body><h1>Title</h1><p class=\"a\">Some  text</p></body>".to_vec()).unwrap();
+//! let generated = Document::new(
+//!     b"<body>\n  <h1>Title</h1>\n  <p class='b'>Some text</p>\n  <hr/>\n</body>".to_vec(),
+//! )
+//! .unwrap();
+//! let differences = diff(&golden, &generated);
+//! let lines: Vec<String> = differences.iter().map(ToString::to_string).collect();
+//!
+//! assert_eq!(lines, [
+//!     "~ /body[1]/p[1]/@class: \"a\" -> \"b\"",
+//!     "+ /body[1]/hr[1]",
+//! ]);
+//! assert_eq!(differences[1], Difference::Added { path: "/body[1]/hr[1]".to_string() });
+//! ```
+
+use std::fmt;
+
+use crate::document::Document;
+use crate::node::Node;
+
+/// Above this number of pairs of children to align, the children are paired by position.
+const MAX_ALIGNMENT_SIZE: usize = 1_000_000;
+
+/// A difference between two documents, reported by `diff()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// An element or text node present in the second document only.
+    Added { path: String },
+    /// An element or text node present in the first document only.
+    Removed { path: String },
+    /// An element replaced by an element with another tag name.
+    Renamed {
+        path: String,
+        from: String,
+        to: String,
+    },
+    /// An attribute present in the second document only.
+    AttributeAdded {
+        path: String,
+        name: String,
+        value: String,
+    },
+    /// An attribute present in the first document only.
+    AttributeRemoved {
+        path: String,
+        name: String,
+        value: String,
+    },
+    /// An attribute whose value differs.
+    AttributeChanged {
+        path: String,
+        name: String,
+        from: String,
+        to: String,
+    },
+    /// A text node whose content differs.
+    TextChanged {
+        path: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl Difference {
+    /// Returns the path of the node concerned by the difference. For the attribute
+    /// differences, this is the path of the element.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            Difference::Added { path }
+            | Difference::Removed { path }
+            | Difference::Renamed { path, .. }
+            | Difference::AttributeAdded { path, .. }
+            | Difference::AttributeRemoved { path, .. }
+            | Difference::AttributeChanged { path, .. }
+            | Difference::TextChanged { path, .. } => path,
+        }
+    }
+}
+
+/// Displays the difference on a single line, prefixed with `+` for an addition, `-` for a
+/// removal, and `~` for a change.
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difference::Added { path } => write!(f, "+ {path}"),
+            Difference::Removed { path } => write!(f, "- {path}"),
+            Difference::Renamed { path, from, to } => write!(f, "~ {path}: <{from}> -> <{to}>"),
+            Difference::AttributeAdded { path, name, value } => {
+                write!(f, "+ {path}/@{name} = {value:?}")
+            }
+            Difference::AttributeRemoved { path, name, value } => {
+                write!(f, "- {path}/@{name} = {value:?}")
+            }
+            Difference::AttributeChanged {
+                path,
+                name,
+                from,
+                to,
+            } => write!(f, "~ {path}/@{name}: {from:?} -> {to:?}"),
+            Difference::TextChanged { path, from, to } => write!(f, "~ {path}: {from:?} -> {to:?}"),
+        }
+    }
+}
+
+/// Options of the comparison done by `diff_with_options()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct DiffOptions {
+    normalize_whitespace: bool,
+    attribute_order: bool,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            normalize_whitespace: true,
+            attribute_order: false,
+        }
+    }
+}
+
+impl DiffOptions {
+    /// Creates the default options.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects if the whitespace of the text is normalized before comparison.
+    ///
+    /// When enabled, the text nodes composed of whitespace only are ignored, and the other
+    /// ones are compared with their leading and trailing whitespace removed, and their inner
+    /// whitespace sequences collapsed to a single space. Enabled by default.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to normalize the whitespace of the text
+    #[inline]
+    pub fn normalize_whitespace(mut self, enabled: bool) -> Self {
+        self.normalize_whitespace = enabled;
+        self
+    }
+
+    /// Returns true if the whitespace of the text is normalized before comparison.
+    #[inline]
+    #[must_use]
+    pub fn has_normalize_whitespace(&self) -> bool {
+        self.normalize_whitespace
+    }
+
+    /// Selects if the order of the attributes is significant.
+    ///
+    /// When enabled, the attributes of two paired elements are compared by position: two
+    /// attributes at the same position with different names are reported as a removed and an
+    /// added attribute. Disabled by default, the attributes being compared by name.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to compare the attributes in order
+    #[inline]
+    pub fn attribute_order(mut self, enabled: bool) -> Self {
+        self.attribute_order = enabled;
+        self
+    }
+
+    /// Returns true if the order of the attributes is significant.
+    #[inline]
+    #[must_use]
+    pub fn has_attribute_order(&self) -> bool {
+        self.attribute_order
+    }
+}
+
+/// Compares two documents, with the default options.
+///
+/// # Arguments
+/// * `first` - The reference document
+/// * `second` - The document compared to the reference
+///
+/// # Returns
+/// The differences, in document order. The documents are equivalent if it is empty.
+#[must_use]
+pub fn diff(first: &Document, second: &Document) -> Vec<Difference> {
+    diff_with_options(first, second, &DiffOptions::default())
+}
+
+/// Compares two documents, with the given options.
+///
+/// # Arguments
+/// * `first` - The reference document
+/// * `second` - The document compared to the reference
+/// * `options` - The comparison options
+///
+/// # Returns
+/// The differences, in document order. The documents are equivalent if it is empty.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::diff::{diff_with_options, DiffOptions};
+///
+/// let first = Document::new(b"<p>A  B</p>".to_vec()).unwrap();
+/// let second = Document::new(b"<p>A B</p>".to_vec()).unwrap();
+/// let options = DiffOptions::new().normalize_whitespace(false);
+///
+/// assert_eq!(diff_with_options(&first, &second, &options).len(), 1);
+/// ```
+#[must_use]
+pub fn diff_with_options(
+    first: &Document,
+    second: &Document,
+    options: &DiffOptions,
+) -> Vec<Difference> {
+    let mut differ = Differ {
+        options,
+        differences: Vec::new(),
+    };
+
+    match (first.root(), second.root()) {
+        (Some(a), Some(b)) => {
+            let a = Child::new(a, "", &mut Counters::default());
+            let b = Child::new(b, "", &mut Counters::default());
+            differ.compare_pair(&a, &b);
+        }
+        (Some(a), None) => differ.removed(&Child::new(a, "", &mut Counters::default())),
+        (None, Some(b)) => differ.added(&Child::new(b, "", &mut Counters::default())),
+        (None, None) => {}
+    }
+
+    differ.differences
+}
+
+impl Document<'_> {
+    /// Returns true if the two documents have the same content, with the default options of
+    /// `diff()`: the same elements, with the same attributes whatever their order, and the
+    /// same text once its whitespace is normalized.
+    ///
+    /// Unlike `==`, which compares the internal representation of the documents, this
+    /// ignores the formatting of the XML source.
+    ///
+    /// # Arguments
+    /// * `other` - The document to compare with
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let first = Document::new(b"<p a=\"1\" b=\"2\">Some text</p>".to_vec()).unwrap();
+    /// let second = Document::new(b"<p  b='2' a='1'>\n  Some\n  text\n</p>".to_vec()).unwrap();
+    ///
+    /// assert!(first != second);
+    /// assert!(first.content_eq(&second));
+    /// ```
+    #[must_use]
+    pub fn content_eq(&self, other: &Document) -> bool {
+        self.content_eq_with_options(other, &DiffOptions::default())
+    }
+
+    /// Returns true if the two documents have the same content, compared with the given
+    /// options. This is equivalent to an empty result of `diff_with_options()`, but stops at
+    /// the first difference.
+    ///
+    /// # Arguments
+    /// * `other` - The document to compare with
+    /// * `options` - The comparison options
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::diff::DiffOptions;
+    ///
+    /// let first = Document::new(b"<p a=\"1\" b=\"2\"/>".to_vec()).unwrap();
+    /// let second = Document::new(b"<p b=\"2\" a=\"1\"/>".to_vec()).unwrap();
+    /// let options = DiffOptions::new().attribute_order(true);
+    ///
+    /// assert!(!first.content_eq_with_options(&second, &options));
+    /// ```
+    #[must_use]
+    pub fn content_eq_with_options(&self, other: &Document, options: &DiffOptions) -> bool {
+        match (self.root(), other.root()) {
+            (Some(a), Some(b)) => content_eq(&a, &b, options),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if a node takes part in the comparison: an element, or a text node which is
+/// not whitespace only when the whitespace is normalized.
+fn is_compared(node: &Node, options: &DiffOptions) -> bool {
+    node.is_element()
+        || (node.is_text()
+            && (!options.normalize_whitespace
+                || node
+                    .text_bytes()
+                    .is_some_and(|text| !text.iter().all(u8::is_ascii_whitespace))))
+}
+
+/// Returns true if two elements have the same content. The trees are traversed with a stack
+/// of the children iterators, as for `Node::walk()`.
+fn content_eq(a: &Node, b: &Node, options: &DiffOptions) -> bool {
+    if !node_eq(a, b, options) {
+        return false;
+    }
+
+    let mut open = vec![(a.children(), b.children())];
+    while let Some((a_children, b_children)) = open.last_mut() {
+        let a_next = a_children.find(|node| is_compared(node, options));
+        let b_next = b_children.find(|node| is_compared(node, options));
+        match (a_next, b_next) {
+            (Some(a), Some(b)) => {
+                if !node_eq(&a, &b, options) {
+                    return false;
+                }
+                if a.is_element() {
+                    open.push((a.children(), b.children()));
+                }
+            }
+            (None, None) => {
+                open.pop();
+            }
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// Returns true if two nodes are equal, without considering their children.
+fn node_eq(a: &Node, b: &Node, options: &DiffOptions) -> bool {
+    if a.is_element() {
+        b.is_element()
+            && a.tag_name_bytes() == b.tag_name_bytes()
+            && a.attribute_count() == b.attribute_count()
+            && if options.attribute_order {
+                a.attributes().zip(b.attributes()).all(|(attr, other)| {
+                    attr.name_bytes() == other.name_bytes()
+                        && attr.value_bytes() == other.value_bytes()
+                })
+            } else {
+                a.attributes().all(|attr| {
+                    b.find_attribute(attr.name_bytes())
+                        .is_some_and(|other| other.value_bytes() == attr.value_bytes())
+                })
+            }
+    } else {
+        let (a_text, b_text) = (a.text().unwrap_or_default(), b.text().unwrap_or_default());
+        !b.is_element()
+            && if options.normalize_whitespace {
+                a_text
+                    .split_ascii_whitespace()
+                    .eq(b_text.split_ascii_whitespace())
+            } else {
+                a_text == b_text
+            }
+    }
+}
+
+/// A compared node, with its path.
+struct Child<'xml> {
+    node: Node<'xml>,
+    path: String,
+}
+
+/// The number of element of each name, and of text nodes, already seen among siblings.
+#[derive(Default)]
+struct Counters {
+    elements: Vec<(Vec<u8>, usize)>,
+    texts: usize,
+}
+
+impl<'xml> Child<'xml> {
+    /// Creates a compared node, its path being built from the one of its parent.
+    fn new(node: Node<'xml>, parent_path: &str, counters: &mut Counters) -> Self {
+        let path = if node.is_element() {
+            let name = node.tag_name_bytes();
+            let position = match counters.elements.iter_mut().find(|(n, _)| n == name) {
+                Some((_, count)) => {
+                    *count += 1;
+                    *count
+                }
+                None => {
+                    counters.elements.push((name.to_vec(), 1));
+                    1
+                }
+            };
+            format!("{parent_path}/{}[{position}]", node.tag_name())
+        } else {
+            counters.texts += 1;
+            format!("{parent_path}/text()[{}]", counters.texts)
+        };
+        Child { node, path }
+    }
+
+    /// Returns true if the two nodes can be paired: two elements with the same name, or two
+    /// text nodes.
+    fn matches(&self, other: &Child) -> bool {
+        if self.node.is_element() {
+            other.node.is_element() && self.node.tag_name_bytes() == other.node.tag_name_bytes()
+        } else {
+            !other.node.is_element()
+        }
+    }
+}
+
+struct Differ<'o> {
+    options: &'o DiffOptions,
+    differences: Vec<Difference>,
+}
+
+impl Differ<'_> {
+    fn added(&mut self, child: &Child) {
+        self.differences.push(Difference::Added {
+            path: child.path.clone(),
+        });
+    }
+
+    fn removed(&mut self, child: &Child) {
+        self.differences.push(Difference::Removed {
+            path: child.path.clone(),
+        });
+    }
+
+    /// Returns the text of a text node, normalized if selected by the options.
+    fn text(&self, node: &Node) -> String {
+        let text = node.text().unwrap_or_default();
+        if self.options.normalize_whitespace {
+            text.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Returns the compared children of an element: the elements and text nodes, without the
+    /// whitespace only text nodes when the whitespace is normalized.
+    fn children<'xml>(&self, element: &Child<'xml>) -> Vec<Child<'xml>> {
+        let mut counters = Counters::default();
+        element
+            .node
+            .children()
+            .filter(|node| node.is_element() || node.is_text())
+            .map(|node| Child::new(node, &element.path, &mut counters))
+            .filter(|child| is_compared(&child.node, self.options))
+            .collect()
+    }
+
+    /// Compares two nodes paired by the alignment, or by position.
+    fn compare_pair(&mut self, a: &Child, b: &Child) {
+        match (a.node.is_element(), b.node.is_element()) {
+            (true, true) => {
+                if a.node.tag_name_bytes() != b.node.tag_name_bytes() {
+                    self.differences.push(Difference::Renamed {
+                        path: a.path.clone(),
+                        from: a.node.tag_name().to_string(),
+                        to: b.node.tag_name().to_string(),
+                    });
+                }
+                self.compare_attributes(a, b);
+                let a_children = self.children(a);
+                let b_children = self.children(b);
+                self.compare_children(&a_children, &b_children);
+            }
+            (false, false) => {
+                let (from, to) = (self.text(&a.node), self.text(&b.node));
+                if from != to {
+                    self.differences.push(Difference::TextChanged {
+                        path: a.path.clone(),
+                        from,
+                        to,
+                    });
+                }
+            }
+            _ => {
+                self.removed(a);
+                self.added(b);
+            }
+        }
+    }
+
+    /// Compares the attributes of two elements, whatever their order.
+    fn compare_attributes(&mut self, a: &Child, b: &Child) {
+        if self.options.attribute_order {
+            self.compare_ordered_attributes(a, b);
+            return;
+        }
+
+        for attr in a.node.attributes() {
+            match b.node.find_attribute(attr.name_bytes()) {
+                None => self.differences.push(Difference::AttributeRemoved {
+                    path: a.path.clone(),
+                    name: attr.name().to_string(),
+                    value: attr.value().to_string(),
+                }),
+                Some(other) if other.value_bytes() != attr.value_bytes() => {
+                    self.differences.push(Difference::AttributeChanged {
+                        path: a.path.clone(),
+                        name: attr.name().to_string(),
+                        from: attr.value().to_string(),
+                        to: other.value().to_string(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        for attr in b.node.attributes() {
+            if a.node.find_attribute(attr.name_bytes()).is_none() {
+                self.differences.push(Difference::AttributeAdded {
+                    path: a.path.clone(),
+                    name: attr.name().to_string(),
+                    value: attr.value().to_string(),
+                });
+            }
+        }
+    }
+
+    /// Compares the attributes of two elements, by position.
+    fn compare_ordered_attributes(&mut self, a: &Child, b: &Child) {
+        let mut a_attrs = a.node.attributes();
+        let mut b_attrs = b.node.attributes();
+        loop {
+            match (a_attrs.next(), b_attrs.next()) {
+                (Some(attr), Some(other)) if attr.name_bytes() == other.name_bytes() => {
+                    if attr.value_bytes() != other.value_bytes() {
+                        self.differences.push(Difference::AttributeChanged {
+                            path: a.path.clone(),
+                            name: attr.name().to_string(),
+                            from: attr.value().to_string(),
+                            to: other.value().to_string(),
+                        });
+                    }
+                }
+                (removed, added) => {
+                    if removed.is_none() && added.is_none() {
+                        break;
+                    }
+                    if let Some(attr) = removed {
+                        self.differences.push(Difference::AttributeRemoved {
+                            path: a.path.clone(),
+                            name: attr.name().to_string(),
+                            value: attr.value().to_string(),
+                        });
+                    }
+                    if let Some(attr) = added {
+                        self.differences.push(Difference::AttributeAdded {
+                            path: a.path.clone(),
+                            name: attr.name().to_string(),
+                            value: attr.value().to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Compares two lists of children, aligned on their longest common subsequence.
+    fn compare_children(&mut self, a: &[Child], b: &[Child]) {
+        let mut a_pos = 0;
+        let mut b_pos = 0;
+        for (a_idx, b_idx) in align(a, b) {
+            self.compare_gap(&a[a_pos..a_idx], &b[b_pos..b_idx]);
+            self.compare_pair(&a[a_idx], &b[b_idx]);
+            a_pos = a_idx + 1;
+            b_pos = b_idx + 1;
+        }
+        self.compare_gap(&a[a_pos..], &b[b_pos..]);
+    }
+
+    /// Compares the children located between two aligned pairs. The elements are paired by
+    /// position, as renamed elements, the others are removed or added.
+    fn compare_gap(&mut self, a: &[Child], b: &[Child]) {
+        let mut b_pos = 0;
+        for a_child in a {
+            let pair = b[b_pos..]
+                .iter()
+                .position(|b_child| a_child.node.is_element() && b_child.node.is_element());
+            match pair {
+                Some(offset) => {
+                    for b_child in &b[b_pos..b_pos + offset] {
+                        self.added(b_child);
+                    }
+                    self.compare_pair(a_child, &b[b_pos + offset]);
+                    b_pos += offset + 1;
+                }
+                None => self.removed(a_child),
+            }
+        }
+        for b_child in &b[b_pos..] {
+            self.added(b_child);
+        }
+    }
+}
+
+/// Returns the index pairs of the longest common subsequence of two lists of children,
+/// after removing their common prefix and suffix. Above `MAX_ALIGNMENT_SIZE`, the remaining
+/// children are not aligned.
+fn align(a: &[Child], b: &[Child]) -> Vec<(usize, usize)> {
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take_while(|(a_child, b_child)| a_child.matches(b_child))
+        .count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(a_child, b_child)| a_child.matches(b_child))
+        .count();
+
+    let mut pairs: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+    let (n, m) = (a_mid.len(), b_mid.len());
+    if n > 0 && m > 0 && n * m <= MAX_ALIGNMENT_SIZE {
+        // lengths[i][j]: length of the common subsequence of a_mid[i..] and b_mid[j..]
+        let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lengths[i * (m + 1) + j] = if a_mid[i].matches(&b_mid[j]) {
+                    lengths[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if a_mid[i].matches(&b_mid[j]) {
+                pairs.push((prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+            } else if lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+    }
+
+    pairs.extend((0..suffix).map(|i| (a.len() - suffix + i, b.len() - suffix + i)));
+    pairs
+}
@@ -7,33 +7,99 @@
 use log::{debug, warn};
 
 use memchr::memchr_iter;
+use std::collections::HashMap;
 use std::fmt::{self};
-
-use crate::attribute::AttributeInfo;
-use crate::defs::{AttrIdx, NodeIdx, ParseXmlError, XmlIdx, XmlLocation};
-use crate::node::Node;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::Read;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::attribute::{Attribute, AttributeInfo};
+use crate::defs::{
+    AttrIdx, AttributeRange, Diagnostic, DomVec, NodeIdx, ParseXmlError, Violation, XmlDeclaration,
+    XmlIdx, XmlLocation,
+};
+use crate::encoding;
+use crate::node::{Node, NodeId};
 use crate::node_info::NodeInfo;
 use crate::node_type::NodeType;
+use crate::parser::ParserOptions;
+use crate::serializer::SelfClosing;
+use crate::walk::{Visitor, WalkAction};
+use crate::xml_buffer::XmlBuffer;
 
 #[cfg(feature = "use_cstr")]
 use std::ffi::CStr;
 
+#[cfg(feature = "allocator_api")]
+use crate::defs::DomAllocator;
+#[cfg(feature = "allocator_api")]
+use std::alloc::Global;
+
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Minimum number of attributes of an element for them to be indexed by name, under the
+/// `index_attributes` parsing option. Smaller elements are searched sequentially.
+pub(crate) const INDEXED_ATTRIBUTES_MIN: usize = 9;
+
+/// The tag name of the synthetic root element of the documents created by
+/// `Document::parse_fragment()`.
+pub const FRAGMENT_ROOT_NAME: &str = "fragment";
+
 /// Represents a parsed XML document.
 ///
 /// The `Document` struct contains a vector of `NodeInfo` representing the nodes in the document,
 /// a vector of `AttributeInfo` representing the attributes, and the raw XML content as a byte vector.
 /// It provides methods to create a new document from XML content, retrieve the root node,
 /// get nodes by index, add new nodes and attributes, and access the XML content.
+///
+/// A `Document` is `Send` and `Sync`: it can be moved to another thread, or read from several
+/// threads at once. Use `NodeId` handles to keep references to its nodes independent of
+/// any borrow.
 
 #[derive(PartialEq, Eq)]
 #[must_use]
-pub struct Document {
-    pub nodes: Vec<NodeInfo>,
-    pub attributes: Vec<AttributeInfo>,
-    pub xml: Vec<u8>,
+pub struct Document<'a> {
+    pub nodes: DomVec<'a, NodeInfo>,
+    pub attributes: DomVec<'a, AttributeInfo>,
+    pub xml: XmlBuffer<'a>,
+    pub(crate) content_len: XmlIdx, // Length of the parsed XML content, followed by the text store
+    pub(crate) prolog_comments: Vec<XmlLocation>,
+    pub(crate) prolog_pis: Vec<(XmlLocation, XmlLocation)>, // Target and data locations
+    pub(crate) declaration: Option<XmlDeclaration>,
+    pub(crate) entity_declarations: HashMap<String, String>, // Entities of the internal DTD subset
+    pub(crate) cdata_nodes: Vec<NodeIdx>, // Text nodes coming from CDATA sections, in order
+    pub(crate) raw_texts: Vec<(NodeIdx, Range<usize>)>, // Original content of modified text nodes, in order
+    pub(crate) raw_text_buffer: Vec<u8>, // Storage of the original content of the text nodes
+    pub(crate) element_spans: Vec<(NodeIdx, ElementSpan)>, // Source spans of the elements, in order
+    detached_from: Option<NodeIdx>,      // Index of the first node created outside of the tree
+    detached_roots: Vec<NodeIdx>, // Created nodes not yet inserted in the tree or another node
+    pub(crate) had_trailing_content: bool,
+    pub(crate) entity_expansions: usize, // Number of entities expanded during parsing
+    pub(crate) entity_expansion_size: usize, // Number of bytes produced by the expansions
+    line_offsets: OnceLock<Vec<XmlIdx>>,
+    languages: OnceLock<AttributeScopes>, // Scopes of the xml:lang attributes
+    bases: OnceLock<AttributeScopes>,     // Scopes of the xml:base attributes
+    ids: OnceLock<HashMap<String, NodeIdx>>, // Elements by id attribute value
+    attribute_order: OnceLock<Vec<AttrIdx>>, // Attributes sorted by name, per element
+    pub(crate) diagnostics: Option<Vec<Diagnostic>>, // Problems recorded when recovering from errors
+    pub(crate) options: ParserOptions,
+    pub(crate) self_closing: SelfClosing, // Form of the serialized elements without children
+    #[cfg(feature = "namespace_aware")]
+    pub(crate) namespaces: Vec<(NodeIdx, XmlLocation)>, // Namespace URI of elements, by node index
 }
 
-impl Document {
+// A document can be sent to, and shared between, threads
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Document<'_>>();
+    assert_send_sync::<Node<'_>>();
+    assert_send_sync::<NodeId>();
+};
+
+impl<'a> Document<'a> {
     /// Creates a new `Document` from the provided XML content.
     ///
     /// # Arguments
@@ -71,11 +137,512 @@ impl Document {
     /// - The `Document` struct is designed to handle XML documents and provides methods for navigating the document tree.
     /// - The `new` method estimates the number of nodes and attributes based on the XML content and allocates memory accordingly.
     ///   This is done to optimize performance and reduce memory reallocations during parsing.
+    /// - The parsing behaviors are the ones selected by the enabled cargo features. Use
+    ///   `Document::new_with_options()` to select them at runtime.
+    /// - The encoding of the content is detected from its byte order mark and XML declaration.
+    ///   UTF-16 content is transcoded to UTF-8 before parsing (see the `encoding` module).
+    #[inline]
     pub fn new(xml: Vec<u8>) -> Result<Self, ParseXmlError> {
-        let mut node_count = memchr_iter(b'<', xml.as_slice()).count();
-        let attr_count = memchr_iter(b'=', xml.as_slice()).count();
+        Self::new_with_options(xml, ParserOptions::default())
+    }
+
+    /// Creates a new `Document` from the provided XML content, using the given parsing options.
+    ///
+    /// The options select at runtime the behaviors otherwise chosen through the `namespace_removal`,
+    /// `parse_escapes`, `trim_pcdata` and `keep_ws_only_pcdata` cargo features, which only
+    /// supply the default values of `ParserOptions`.
+    ///
+    /// # Arguments
+    /// - `xml`: A byte vector containing the XML content to be parsed. the Document instance becomes the owner of the XML content
+    /// - `options`: The parsing options to use
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing, such as invalid XML or insufficient memory.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    /// - `ParseXmlError::EntityLimitExceeded`: If the entity expansion limits of the options are exceeded.
+    /// - `ParseXmlError::LimitExceeded`: If the size, node or attribute limits of the options are exceeded.
+    /// - `ParseXmlError::InvalidEncoding`: If the content is not in a supported encoding (see the `encoding` module).
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><a>1</a> <b>2</b></root>".to_vec();
+    /// let options = ParserOptions::new().keep_ws_only_pcdata(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().children().count(), 3);
+    /// assert!(document.options().has_keep_ws_only_pcdata());
+    /// ```
+    #[inline]
+    pub fn new_with_options(xml: Vec<u8>, options: ParserOptions) -> Result<Self, ParseXmlError> {
+        Self::from_buffer(encoding::to_utf8(xml.into(), &options)?, options, false)
+    }
+
+    /// Creates a new `Document` from the provided XML content, recovering from malformed constructs.
+    ///
+    /// Instead of aborting on the first problem, the parser records each one as a `Diagnostic`
+    /// and resynchronizes: stray `<` characters and malformed attributes are skipped, a
+    /// closing tag matching an enclosing element implicitly closes the elements opened since,
+    /// other mismatched closing tags are ignored, and the elements still open at the end
+    /// of the content are closed. The returned document holds everything parsed up to that
+    /// point.
+    ///
+    /// # Arguments
+    /// - `xml`: A byte vector containing the XML content to be parsed. the Document instance becomes the owner of the XML content
+    /// - `options`: The parsing options to use
+    ///
+    /// # Returns
+    /// - `Ok((Document, Vec<Diagnostic>))`: The partial document and the problems found, in order. The
+    ///   diagnostics list is empty if the XML content is well-formed.
+    /// - `Err(ParseXmlError)`: If the document cannot be created at all.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is too large for the selected XML size feature.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::defs::DiagnosticKind;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><a>1 < 2</b><c x=1 y='2'/></root>".to_vec();
+    /// let (document, diagnostics) =
+    ///     Document::new_recovering(xml_data, ParserOptions::default()).unwrap();
+    ///
+    /// let kinds: Vec<_> = diagnostics.iter().map(|d| d.kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         DiagnosticKind::InvalidTagName,
+    ///         DiagnosticKind::MismatchedClosingTag,
+    ///         DiagnosticKind::InvalidAttribute,
+    ///         DiagnosticKind::MismatchedClosingTag,
+    ///     ]
+    /// );
+    ///
+    /// let c = document.root().unwrap().descendants().find(|n| n.is("c")).unwrap();
+    /// assert_eq!(c.get_attribute("y"), Some("2"));
+    /// ```
+    pub fn new_recovering(
+        xml: Vec<u8>,
+        options: ParserOptions,
+    ) -> Result<(Self, Vec<Diagnostic>), ParseXmlError> {
+        let mut document =
+            Self::from_buffer(encoding::to_utf8(xml.into(), &options)?, options, true)?;
+        let diagnostics = document.diagnostics.take().unwrap_or_default();
+        Ok((document, diagnostics))
+    }
+
+    /// Creates a new `Document` from a fragment of XML content, which may hold several
+    /// top-level elements and text nodes instead of a single root element.
+    ///
+    /// The top-level nodes become the children of a synthetic root element named
+    /// `FRAGMENT_ROOT_NAME` (`fragment`), so the whole content is parsed, as for the snippets
+    /// produced by templating systems. The synthetic root element is not part of the content:
+    /// the positions of the nodes are the ones of the given content, and its span, with the
+    /// `keep_element_spans` option, covers the whole content.
+    ///
+    /// # Arguments
+    /// - `xml`: A byte vector containing the XML fragment to be parsed. the Document instance becomes the owner of the XML content
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the fragment is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing.
+    ///
+    /// # Errors
+    /// The same errors as `Document::new_with_options()`. An element left open in the
+    /// fragment is reported as not matching the end of the synthetic root element.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::parse_fragment(b"<p>a</p><p>b</p>text".to_vec()).unwrap();
+    /// let fragment = document.root().unwrap();
+    ///
+    /// assert_eq!(fragment.tag_name(), "fragment");
+    /// assert_eq!(fragment.children().count(), 3);
+    /// assert_eq!(fragment.text_content(), "abtext");
+    /// assert_eq!(fragment.last_child().unwrap().position(), 16);
+    /// ```
+    #[inline]
+    pub fn parse_fragment(xml: Vec<u8>) -> Result<Self, ParseXmlError> {
+        Self::parse_fragment_with_options(xml, ParserOptions::default())
+    }
+
+    /// Creates a new `Document` from a fragment of XML content, as `parse_fragment()`, using
+    /// the given parsing options.
+    ///
+    /// # Arguments
+    /// - `xml`: A byte vector containing the XML fragment to be parsed. the Document instance becomes the owner of the XML content
+    /// - `options`: The parsing options to use
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the fragment is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing.
+    ///
+    /// # Errors
+    /// The same errors as `Document::new_with_options()`.
+    pub fn parse_fragment_with_options(
+        xml: Vec<u8>,
+        options: ParserOptions,
+    ) -> Result<Self, ParseXmlError> {
+        let mut xml = encoding::to_utf8(xml.into(), &options)?;
+        let content_len = xml.len() as XmlIdx;
+        xml.extend_from_slice(b"</");
+        xml.extend_from_slice(FRAGMENT_ROOT_NAME.as_bytes());
+        xml.push(b'>');
+
+        let (node_capacity, attr_capacity) = Self::estimate_capacities(&xml, &options)?;
+        let doc = Document::with_buffer(xml, node_capacity, attr_capacity);
+        let mut doc = doc.parse_buffer(options, false, true, node_capacity, attr_capacity)?;
+
+        // The span of the synthetic root element does not include its end tag
+        if let Some((_, span)) = doc.element_spans.first_mut() {
+            span.outer.end = content_len;
+        }
+        Ok(doc)
+    }
+
+    /// Creates a new `Document` from a string containing the XML content.
+    ///
+    /// As the content is known to be valid UTF-8, and the in-place processing of the parser
+    /// keeps it valid, the string accessors (`tag_name()`, `text()`, `Attribute::value()`, etc.)
+    /// never return the "non valid utf-8" replacement string for such a document.
+    ///
+    /// # Arguments
+    /// - `xml`: A string containing the XML content to be parsed. the Document instance becomes the owner of the XML content
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing, such as invalid XML or insufficient memory.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::from_string(String::from("<root>Café, thé</root>")).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().text(), Some("Café, thé"));
+    ///
+    /// // `&str` content is parsed through the `FromStr` trait
+    /// let document: Document = "<root><child/></root>".parse().unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().tag_name(), "child");
+    /// ```
+    #[inline]
+    pub fn from_string(xml: String) -> Result<Self, ParseXmlError> {
+        // The content is already UTF-8, whatever its XML declaration says
+        Self::from_buffer(xml.into_bytes().into(), ParserOptions::default(), false)
+    }
+
+    /// Creates a new `Document` from the XML content read from the given source.
+    ///
+    /// The whole content is read before being parsed, as the parser works in place.
+    ///
+    /// # Arguments
+    /// - `reader`: The source of the XML content (file, network stream, archive entry, etc.)
+    /// - `size_hint`: The expected size of the XML content in bytes, if known, used to allocate
+    ///   the buffer once. The content may be shorter or longer.
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully read and parsed.
+    /// - `Err(ParseXmlError)`: If there is an error during reading or parsing.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::Io`: If the reader returns an error.
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed, contains errors, or is too large.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the buffer, or the document's nodes or attributes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use xhtml_parser::Document;
+    ///
+    /// let source = Cursor::new(b"<root><child>Text</child></root>");
+    /// let document = Document::from_reader(source, Some(32)).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().tag_name(), "child");
+    /// ```
+    pub fn from_reader<R: Read>(
+        reader: R,
+        size_hint: Option<usize>,
+    ) -> Result<Self, ParseXmlError> {
+        let mut xml = Vec::new();
+
+        if let Some(size) = size_hint {
+            if size > XmlIdx::MAX as usize {
+                return Err(ParseXmlError::InvalidXml(
+                    "XML document is too large!".to_string(),
+                ));
+            }
+            xml.try_reserve_exact(size)
+                .map_err(|_| ParseXmlError::NotEnoughMemory)?;
+        }
+
+        // Reading one byte past the maximum size is enough to report a too large document
+        let limit = (XmlIdx::MAX as usize).saturating_add(1);
+        reader
+            .take(limit as u64)
+            .read_to_end(&mut xml)
+            .map_err(ParseXmlError::Io)?;
+
+        Self::new(xml)
+    }
+
+    /// Creates a new `Document` from the XML content read from the given asynchronous source.
+    ///
+    /// The content is accumulated asynchronously, then parsed in place as with `Document::new()`.
+    /// The parsing itself is done synchronously, as it works on the complete in-memory buffer.
+    ///
+    /// # Arguments
+    /// - `reader`: The asynchronous source of the XML content (network stream, file, etc.)
+    /// - `size_hint`: The expected size of the XML content in bytes, if known, used to allocate
+    ///   the buffer once. The content may be shorter or longer.
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully read and parsed.
+    /// - `Err(ParseXmlError)`: If there is an error during reading or parsing.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::Io`: If the reader returns an error.
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed, contains errors, or is too large.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the buffer, or the document's nodes or attributes.
+    ///
+    /// # Note
+    /// This method is only available when the `tokio` feature is enabled
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let source: &[u8] = b"<root><child>Text</child></root>";
+    /// let document = Document::from_async_reader(source, None).await.unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().tag_name(), "child");
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(
+        reader: R,
+        size_hint: Option<usize>,
+    ) -> Result<Self, ParseXmlError> {
+        let mut xml = Vec::new();
+
+        if let Some(size) = size_hint {
+            if size > XmlIdx::MAX as usize {
+                return Err(ParseXmlError::InvalidXml(
+                    "XML document is too large!".to_string(),
+                ));
+            }
+            xml.try_reserve_exact(size)
+                .map_err(|_| ParseXmlError::NotEnoughMemory)?;
+        }
+
+        // Reading one byte past the maximum size is enough to report a too large document
+        let limit = (XmlIdx::MAX as usize).saturating_add(1);
+        reader
+            .take(limit as u64)
+            .read_to_end(&mut xml)
+            .await
+            .map_err(ParseXmlError::Io)?;
+
+        Self::new(xml)
+    }
+
+    /// Creates a new `Document` parsing in place the XML content of a borrowed buffer.
+    ///
+    /// No copy of the XML content is made: the document keeps a mutable borrow of the buffer,
+    /// which is modified by the parsing as with `Document::new()`. This suits content located
+    /// in a memory-mapped file or an arena. Operations that need to grow the content, such as
+    /// `set_text()` with a longer text, first copy it into a vector owned by the document.
+    ///
+    /// # Arguments
+    /// - `xml`: A mutable byte slice containing the XML content to be parsed.
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing, such as invalid XML or insufficient memory.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut buffer = *b"<root><child>Fish and Chips</child></root>";
+    /// let document = Document::new_borrowed(&mut buffer).unwrap();
+    ///
+    /// let text = document.root().unwrap().first_child().unwrap().first_child().unwrap();
+    ///
+    /// assert!(document.xml.is_borrowed());
+    /// assert_eq!(text.text(), Some("Fish and Chips"));
+    /// ```
+    #[inline]
+    pub fn new_borrowed(xml: &'a mut [u8]) -> Result<Self, ParseXmlError> {
+        let options = ParserOptions::default();
+        Self::from_buffer(encoding::to_utf8(xml.into(), &options)?, options, false)
+    }
+
+    /// Creates a new `Document` parsing in place the XML content of a borrowed buffer, using
+    /// the given parsing options.
+    ///
+    /// See `Document::new_borrowed()` and `Document::new_with_options()`.
+    ///
+    /// # Arguments
+    /// - `xml`: A mutable byte slice containing the XML content to be parsed.
+    /// - `options`: The parsing options to use
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing, such as invalid XML or insufficient memory.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    #[inline]
+    pub fn new_borrowed_with_options(
+        xml: &'a mut [u8],
+        options: ParserOptions,
+    ) -> Result<Self, ParseXmlError> {
+        Self::from_buffer(encoding::to_utf8(xml.into(), &options)?, options, false)
+    }
+
+    /// Creates a new `Document` parsing in place the XML content of a borrowed buffer, with
+    /// the nodes and attributes vectors allocated by the given allocator.
+    ///
+    /// This permits placing the whole document in a dedicated memory region (a separate heap,
+    /// external RAM, etc.): the XML buffer is supplied by the caller, and the vectors use the
+    /// allocator. UTF-16 and legacy encoded content is still transcoded into a buffer of the
+    /// global allocator (see the `encoding` module).
+    ///
+    /// The allocator must be `Sync`, as the document can be shared between threads. Requires
+    /// the `allocator_api` feature, and a nightly compiler.
+    ///
+    /// # Arguments
+    /// - `xml`: A mutable byte slice containing the XML content to be parsed.
+    /// - `options`: The parsing options to use
+    /// - `allocator`: The allocator of the nodes and attributes vectors
+    ///
+    /// # Returns
+    /// - `Ok(Document)`: If the XML content is successfully parsed and a document is created.
+    /// - `Err(ParseXmlError)`: If there is an error during parsing, such as invalid XML or insufficient memory.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::NoMoreSpace`: If there is not enough space to add new nodes or attributes.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to allocate the document's nodes or attributes.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use std::alloc::{AllocError, Allocator, Global, Layout};
+    /// use std::ptr::NonNull;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// // Counts the bytes allocated through it
+    /// struct Counting(AtomicUsize);
+    ///
+    /// unsafe impl Allocator for Counting {
+    ///     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+    ///         self.0.fetch_add(layout.size(), Ordering::Relaxed);
+    ///         Global.allocate(layout)
+    ///     }
+    ///
+    ///     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    ///         unsafe { Global.deallocate(ptr, layout) }
+    ///     }
+    /// }
+    ///
+    /// let allocator = Counting(AtomicUsize::new(0));
+    /// let mut xml_data = b"<root><child a=\"1\">Text</child></root>".to_vec();
+    /// let document = Document::new_in(&mut xml_data, ParserOptions::default(), &allocator).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().get_attribute("a"), Some("1"));
+    /// assert!(allocator.0.load(Ordering::Relaxed) > 0);
+    /// ```
+    #[cfg(feature = "allocator_api")]
+    pub fn new_in(
+        xml: &'a mut [u8],
+        options: ParserOptions,
+        allocator: DomAllocator<'a>,
+    ) -> Result<Self, ParseXmlError> {
+        let xml = encoding::to_utf8(xml.into(), &options)?;
+        let (node_capacity, attr_capacity) = Self::estimate_capacities(&xml, &options)?;
+
+        let mut doc = Document::with_buffer(xml, 0, 0);
+        doc.nodes = Vec::with_capacity_in(node_capacity, allocator);
+        doc.attributes = Vec::with_capacity_in(attr_capacity, allocator);
+        doc.parse_buffer(options, false, false, node_capacity, attr_capacity)
+    }
+
+    /// Parses the XML content of the buffer into a new `Document`.
+    ///
+    /// # Arguments
+    /// - `xml`: The XML content, owned or borrowed.
+    /// - `options`: The parsing options to use
+    /// - `recovering`: Whether malformed constructs are recorded as diagnostics instead of
+    ///   aborting the parsing
+    fn from_buffer(
+        xml: XmlBuffer<'a>,
+        options: ParserOptions,
+        recovering: bool,
+    ) -> Result<Self, ParseXmlError> {
+        let (node_capacity, attr_capacity) = Self::estimate_capacities(&xml, &options)?;
+        let doc = Document::with_buffer(xml, node_capacity, attr_capacity);
+        doc.parse_buffer(options, recovering, false, node_capacity, attr_capacity)
+    }
+
+    /// Estimates the capacities of the nodes and attributes vectors required to parse the
+    /// XML content.
+    ///
+    /// # Arguments
+    /// - `xml`: The XML content.
+    /// - `options`: The parsing options to use
+    ///
+    /// # Returns
+    /// The estimated node and attribute capacities.
+    fn estimate_capacities(
+        xml: &[u8],
+        options: &ParserOptions,
+    ) -> Result<(usize, usize), ParseXmlError> {
+        options.check_input_len(xml.len())?;
+
+        let mut node_count = memchr_iter(b'<', xml).count();
+        let mut attr_count = memchr_iter(b'=', xml).count();
         node_count += (node_count / 10) + 1; // Add 10% buffer for nodes
 
+        // The runtime limits bound the reserved memory
+        if let Some(max) = options.max_nodes {
+            node_count = node_count.min(max);
+        }
+        if let Some(max) = options.max_attributes {
+            attr_count = attr_count.min(max);
+        }
+
         debug!("Estimated node count: {node_count}");
         debug!("Estimated attribute count: {attr_count}");
 
@@ -97,80 +664,257 @@ impl Document {
             ));
         }
 
-        let mut doc = Document {
-            nodes: Vec::with_capacity(node_count + 1), // +1 for root node
-            attributes: Vec::with_capacity(attr_count),
-            xml,
-        };
-        if doc.nodes.capacity() <= node_count || doc.attributes.capacity() < attr_count {
+        Ok((node_count + 1, attr_count)) // +1 for root node
+    }
+
+    /// Parses the XML buffer of a document created with `with_buffer()`.
+    ///
+    /// # Arguments
+    /// - `options`: The parsing options to use
+    /// - `recovering`: Whether malformed constructs are recorded as diagnostics instead of
+    ///   aborting the parsing
+    /// - `fragment`: Whether the content is parsed as a fragment, being followed by the end
+    ///   tag of the synthetic root element
+    /// - `node_capacity`: The required capacity of the nodes vector.
+    /// - `attr_capacity`: The required capacity of the attributes vector.
+    fn parse_buffer(
+        mut self,
+        options: ParserOptions,
+        recovering: bool,
+        fragment: bool,
+        node_capacity: usize,
+        attr_capacity: usize,
+    ) -> Result<Self, ParseXmlError> {
+        self.options = options;
+        if recovering {
+            self.diagnostics = Some(Vec::new());
+        }
+        if self.nodes.capacity() < node_capacity || self.attributes.capacity() < attr_capacity {
             return Err(ParseXmlError::NotEnoughMemory);
         }
 
         // Add the head node as the first node in the document.
         #[cfg(not(feature = "forward_only"))]
-        doc.nodes.push(NodeInfo::new(0, 0, NodeType::Head));
+        self.nodes.push(NodeInfo::new(0, 0, NodeType::Head));
         #[cfg(feature = "forward_only")]
-        doc.nodes.push(NodeInfo::new(NodeType::Head));
+        self.nodes.push(NodeInfo::new(NodeType::Head));
 
-        doc.parse()?;
-        doc.nodes.shrink_to_fit();
-        doc.attributes.shrink_to_fit();
+        self.parse_content(fragment)?;
+        self.nodes.shrink_to_fit();
+        self.attributes.shrink_to_fit();
 
         warn!(
             "Document created with {} nodes and {} attributes",
-            doc.nodes.len(),
-            doc.attributes.len()
+            self.nodes.len(),
+            self.attributes.len()
         );
 
         warn!(
             "Warning: Expected {} nodes, but found {}",
-            node_count,
-            doc.nodes.len()
+            node_capacity - 1,
+            self.nodes.len()
         );
 
-        if attr_count < doc.attributes.len() {
+        if attr_capacity < self.attributes.len() {
             warn!(
                 "Expected {} attributes, but found {}",
-                attr_count,
-                doc.attributes.len()
+                attr_capacity,
+                self.attributes.len()
             );
         }
 
-        Ok(doc)
+        Ok(self)
     }
 
-    /// Returns the root node of the document.
-    #[inline]
-    #[must_use]
-    pub fn root(&self) -> Option<Node<'_>> {
-        #[cfg(not(feature = "forward_only"))]
-        if self.nodes.len() > 1 {
-            Some(Node::new(1, &self.nodes[1], self))
-        } else {
-            None // No nodes in the document
-        }
-
-        #[cfg(feature = "forward_only")]
-        if self.nodes.len() > 1 {
-            Some(Node::new(1, 0, &self.nodes[1], self))
-        } else {
-            None // No nodes in the document
+    /// Creates a document holding the XML buffer, without parsing it.
+    ///
+    /// # Arguments
+    /// - `xml`: The XML content.
+    /// - `node_capacity`: The initial capacity of the nodes vector.
+    /// - `attr_capacity`: The initial capacity of the attributes vector.
+    pub(crate) fn with_buffer(
+        xml: XmlBuffer<'a>,
+        node_capacity: usize,
+        attr_capacity: usize,
+    ) -> Self {
+        Document {
+            #[cfg(not(feature = "allocator_api"))]
+            nodes: Vec::with_capacity(node_capacity),
+            #[cfg(not(feature = "allocator_api"))]
+            attributes: Vec::with_capacity(attr_capacity),
+            #[cfg(feature = "allocator_api")]
+            nodes: Vec::with_capacity_in(node_capacity, &Global),
+            #[cfg(feature = "allocator_api")]
+            attributes: Vec::with_capacity_in(attr_capacity, &Global),
+            content_len: xml.len() as XmlIdx,
+            xml,
+            prolog_comments: Vec::new(),
+            prolog_pis: Vec::new(),
+            declaration: None,
+            entity_declarations: HashMap::new(),
+            cdata_nodes: Vec::new(),
+            raw_texts: Vec::new(),
+            element_spans: Vec::new(),
+            raw_text_buffer: Vec::new(),
+            detached_from: None,
+            detached_roots: Vec::new(),
+            had_trailing_content: false,
+            entity_expansions: 0,
+            entity_expansion_size: 0,
+            line_offsets: OnceLock::new(),
+            languages: OnceLock::new(),
+            bases: OnceLock::new(),
+            ids: OnceLock::new(),
+            attribute_order: OnceLock::new(),
+            diagnostics: None,
+            options: ParserOptions::default(),
+            self_closing: SelfClosing::Always,
+            #[cfg(feature = "namespace_aware")]
+            namespaces: Vec::new(),
         }
     }
 
-    /// Checks if the document is empty.
-    ///
-    /// # Returns
-    /// - `true`: If the document contains only the head node (no other nodes).
-    /// - `false`: If the document contains nodes other than the head node.
+    /// Returns the parsing options used to create the document.
     #[inline]
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.nodes.len() <= 1 // Only the head node exists
+    pub fn options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    /// Removes all nodes and attributes of the document, and releases its XML content.
+    ///
+    /// The allocated capacity of the nodes and attributes vectors is kept, for the document to
+    /// be filled again with `reparse()`. The parsing options are kept as well.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<root><child/></root>".to_vec()).unwrap();
+    /// let capacity = document.nodes.capacity();
+    /// document.clear();
+    ///
+    /// assert!(document.root().is_none());
+    /// assert_eq!(document.nodes.capacity(), capacity);
+    /// ```
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        #[cfg(not(feature = "forward_only"))]
+        self.nodes.push(NodeInfo::new(0, 0, NodeType::Head));
+        #[cfg(feature = "forward_only")]
+        self.nodes.push(NodeInfo::new(NodeType::Head));
+
+        self.attributes.clear();
+        self.xml = XmlBuffer::Owned(Vec::new());
+        self.content_len = 0;
+        self.prolog_comments.clear();
+        self.prolog_pis.clear();
+        self.declaration = None;
+        self.entity_declarations.clear();
+        self.cdata_nodes.clear();
+        self.raw_texts.clear();
+        self.element_spans.clear();
+        self.raw_text_buffer.clear();
+        self.detached_from = None;
+        self.detached_roots.clear();
+        self.had_trailing_content = false;
+        self.entity_expansions = 0;
+        self.entity_expansion_size = 0;
+        self.line_offsets = OnceLock::new();
+        self.languages = OnceLock::new();
+        self.bases = OnceLock::new();
+        self.ids = OnceLock::new();
+        self.attribute_order = OnceLock::new();
+        self.diagnostics = None;
+        #[cfg(feature = "namespace_aware")]
+        self.namespaces.clear();
+    }
+
+    /// Parses new XML content into the document, reusing the allocated capacity of its nodes
+    /// and attributes vectors.
+    ///
+    /// The document is cleared first, and the new content is parsed with the parsing options
+    /// of the document. The vectors only grow when the new content needs more room, and are
+    /// not shrunk after parsing, which avoids reallocating them for each document of a batch.
+    /// If parsing fails, the document is left cleared.
+    ///
+    /// # Arguments
+    /// - `xml`: The new XML content, owned (`Vec<u8>`) or borrowed (`&mut [u8]`).
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or contains errors.
+    /// - `ParseXmlError::InvalidEncoding`: If the content is not in a supported encoding.
+    /// - `ParseXmlError::NotEnoughMemory`: If the vectors cannot grow to hold the new content.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<chapter><p>One</p><p>Two</p></chapter>".to_vec()).unwrap();
+    ///
+    /// for (chapter, first) in [("<chapter><p>Three</p></chapter>", "p"), ("<chapter><h1>Four</h1></chapter>", "h1")] {
+    ///     document.reparse(chapter.as_bytes().to_vec()).unwrap();
+    ///     assert_eq!(document.root().unwrap().first_child().unwrap().tag_name(), first);
+    /// }
+    ///
+    /// assert_eq!(document.to_xml(), "<chapter><h1>Four</h1></chapter>");
+    /// ```
+    pub fn reparse(&mut self, xml: impl Into<XmlBuffer<'a>>) -> Result<(), ParseXmlError> {
+        self.clear();
+
+        let xml = encoding::to_utf8(xml.into(), &self.options)?;
+        let (node_capacity, attr_capacity) = Self::estimate_capacities(&xml, &self.options)?;
+        self.xml = xml;
+
+        // The head node is already there
+        if self.nodes.try_reserve(node_capacity - 1).is_err()
+            || self.attributes.try_reserve(attr_capacity).is_err()
+        {
+            self.clear();
+            return Err(ParseXmlError::NotEnoughMemory);
+        }
+
+        let result = self.parse();
+        if result.is_err() {
+            self.clear();
+        }
+        result
+    }
+
+    /// Returns the root node of the document.
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> Option<Node<'_>> {
+        #[cfg(not(feature = "forward_only"))]
+        if self.nodes.len() > 1 {
+            Some(Node::new(1, &self.nodes[1], self))
+        } else {
+            None // No nodes in the document
+        }
+
+        #[cfg(feature = "forward_only")]
+        if self.nodes.len() > 1 {
+            Some(Node::new(1, 0, &self.nodes[1], self))
+        } else {
+            None // No nodes in the document
+        }
+    }
+
+    /// Checks if the document is empty.
+    ///
+    /// # Returns
+    /// - `true`: If the document contains only the head node (no other nodes).
+    /// - `false`: If the document contains nodes other than the head node.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() <= 1 // Only the head node exists
     }
 
     /// Returns the index of the last node in the document.
     ///
+    /// The nodes created by `create_element()` and `create_text()` and not yet inserted in the
+    /// tree are not part of the document.
+    ///
     /// # Returns
     /// - `NodeIdx`: The index of the last node in the document.
     /// - `0`: If the document is empty (no nodes).
@@ -180,7 +924,10 @@ impl Document {
         if self.is_empty() {
             0 // No nodes, return 0
         } else {
-            (self.nodes.len() - 1) as NodeIdx // Last node index
+            // Last node index, before the detached nodes
+            self.detached_from
+                .map_or(self.nodes.len() as NodeIdx, |detached_from| detached_from)
+                - 1
         }
     }
 
@@ -233,10 +980,171 @@ impl Document {
         Ok(Node::new(node_idx, 0, &self.nodes[node_idx as usize], self))
     }
 
-    /// Returns the XML content of the document as a byte vector.
+    /// Retrieves the node designated by a handle.
+    ///
+    /// # Arguments
+    /// - `id`: The handle of the node, as returned by `Node::id()`.
+    ///
+    /// # Returns
+    /// - `Some(Node)`: The node designated by the handle.
+    /// - `None`: If the handle is out of the bounds of the document.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::{Document, NodeId};
+    /// use std::thread;
+    ///
+    /// let xml_data = b"<root><child>Text</child></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let id: NodeId = document.root().unwrap().first_child().unwrap().id();
+    ///
+    /// let text = thread::scope(|scope| {
+    ///     scope
+    ///         .spawn(|| document.node(id).unwrap().first_child().unwrap().text())
+    ///         .join()
+    ///         .unwrap()
+    /// });
+    ///
+    /// assert_eq!(text, Some("Text"));
+    /// assert!(document.node(NodeId(100)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn node(&self, id: NodeId) -> Option<Node<'_>> {
+        self.get_node(id.0).ok()
+    }
+
+    /// Returns the byte offsets of the start of each line in the XML buffer.
+    ///
+    /// The first entry is always `0`. The offsets are computed on the first call and cached.
+    /// They permit external tools to convert byte positions, such as the ones reported in
+    /// parsing error messages, into line and column numbers.
+    ///
+    /// # Notes
+    /// The parser modifies the XML buffer in place (escape sequences translation, string
+    /// terminators under the `use_cstr` feature). Line feeds located inside translated text
+    /// or replaced by a terminator can then be missing or displaced.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::new(b"<root>\n  <a/>\n</root>".to_vec()).unwrap();
+    ///
+    /// assert_eq!(document.line_offsets(), &[0, 7, 14]);
+    /// ```
+    #[must_use]
+    pub fn line_offsets(&self) -> &[XmlIdx] {
+        self.line_offsets.get_or_init(|| {
+            std::iter::once(0)
+                .chain(memchr_iter(b'\n', &self.xml).map(|pos| (pos + 1) as XmlIdx))
+                .collect()
+        })
+    }
+
+    /// Converts a byte position in the XML buffer into line and column numbers.
+    ///
+    /// Both numbers start at `1`. The column counts the UTF-8 characters preceding the
+    /// position on its line. The line offsets are computed on the first call and cached (see
+    /// `line_offsets()` and its notes on the in-place modifications of the buffer), answering
+    /// the subsequent conversions with a binary search.
+    ///
+    /// # Arguments
+    /// - `pos`: The byte position to convert, such as the one returned by `Node::position()`.
+    ///
+    /// # Returns
+    /// The line and column numbers of the position. A position beyond the end of the XML
+    /// buffer is located after its last character.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::new("<root>\n  <é/>\n  <b/>\n</root>".as_bytes().to_vec()).unwrap();
+    /// let b = document.find_first("b").unwrap();
+    ///
+    /// assert_eq!(document.line_col(0), (1, 1));
+    /// assert_eq!(document.line_col(b.position()), (3, 4));
+    /// ```
+    #[must_use]
+    pub fn line_col(&self, pos: XmlIdx) -> (usize, usize) {
+        let pos = pos.min(self.xml.len() as XmlIdx);
+        let offsets = self.line_offsets();
+        let line = offsets.partition_point(|&offset| offset <= pos);
+        let line_start = offsets[line - 1] as usize;
+
+        (line, column_number(&self.xml[line_start..pos as usize]))
+    }
+
+    /// Returns the index of the element declaring the language (`xml:lang` or `lang` attribute)
+    /// in scope for the node, or `0` if none.
+    ///
+    /// The language scopes are computed on the first call and cached.
+    pub(crate) fn language_declaration(&self, node_idx: NodeIdx) -> NodeIdx {
+        self.languages
+            .get_or_init(|| self.compute_scopes(|node| node.declared_language().is_some()))
+            .declaration(node_idx)
+    }
+
+    /// Returns the scopes of the `xml:base` attributes, computed on the first call and cached.
+    pub(crate) fn base_scopes(&self) -> &AttributeScopes {
+        self.bases
+            .get_or_init(|| self.compute_scopes(|node| node.declared_base().is_some()))
+    }
+
+    /// Walks the tree in document order, recording where the declaration in scope changes.
+    ///
+    /// # Arguments
+    /// * `declares` - Returns true if the element holds a declaration
+    fn compute_scopes(&self, declares: impl Fn(&Node) -> bool) -> AttributeScopes {
+        let mut scopes = AttributeScopes::default();
+
+        if self.nodes.len() <= 1 {
+            return scopes;
+        }
+
+        // For each open element: its next sibling, its declaration and the one of its parent
+        let mut stack: Vec<(NodeIdx, NodeIdx, NodeIdx)> = Vec::new();
+        let mut last_idx: NodeIdx = 1;
+        let mut node_idx: NodeIdx = 1;
+        let mut inherited: NodeIdx = 0;
+
+        loop {
+            if node_idx != 0 {
+                last_idx = node_idx;
+                let info = &self.nodes[node_idx as usize];
+                if info.is_element() {
+                    let decl = if self.get_node(node_idx).is_ok_and(|node| declares(&node)) {
+                        scopes.outer.push((node_idx, inherited));
+                        scopes.push_span(node_idx, node_idx);
+                        node_idx
+                    } else {
+                        inherited
+                    };
+                    stack.push((info.next_sibling_idx(), decl, inherited));
+                    inherited = decl;
+                    node_idx = info.first_child_idx();
+                } else {
+                    node_idx = info.next_sibling_idx();
+                }
+            } else if let Some((next_idx, decl, parent_decl)) = stack.pop() {
+                if decl != parent_decl {
+                    scopes.push_span(last_idx + 1, parent_decl);
+                }
+                inherited = parent_decl;
+                node_idx = next_idx;
+            } else {
+                break;
+            }
+        }
+
+        scopes
+    }
+
+    /// Returns the XML content of the document as a byte slice.
     #[inline]
     #[must_use]
-    pub fn get_xml_content(&mut self) -> &Vec<u8> {
+    pub fn get_xml_content(&mut self) -> &[u8] {
         &self.xml
     }
 
@@ -322,6 +1230,15 @@ impl Document {
             return Err(ParseXmlError::NoMoreSpace);
         }
 
+        // The head node is not counted
+        if let Some(max) = self.options.max_nodes {
+            if self.nodes.len() > max {
+                return Err(ParseXmlError::LimitExceeded(format!(
+                    "Document has more than {max} nodes"
+                )));
+            }
+        }
+
         if let NodeType::Element { attributes, .. } = &mut node_type {
             *attributes = self.attributes.len() as AttrIdx..self.attributes.len() as AttrIdx;
         }
@@ -382,8 +1299,17 @@ impl Document {
         name: XmlLocation,
         value: XmlLocation,
     ) -> Result<AttrIdx, ParseXmlError> {
+        if let Some(max) = self.options.max_attributes {
+            if self.attributes.len() >= max {
+                return Err(ParseXmlError::LimitExceeded(format!(
+                    "Document has more than {max} attributes"
+                )));
+            }
+        }
+
         let attribute_idx = self.attributes.len() as AttrIdx;
         self.attributes.push(AttributeInfo::new(name, value));
+        self.attribute_order = OnceLock::new();
         let node_info = &mut self.nodes[node_idx as usize];
 
         if !node_info.is_element() {
@@ -411,203 +1337,1707 @@ impl Document {
         Ok(attribute_idx)
     }
 
-    /// Retrieves a string slice from the XML content based on the given range.
-    /// # Arguments
-    /// - `range`: A reference to an `XmlLocation` that specifies the start and end indices of the desired substring.
-    /// # Returns
-    /// - `&str`: A string slice containing the XML content from the specified range.
-    #[inline]
-    #[must_use]
-    pub fn get_str_from_location(&self, location: XmlLocation) -> &str {
-        #[cfg(not(feature = "use_cstr"))]
-        {
-            let xml_content = &self.xml[location.start as usize..location.end as usize];
-            std::str::from_utf8(xml_content).unwrap_or("non valid utf-8")
+    /// Retrieves a string slice from the XML content based on the given range.
+    ///
+    /// If the content is not valid UTF-8, the "non valid utf-8" string is returned instead.
+    /// Use `get_bytes_from_location()`, or the `invalid_utf8` parsing option, to get the
+    /// actual content.
+    ///
+    /// # Arguments
+    /// - `range`: A reference to an `XmlLocation` that specifies the start and end indices of the desired substring.
+    /// # Returns
+    /// - `&str`: A string slice containing the XML content from the specified range.
+    #[inline]
+    #[must_use]
+    pub fn get_str_from_location(&self, location: XmlLocation) -> &str {
+        #[cfg(not(feature = "use_cstr"))]
+        {
+            let xml_content = &self.xml[location.start as usize..location.end as usize];
+            std::str::from_utf8(xml_content).unwrap_or("non valid utf-8")
+        }
+
+        #[cfg(feature = "use_cstr")]
+        {
+            let content = std::ffi::CStr::from_bytes_until_nul(&self.xml[location as usize..])
+                .unwrap_or(c"cstr not valid");
+            content.to_str().unwrap_or("non valid utf-8")
+        }
+    }
+
+    /// Retrieves a byte slice from the XML content based on the given location.
+    ///
+    /// Unlike `get_str_from_location()`, the actual content is returned, even if it is not
+    /// valid UTF-8.
+    ///
+    /// # Arguments
+    /// - `location`: An `XmlLocation` that specifies the desired content.
+    ///
+    /// # Returns
+    /// - `&[u8]`: A byte slice containing the XML content at the specified location.
+    #[inline]
+    #[must_use]
+    pub fn get_bytes_from_location(&self, location: XmlLocation) -> &[u8] {
+        #[cfg(not(feature = "use_cstr"))]
+        {
+            &self.xml[location.start as usize..location.end as usize]
+        }
+
+        #[cfg(feature = "use_cstr")]
+        {
+            self.get_cstr_from_location(location).to_bytes()
+        }
+    }
+
+    #[cfg(feature = "use_cstr")]
+    /// Retrieves a CStr from the XML content based on the given location.
+    ///
+    /// # Arguments
+    /// - `location`: An `XmlLocation` that specifies the start index of the CStr in the XML content.
+    ///
+    /// # Returns
+    /// - `&str`: A string slice containing the CStr from the specified location.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn get_cstr_from_location(&self, location: XmlLocation) -> &CStr {
+        CStr::from_bytes_until_nul(&self.xml[location as usize..]).unwrap_or(c"cstr not valid")
+    }
+
+    /// Returns an iterator over all nodes in the document.
+    ///
+    /// This method provides an iterator that traverses all nodes in the document, starting from the root node.
+    ///
+    /// # Returns
+    /// - `Nodes`: An iterator that yields `Node` instances in the order they appear in the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::Node;
+    ///
+    /// let xml_data = b"<root><child>Text</child><last/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let all_nodes: Vec<Node> = document.all_nodes().collect();
+    ///
+    /// assert_eq!(all_nodes.len(), 4); // root, child, Text, totototo
+    #[inline]
+    #[must_use]
+    pub fn all_nodes(&self) -> Nodes<'_> {
+        Nodes::new(self)
+    }
+
+    /// Returns an iterator over the processing instructions of the document, in document
+    /// order, yielding their `(target, data)` pairs.
+    ///
+    /// The processing instructions are only kept in the document when the
+    /// `keep_processing_instructions` parser option is set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><?xml-stylesheet href=\"a.css\"?><?page-break?></root>".to_vec();
+    /// let options = ParserOptions::new().keep_processing_instructions(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let pis: Vec<(&str, &str)> = document.iter_processing_instructions().collect();
+    ///
+    /// assert_eq!(pis, vec![("xml-stylesheet", "href=\"a.css\""), ("page-break", "")]);
+    /// ```
+    pub fn iter_processing_instructions(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.all_nodes()
+            .filter_map(|node| Some((node.pi_target()?, node.pi_data()?)))
+    }
+
+    /// Returns an iterator over the descendants of a given node.
+    ///
+    /// This method provides an iterator that traverses all descendant nodes of the specified node index.
+    ///
+    /// # Arguments
+    /// - `node_idx`: The index of the node whose descendants are to be iterated over.
+    ///
+    /// # Returns
+    /// - `Nodes`: An iterator that yields `Node` instances representing the descendants of the specified node.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::Node;
+    ///
+    /// let xml_data = b"<root><child>Text</child><last/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let descendants: Vec<Node> = document.descendants(root_node.idx()).collect();
+    ///
+    /// assert_eq!(descendants.len(), 3); // child, Text, and last
+    /// assert!(descendants[0].is("child"));
+    /// assert_eq!(descendants[1].text().unwrap(), "Text");
+    /// assert!(descendants[2].is("last"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn descendants(&self, node_idx: NodeIdx) -> Nodes<'_> {
+        Nodes::descendants(self, node_idx)
+    }
+
+    /// Returns the last descendant of a given node index.
+    ///
+    /// This method finds the last descendant node of the specified node index in the document.
+    ///
+    /// # Arguments
+    /// - `node_idx`: The index of the node whose last descendant is to be found.
+    ///
+    /// # Returns
+    /// - `NodeIdx`: The index of the last descendant node.
+    /// - `0`: If the node index is invalid or if there are no descendants for the root node.
+    ///
+    /// # Example
+    /// ```rust
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::Node;
+    ///
+    /// let xml_data = b"<root><child>Text</child>boo<last/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let last_descendant_idx = document.last_descendant(root_node.idx());
+    ///
+    /// assert!(last_descendant_idx.is_some()); // There should be descendants
+    /// let last_descendant = document.get_node(last_descendant_idx.unwrap()).unwrap();
+    /// assert!(last_descendant.is("last")); // The last descendant should be "last"
+    /// assert_eq!(document.last_descendant(last_descendant.idx()), None);
+    /// ```
+    ///
+    /// # Notes
+    /// - The method checks if the node index is valid and returns `0` if it is not.
+    /// - If the node index is `0` or if it is the root node with no descendants, it returns `0`.
+    /// # Errors
+    /// - If the node index is invalid or out of bounds, it returns `0`.
+    /// - If the node index is `1` and there are no descendants, it returns `0`.
+    #[must_use]
+    pub fn last_descendant(&self, node_idx: NodeIdx) -> Option<NodeIdx> {
+        if node_idx == 0
+            || self.nodes[node_idx as usize].first_child_idx() == 0
+            || node_idx as usize >= (self.nodes.len() - 1)
+        {
+            None // Invalid node index, or there is no node following that node
+        } else if node_idx == 1 {
+            // If the node is the root, return the last node index
+            Some(self.last_node_idx())
+        } else {
+            #[cfg(not(feature = "forward_only"))]
+            {
+                let mut up_idx = node_idx;
+                let mut last_descendant = self.nodes[up_idx as usize].next_sibling_idx();
+                while last_descendant == 0 {
+                    up_idx = self.nodes[up_idx as usize].parent_idx;
+                    if up_idx <= 1 {
+                        last_descendant = self.last_node_idx() + 1; // No more parents, will return the last node_idx
+                        break;
+                    }
+                    last_descendant = self.nodes[up_idx as usize].next_sibling_idx();
+                }
+
+                Some(last_descendant - 1)
+            }
+
+            #[cfg(feature = "forward_only")]
+            {
+                let mut curr_node_idx = self.nodes[node_idx as usize].first_child_idx();
+                // Start from the first child of the node
+
+                loop {
+                    while self.nodes[curr_node_idx as usize].next_sibling_idx() != 0 {
+                        curr_node_idx = self.nodes[curr_node_idx as usize].next_sibling_idx();
+                    }
+                    if self.nodes[curr_node_idx as usize].first_child_idx() != 0 {
+                        curr_node_idx = self.nodes[curr_node_idx as usize].first_child_idx();
+                    } else {
+                        break; // Found the last descendant
+                    }
+                }
+                Some(curr_node_idx)
+            }
+        }
+    }
+
+    /// Returns the next sequential node after the node index parameter.
+    #[inline]
+    #[must_use]
+    pub fn next_seq_node(&self, current: NodeIdx) -> Option<Node<'_>> {
+        let next = current + 1;
+        if next < self.nodes.len() as NodeIdx {
+            self.get_node(next).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the previous sequential node before the node index parameter.
+    #[inline]
+    #[must_use]
+    pub fn previous_seq_node(&self, current: NodeIdx) -> Option<Node<'_>> {
+        let previous = current - 1;
+        if previous > 0 {
+            self.get_node(previous).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the comments located before the root element.
+    ///
+    /// As there is no parent node for them, these comments (common for license or header
+    /// information) are recorded at the document level, in order of appearance. Their content
+    /// is returned as-is, without the `<!--` and `-->` delimiters.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<!-- header --><root/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let comments: Vec<&str> = document.prolog_comments().collect();
+    ///
+    /// assert_eq!(comments, vec![" header "]);
+    /// ```
+    #[inline]
+    pub fn prolog_comments(&self) -> impl Iterator<Item = &str> {
+        self.prolog_comments.iter().map(|location| {
+            #[cfg(feature = "use_cstr")]
+            {
+                self.get_str_from_location(*location)
+            }
+
+            #[cfg(not(feature = "use_cstr"))]
+            self.get_str_from_location(location.clone())
+        })
+    }
+
+    /// Returns an iterator over the processing instructions located before the root element,
+    /// as `(target, data)` pairs.
+    ///
+    /// The XML declaration (`<?xml ...?>`), available through `declaration()`, is not part of
+    /// them. The data is returned without its surrounding whitespace.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<?xml version=\"1.0\"?><?xml-stylesheet href=\"style.css\"?><root/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let pis: Vec<(&str, &str)> = document.prolog_processing_instructions().collect();
+    ///
+    /// assert_eq!(pis, vec![("xml-stylesheet", "href=\"style.css\"")]);
+    /// ```
+    #[inline]
+    pub fn prolog_processing_instructions(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.prolog_pis.iter().map(|(target, data)| {
+            #[cfg(feature = "use_cstr")]
+            {
+                (
+                    self.get_str_from_location(*target),
+                    self.get_str_from_location(*data),
+                )
+            }
+
+            #[cfg(not(feature = "use_cstr"))]
+            (
+                self.get_str_from_location(target.clone()),
+                self.get_str_from_location(data.clone()),
+            )
+        })
+    }
+
+    /// Returns the pseudo-attributes of the XML declaration (`<?xml ...?>`) located at the
+    /// beginning of the document, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<?xml version=\"1.0\" encoding='utf-8' standalone=\"yes\"?><root/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let declaration = document.declaration().unwrap();
+    ///
+    /// assert_eq!(declaration.version.as_deref(), Some("1.0"));
+    /// assert_eq!(declaration.encoding.as_deref(), Some("utf-8"));
+    /// assert!(declaration.is_standalone());
+    ///
+    /// let document = Document::new(b"<root/>".to_vec()).unwrap();
+    /// assert!(document.declaration().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn declaration(&self) -> Option<&XmlDeclaration> {
+        self.declaration.as_ref()
+    }
+
+    /// Returns the replacement text of a general entity declared in the internal subset of
+    /// the DOCTYPE declaration (`<!ENTITY name "value">`).
+    ///
+    /// The character references and predefined entities of the declared value are expanded;
+    /// the other entity references are kept as-is. When an entity is declared several times,
+    /// the first declaration is used. The declared entities are expanded in the text and
+    /// attribute values as the predefined ones, under the same parsing options.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the entity, without the `&` and `;`
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<!DOCTYPE doc [<!ENTITY co \"ACME\"><!ENTITY c \"&#169;\">]><doc>&c;&co;</doc>".to_vec();
+    /// let options = ParserOptions::new().parse_escapes(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    ///
+    /// assert_eq!(document.declared_entity("co"), Some("ACME"));
+    /// assert_eq!(document.declared_entity("c"), Some("\u{a9}"));
+    /// assert_eq!(document.root().unwrap().text_content(), "\u{a9}ACME");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn declared_entity(&self, name: &str) -> Option<&str> {
+        self.entity_declarations.get(name).map(String::as_str)
+    }
+
+    /// Returns `true` if some content was found after the end of the root element.
+    ///
+    /// The elements following the root element are kept as its next siblings, the text
+    /// located outside of any element being ignored. Whitespace, comments and processing
+    /// instructions following the root element are not considered as trailing content.
+    #[inline]
+    #[must_use]
+    pub fn had_trailing_content(&self) -> bool {
+        self.had_trailing_content
+    }
+
+    /// Verifies that the document respects the XML single root rule.
+    ///
+    /// The parser is lenient and keeps the elements following the root element as its next
+    /// siblings. This method permits strict callers to reject such documents after parsing.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the document has a single root element.
+    /// - `Err(ParseXmlError)`: If some content was found after the root element.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::MultipleRoots`: If some content was found after the root element.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::defs::ParseXmlError;
+    ///
+    /// let document = Document::new(b"<a/>".to_vec()).unwrap();
+    /// assert!(document.ensure_single_root().is_ok());
+    ///
+    /// let document = Document::new(b"<a/><b/>".to_vec()).unwrap();
+    /// assert!(matches!(document.ensure_single_root(), Err(ParseXmlError::MultipleRoots)));
+    /// ```
+    #[inline]
+    pub fn ensure_single_root(&self) -> Result<(), ParseXmlError> {
+        if self.had_trailing_content {
+            Err(ParseXmlError::MultipleRoots)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if the document looks like an HTML document rather than a generic XML one.
+    ///
+    /// This is a quick heuristic, useful to decide if HTML-lenient processing is required.
+    /// A document is considered HTML if any of the following is true:
+    ///
+    /// - the root element is `html`,
+    /// - a `<!DOCTYPE html ...>` declaration precedes the root element,
+    /// - a `head` or `body` element is present in the document.
+    ///
+    /// Tag names and the DOCTYPE declaration are compared case-insensitively.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::new(b"<!DOCTYPE html><html><body/></html>".to_vec()).unwrap();
+    /// assert!(document.is_probably_html());
+    ///
+    /// let document = Document::new(b"<config><entry/></config>".to_vec()).unwrap();
+    /// assert!(!document.is_probably_html());
+    /// ```
+    #[must_use]
+    pub fn is_probably_html(&self) -> bool {
+        let Some(root) = self.root() else {
+            return false;
+        };
+
+        if root.tag_name().eq_ignore_ascii_case("html") || self.has_html_doctype(&root) {
+            return true;
+        }
+
+        self.all_nodes().any(|node| {
+            node.is_element()
+                && (node.tag_name().eq_ignore_ascii_case("head")
+                    || node.tag_name().eq_ignore_ascii_case("body"))
+        })
+    }
+
+    /// Looks for a `<!DOCTYPE html` declaration, followed by whitespace or `>`, located
+    /// before the root element.
+    fn has_html_doctype(&self, root: &Node) -> bool {
+        let root_start = match root.node_info.node_type() {
+            #[cfg(feature = "use_cstr")]
+            NodeType::Element { name, .. } => *name as usize,
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::Element { name, .. } => name.start as usize,
+            _ => return false,
+        };
+
+        self.xml[..root_start]
+            .windows(9)
+            .position(|w| w.eq_ignore_ascii_case(b"<!DOCTYPE"))
+            .is_some_and(|pos| {
+                let rest = self.xml[pos + 9..root_start].trim_ascii_start();
+                rest.len() > 4
+                    && rest[..4].eq_ignore_ascii_case(b"html")
+                    && (rest[4] == b'>' || rest[4].is_ascii_whitespace())
+            })
+    }
+
+    /// Returns the first element with the given tag name, in document order.
+    ///
+    /// The search stops at the first match.
+    ///
+    /// # Arguments
+    /// - `tag_name`: The tag name of the element to find.
+    ///
+    /// # Returns
+    /// - `Some(Node)`: The first element with the given tag name.
+    /// - `None`: If there is no such element in the document.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><h1>First</h1><h1>Second</h1></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let h1 = document.find_first("h1").unwrap();
+    ///
+    /// assert_eq!(h1.first_child().unwrap().text().unwrap(), "First");
+    /// assert!(document.find_first("title").is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn find_first(&self, tag_name: &str) -> Option<Node<'_>> {
+        self.all_nodes().find(|node| node.is(tag_name))
+    }
+
+    /// Returns an iterator over the elements of the document with the given tag name, in
+    /// document order.
+    ///
+    /// The nodes are scanned sequentially in the node vector, and the tag names are compared
+    /// as bytes, without allocating.
+    ///
+    /// # Arguments
+    /// - `tag_name`: The tag name of the elements to iterate over.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><h1>First</h1><div><h1>Second</h1></div></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let titles: Vec<_> = document.elements_named("h1").map(|h1| h1.text_content()).collect();
+    ///
+    /// assert_eq!(titles, ["First", "Second"]);
+    /// ```
+    #[inline]
+    pub fn elements_named<'s>(&'s self, tag_name: &'s str) -> impl Iterator<Item = Node<'s>> + 's {
+        self.all_nodes()
+            .filter(move |node| node.is_element() && node.is_bytes(tag_name.as_bytes()))
+    }
+
+    /// Builds a map from the values of the given attribute to the elements holding them.
+    ///
+    /// The document is scanned once, turning repeated lookups by attribute value (e.g. resolving
+    /// `idref`s against `id`s) into constant time operations. If several elements hold the same
+    /// value, the last one in document order is kept.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute to index.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><a id=\"x\"/><b id=\"y\"/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let index = document.attribute_value_index("id");
+    ///
+    /// assert!(index["y"].is("b"));
+    /// assert!(index.get("z").is_none());
+    /// ```
+    #[must_use]
+    pub fn attribute_value_index(&self, name: &str) -> HashMap<&str, Node<'_>> {
+        let mut index = HashMap::new();
+        for node in self.all_nodes() {
+            if let Some(value) = node.get_attribute(name) {
+                index.insert(value, node);
+            }
+        }
+        index
+    }
+
+    /// Returns the element having the given `id` attribute value.
+    ///
+    /// An index of the `id` attribute values is built on the first call, answering the
+    /// subsequent lookups in constant time. If several elements hold the same value, the first
+    /// one in document order is returned. The index is rebuilt after the document is modified.
+    ///
+    /// # Arguments
+    /// - `id`: The value of the `id` attribute to look for.
+    ///
+    /// # Returns
+    /// - `Some(Node)`: The element having the `id`.
+    /// - `None`: If there is no such element in the document.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<body><p id=\"note1\">A</p><aside id=\"note2\">B</aside></body>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert!(document.get_element_by_id("note2").unwrap().is("aside"));
+    /// assert!(document.get_element_by_id("note3").is_none());
+    /// ```
+    #[must_use]
+    pub fn get_element_by_id(&self, id: &str) -> Option<Node<'_>> {
+        let ids = self.ids.get_or_init(|| {
+            let mut ids = HashMap::new();
+            for node in self.all_nodes() {
+                if let Some(value) = node.get_attribute("id") {
+                    ids.entry(value.to_string()).or_insert(node.idx());
+                }
+            }
+            ids
+        });
+
+        ids.get(id)
+            .and_then(|&node_idx| self.get_node(node_idx).ok())
+    }
+
+    /// Searches the attribute named `name` among the given attributes of an element, with a
+    /// binary search in the attribute index. The index is built on the first call.
+    ///
+    /// # Arguments
+    /// - `attributes`: The attributes range of the element, of at least
+    ///   `INDEXED_ATTRIBUTES_MIN` attributes.
+    /// - `name`: The name of the attribute.
+    ///
+    /// # Returns
+    /// The first attribute having the name, in document order, if any.
+    pub(crate) fn find_indexed_attribute(
+        &self,
+        attributes: &AttributeRange,
+        name: &[u8],
+    ) -> Option<Attribute<'_>> {
+        let order = self
+            .attribute_order
+            .get_or_init(|| self.sorted_attribute_order());
+        let sorted = &order[attributes.start as usize..attributes.end as usize];
+
+        let pos = sorted.partition_point(|&attr_idx| self.attribute_name(attr_idx) < name);
+        sorted
+            .get(pos)
+            .filter(|&&attr_idx| self.attribute_name(attr_idx) == name)
+            .map(|&attr_idx| Attribute::new(self, &self.attributes[attr_idx as usize]))
+    }
+
+    /// Returns the attribute indexes, with the attributes of each element having at least
+    /// `INDEXED_ATTRIBUTES_MIN` attributes sorted by name. The sort is stable, such that
+    /// duplicated names stay in document order.
+    fn sorted_attribute_order(&self) -> Vec<AttrIdx> {
+        let mut order: Vec<AttrIdx> = (0..self.attributes.len() as AttrIdx).collect();
+        for node_info in &self.nodes {
+            if let NodeType::Element { attributes, .. } = node_info.node_type() {
+                if (attributes.end - attributes.start) as usize >= INDEXED_ATTRIBUTES_MIN {
+                    order[attributes.start as usize..attributes.end as usize]
+                        .sort_by(|&a, &b| self.attribute_name(a).cmp(self.attribute_name(b)));
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns the name of an attribute, as a byte slice.
+    #[inline]
+    fn attribute_name(&self, attr_idx: AttrIdx) -> &[u8] {
+        Attribute::new(self, &self.attributes[attr_idx as usize]).name_bytes()
+    }
+
+    /// Returns a histogram of the number of nodes located at each depth of the document.
+    ///
+    /// The returned vector is indexed by depth, the root element being at depth `0`. All node
+    /// types (elements and text nodes) are counted. This helps identify pathological nesting
+    /// in large documents. The vector is empty if the document has no root.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><a><b/></a><c>text</c></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(document.count_depth_histogram(), vec![1, 2, 2]);
+    /// ```
+    #[must_use]
+    pub fn count_depth_histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        if self.is_empty() {
+            return histogram;
+        }
+
+        // Stack of the next node to visit at each depth
+        let mut stack: Vec<NodeIdx> = vec![1];
+
+        while let Some(&node_idx) = stack.last() {
+            if node_idx == 0 {
+                stack.pop();
+                if let Some(top) = stack.last_mut() {
+                    *top = self.nodes[*top as usize].next_sibling_idx();
+                }
+                continue;
+            }
+
+            let depth = stack.len() - 1;
+            if histogram.len() <= depth {
+                histogram.push(0);
+            }
+            histogram[depth] += 1;
+
+            stack.push(self.nodes[node_idx as usize].first_child_idx());
+        }
+
+        histogram
+    }
+
+    /// Computes a structural hash of the subtree starting at the given node.
+    ///
+    /// The node and all its descendants are visited in document order, from `node_idx` up to
+    /// its `last_descendant()`. For each node, its type, tag name, attributes (in order), text
+    /// content, and number of children are hashed. Two subtrees with the same structure and
+    /// content get the same hash, even if they are located in different documents or at different
+    /// positions. This permits the detection of changes in parts of a large document, without
+    /// hashing the whole document.
+    ///
+    /// The hash value is stable for a given build of the crate, but should not be persisted.
+    ///
+    /// # Arguments
+    /// - `node_idx`: The index of the subtree's top node.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The hash value of the subtree.
+    /// - `Err(ParseXmlError)`: If the node index is invalid.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the node index is invalid or out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let doc1 = Document::new(b"<root><p>Same</p></root>".to_vec()).unwrap();
+    /// let doc2 = Document::new(b"<top><x/><p>Same</p></top>".to_vec()).unwrap();
+    ///
+    /// assert_eq!(doc1.subtree_hash(2).unwrap(), doc2.subtree_hash(3).unwrap());
+    /// ```
+    pub fn subtree_hash(&self, node_idx: NodeIdx) -> Result<u64, ParseXmlError> {
+        let node = self.get_node(node_idx)?;
+        let mut hasher = DefaultHasher::new();
+
+        Self::hash_node(&node, &mut hasher);
+        node.descendants()
+            .for_each(|descendant| Self::hash_node(&descendant, &mut hasher));
+
+        Ok(hasher.finish())
+    }
+
+    /// Feeds the structural information of a single node to `hasher`.
+    fn hash_node(node: &Node, hasher: &mut DefaultHasher) {
+        if node.is_element() {
+            b'E'.hash(hasher);
+            node.tag_name_bytes().hash(hasher);
+            for attr in node.attributes() {
+                attr.name_bytes().hash(hasher);
+                attr.value_bytes().hash(hasher);
+            }
+        } else if let Some(text) = node.text_bytes() {
+            b'T'.hash(hasher);
+            text.hash(hasher);
+        }
+        node.children().count().hash(hasher);
+    }
+
+    /// Replaces the content of a text node.
+    ///
+    /// If the new text is not longer than the current one, it is written in place in the XML buffer.
+    /// Otherwise, it is appended at the end of the XML buffer (the text store area, past the
+    /// original XML content) and the node is updated to point to its new location. A borrowed
+    /// XML buffer is then copied into a vector owned by the document. The text of a parsed
+    /// node is never overwritten when the `decode_entities` option is disabled, as the
+    /// serializer writes the parsed text as-is, with its escape sequences.
+    ///
+    /// # Arguments
+    /// - `node_idx`: The index of the text node to update.
+    /// - `text`: The new content of the text node.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the text node was updated.
+    /// - `Err(ParseXmlError)`: If the node cannot be updated.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the node index is invalid or is not a text node.
+    /// - `ParseXmlError::NoMoreSpace`: If the XML buffer would become too large for the selected `xxxx_xml_size` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>Text</root>".to_vec();
+    /// let mut document = Document::new(xml_data).unwrap();
+    /// document.set_text(2, "A longer text").unwrap();
+    ///
+    /// assert_eq!(document.get_node(2).unwrap().text().unwrap(), "A longer text");
+    /// ```
+    pub fn set_text(&mut self, node_idx: NodeIdx, text: &str) -> Result<(), ParseXmlError> {
+        if node_idx as usize >= self.nodes.len() {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Invalid node index: {node_idx}"
+            )));
+        }
+
+        let NodeType::Text(location) = self.nodes[node_idx as usize].node_type() else {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Node index {node_idx} is not a text node"
+            )));
+        };
+
+        #[cfg(not(feature = "use_cstr"))]
+        let (start, current_len) = (
+            location.start as usize,
+            (location.end - location.start) as usize,
+        );
+
+        #[cfg(feature = "use_cstr")]
+        let (start, current_len) = (
+            *location as usize,
+            self.get_cstr_from_location(*location).to_bytes().len(),
+        );
+
+        let bytes = text.as_bytes();
+
+        let in_place = bytes.len() <= current_len
+            && (self.options.decode_entities || !self.is_parsed_location(location));
+
+        let location = if in_place {
+            self.xml[start..start + bytes.len()].copy_from_slice(bytes);
+
+            #[cfg(feature = "use_cstr")]
+            {
+                self.xml[start + bytes.len()] = 0; // Null-terminate the string
+                start as XmlIdx
+            }
+
+            #[cfg(not(feature = "use_cstr"))]
+            {
+                start as XmlIdx..(start + bytes.len()) as XmlIdx
+            }
+        } else {
+            self.store_str(text)?
+        };
+
+        self.nodes[node_idx as usize].set_node_type(NodeType::Text(location));
+        Ok(())
+    }
+
+    /// Returns true if the location is part of the parsed XML content, and false if it is
+    /// located in the text store (text of the created or updated nodes, and entities expanded
+    /// out of place).
+    #[inline]
+    pub(crate) fn is_parsed_location(&self, location: &XmlLocation) -> bool {
+        #[cfg(feature = "use_cstr")]
+        let start = *location;
+
+        #[cfg(not(feature = "use_cstr"))]
+        let start = location.start;
+
+        start < self.content_len
+    }
+
+    /// Appends a string at the end of the XML buffer (the text store area, past the original
+    /// XML content).
+    ///
+    /// # Returns
+    /// The location of the stored string.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::NoMoreSpace`: If the XML buffer would become too large for the selected `xxxx_xml_size` feature.
+    pub(crate) fn store_str(&mut self, text: &str) -> Result<XmlLocation, ParseXmlError> {
+        let start = self.xml.len();
+        if start + text.len() + 1 > XmlIdx::MAX as usize {
+            return Err(ParseXmlError::NoMoreSpace);
+        }
+        self.xml.extend_from_slice(text.as_bytes());
+
+        #[cfg(feature = "use_cstr")]
+        {
+            self.xml.push(0); // Null-terminate the string
+            Ok(start as XmlIdx)
+        }
+
+        #[cfg(not(feature = "use_cstr"))]
+        Ok(start as XmlIdx..(start + text.len()) as XmlIdx)
+    }
+
+    /// Replaces every text node whose content is exactly `from` with `to`.
+    ///
+    /// This is a convenience over `set_text()`, useful for simple variable substitution in
+    /// generated XHTML templates.
+    ///
+    /// # Arguments
+    /// - `from`: The text to be replaced. Only text nodes that are equal to it are replaced.
+    /// - `to`: The replacement text.
+    ///
+    /// # Returns
+    /// - `Ok(usize)`: The number of replaced text nodes.
+    /// - `Err(ParseXmlError)`: If a text node cannot be updated.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::NoMoreSpace`: If the XML buffer would become too large for the selected `xxxx_xml_size` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<p><b>{{name}}</b>, welcome {{name}}</p>".to_vec();
+    /// let mut document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(document.replace_text_globally("{{name}}", "Alice").unwrap(), 1);
+    /// assert_eq!(document.get_node(3).unwrap().text().unwrap(), "Alice");
+    /// ```
+    pub fn replace_text_globally(&mut self, from: &str, to: &str) -> Result<usize, ParseXmlError> {
+        let matching: Vec<NodeIdx> = self
+            .all_nodes()
+            .filter(|node| node.text() == Some(from))
+            .map(|node| node.idx())
+            .collect();
+
+        for node_idx in &matching {
+            self.set_text(*node_idx, to)?;
+        }
+
+        Ok(matching.len())
+    }
+
+    /// Reserves capacity for at least `additional` more bytes in the text store area.
+    ///
+    /// Texts that do not fit in place are appended by `set_text()` at the end of the XML buffer.
+    /// Reserving the space beforehand avoids incremental reallocations when a large number of
+    /// text nodes are rewritten. Under the `use_cstr` feature, one byte per appended text is
+    /// required for the null terminator.
+    ///
+    /// # Arguments
+    /// - `additional`: The number of bytes to reserve.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the capacity was reserved.
+    /// - `Err(ParseXmlError)`: If the capacity cannot be reserved.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::NoMoreSpace`: If the XML buffer would become too large for the selected `xxxx_xml_size` feature.
+    /// - `ParseXmlError::NotEnoughMemory`: If there is not enough memory to reserve the capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<root>Text</root>".to_vec()).unwrap();
+    /// document.reserve_text_store(100).unwrap();
+    ///
+    /// assert!(document.xml.capacity() >= document.xml.len() + 100);
+    /// ```
+    pub fn reserve_text_store(&mut self, additional: usize) -> Result<(), ParseXmlError> {
+        if self.xml.len() + additional > XmlIdx::MAX as usize {
+            return Err(ParseXmlError::NoMoreSpace);
+        }
+
+        self.xml
+            .try_reserve(additional)
+            .map_err(|_| ParseXmlError::NotEnoughMemory)
+    }
+
+    /// Creates a new element, outside of the tree.
+    ///
+    /// The element is detached: it is not part of the document until it is inserted with
+    /// `append_child()` or `insert_before()`. Children can be appended to it before its
+    /// insertion. The name and attribute values are stored in the text store area.
+    ///
+    /// # Arguments
+    /// - `name`: The tag name of the element.
+    /// - `attributes`: The `(name, value)` pairs of the attributes of the element.
+    ///
+    /// # Returns
+    /// - `Ok(NodeIdx)`: The index of the new element.
+    /// - `Err(ParseXmlError)`: If the element cannot be created.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the name of the element or of one of its attributes is
+    ///   not a valid XML name.
+    /// - `ParseXmlError::NoMoreSpace`: If the nodes, attributes or XML buffer would become too large for the selected size features.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<nav><a href=\"1.xhtml\">1</a></nav>".to_vec()).unwrap();
+    /// let link = document.create_element("a", &[("href", "2.xhtml")]).unwrap();
+    /// let text = document.create_text("2").unwrap();
+    /// document.append_child(link, text).unwrap();
+    /// document.append_child(1, link).unwrap();
+    ///
+    /// assert_eq!(document.to_xml(), "<nav><a href=\"1.xhtml\">1</a><a href=\"2.xhtml\">2</a></nav>");
+    /// ```
+    pub fn create_element(
+        &mut self,
+        name: &str,
+        attributes: &[(&str, &str)],
+    ) -> Result<NodeIdx, ParseXmlError> {
+        Self::check_name(name, "element")?;
+        for (attr_name, _) in attributes {
+            Self::check_name(attr_name, "attribute")?;
+        }
+        if self.attributes.len() + attributes.len() > AttrIdx::MAX as usize {
+            return Err(ParseXmlError::NoMoreSpace);
+        }
+
+        let name = self.store_str(name)?;
+        let first_attribute = self.attributes.len() as AttrIdx;
+        for (attr_name, attr_value) in attributes {
+            let attr_name = self.store_str(attr_name)?;
+            let attr_value = self.store_str(attr_value)?;
+            self.attributes
+                .push(AttributeInfo::new(attr_name, attr_value));
+        }
+        self.attribute_order = OnceLock::new();
+
+        self.add_detached_node(NodeType::Element {
+            name,
+            attributes: first_attribute..self.attributes.len() as AttrIdx,
+        })
+    }
+
+    /// Creates a new text node, outside of the tree.
+    ///
+    /// The node is detached: it is not part of the document until it is inserted with
+    /// `append_child()` or `insert_before()`. The text is stored as-is in the text store area:
+    /// it is escaped when the document is serialized.
+    ///
+    /// # Arguments
+    /// - `text`: The content of the text node.
+    ///
+    /// # Returns
+    /// - `Ok(NodeIdx)`: The index of the new text node.
+    /// - `Err(ParseXmlError)`: If the node cannot be created.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::NoMoreSpace`: If the nodes or XML buffer would become too large for the selected size features.
+    pub fn create_text(&mut self, text: &str) -> Result<NodeIdx, ParseXmlError> {
+        let location = self.store_str(text)?;
+        self.add_detached_node(NodeType::Text(location))
+    }
+
+    /// Adds a node outside of the tree, at the end of the nodes vector.
+    fn add_detached_node(&mut self, node_type: NodeType) -> Result<NodeIdx, ParseXmlError> {
+        if self.is_empty() {
+            return Err(ParseXmlError::InvalidXml(
+                "Nodes cannot be created in an empty document".to_string(),
+            ));
+        }
+
+        let node_idx = self.nodes.len() as NodeIdx;
+        if node_idx == NodeIdx::MAX {
+            return Err(ParseXmlError::NoMoreSpace);
+        }
+
+        #[cfg(not(feature = "forward_only"))]
+        self.nodes.push(NodeInfo::new(node_idx, 0, node_type));
+        #[cfg(feature = "forward_only")]
+        self.nodes.push(NodeInfo::new(node_type));
+
+        self.detached_from.get_or_insert(node_idx);
+        self.detached_roots.push(node_idx);
+        Ok(node_idx)
+    }
+
+    /// Returns the indexes of the created nodes waiting for insertion, in creation order.
+    ///
+    /// As these nodes get new indexes when the nodes of the tree are renumbered, this permits
+    /// to find them back after an insertion or a removal.
+    #[inline]
+    #[must_use]
+    pub fn pending_nodes(&self) -> &[NodeIdx] {
+        &self.detached_roots
+    }
+
+    /// Returns true if the node is not part of the tree, being created and not yet inserted.
+    #[inline]
+    fn is_detached(&self, node_idx: NodeIdx) -> bool {
+        self.detached_from
+            .is_some_and(|detached_from| node_idx >= detached_from)
+    }
+
+    /// Checks that the node can be inserted: it must be a created node, not yet inserted.
+    fn check_insertable(&self, node_idx: NodeIdx) -> Result<(), ParseXmlError> {
+        if self.detached_roots.contains(&node_idx) {
+            Ok(())
+        } else {
+            Err(ParseXmlError::InvalidXml(format!(
+                "Node index {node_idx} is not a created node waiting for insertion"
+            )))
+        }
+    }
+
+    /// Inserts a created node as the last child of an element.
+    ///
+    /// If the parent element is part of the tree, the nodes are then renumbered to keep them
+    /// in document order: node indexes obtained before the call must be considered invalid.
+    /// The other created nodes waiting for insertion are kept, with new indexes available
+    /// through `pending_nodes()`.
+    ///
+    /// # Arguments
+    /// - `parent_idx`: The index of the parent element.
+    /// - `child_idx`: The index of the node to insert, returned by `create_element()` or `create_text()`.
+    ///
+    /// # Returns
+    /// - `Ok(NodeIdx)`: The index of the inserted node.
+    /// - `Err(ParseXmlError)`: If the node cannot be inserted.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the parent is not an element, or the child is not a created node waiting for insertion, or is an ancestor of the parent.
+    pub fn append_child(
+        &mut self,
+        parent_idx: NodeIdx,
+        child_idx: NodeIdx,
+    ) -> Result<NodeIdx, ParseXmlError> {
+        self.check_insertable(child_idx)?;
+        if parent_idx as usize >= self.nodes.len() || !self.nodes[parent_idx as usize].is_element()
+        {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Node index {parent_idx} is not an element"
+            )));
+        }
+        if self
+            .descendants_of_detached(child_idx)
+            .contains(&parent_idx)
+        {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Node index {child_idx} cannot be appended to its own subtree"
+            )));
+        }
+
+        let first_child_idx = self.nodes[parent_idx as usize].first_child_idx();
+        if first_child_idx == 0 {
+            self.nodes[parent_idx as usize].set_first_child_idx(child_idx);
+        } else {
+            let mut last_child_idx = first_child_idx;
+            while self.nodes[last_child_idx as usize].next_sibling_idx() != 0 {
+                last_child_idx = self.nodes[last_child_idx as usize].next_sibling_idx();
+            }
+            self.nodes[last_child_idx as usize].set_next_sibling_idx(child_idx);
+
+            #[cfg(not(feature = "forward_only"))]
+            {
+                self.nodes[child_idx as usize].set_prev_sibling_idx(last_child_idx);
+                self.nodes[first_child_idx as usize].set_prev_sibling_idx(child_idx);
+            }
+        }
+
+        #[cfg(not(feature = "forward_only"))]
+        self.nodes[child_idx as usize].set_parent_idx(parent_idx);
+
+        self.attach(child_idx, parent_idx)
+    }
+
+    /// Inserts a created node as the previous sibling of another node.
+    ///
+    /// If the reference node is part of the tree, the nodes are then renumbered to keep them
+    /// in document order: node indexes obtained before the call must be considered invalid.
+    /// The other created nodes waiting for insertion are kept, with new indexes available
+    /// through `pending_nodes()`.
+    ///
+    /// # Arguments
+    /// - `reference_idx`: The index of the node before which the new node is inserted.
+    /// - `child_idx`: The index of the node to insert, returned by `create_element()` or `create_text()`.
+    ///
+    /// # Returns
+    /// - `Ok(NodeIdx)`: The index of the inserted node.
+    /// - `Err(ParseXmlError)`: If the node cannot be inserted.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the reference node has no parent, or the child is not a created node waiting for insertion, or is an ancestor of the reference node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<body><p>Text</p></body>".to_vec()).unwrap();
+    /// let title = document.create_element("h1", &[]).unwrap();
+    /// document.insert_before(2, title).unwrap();
+    ///
+    /// assert_eq!(document.to_xml(), "<body><h1/><p>Text</p></body>");
+    /// ```
+    pub fn insert_before(
+        &mut self,
+        reference_idx: NodeIdx,
+        child_idx: NodeIdx,
+    ) -> Result<NodeIdx, ParseXmlError> {
+        self.check_insertable(child_idx)?;
+
+        // The node having the reference as first child or next sibling
+        let previous = if reference_idx > 1 && (reference_idx as usize) < self.nodes.len() {
+            self.nodes.iter().enumerate().find(|(_, info)| {
+                info.first_child_idx() == reference_idx || info.next_sibling_idx() == reference_idx
+            })
+        } else {
+            None
+        };
+        let Some((previous_idx, previous_info)) = previous else {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "No node can be inserted before node index {reference_idx}"
+            )));
+        };
+        let previous_idx = previous_idx as NodeIdx;
+        if self
+            .descendants_of_detached(child_idx)
+            .contains(&previous_idx)
+        {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Node index {child_idx} cannot be inserted in its own subtree"
+            )));
+        }
+
+        let is_first_child = previous_info.first_child_idx() == reference_idx;
+        if is_first_child {
+            self.nodes[previous_idx as usize].set_first_child_idx(child_idx);
+        } else {
+            self.nodes[previous_idx as usize].set_next_sibling_idx(child_idx);
+        }
+        self.nodes[child_idx as usize].set_next_sibling_idx(reference_idx);
+
+        #[cfg(not(feature = "forward_only"))]
+        {
+            let reference_info = &self.nodes[reference_idx as usize];
+            let (parent_idx, prev_sibling_idx) =
+                (reference_info.parent_idx, reference_info.prev_sibling_idx());
+            self.nodes[reference_idx as usize].set_prev_sibling_idx(child_idx);
+            self.nodes[child_idx as usize].set_prev_sibling_idx(prev_sibling_idx);
+            self.nodes[child_idx as usize].set_parent_idx(parent_idx);
+        }
+
+        self.attach(child_idx, reference_idx)
+    }
+
+    /// Parses a fragment of XML content and appends its nodes as the last children of an
+    /// element.
+    ///
+    /// The fragment is parsed as with `Document::parse_fragment()`, using the parsing options
+    /// of the document, so it may hold several top-level elements and text nodes. Its nodes
+    /// are then created in this document, their names, attribute values and text being stored
+    /// in the text store area, and inserted. As for `append_child()`, the nodes are renumbered:
+    /// node indexes obtained before the call must be considered invalid.
+    ///
+    /// # Arguments
+    /// - `parent_idx`: The index of the parent element.
+    /// - `xml`: The XML fragment to append.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<NodeIdx>)`: The indexes of the inserted top-level nodes, in order.
+    /// - `Err(ParseXmlError)`: If the fragment cannot be parsed or appended.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the parent is not an element, or the fragment is not well-formed. The document is then left unchanged.
+    /// - `ParseXmlError::NoMoreSpace`: If the nodes, attributes or XML buffer would become too large for the selected size features.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let mut document = Document::new(b"<ul><li>1</li></ul>".to_vec()).unwrap();
+    /// let items = document.append_fragment(1, b"<li>2</li><li class=\"last\">3 &amp; 4</li>").unwrap();
+    ///
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(document.get_node(items[1]).unwrap().get_attribute("class"), Some("last"));
+    /// assert_eq!(
+    ///     document.to_xml(),
+    ///     "<ul><li>1</li><li>2</li><li class=\"last\">3 &amp; 4</li></ul>"
+    /// );
+    /// ```
+    pub fn append_fragment(
+        &mut self,
+        parent_idx: NodeIdx,
+        xml: &[u8],
+    ) -> Result<Vec<NodeIdx>, ParseXmlError> {
+        if parent_idx as usize >= self.nodes.len() || !self.nodes[parent_idx as usize].is_element()
+        {
+            return Err(ParseXmlError::InvalidXml(format!(
+                "Node index {parent_idx} is not an element"
+            )));
+        }
+
+        let fragment = Document::parse_fragment_with_options(xml.to_vec(), self.options.clone())?;
+        let Some(fragment_root) = fragment.root() else {
+            return Ok(Vec::new());
+        };
+
+        // Create the nodes as detached subtrees, traversed with a stack of the children
+        // iterators
+        let first_pending = self.detached_roots.len();
+        for top in fragment_root.children() {
+            let Some(top_idx) = self.create_copy(&top)? else {
+                continue;
+            };
+            let mut open = vec![(top.children(), top_idx)];
+            while let Some((children, copy_idx)) = open.last_mut() {
+                let copy_idx = *copy_idx;
+                let Some(node) = children.next() else {
+                    open.pop();
+                    continue;
+                };
+                if let Some(node_idx) = self.create_copy(&node)? {
+                    self.append_child(copy_idx, node_idx)?;
+                    if node.is_element() {
+                        open.push((node.children(), node_idx));
+                    }
+                }
+            }
+        }
+
+        // Insert the top-level nodes, the nodes already inserted keeping their index
+        let mut inserted = Vec::with_capacity(self.detached_roots.len() - first_pending);
+        while self.detached_roots.len() > first_pending {
+            let child_idx = self.detached_roots[first_pending];
+            inserted.push(self.append_child(parent_idx, child_idx)?);
+        }
+        Ok(inserted)
+    }
+
+    /// Creates a detached copy of a node of another document, without its children.
+    ///
+    /// # Returns
+    /// The index of the copy, or `None` for a node that cannot be copied.
+    fn create_copy(&mut self, node: &Node) -> Result<Option<NodeIdx>, ParseXmlError> {
+        let node_type = match node.get_node_type() {
+            NodeType::Element { .. } => {
+                let attributes: Vec<(&str, &str)> = node
+                    .attributes()
+                    .map(|attr| (attr.name(), attr.value()))
+                    .collect();
+                return self.create_element(node.tag_name(), &attributes).map(Some);
+            }
+            NodeType::Text(_) => NodeType::Text(self.store_str(node.text().unwrap_or_default())?),
+            NodeType::Comment(_) => {
+                NodeType::Comment(self.store_str(node.comment().unwrap_or_default())?)
+            }
+            NodeType::EntityRef(_) => {
+                NodeType::EntityRef(self.store_str(node.entity_name().unwrap_or_default())?)
+            }
+            NodeType::ProcessingInstruction { .. } => NodeType::ProcessingInstruction {
+                target: self.store_str(node.pi_target().unwrap_or_default())?,
+                data: self.store_str(node.pi_data().unwrap_or_default())?,
+            },
+            NodeType::Head => return Ok(None),
+        };
+        self.add_detached_node(node_type).map(Some)
+    }
+
+    /// Completes the insertion of a created node, linked to a node located in the tree or in a
+    /// created subtree. In the tree, the nodes are renumbered.
+    fn attach(&mut self, child_idx: NodeIdx, linked_to: NodeIdx) -> Result<NodeIdx, ParseXmlError> {
+        self.detached_roots.retain(|&idx| idx != child_idx);
+
+        if self.is_detached(linked_to) {
+            Ok(child_idx)
+        } else {
+            let new_indexes = self.rebuild_nodes_without(&vec![false; self.nodes.len()])?;
+            Ok(new_indexes[child_idx as usize])
         }
+    }
 
-        #[cfg(feature = "use_cstr")]
-        {
-            let content = std::ffi::CStr::from_bytes_until_nul(&self.xml[location as usize..])
-                .unwrap_or(c"cstr not valid");
-            content.to_str().unwrap_or("non valid utf-8")
+    /// Returns the indexes of the nodes of a detached subtree, including its root.
+    fn descendants_of_detached(&self, root_idx: NodeIdx) -> Vec<NodeIdx> {
+        let mut nodes = vec![root_idx];
+        let mut pos = 0;
+
+        while pos < nodes.len() {
+            let mut child_idx = self.nodes[nodes[pos] as usize].first_child_idx();
+            while child_idx != 0 {
+                nodes.push(child_idx);
+                child_idx = self.nodes[child_idx as usize].next_sibling_idx();
+            }
+            pos += 1;
         }
+
+        nodes
     }
 
-    #[cfg(feature = "use_cstr")]
-    /// Retrieves a CStr from the XML content based on the given location.
+    /// Removes a node and its descendants.
+    ///
+    /// The nodes are then renumbered to keep them in document order: node indexes obtained
+    /// before the call must be considered invalid. To remove several nodes, use
+    /// `remove_nodes()`, which renumbers the nodes only once.
     ///
     /// # Arguments
-    /// - `location`: An `XmlLocation` that specifies the start index of the CStr in the XML content.
+    /// - `node_idx`: The index of the node to remove.
     ///
     /// # Returns
-    /// - `&str`: A string slice containing the CStr from the specified location.
+    /// - `Ok(())`: If the node was removed.
+    /// - `Err(ParseXmlError)`: If the node cannot be removed.
     ///
-    #[inline]
-    #[must_use]
-    pub fn get_cstr_from_location(&self, location: XmlLocation) -> &CStr {
-        CStr::from_bytes_until_nul(&self.xml[location as usize..]).unwrap_or(c"cstr not valid")
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the node index is invalid or is the root element.
+    pub fn remove_node(&mut self, node_idx: NodeIdx) -> Result<(), ParseXmlError> {
+        self.remove_nodes(&[node_idx]).map(|_| ())
     }
 
-    /// Returns an iterator over all nodes in the document.
+    /// Removes nodes and their descendants.
     ///
-    /// This method provides an iterator that traverses all nodes in the document, starting from the root node.
+    /// The nodes are then renumbered once to keep them in document order: node indexes
+    /// obtained before the call must be considered invalid.
+    ///
+    /// # Arguments
+    /// - `node_indexes`: The indexes of the nodes to remove.
     ///
     /// # Returns
-    /// - `Nodes`: An iterator that yields `Node` instances in the order they appear in the document.
+    /// - `Ok(usize)`: The number of removed nodes, including their descendants.
+    /// - `Err(ParseXmlError)`: If a node cannot be removed. No node is then removed.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If a node index is invalid or is the root element.
     ///
     /// # Example
-    /// ```rust
+    /// ```
     /// use xhtml_parser::Document;
-    /// use xhtml_parser::Node;
     ///
-    /// let xml_data = b"<root><child>Text</child><last/></root>".to_vec();
-    /// let document = Document::new(xml_data).unwrap();
-    /// let all_nodes: Vec<Node> = document.all_nodes().collect();
+    /// let xml_data = b"<body><script>ad()</script><p>Text</p><script/></body>".to_vec();
+    /// let mut document = Document::new(xml_data).unwrap();
+    /// let scripts: Vec<_> = document
+    ///     .all_nodes()
+    ///     .filter(|node| node.is("script"))
+    ///     .map(|node| node.idx())
+    ///     .collect();
     ///
-    /// assert_eq!(all_nodes.len(), 4); // root, child, Text, totototo
-    #[inline]
-    #[must_use]
-    pub fn all_nodes(&self) -> Nodes<'_> {
-        Nodes::new(self)
+    /// assert_eq!(document.remove_nodes(&scripts).unwrap(), 3);
+    /// assert_eq!(document.to_xml(), "<body><p>Text</p></body>");
+    /// ```
+    pub fn remove_nodes(&mut self, node_indexes: &[NodeIdx]) -> Result<usize, ParseXmlError> {
+        let mut removed = vec![false; self.nodes.len()];
+
+        for &node_idx in node_indexes {
+            if node_idx <= 1 || node_idx as usize >= self.nodes.len() {
+                return Err(ParseXmlError::InvalidXml(format!(
+                    "Node index {node_idx} cannot be removed"
+                )));
+            }
+            removed[node_idx as usize] = true;
+        }
+
+        let count = self.nodes.len();
+        self.rebuild_nodes_without(&removed)?;
+        Ok(count - self.nodes.len())
     }
 
-    /// Returns an iterator over the descendants of a given node.
+    /// Removes insignificant whitespace from an already parsed document.
     ///
-    /// This method provides an iterator that traverses all descendant nodes of the specified node index.
+    /// Leading and trailing ASCII whitespace is trimmed from every text node, and text nodes
+    /// that become empty (i.e. whitespace-only nodes) are removed from the tree. This permits
+    /// a document parsed with whitespace retained to be cleaned for a later processing stage.
+    /// The text located inside the elements listed by the `preserve_whitespace_tags` parser
+    /// option is left untouched.
     ///
-    /// # Arguments
-    /// - `node_idx`: The index of the node whose descendants are to be iterated over.
+    /// Trimming is done in place. When nodes are removed, the node vector is rebuilt in a single
+    /// pass: node indexes obtained before the call must be considered invalid.
     ///
     /// # Returns
-    /// - `Nodes`: An iterator that yields `Node` instances representing the descendants of the specified node.
+    /// - `Ok(usize)`: The number of removed text nodes.
+    /// - `Err(ParseXmlError)`: If the node tree cannot be rebuilt.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::NoMoreSpace`: If the node tree cannot be rebuilt (should not happen as the number of nodes decreases).
     ///
     /// # Example
-    /// ```rust
+    /// ```
     /// use xhtml_parser::Document;
-    /// use xhtml_parser::Node;
     ///
-    /// let xml_data = b"<root><child>Text</child><last/></root>".to_vec();
-    /// let document = Document::new(xml_data).unwrap();
-    /// let root_node = document.root().unwrap();
-    /// let descendants: Vec<Node> = document.descendants(root_node.idx()).collect();
+    /// let mut document = Document::new(b"<root><a>  Text\n</a></root>".to_vec()).unwrap();
+    /// document.trim_document().unwrap();
     ///
-    /// assert_eq!(descendants.len(), 3); // child, Text, and last
-    /// assert!(descendants[0].is("child"));
-    /// assert_eq!(descendants[1].text().unwrap(), "Text");
-    /// assert!(descendants[2].is("last"));
+    /// assert_eq!(document.root().unwrap().text_content(), "Text");
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn descendants(&self, node_idx: NodeIdx) -> Nodes<'_> {
-        Nodes::descendants(self, node_idx)
+    pub fn trim_document(&mut self) -> Result<usize, ParseXmlError> {
+        let mut removed = vec![false; self.nodes.len()];
+        let mut removed_count = 0;
+        let mut preserved_until = 0; // Last node of the subtree whose whitespace is preserved
+
+        for (node_idx, is_removed) in removed.iter_mut().enumerate() {
+            if node_idx <= preserved_until {
+                continue;
+            }
+            if self.nodes[node_idx].is_element()
+                && self
+                    .options
+                    .preserves_whitespace(self.element_name(node_idx as NodeIdx))
+            {
+                preserved_until = self
+                    .last_descendant(node_idx as NodeIdx)
+                    .map_or(node_idx, |idx| idx as usize);
+                continue;
+            }
+
+            let NodeType::Text(location) = self.nodes[node_idx].node_type() else {
+                continue;
+            };
+
+            #[cfg(not(feature = "use_cstr"))]
+            let (start, text) = (
+                location.start,
+                &self.xml[location.start as usize..location.end as usize],
+            );
+
+            #[cfg(feature = "use_cstr")]
+            let (start, text) = (*location, self.get_cstr_from_location(*location).to_bytes());
+
+            let trimmed_len = text.trim_ascii().len();
+            if trimmed_len == 0 {
+                *is_removed = true;
+                removed_count += 1;
+                continue;
+            }
+            if trimmed_len == text.len() {
+                continue;
+            }
+
+            let start = start + (text.len() - text.trim_ascii_start().len()) as XmlIdx;
+
+            #[cfg(not(feature = "use_cstr"))]
+            let location = start..(start + trimmed_len as XmlIdx);
+
+            #[cfg(feature = "use_cstr")]
+            let location = {
+                self.xml[start as usize + trimmed_len] = 0; // Null-terminate the string
+                start
+            };
+
+            self.nodes[node_idx].set_node_type(NodeType::Text(location));
+        }
+
+        if removed_count > 0 {
+            self.rebuild_nodes_without(&removed)?;
+        }
+
+        Ok(removed_count)
     }
 
-    /// Returns the last descendant of a given node index.
+    /// Rebuilds the node vector, leaving out the nodes (and their descendants) flagged in `removed`.
     ///
-    /// This method finds the last descendant node of the specified node index in the document.
+    /// The tree is walked in document order and the kept nodes are added again, such that the
+    /// sequential ordering of the node indexes is preserved. The created nodes waiting for
+    /// insertion are added after the tree.
+    ///
+    /// # Returns
+    /// The new index of each old node, or `0` if it was removed.
+    fn rebuild_nodes_without(&mut self, removed: &[bool]) -> Result<Vec<NodeIdx>, ParseXmlError> {
+        #[cfg(not(feature = "allocator_api"))]
+        let old_nodes = std::mem::take(&mut self.nodes);
+        #[cfg(feature = "allocator_api")]
+        let old_nodes = {
+            let allocator = *self.nodes.allocator();
+            std::mem::replace(&mut self.nodes, Vec::new_in(allocator))
+        };
+        self.nodes.reserve_exact(old_nodes.len());
+
+        #[cfg(not(feature = "forward_only"))]
+        self.nodes.push(NodeInfo::new(0, 0, NodeType::Head));
+        #[cfg(feature = "forward_only")]
+        self.nodes.push(NodeInfo::new(NodeType::Head));
+
+        let mut new_indexes: Vec<NodeIdx> = vec![0; old_nodes.len()];
+        self.line_offsets = OnceLock::new();
+        self.languages = OnceLock::new();
+        self.bases = OnceLock::new();
+        self.ids = OnceLock::new();
+        self.attribute_order = OnceLock::new();
+
+        self.copy_children(&old_nodes, 0, 0, removed, &mut new_indexes)?;
+
+        self.detached_from = None;
+        for root_idx in std::mem::take(&mut self.detached_roots) {
+            if removed[root_idx as usize] {
+                continue;
+            }
+
+            let node_idx =
+                self.add_detached_node(old_nodes[root_idx as usize].node_type().clone())?;
+            new_indexes[root_idx as usize] = node_idx;
+            self.copy_children(&old_nodes, root_idx, node_idx, removed, &mut new_indexes)?;
+        }
+
+        self.cdata_nodes.retain_mut(|node_idx| {
+            *node_idx = new_indexes[*node_idx as usize];
+            *node_idx != 0
+        });
+
+        self.raw_texts.retain_mut(|(node_idx, _)| {
+            *node_idx = new_indexes[*node_idx as usize];
+            *node_idx != 0
+        });
+
+        self.element_spans.retain_mut(|(node_idx, _)| {
+            *node_idx = new_indexes[*node_idx as usize];
+            *node_idx != 0
+        });
+
+        #[cfg(feature = "namespace_aware")]
+        self.namespaces.retain_mut(|(node_idx, _)| {
+            *node_idx = new_indexes[*node_idx as usize];
+            *node_idx != 0
+        });
+
+        Ok(new_indexes)
+    }
+
+    /// Adds again the descendants of an old node, in document order, to the new node.
+    fn copy_children(
+        &mut self,
+        old_nodes: &[NodeInfo],
+        old_parent_idx: NodeIdx,
+        new_parent_idx: NodeIdx,
+        removed: &[bool],
+        new_indexes: &mut [NodeIdx],
+    ) -> Result<(), ParseXmlError> {
+        // For each level: the next old node to visit, the new parent and its last new child
+        let mut stack: Vec<(NodeIdx, NodeIdx, NodeIdx)> = vec![(
+            old_nodes[old_parent_idx as usize].first_child_idx(),
+            new_parent_idx,
+            0,
+        )];
+
+        while let Some(&(old_idx, parent_idx, last_child_idx)) = stack.last() {
+            if old_idx == 0 {
+                stack.pop();
+                continue;
+            }
+
+            let old_info = &old_nodes[old_idx as usize];
+            let level = stack.len() - 1;
+            stack[level].0 = old_info.next_sibling_idx();
+
+            if removed[old_idx as usize] {
+                continue;
+            }
+
+            let node_idx =
+                self.add_node(parent_idx, last_child_idx, old_info.node_type().clone())?;
+            // add_node() resets the attributes range of elements
+            self.nodes[node_idx as usize].set_node_type(old_info.node_type().clone());
+
+            stack[level].2 = node_idx;
+            stack.push((old_info.first_child_idx(), node_idx, 0));
+            new_indexes[old_idx as usize] = node_idx;
+        }
+
+        Ok(())
+    }
+
+    /// Validates the document against allowlists of element tag names and attribute names.
+    ///
+    /// All element nodes of the document are visited in document order. Every element whose
+    /// tag name is not in `tags`, and every attribute whose name is not in `attrs`, is reported
+    /// as a `Violation`. This is the gate of a sanitizer for untrusted XHTML content.
     ///
     /// # Arguments
-    /// - `node_idx`: The index of the node whose last descendant is to be found.
+    /// - `tags`: The allowed element tag names.
+    /// - `attrs`: The allowed attribute names, for any element.
     ///
     /// # Returns
-    /// - `NodeIdx`: The index of the last descendant node.
-    /// - `0`: If the node index is invalid or if there are no descendants for the root node.
+    /// - `Ok(())`: If all elements and attributes are allowed.
+    /// - `Err(Vec<Violation>)`: The list of violations, in document order.
+    ///
+    /// # Errors
+    /// - `Violation::Element`: For each element with a tag name not present in `tags`.
+    /// - `Violation::Attribute`: For each attribute with a name not present in `attrs`.
     ///
     /// # Example
-    /// ```rust
+    /// ```
     /// use xhtml_parser::Document;
-    /// use xhtml_parser::Node;
+    /// use xhtml_parser::defs::Violation;
     ///
-    /// let xml_data = b"<root><child>Text</child>boo<last/></root>".to_vec();
+    /// let xml_data = b"<div><p onclick=\"go()\">Hi</p><script>x</script></div>".to_vec();
     /// let document = Document::new(xml_data).unwrap();
-    /// let root_node = document.root().unwrap();
-    /// let last_descendant_idx = document.last_descendant(root_node.idx());
+    /// let violations = document
+    ///     .validate_against_allowed(&["div", "p"], &["class"])
+    ///     .unwrap_err();
     ///
-    /// assert!(last_descendant_idx.is_some()); // There should be descendants
-    /// let last_descendant = document.get_node(last_descendant_idx.unwrap()).unwrap();
-    /// assert!(last_descendant.is("last")); // The last descendant should be "last"
-    /// assert_eq!(document.last_descendant(last_descendant.idx()), None);
+    /// assert_eq!(violations.len(), 2);
+    /// assert!(matches!(&violations[0], Violation::Attribute { name, .. } if name == "onclick"));
+    /// assert!(matches!(&violations[1], Violation::Element { tag_name, .. } if tag_name == "script"));
     /// ```
-    ///
-    /// # Notes
-    /// - The method checks if the node index is valid and returns `0` if it is not.
-    /// - If the node index is `0` or if it is the root node with no descendants, it returns `0`.
-    /// # Errors
-    /// - If the node index is invalid or out of bounds, it returns `0`.
-    /// - If the node index is `1` and there are no descendants, it returns `0`.
-    #[must_use]
-    pub fn last_descendant(&self, node_idx: NodeIdx) -> Option<NodeIdx> {
-        if node_idx == 0
-            || self.nodes[node_idx as usize].first_child_idx() == 0
-            || node_idx as usize >= (self.nodes.len() - 1)
-        {
-            None // Invalid node index, or there is no node following that node
-        } else if node_idx == 1 {
-            // If the node is the root, return the last node index
-            Some(self.last_node_idx())
-        } else {
-            #[cfg(not(feature = "forward_only"))]
-            {
-                let mut up_idx = self.nodes[node_idx as usize].parent_idx;
-                let mut last_descendant = self.nodes[up_idx as usize].next_sibling_idx();
-                while last_descendant == 0 {
-                    up_idx = self.nodes[up_idx as usize].parent_idx;
-                    if up_idx <= 1 {
-                        last_descendant = self.nodes.len() as NodeIdx; // No more parents, will return the last node_idx
-                        break;
-                    }
-                    last_descendant = self.nodes[up_idx as usize].next_sibling_idx();
-                }
-
-                Some(last_descendant - 1)
+    pub fn validate_against_allowed(
+        &self,
+        tags: &[&str],
+        attrs: &[&str],
+    ) -> Result<(), Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for node in self.all_nodes().filter(Node::is_element) {
+            if !tags.contains(&node.tag_name()) {
+                violations.push(Violation::Element {
+                    node_idx: node.idx(),
+                    tag_name: node.tag_name().to_string(),
+                });
             }
-
-            #[cfg(feature = "forward_only")]
-            {
-                let mut curr_node_idx = self.nodes[node_idx as usize].first_child_idx();
-                // Start from the first child of the node
-
-                loop {
-                    while self.nodes[curr_node_idx as usize].next_sibling_idx() != 0 {
-                        curr_node_idx = self.nodes[curr_node_idx as usize].next_sibling_idx();
-                    }
-                    if self.nodes[curr_node_idx as usize].first_child_idx() != 0 {
-                        curr_node_idx = self.nodes[curr_node_idx as usize].first_child_idx();
-                    } else {
-                        break; // Found the last descendant
-                    }
+            for attr in node.attributes() {
+                if !attrs.contains(&attr.name()) {
+                    violations.push(Violation::Attribute {
+                        node_idx: node.idx(),
+                        name: attr.name().to_string(),
+                    });
                 }
-                Some(curr_node_idx)
             }
         }
-    }
 
-    /// Returns the next sequential node after the node index parameter.
-    #[inline]
-    #[must_use]
-    pub fn next_seq_node(&self, current: NodeIdx) -> Option<Node<'_>> {
-        let next = current + 1;
-        if next < self.nodes.len() as NodeIdx {
-            self.get_node(next).ok()
+        if violations.is_empty() {
+            Ok(())
         } else {
-            None
+            Err(violations)
         }
     }
+}
+
+/// Parses a `&str` into a `Document` owning a copy of the XML content.
+///
+/// See `Document::from_string()`.
+impl FromStr for Document<'_> {
+    type Err = ParseXmlError;
 
-    /// Returns the previous sequential node before the node index parameter.
     #[inline]
-    #[must_use]
-    pub fn previous_seq_node(&self, current: NodeIdx) -> Option<Node<'_>> {
-        let previous = current - 1;
-        if previous > 0 {
-            self.get_node(previous).ok()
-        } else {
-            None
-        }
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        Self::from_string(xml.to_string())
     }
 }
 
-impl fmt::Debug for Document {
+impl fmt::Debug for Document<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         if let Some(root) = self.root() {
             // write!(f, "Document [{}]", root.tag_name())?;
@@ -648,50 +3078,121 @@ impl fmt::Debug for Document {
                 Ok(())
             }
 
-            fn print_node(
-                node: &Node,
-                indent: usize,
-                f: &mut fmt::Formatter,
-            ) -> Result<(), fmt::Error> {
-                if node.is_element() {
-                    writeln_indented!(indent, f, "Element {{");
-                    writeln_indented!(indent, f, "    tag_name: {:?}", node.tag_name());
-                    print_into_iter("attributes", node.attributes(), indent + 1, f)?;
-
-                    if node.has_children() {
-                        writeln_indented!(indent, f, "    children: [");
-                        print_children(node, indent + 2, f)?;
-                        writeln_indented!(indent, f, "    ]");
-                    }
+            /// Prints the outline of the visited nodes.
+            struct DebugPrinter<'f, 'a> {
+                f: &'f mut fmt::Formatter<'a>,
+                open: Vec<bool>, // If the open elements have children
+                result: fmt::Result,
+            }
 
-                    writeln_indented!(indent, f, "}}");
-                } else if node.is_text() {
-                    writeln_indented!(indent, f, "Text {{");
-                    writeln_indented!(indent, f, "    \"{}\"", node.text().unwrap_or("No text"));
-                    writeln_indented!(indent, f, "}}");
-                    //writeln_indented!(indent, f, "{:?}", node);
-                    // } else if node.is_root() {
-                    //     writeln_indented!(indent, f, "Root {{}}");
-                } else {
-                    writeln_indented!(indent, f, "Unknown Node!");
+            impl DebugPrinter<'_, '_> {
+                /// Runs a print operation at the indentation of the visited node, unless a
+                /// previous one failed.
+                fn print(&mut self, print: impl FnOnce(&mut fmt::Formatter, usize) -> fmt::Result) {
+                    if self.result.is_ok() {
+                        self.result = print(self.f, 1 + 2 * self.open.len());
+                    }
                 }
-                Ok(())
             }
 
-            fn print_children(
-                parent: &Node,
-                indent: usize,
-                f: &mut fmt::Formatter,
-            ) -> Result<(), fmt::Error> {
-                for child in parent.children() {
-                    print_node(&child, indent, f)?;
+            impl<'xml> Visitor<'xml> for DebugPrinter<'_, '_> {
+                fn enter_element(&mut self, element: &Node<'xml>) -> WalkAction {
+                    let has_children = element.has_children();
+                    self.print(|f, indent| {
+                        writeln_indented!(indent, f, "Element {{");
+                        writeln_indented!(indent, f, "    tag_name: {:?}", element.tag_name());
+                        print_into_iter("attributes", element.attributes(), indent + 1, f)?;
+                        if has_children {
+                            writeln_indented!(indent, f, "    children: [");
+                        }
+                        Ok(())
+                    });
+                    self.open.push(has_children);
+                    WalkAction::Continue
                 }
 
-                Ok(())
+                fn leave_element(&mut self, _element: &Node<'xml>) {
+                    let has_children = self.open.pop().unwrap_or_default();
+                    self.print(|f, indent| {
+                        if has_children {
+                            writeln_indented!(indent, f, "    ]");
+                        }
+                        writeln_indented!(indent, f, "}}");
+                        Ok(())
+                    });
+                }
+
+                fn text(&mut self, text: &Node<'xml>) {
+                    self.print(|f, indent| {
+                        writeln_indented!(indent, f, "Text {{");
+                        writeln_indented!(
+                            indent,
+                            f,
+                            "    \"{}\"",
+                            text.text().unwrap_or("No text")
+                        );
+                        writeln_indented!(indent, f, "}}");
+                        Ok(())
+                    });
+                }
+
+                fn comment(&mut self, comment: &Node<'xml>) {
+                    self.print(|f, indent| {
+                        writeln_indented!(indent, f, "Comment {{");
+                        writeln_indented!(
+                            indent,
+                            f,
+                            "    \"{}\"",
+                            comment.comment().unwrap_or_default()
+                        );
+                        writeln_indented!(indent, f, "}}");
+                        Ok(())
+                    });
+                }
+
+                fn processing_instruction(&mut self, pi: &Node<'xml>) {
+                    self.print(|f, indent| {
+                        writeln_indented!(indent, f, "ProcessingInstruction {{");
+                        writeln_indented!(
+                            indent,
+                            f,
+                            "    target: {:?}",
+                            pi.pi_target().unwrap_or_default()
+                        );
+                        writeln_indented!(
+                            indent,
+                            f,
+                            "    data: {:?}",
+                            pi.pi_data().unwrap_or_default()
+                        );
+                        writeln_indented!(indent, f, "}}");
+                        Ok(())
+                    });
+                }
+
+                fn entity_ref(&mut self, entity_ref: &Node<'xml>) {
+                    self.print(|f, indent| {
+                        writeln_indented!(indent, f, "EntityRef {{");
+                        writeln_indented!(
+                            indent,
+                            f,
+                            "    name: {:?}",
+                            entity_ref.entity_name().unwrap_or_default()
+                        );
+                        writeln_indented!(indent, f, "}}");
+                        Ok(())
+                    });
+                }
             }
 
             writeln!(f, "Document [")?;
-            print_node(&root, 1, f)?;
+            let mut printer = DebugPrinter {
+                f,
+                open: Vec::new(),
+                result: Ok(()),
+            };
+            root.walk(&mut printer);
+            printer.result?;
             writeln!(f, "]")?;
 
             Ok(())
@@ -745,7 +3246,7 @@ impl<'a> Nodes<'a> {
     /// - `Nodes`: An iterator that yields `Node` instances representing the nodes in the document.
     #[inline]
     #[must_use]
-    pub fn new(document: &'a Document) -> Self {
+    pub fn new(document: &'a Document<'a>) -> Self {
         let last_node_idx = document.last_node_idx();
         if last_node_idx == 0 {
             return Nodes {
@@ -772,7 +3273,7 @@ impl<'a> Nodes<'a> {
     /// - `Nodes`: An iterator that yields `Node` instances representing the descendants of the specified node.
     #[inline]
     #[must_use]
-    pub fn descendants(document: &'a Document, node_idx: NodeIdx) -> Self {
+    pub fn descendants(document: &'a Document<'a>, node_idx: NodeIdx) -> Self {
         match document.last_descendant(node_idx) {
             None => Nodes {
                 front: None,
@@ -833,6 +3334,63 @@ impl DoubleEndedIterator for Nodes<'_> {
     }
 }
 
+/// Returns the column number of the character following the given start of a line, counting
+/// the UTF-8 characters (the bytes that are not continuation bytes).
+pub(crate) fn column_number(line_start: &[u8]) -> usize {
+    line_start
+        .iter()
+        .filter(|&&byte| byte & 0xC0 != 0x80)
+        .count()
+        + 1
+}
+
+/// The span of an element in the XML content, recorded with the `keep_element_spans` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ElementSpan {
+    pub(crate) outer: Range<XmlIdx>, // From the '<' of the start tag to the end of the end tag
+    pub(crate) inner: Range<XmlIdx>, // Between the start tag and the end tag
+}
+
+/// The scopes of an inherited attribute, such as `xml:lang`, in a document.
+///
+/// The nodes of a subtree having consecutive indexes, the document is split into spans of
+/// nodes sharing the same declaring element. Looking up the declaration in scope for a node
+/// is then a binary search, without climbing the tree.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct AttributeScopes {
+    spans: Vec<(NodeIdx, NodeIdx)>, // First node index of the span, declaring element index
+    outer: Vec<(NodeIdx, NodeIdx)>, // Declaring element index, declaration in scope for its parent
+}
+
+impl AttributeScopes {
+    /// Adds a span, replacing the previous one if it starts at the same node.
+    fn push_span(&mut self, start: NodeIdx, decl: NodeIdx) {
+        match self.spans.last_mut() {
+            Some(last) if last.0 == start => last.1 = decl,
+            _ => self.spans.push((start, decl)),
+        }
+    }
+
+    /// Returns the index of the element whose declaration is in scope for the node, or `0`.
+    pub(crate) fn declaration(&self, node_idx: NodeIdx) -> NodeIdx {
+        let pos = self.spans.partition_point(|(start, _)| *start <= node_idx);
+
+        if pos == 0 {
+            0
+        } else {
+            self.spans[pos - 1].1
+        }
+    }
+
+    /// Returns the index of the element whose declaration is in scope for the parent of the
+    /// declaring element, or `0`.
+    pub(crate) fn outer_declaration(&self, decl_idx: NodeIdx) -> NodeIdx {
+        self.outer
+            .binary_search_by_key(&decl_idx, |(idx, _)| *idx)
+            .map_or(0, |pos| self.outer[pos].1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,262 @@
+//! Character encoding detection.
+//!
+//! The parser works on UTF-8 content. This module implements the encoding sniffing of
+//! the XML specification (Appendix F): the byte order mark is inspected first, then the
+//! `encoding` pseudo-attribute of the XML declaration. UTF-16 content is transcoded to
+//! UTF-8 before parsing, and other declared encodings are rejected with a
+//! `ParseXmlError::InvalidEncoding` error, instead of producing "non valid utf-8" strings.
+//!
+//! With the `legacy_encodings` parsing option, ISO-8859-1 (Latin-1) and Windows-1252
+//! content is also transcoded to UTF-8. Content without byte order mark or encoding
+//! declaration that is not valid UTF-8 is then decoded as Windows-1252, as is common
+//! with older HTML files.
+//!
+//! The `invalid_utf8` parsing option selects how the UTF-8 content containing invalid
+//! sequences is handled: kept as-is (the default), rejected, or repaired.
+//!
+//! # Example
+//! ```
+//! use xhtml_parser::Document;
+//! use xhtml_parser::encoding::{detect, Encoding};
+//!
+//! // "<a>é</a>" in UTF-16 little-endian, with a byte order mark
+//! let mut xml_data = vec![0xFF, 0xFE];
+//! for unit in "<a>é</a>".encode_utf16() {
+//!     xml_data.extend_from_slice(&unit.to_le_bytes());
+//! }
+//!
+//! assert_eq!(detect(&xml_data).unwrap(), Encoding::Utf16Le);
+//!
+//! let document = Document::new(xml_data).unwrap();
+//! assert_eq!(document.root().unwrap().first_child().unwrap().text(), Some("é"));
+//! ```
+
+use crate::defs::ParseXmlError;
+use crate::parser::{InvalidUtf8, ParserOptions};
+use crate::xml_buffer::XmlBuffer;
+
+/// The character encodings supported by the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// UTF-8, or one of its subsets (US-ASCII)
+    Utf8,
+    /// UTF-16, little-endian
+    Utf16Le,
+    /// UTF-16, big-endian
+    Utf16Be,
+    /// ISO-8859-1 (Latin-1), decoded with the `legacy_encodings` parsing option
+    Latin1,
+    /// Windows-1252, decoded with the `legacy_encodings` parsing option
+    Windows1252,
+}
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16BE_BOM: &[u8] = b"\xFE\xFF";
+
+/// The characters of the 0x80..=0x9F range of Windows-1252. The unassigned bytes are mapped
+/// to the C1 control characters, as with ISO-8859-1.
+const WINDOWS_1252_C1: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// The names of the supported encodings, as found in the XML declaration.
+const ENCODING_NAMES: &[(&str, Encoding)] = &[
+    ("utf-8", Encoding::Utf8),
+    ("utf8", Encoding::Utf8),
+    ("us-ascii", Encoding::Utf8),
+    ("ascii", Encoding::Utf8),
+    ("iso-8859-1", Encoding::Latin1),
+    ("iso8859-1", Encoding::Latin1),
+    ("iso_8859-1", Encoding::Latin1),
+    ("latin1", Encoding::Latin1),
+    ("l1", Encoding::Latin1),
+    ("windows-1252", Encoding::Windows1252),
+    ("cp1252", Encoding::Windows1252),
+];
+
+/// Detects the character encoding of the XML content.
+///
+/// The byte order mark is used if present. Otherwise, UTF-16 content is recognized by the
+/// encoding of its leading `<?`, and the `encoding` pseudo-attribute of the XML declaration
+/// is checked. Content without byte order mark or declaration is considered UTF-8.
+///
+/// # Arguments
+/// * `xml` - The XML content
+///
+/// # Returns
+/// The encoding of the content
+///
+/// # Errors
+/// Returns `ParseXmlError::InvalidEncoding` if the XML declaration of content that is not
+/// UTF-16 names an encoding other than UTF-8, US-ASCII, ISO-8859-1 or Windows-1252.
+pub fn detect(xml: &[u8]) -> Result<Encoding, ParseXmlError> {
+    if xml.starts_with(UTF16LE_BOM) || xml.starts_with(b"<\0?\0") {
+        return Ok(Encoding::Utf16Le);
+    }
+    if xml.starts_with(UTF16BE_BOM) || xml.starts_with(b"\0<\0?") {
+        return Ok(Encoding::Utf16Be);
+    }
+
+    let xml = xml.strip_prefix(UTF8_BOM).unwrap_or(xml);
+    let Some(name) = declared_encoding(xml) else {
+        return Ok(Encoding::Utf8);
+    };
+
+    ENCODING_NAMES
+        .iter()
+        .find(|(supported, _)| name.eq_ignore_ascii_case(supported.as_bytes()))
+        .map(|(_, encoding)| *encoding)
+        .ok_or_else(|| unsupported(name))
+}
+
+/// Returns the error reporting an unsupported encoding.
+///
+/// # Arguments
+/// * `name` - The name of the encoding
+fn unsupported(name: &[u8]) -> ParseXmlError {
+    ParseXmlError::InvalidEncoding(format!(
+        "Unsupported encoding '{}'",
+        String::from_utf8_lossy(name)
+    ))
+}
+
+/// Returns the value of the `encoding` pseudo-attribute of the XML declaration, if any.
+///
+/// # Arguments
+/// * `xml` - The XML content, in an ASCII-compatible encoding, without byte order mark
+fn declared_encoding(xml: &[u8]) -> Option<&[u8]> {
+    let declaration = xml.strip_prefix(b"<?xml")?;
+    if !declaration.first().is_some_and(u8::is_ascii_whitespace) {
+        return None;
+    }
+    let declaration = &declaration[..declaration.windows(2).position(|w| w == b"?>")?];
+
+    let pos = declaration.windows(8).position(|w| w == b"encoding")?;
+    let rest = declaration[pos + 8..]
+        .trim_ascii_start()
+        .strip_prefix(b"=")?;
+    let rest = rest.trim_ascii_start();
+    let quote = *rest.first().filter(|&&q| q == b'"' || q == b'\'')?;
+    let value = &rest[1..];
+    Some(&value[..value.iter().position(|&b| b == quote)?])
+}
+
+/// Converts the XML content to UTF-8, according to its detected encoding.
+///
+/// UTF-8 content is returned as-is. UTF-16 content is transcoded into an owned buffer,
+/// without its byte order mark. With the `legacy_encodings` option, ISO-8859-1 and
+/// Windows-1252 content is transcoded into an owned buffer, and undeclared content that
+/// is not valid UTF-8 is decoded as Windows-1252. The other UTF-8 content that is not valid
+/// is handled according to the `invalid_utf8` option.
+///
+/// # Arguments
+/// * `xml` - The XML content
+/// * `options` - The parsing options
+///
+/// # Errors
+/// Returns `ParseXmlError::InvalidEncoding` if the encoding is not supported, if the
+/// UTF-16 content is malformed, or if invalid UTF-8 content is rejected.
+pub(crate) fn to_utf8<'a>(
+    xml: XmlBuffer<'a>,
+    options: &ParserOptions,
+) -> Result<XmlBuffer<'a>, ParseXmlError> {
+    match detect(&xml)? {
+        Encoding::Utf8 => {
+            if !options.legacy_encodings && options.invalid_utf8 == InvalidUtf8::PassThrough {
+                return Ok(xml);
+            }
+            let Err(err) = std::str::from_utf8(&xml) else {
+                return Ok(xml);
+            };
+
+            let undeclared = !xml.starts_with(UTF8_BOM) && declared_encoding(&xml).is_none();
+            if options.legacy_encodings && undeclared {
+                return Ok(decode_single_byte(&xml, Encoding::Windows1252));
+            }
+
+            match options.invalid_utf8 {
+                InvalidUtf8::PassThrough => Ok(xml),
+                InvalidUtf8::Reject => Err(ParseXmlError::InvalidEncoding(format!(
+                    "Invalid UTF-8 sequence at position {}",
+                    err.valid_up_to()
+                ))),
+                InvalidUtf8::Replace => Ok(XmlBuffer::Owned(
+                    String::from_utf8_lossy(&xml).into_owned().into_bytes(),
+                )),
+            }
+        }
+        Encoding::Utf16Le => decode_utf16(&xml, true),
+        Encoding::Utf16Be => decode_utf16(&xml, false),
+        encoding @ (Encoding::Latin1 | Encoding::Windows1252) => {
+            if options.legacy_encodings {
+                Ok(decode_single_byte(&xml, encoding))
+            } else {
+                Err(ParseXmlError::InvalidEncoding(format!(
+                    "Encoding {encoding:?} requires the legacy_encodings parsing option"
+                )))
+            }
+        }
+    }
+}
+
+/// Transcodes UTF-16 content to UTF-8.
+///
+/// # Arguments
+/// * `xml` - The UTF-16 content, with or without byte order mark
+/// * `little_endian` - True if the content is little-endian
+fn decode_utf16(xml: &[u8], little_endian: bool) -> Result<XmlBuffer<'static>, ParseXmlError> {
+    let content = xml
+        .strip_prefix(if little_endian {
+            UTF16LE_BOM
+        } else {
+            UTF16BE_BOM
+        })
+        .unwrap_or(xml);
+    if content.len() % 2 != 0 {
+        return Err(ParseXmlError::InvalidEncoding(
+            "UTF-16 content with an odd number of bytes".to_string(),
+        ));
+    }
+
+    let units = content.chunks_exact(2).map(|pair| {
+        if little_endian {
+            u16::from_le_bytes([pair[0], pair[1]])
+        } else {
+            u16::from_be_bytes([pair[0], pair[1]])
+        }
+    });
+
+    let transcoded = char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|err| {
+            ParseXmlError::InvalidEncoding(format!(
+                "Invalid UTF-16 content: unpaired surrogate {:#06x}",
+                err.unpaired_surrogate()
+            ))
+        })?;
+
+    Ok(XmlBuffer::Owned(transcoded.into_bytes()))
+}
+
+/// Transcodes ISO-8859-1 or Windows-1252 content to UTF-8.
+///
+/// # Arguments
+/// * `xml` - The content
+/// * `encoding` - The encoding of the content, `Encoding::Latin1` or `Encoding::Windows1252`
+pub(crate) fn decode_single_byte(xml: &[u8], encoding: Encoding) -> XmlBuffer<'static> {
+    let transcoded: String = xml
+        .iter()
+        .map(|&byte| match byte {
+            0x80..=0x9F if encoding == Encoding::Windows1252 => {
+                WINDOWS_1252_C1[(byte - 0x80) as usize]
+            }
+            _ => char::from(byte),
+        })
+        .collect();
+
+    XmlBuffer::Owned(transcoded.into_bytes())
+}
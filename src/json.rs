@@ -0,0 +1,345 @@
+//! Export of a document to JSON.
+//!
+//! The node tree is written as a compact JSON tree, handy for debugging, for piping into
+//! JavaScript tooling, or for golden-file tests:
+//!
+//! - An element is an object with a `name` member, an `attributes` member if it has
+//!   attributes, and a `children` array member if it has children.
+//! - A text node (CDATA sections included) is a string.
+//! - A comment is an object with a `comment` member.
+//! - A processing instruction is an object with `target` and `data` members.
+//! - An entity reference is an object with an `entity` member, holding the entity name.
+//!
+//! As for the XML serialization, what the parser did not keep is not exported.
+
+use std::io::{self, Write};
+
+use crate::document::Document;
+use crate::node::Node;
+use crate::node_type::NodeType;
+
+impl Document<'_> {
+    /// Exports the document to a JSON string.
+    ///
+    /// # Returns
+    /// The JSON tree of the root element, or `null` if the document has no root element.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root a=\"1\"><b>Fish and Chips</b><c/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(
+    ///     document.to_json(),
+    ///     r#"{"name":"root","attributes":{"a":"1"},"children":[{"name":"b","children":["Fish and Chips"]},{"name":"c"}]}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.to_json_with(&JsonOptions::default())
+    }
+
+    /// Exports the document to a JSON string, using the given options.
+    ///
+    /// # Arguments
+    /// * `options` - The export options
+    ///
+    /// # Returns
+    /// The JSON tree of the root element, or `null` if the document has no root element.
+    #[must_use]
+    pub fn to_json_with(&self, options: &JsonOptions) -> String {
+        let mut json = Vec::new();
+        // Writing to a vector cannot fail
+        let _ = self.write_json_with(&mut json, options);
+        // Only valid UTF-8 strings are written
+        String::from_utf8(json).unwrap_or_default()
+    }
+
+    /// Exports the document as JSON to the given writer, using the given options.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination of the JSON content
+    /// * `options` - The export options
+    ///
+    /// # Errors
+    /// Returns the errors reported by the writer.
+    pub fn write_json_with<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &JsonOptions,
+    ) -> io::Result<()> {
+        match self.root() {
+            Some(root) => write_subtree(writer, &root, options),
+            None => writer.write_all(b"null"),
+        }
+    }
+}
+
+impl Node<'_> {
+    /// Exports the node and its descendants to a JSON string.
+    ///
+    /// # Returns
+    /// The JSON tree of the node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><body><p>Some <b>bold</b> text</p></body></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let p = document.find_first("p").unwrap();
+    ///
+    /// assert_eq!(
+    ///     p.to_json(),
+    ///     r#"{"name":"p","children":["Some ",{"name":"b","children":["bold"]}," text"]}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.to_json_with(&JsonOptions::default())
+    }
+
+    /// Exports the node and its descendants to a JSON string, using the given options.
+    ///
+    /// # Arguments
+    /// * `options` - The export options
+    ///
+    /// # Returns
+    /// The JSON tree of the node.
+    #[must_use]
+    pub fn to_json_with(&self, options: &JsonOptions) -> String {
+        let mut json = Vec::new();
+        // Writing to a vector cannot fail
+        let _ = write_subtree(&mut json, self, options);
+        // Only valid UTF-8 strings are written
+        String::from_utf8(json).unwrap_or_default()
+    }
+}
+
+/// Options of the JSON export.
+///
+/// By default, the attributes of an element are exported as an object mapping their names to
+/// their values, and adjacent text nodes (as produced by CDATA sections) are merged into a
+/// single string.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::json::JsonOptions;
+/// use xhtml_parser::parser::ParserOptions;
+///
+/// let xml_data = b"<a x=\"1\" y=\"2\">One <![CDATA[& two]]></a>".to_vec();
+/// let document = Document::new_with_options(xml_data, ParserOptions::new().keep_cdata(true)).unwrap();
+///
+/// assert_eq!(
+///     document.to_json(),
+///     r#"{"name":"a","attributes":{"x":"1","y":"2"},"children":["One & two"]}"#
+/// );
+///
+/// let options = JsonOptions::new().attributes_as_object(false).merge_text(false);
+///
+/// assert_eq!(
+///     document.to_json_with(&options),
+///     r#"{"name":"a","attributes":[["x","1"],["y","2"]],"children":["One ","& two"]}"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct JsonOptions {
+    attributes_as_object: bool,
+    merge_text: bool,
+}
+
+impl Default for JsonOptions {
+    #[inline]
+    fn default() -> Self {
+        JsonOptions {
+            attributes_as_object: true,
+            merge_text: true,
+        }
+    }
+}
+
+impl JsonOptions {
+    /// Creates the default export options.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects if the attributes are exported as an object mapping their names to their
+    /// values, or as an array of `[name, value]` pairs keeping their order and duplicates.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to export the attributes as an object
+    #[inline]
+    pub fn attributes_as_object(mut self, enabled: bool) -> Self {
+        self.attributes_as_object = enabled;
+        self
+    }
+
+    /// Selects if adjacent text nodes are merged into a single string.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to merge the adjacent text nodes
+    #[inline]
+    pub fn merge_text(mut self, enabled: bool) -> Self {
+        self.merge_text = enabled;
+        self
+    }
+
+    /// Returns true if the attributes are exported as an object.
+    #[inline]
+    #[must_use]
+    pub fn has_attributes_as_object(&self) -> bool {
+        self.attributes_as_object
+    }
+
+    /// Returns true if the adjacent text nodes are merged.
+    #[inline]
+    #[must_use]
+    pub fn has_merge_text(&self) -> bool {
+        self.merge_text
+    }
+}
+
+/// Writes the node and its descendants, without recursion so that deeply nested documents
+/// cannot overflow the stack.
+fn write_subtree<W: Write>(writer: &mut W, node: &Node, options: &JsonOptions) -> io::Result<()> {
+    // The elements whose children are being written
+    let mut open: Vec<Node> = Vec::new();
+    let mut current = node.clone();
+
+    loop {
+        match current.get_node_type() {
+            NodeType::Element { .. } => {
+                write_element_start(writer, &current, options)?;
+                if let Some(first_child) = current.first_child() {
+                    writer.write_all(b",\"children\":[")?;
+                    open.push(current);
+                    current = first_child;
+                    continue;
+                }
+                writer.write_all(b"}")?;
+            }
+            NodeType::Text(_) => {
+                if options.merge_text && !open.is_empty() {
+                    let mut text = current.text().unwrap_or_default().to_string();
+                    while let Some(next) = current.next_sibling().filter(Node::is_text) {
+                        text.push_str(next.text().unwrap_or_default());
+                        current = next;
+                    }
+                    write_string(writer, &text)?;
+                } else {
+                    write_string(writer, current.text().unwrap_or_default())?;
+                }
+            }
+            NodeType::Comment(_) => {
+                writer.write_all(b"{\"comment\":")?;
+                write_string(writer, current.comment().unwrap_or_default())?;
+                writer.write_all(b"}")?;
+            }
+            NodeType::EntityRef(_) => {
+                writer.write_all(b"{\"entity\":")?;
+                write_string(writer, current.entity_name().unwrap_or_default())?;
+                writer.write_all(b"}")?;
+            }
+            NodeType::ProcessingInstruction { .. } => {
+                writer.write_all(b"{\"target\":")?;
+                write_string(writer, current.pi_target().unwrap_or_default())?;
+                writer.write_all(b",\"data\":")?;
+                write_string(writer, current.pi_data().unwrap_or_default())?;
+                writer.write_all(b"}")?;
+            }
+            NodeType::Head => writer.write_all(b"null")?,
+        }
+
+        // Move to the next sibling, closing the elements whose children are all written
+        loop {
+            if open.is_empty() {
+                return Ok(());
+            }
+            if let Some(next) = current.next_sibling() {
+                writer.write_all(b",")?;
+                current = next;
+                break;
+            }
+            if let Some(parent) = open.pop() {
+                writer.write_all(b"]}")?;
+                current = parent;
+            }
+        }
+    }
+}
+
+/// Writes the name and attributes of an element, without the closing `}`.
+fn write_element_start<W: Write>(
+    writer: &mut W,
+    node: &Node,
+    options: &JsonOptions,
+) -> io::Result<()> {
+    writer.write_all(b"{\"name\":")?;
+    write_string(writer, node.tag_name())?;
+
+    if node.attributes().next().is_none() {
+        return Ok(());
+    }
+
+    writer.write_all(if options.attributes_as_object {
+        b",\"attributes\":{"
+    } else {
+        b",\"attributes\":["
+    })?;
+    for (idx, attribute) in node.attributes().enumerate() {
+        if idx > 0 {
+            writer.write_all(b",")?;
+        }
+        if options.attributes_as_object {
+            write_string(writer, attribute.name())?;
+            writer.write_all(b":")?;
+            write_string(writer, attribute.value())?;
+        } else {
+            writer.write_all(b"[")?;
+            write_string(writer, attribute.name())?;
+            writer.write_all(b",")?;
+            write_string(writer, attribute.value())?;
+            writer.write_all(b"]")?;
+        }
+    }
+    writer.write_all(if options.attributes_as_object {
+        b"}"
+    } else {
+        b"]"
+    })
+}
+
+/// Writes the text as a JSON string, escaping the quotes, backslashes and control characters.
+fn write_string<W: Write>(writer: &mut W, text: &str) -> io::Result<()> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+
+    writer.write_all(b"\"")?;
+    for (pos, &byte) in bytes.iter().enumerate() {
+        let escape: &[u8] = match byte {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0x00..=0x1F => {
+                writer.write_all(&bytes[start..pos])?;
+                write!(writer, "\\u{byte:04x}")?;
+                start = pos + 1;
+                continue;
+            }
+            _ => continue,
+        };
+        writer.write_all(&bytes[start..pos])?;
+        writer.write_all(escape)?;
+        start = pos + 1;
+    }
+    writer.write_all(&bytes[start..])?;
+    writer.write_all(b"\"")
+}
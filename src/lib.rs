@@ -26,7 +26,9 @@
 //!
 //! - `default`: Enables the default features of the parser.
 //! - `namespace_removal`: Enables removal of XML namespaces from tag names during parsing. Default is **enabled**.
+//! - `namespace_aware`: Records the namespace declarations (`xmlns` and `xmlns:prefix` attributes) during parsing, to resolve the namespace URI of elements through `Node::namespace_uri()`. Default is *disabled*.
 //! - `parse_escapes`: Enables parsing of character escapes sequences (`&..;`) in `PCData` nodes. Default is **enabled**.
+//! - `html5_entities`: Translates the full set of HTML5 named character references (`&hellip;`, `&rarr;`, `&alpha;`, etc.), in addition to the XHTML 1.0 ones. Default is *disabled*.
 //! - `keep_ws_only_pcdata`: all `PCData` nodes that are composed of whitespace only will be kept. Default is *disabled*.
 //! - `trim_pcdata`: trim whitespaces at beginning and end of `PCData` nodes. Default is *disabled*.
 //! - `small_node_count`: Uses 16-bit indices for the nodes vector. Default is **enabled**.
@@ -40,8 +42,15 @@
 //! - `large_xml_size`: Allow XML files up to 16 Hexa-Bytes in length. Default is *disabled*.
 //! - `use_cstr`: Uses an index into a null-terminated `[u8]` slice (C-style string) instead of a `Range` to represent string locations in the XML content. Default is *disabled*.
 //! - `forward_only`: Removes node information and methods that permit going backward in the node structure. Default is *disabled*.
+//! - `css`: Adds the `css` module and the `Node::select_css()` method, selecting elements with CSS selectors. Default is *disabled*.
+//! - `fast_scan`: Uses `memchr` and SWAR (eight bytes at a time) loops instead of byte-at-a-time table lookups for the most frequent scans of the parser (whitespace, tag name ends, `PCData` and attribute value contents). Default is *disabled*.
+//! - `tokio`: Adds the `Document::from_async_reader()` method, reading the XML content from a `tokio::io::AsyncRead` source. Default is *disabled*.
+//! - `rayon`: Adds the `par` module, with the `Document::par_all_nodes()` and `Node::par_descendants()` parallel iterators. Default is *disabled*.
+//! - `allocator_api`: Adds the `Document::new_in()` method, allocating the nodes and attributes vectors with a caller-supplied allocator. Requires a nightly compiler. Default is *disabled*.
 //! - `all_features` to get all features enabled under a single one, but without the following: `xxxx_node_count`, `xxxx_attr_count`, and `xxxx_xml_size`.
 //!
+//! The `namespace_removal`, `parse_escapes`, `keep_ws_only_pcdata`, and `trim_pcdata` features only supply the default values of `ParserOptions`. These behaviors can be selected at runtime with `Document::new_with_options()`. The two whitespace options can also be selected together with `ParserOptions::whitespace_mode()`.
+//!
 //! ## Basic performance comparison
 //!
 //! For performance comparison, a series of 20 runs were done with both PUGIXML (GNU C++), roxmltree (Rust crate), and this crate, using `-O3` optimization and parsing the same 5.5 MB XML file containing 25K nodes and 25K attributes. Used the last version of PUGIXML, roxmltree, and this crate with the default options. The values shown are the average summation of the durations with their standard deviation. Results may vary depending on the computer performance and many other aspects (system load, operating system, compiler versions, enabled options/features, data caching, etc.).
@@ -177,15 +186,31 @@
 //!
 //! Initial release.
 //!
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 pub mod attribute;
+pub mod builder;
+#[cfg(feature = "css")]
+pub mod css;
 pub mod defs;
+pub mod diff;
 pub mod document;
+pub mod encoding;
+pub mod json;
 pub mod node;
 pub mod node_info;
 pub mod node_type;
+#[cfg(feature = "rayon")]
+pub mod par;
 pub mod parser;
+pub mod query;
+pub mod serializer;
+pub mod uri;
+pub mod walk;
+pub mod xml_buffer;
+pub mod xpath;
 
 pub use attribute::Attribute;
 pub use document::Document;
-pub use node::Node;
+pub use node::{Node, NodeId};
 pub use node_type::NodeType;
@@ -35,11 +35,16 @@
 //! # Note
 //! This module is part of the `xhtml_parser` crate and is designed to work with XML documents.
 
-use crate::attribute::Attributes;
+use crate::attribute::{Attribute, Attributes};
 use crate::defs::{NodeIdx, XmlIdx};
-use crate::document::{Document, Nodes};
+use crate::document::{Document, Nodes, INDEXED_ATTRIBUTES_MIN};
 use crate::node_info::NodeInfo;
 use crate::node_type::NodeType;
+use crate::uri;
+use crate::walk::{Visitor, WalkAction};
+
+use std::borrow::Cow;
+use std::ops::Range;
 
 #[cfg(feature = "use_cstr")]
 use std::ffi::CStr;
@@ -55,7 +60,29 @@ pub struct Node<'xml> {
     #[cfg(feature = "forward_only")]
     pub parent_idx: NodeIdx,
     pub node_info: &'xml NodeInfo,
-    pub doc: &'xml Document,
+    pub doc: &'xml Document<'xml>,
+}
+
+/// A copyable handle on a node of a document, independent of any borrow of the document.
+///
+/// Unlike `Node`, a `NodeId` can be stored in long-lived structures or sent to other threads.
+/// It is resolved back to a `Node` with `Document::node()`.
+///
+/// # Notes
+/// A `NodeId` is the index of the node. It is invalidated when the document nodes are
+/// compacted (`Document::remove_node()`, `Document::remove_nodes()`,
+/// `Document::trim_document()`), cleared or reparsed: it then designates another node, or
+/// none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub NodeIdx);
+
+impl NodeId {
+    /// Returns the index of the node in the document.
+    #[inline]
+    #[must_use]
+    pub fn idx(self) -> NodeIdx {
+        self.0
+    }
 }
 
 impl<'xml> Node<'xml> {
@@ -71,7 +98,7 @@ impl<'xml> Node<'xml> {
         #[cfg(feature = "forward_only")] // Only used in forward-only mode
         parent_idx: NodeIdx,
         node_info: &'xml NodeInfo,
-        doc: &'xml Document,
+        doc: &'xml Document<'xml>,
     ) -> Self {
         Node {
             idx,
@@ -89,6 +116,23 @@ impl<'xml> Node<'xml> {
         self.idx
     }
 
+    /// Returns a handle on the node, which does not borrow the document.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><child/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let id = document.root().unwrap().first_child().unwrap().id();
+    ///
+    /// assert_eq!(document.node(id).unwrap().tag_name(), "child");
+    /// ```
+    #[inline]
+    pub fn id(&self) -> NodeId {
+        NodeId(self.idx)
+    }
+
     /// Returns the index of the parent node, if it exists.
     #[inline]
     #[must_use]
@@ -134,15 +178,31 @@ impl<'xml> Node<'xml> {
         }
     }
 
+    /// Returns the tag name of the node as a byte slice.
+    /// If the node is not an element, it returns an empty slice.
+    ///
+    /// Unlike `tag_name()`, the actual name is returned, even if it is not valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<caf\xE9/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.tag_name(), "non valid utf-8");
+    /// assert_eq!(root_node.tag_name_bytes(), b"caf\xE9");
+    /// ```
     #[inline]
     #[must_use]
     pub fn tag_name_bytes(&self) -> &[u8] {
         match &self.node_info.node_type() {
             #[cfg(feature = "use_cstr")]
-            NodeType::Element { name, .. } => self.doc.get_cstr_from_location(*name).to_bytes(),
+            NodeType::Element { name, .. } => self.doc.get_bytes_from_location(*name),
 
             #[cfg(not(feature = "use_cstr"))]
-            NodeType::Element { name, .. } => &self.doc.xml[name.start as usize..name.end as usize],
+            NodeType::Element { name, .. } => self.doc.get_bytes_from_location(name.clone()),
 
             _ => b"", // No tag name for non-element nodes
         }
@@ -193,6 +253,97 @@ impl<'xml> Node<'xml> {
         self.tag_name_cstr() == tag_name
     }
 
+    /// Returns the local part of the tag name, i.e. without its namespace prefix.
+    /// If the node is not an element, it returns an empty string.
+    ///
+    /// With the `namespace_removal` option enabled, this is the same as `tag_name()`.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<svg:svg xmlns:svg=\"http://www.w3.org/2000/svg\"/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.local_name(), "svg");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn local_name(&self) -> &str {
+        let tag_name = self.tag_name();
+        tag_name
+            .split_once(':')
+            .map_or(tag_name, |(_prefix, local)| local)
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    /// Returns true if the element's namespace URI and local name match the provided ones.
+    ///
+    /// This is the proper way to match namespaced elements, as prefixes are chosen freely
+    /// by each document.
+    ///
+    /// # Arguments
+    /// - `uri`: The namespace URI to match.
+    /// - `local`: The local name to match.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<atom:feed xmlns:atom=\"http://www.w3.org/2005/Atom\"/>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert!(root_node.matches_ns("http://www.w3.org/2005/Atom", "feed"));
+    /// ```
+    ///
+    /// # Note
+    /// This method is only available when the `namespace_aware` feature is enabled
+    #[inline]
+    #[must_use]
+    pub fn matches_ns(&self, uri: &str, local: &str) -> bool {
+        self.namespace_uri() == Some(uri) && self.local_name() == local
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    /// Returns the namespace URI of the element, resolved from its prefix.
+    ///
+    /// The prefix of the element name (or the default namespace if there is no prefix) is
+    /// resolved against the `xmlns` declarations in scope when the element was parsed.
+    /// If the node is not an element, or its prefix is not bound to a namespace, it returns None.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<a:b xmlns:a=\"urn:x\"><c/></a:b>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.namespace_uri(), Some("urn:x"));
+    /// assert!(root_node.first_child().unwrap().namespace_uri().is_none());
+    /// ```
+    ///
+    /// # Note
+    /// This method is only available when the `namespace_aware` feature is enabled
+    #[must_use]
+    pub fn namespace_uri(&self) -> Option<&'xml str> {
+        let namespaces = &self.doc.namespaces;
+        namespaces
+            .binary_search_by_key(&self.idx, |(node_idx, _)| *node_idx)
+            .ok()
+            .map(|pos| {
+                #[cfg(feature = "use_cstr")]
+                {
+                    self.doc.get_str_from_location(namespaces[pos].1)
+                }
+
+                #[cfg(not(feature = "use_cstr"))]
+                self.doc.get_str_from_location(namespaces[pos].1.clone())
+            })
+    }
+
     /// Returns the text content of the node.
     /// If the node is not a text node, it returns an empty string.
     ///
@@ -225,24 +376,108 @@ impl<'xml> Node<'xml> {
         }
     }
 
+    /// Returns the original content of the text node, as found in the XML content, before
+    /// the translation of its escape sequences and line endings.
+    ///
+    /// The original content is only kept when the `keep_raw_text` parsing option is enabled.
+    /// The whitespace trimmed by the `trim_pcdata` option is not part of it. For the text
+    /// nodes not modified by the parsing, such as CDATA sections, and the ones created after
+    /// parsing, the text content itself is returned.
+    ///
+    /// # Returns
+    /// The original content, or `None` if the node is not a text node or if the
+    /// `keep_raw_text` option was not enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root>Fish &amp; Chips</root>".to_vec();
+    /// let options = ParserOptions::new().parse_escapes(true).keep_raw_text(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let text = document.root().unwrap().first_child().unwrap();
+    ///
+    /// assert_eq!(text.text(), Some("Fish & Chips"));
+    /// assert_eq!(text.raw_text(), Some("Fish &amp; Chips"));
+    /// ```
+    #[must_use]
+    pub fn raw_text(&self) -> Option<&'xml str> {
+        if !self.is_text() || !self.doc.options.keep_raw_text {
+            return None;
+        }
+
+        match self
+            .doc
+            .raw_texts
+            .binary_search_by_key(&self.idx(), |(node_idx, _)| *node_idx)
+        {
+            Ok(pos) => {
+                let range = self.doc.raw_texts[pos].1.clone();
+                Some(
+                    std::str::from_utf8(&self.doc.raw_text_buffer[range])
+                        .unwrap_or("non valid utf-8"),
+                )
+            }
+            Err(_) => self.text(),
+        }
+    }
+
+    /// Returns the text content of the node as a byte slice.
+    /// If the node is not a text node, it returns `None`.
+    ///
+    /// Unlike `text()`, the actual content is returned, even if it is not valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>caf\xE9</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let text_node = document.root().unwrap().first_child().unwrap();
+    ///
+    /// assert_eq!(text_node.text(), Some("non valid utf-8"));
+    /// assert_eq!(text_node.text_bytes(), Some(&b"caf\xE9"[..]));
+    /// ```
     #[inline]
     #[must_use]
     pub fn text_bytes(&self) -> Option<&'xml [u8]> {
         match &self.node_info.node_type() {
             #[cfg(not(feature = "use_cstr"))]
             NodeType::Text(text_location) => {
-                Some(&self.doc.xml[text_location.start as usize..text_location.end as usize])
+                Some(self.doc.get_bytes_from_location(text_location.clone()))
             }
-
             #[cfg(feature = "use_cstr")]
-            NodeType::Text(text_location) => {
-                Some(self.doc.get_cstr_from_location(*text_location).to_bytes())
-            }
-
+            NodeType::Text(text_location) => Some(self.doc.get_bytes_from_location(*text_location)),
             _ => None,
         }
     }
 
+    /// Returns an iterator over the text content of the node, as byte slices of at most `chunk_size` bytes.
+    ///
+    /// This permits the streaming of a large text node to a writer or a hasher, without copying it.
+    /// The chunks are slices of the XML buffer and are not guaranteed to end on UTF-8 character
+    /// boundaries. If the node is not a text node, the iterator is empty.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>The Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let text_node = document.root().unwrap().first_child().unwrap();
+    /// let chunks: Vec<&[u8]> = text_node.to_text_events(4).collect();
+    ///
+    /// assert_eq!(chunks, vec![b"The ".as_slice(), b"Text".as_slice()]);
+    /// ```
+    #[inline]
+    pub fn to_text_events(&self, chunk_size: usize) -> core::slice::Chunks<'xml, u8> {
+        self.text_bytes().unwrap_or_default().chunks(chunk_size)
+    }
+
     #[cfg(feature = "use_cstr")]
     /// Returns the text content of the node as a CStr.
     /// If the node is not a text node, it returns None.
@@ -293,6 +528,44 @@ impl<'xml> Node<'xml> {
         Attributes::new(self)
     }
 
+    /// Returns an iterator over the attribute names of this node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root name=\"The root\" id=\"1\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let names: Vec<_> = root_node.attribute_names().collect();
+    ///
+    /// assert_eq!(names, vec!["name", "id"]);
+    /// ```
+    #[inline]
+    pub fn attribute_names(&self) -> impl DoubleEndedIterator<Item = &'xml str> {
+        self.attributes().map(|attr| attr.name())
+    }
+
+    /// Returns an iterator over the attribute names of this node, as byte slices.
+    ///
+    /// This avoids UTF-8 validation when names are simply compared against known ASCII names.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root name=\"The root\" id=\"1\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let names: Vec<_> = root_node.attribute_names_bytes().collect();
+    ///
+    /// assert_eq!(names, vec![b"name".as_slice(), b"id".as_slice()]);
+    /// ```
+    #[inline]
+    pub fn attribute_names_bytes(&self) -> impl DoubleEndedIterator<Item = &'xml [u8]> {
+        self.attributes().map(|attr| attr.name_bytes())
+    }
+
     /// Returns the first child index of the node, if it exists, None otherwise.
     ///
     /// If the node has no children, it returns None.
@@ -435,6 +708,92 @@ impl<'xml> Node<'xml> {
         }
     }
 
+    /// Returns an iterator over the siblings following the node, in document order.
+    ///
+    /// The node itself is not part of the iteration.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><h1/><p/>Text<p/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let h1 = document.find_first("h1").unwrap();
+    ///
+    /// assert_eq!(h1.next_siblings().filter(|node| node.is("p")).count(), 2);
+    /// assert_eq!(h1.next_siblings().count(), 3);
+    /// ```
+    #[inline]
+    pub fn next_siblings(&self) -> impl Iterator<Item = Node<'xml>> {
+        std::iter::successors(self.next_sibling(), Node::next_sibling)
+    }
+
+    #[cfg(not(feature = "forward_only"))]
+    /// Returns an iterator over the siblings preceding the node, from the nearest one.
+    ///
+    /// The node itself is not part of the iteration.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><a/><b/><c/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let c = document.find_first("c").unwrap();
+    /// let names: Vec<_> = c.prev_siblings().map(|node| node.tag_name().to_string()).collect();
+    ///
+    /// assert_eq!(names, ["b", "a"]);
+    /// ```
+    #[inline]
+    pub fn prev_siblings(&self) -> impl Iterator<Item = Node<'xml>> {
+        std::iter::successors(self.prev_sibling(), Node::prev_sibling)
+    }
+
+    /// Returns the next sibling of the node that is an element, skipping the text, comment and
+    /// processing instruction nodes, if it exists, None otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root>\n  <a/>\n  <b/>\n</root>".to_vec();
+    /// let options = ParserOptions::new().keep_ws_only_pcdata(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let a = document.find_first("a").unwrap();
+    ///
+    /// assert!(a.next_sibling().unwrap().is_text());
+    /// assert!(a.next_element_sibling().unwrap().is("b"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_element_sibling(&self) -> Option<Node<'xml>> {
+        self.next_siblings().find(Node::is_element)
+    }
+
+    #[cfg(not(feature = "forward_only"))]
+    /// Returns the previous sibling of the node that is an element, skipping the text, comment
+    /// and processing instruction nodes, if it exists, None otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root>\n  <a/>\n  <b/>\n</root>".to_vec();
+    /// let options = ParserOptions::new().keep_ws_only_pcdata(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let b = document.find_first("b").unwrap();
+    ///
+    /// assert!(b.prev_sibling().unwrap().is_text());
+    /// assert!(b.prev_element_sibling().unwrap().is("a"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn prev_element_sibling(&self) -> Option<Node<'xml>> {
+        self.prev_siblings().find(Node::is_element)
+    }
+
     /// Returns an iterator over the children of the node.
     /// If the node has no children, it returns an empty iterator.
     ///
@@ -477,6 +836,33 @@ impl<'xml> Node<'xml> {
         }
     }
 
+    #[cfg(feature = "forward_only")]
+    /// Returns an iterator over the children of the node, in reverse order.
+    ///
+    /// As the `forward_only` feature removes the backward links between siblings, `NodeChildren`
+    /// cannot be used as a `DoubleEndedIterator`. This method collects the children in a forward
+    /// pass into a temporary `Vec` and then yields them from last to first. This allocation is
+    /// the cost of reverse iteration in this configuration: use `children()` when the order
+    /// doesn't matter.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><child1/><child2/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let children: Vec<_> = root_node.children_rev().collect();
+    ///
+    /// assert_eq!(children.len(), 2);
+    /// assert!(children[0].is("child2"));
+    /// assert!(children[1].is("child1"));
+    /// ```
+    #[inline]
+    pub fn children_rev(&self) -> std::iter::Rev<std::vec::IntoIter<Node<'xml>>> {
+        self.children().collect::<Vec<_>>().into_iter().rev()
+    }
+
     /// Returns an iterator over all descendants of the node.
     ///
     /// This includes all children, grandchildren, and so on.
@@ -497,54 +883,507 @@ impl<'xml> Node<'xml> {
         Nodes::descendants(self.doc, self.idx)
     }
 
-    /// Returns true if the node is the root node, false otherwise.
+    /// Calls a closure on every descendant of the node, in document order.
+    ///
+    /// This is a lighter alternative to iterating over `descendants()`: the nodes are visited
+    /// through a sequential walk of the node indexes, bounded by the last descendant of the node.
+    ///
+    /// # Arguments
+    /// - `f`: The closure to call with each descendant node.
     ///
     /// # Example
     /// ```
     /// use xhtml_parser::Document;
     ///
-    /// let xml_data = b"<root><child/></root>".to_vec();
+    /// let xml_data = b"<root><child1><subchild/></child1><child2/></root>".to_vec();
     /// let document = Document::new(xml_data).unwrap();
     /// let root_node = document.root().unwrap();
+    /// let mut count = 0;
     ///
-    /// assert!(root_node.is_root());
+    /// root_node.with_each_descendant(|_| count += 1);
+    ///
+    /// assert_eq!(count, 3);
     /// ```
     #[inline]
-    #[must_use]
-    pub fn is_root(&self) -> bool {
-        self.idx == 1 // The root node is always at index 1
+    pub fn with_each_descendant(&self, mut f: impl FnMut(&Node<'xml>)) {
+        if let Some(last_idx) = self.doc.last_descendant(self.idx) {
+            for idx in (self.idx + 1)..=last_idx {
+                if let Ok(node) = self.doc.get_node(idx) {
+                    f(&node);
+                }
+            }
+        }
     }
 
-    /// Returns true if the node has children, false otherwise.
+    /// Returns the number of descendants of the node satisfying the given predicate.
+    ///
+    /// The descendants are not collected, which makes it suitable for checks scoped to a subtree.
+    ///
+    /// # Arguments
+    /// - `pred`: The predicate to be satisfied by the counted nodes.
     ///
     /// # Example
     /// ```
     /// use xhtml_parser::Document;
     ///
-    /// let xml_data = b"<root><child/></root>".to_vec();
+    /// let xml_data = b"<section><img alt=\"A\"/><p><img/></p></section>".to_vec();
     /// let document = Document::new(xml_data).unwrap();
-    /// let root_node = document.root().unwrap();
+    /// let section = document.root().unwrap();
+    /// let count = section.count_descendants_matching(|n| n.is("img") && n.get_attribute("alt").is_none());
     ///
-    /// assert!(root_node.has_children());
+    /// assert_eq!(count, 1);
     /// ```
-    #[inline]
     #[must_use]
-    pub fn has_children(&self) -> bool {
-        self.first_child_idx().is_some()
+    pub fn count_descendants_matching<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&Node<'xml>) -> bool,
+    {
+        self.descendants().filter(|node| pred(node)).count()
     }
 
-    /// Returns true if the node is a `NodeType::Element`, false otherwise.
+    /// Returns an iterator over all text nodes that are descendants of the node, in document order.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>Hello <b>World</b>!</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let texts: Vec<_> = root_node.text_descendants().map(|n| n.text().unwrap()).collect();
+    ///
+    /// assert_eq!(texts, vec!["Hello ", "World", "!"]);
+    /// ```
     #[inline]
-    #[must_use]
-    pub fn is_element(&self) -> bool {
-        matches!(self.node_info.node_type(), NodeType::Element { .. })
+    pub fn text_descendants(&self) -> impl Iterator<Item = Node<'xml>> {
+        self.descendants().filter(Node::is_text)
     }
 
-    /// Returns true if the node is a `NodeType::Text`, false otherwise.
-    #[inline]
-    #[must_use]
-    pub fn is_text(&self) -> bool {
-        matches!(self.node_info.node_type(), NodeType::Text(_))
+    /// Returns the concatenation of all text nodes that are descendants of the node.
+    ///
+    /// If the node is a text node, its own text is returned. The entity reference nodes kept
+    /// with the `keep_entity_refs` option contribute their replacement text, or the reference
+    /// itself if the entity is unknown.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>Hello <b>World</b>!</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.text_content(), "Hello World!");
+    /// ```
+    #[must_use]
+    pub fn text_content(&self) -> String {
+        if let Some(text) = self.text_fragment() {
+            text.into_owned()
+        } else {
+            self.descendants()
+                .filter_map(|node| node.text_fragment())
+                .collect()
+        }
+    }
+
+    /// Returns the text the node contributes to the text content of its ancestors: the text of
+    /// a text node, or the replacement text of an entity reference node. The reference itself
+    /// is returned for an unknown entity, as the parser keeps it without `keep_entity_refs`.
+    fn text_fragment(&self) -> Option<Cow<'xml, str>> {
+        if let Some(text) = self.text() {
+            return Some(Cow::Borrowed(text));
+        }
+
+        let name = self.entity_name()?;
+        Some(
+            self.entity_value()
+                .map_or_else(|| Cow::Owned(format!("&{name};")), Cow::Borrowed),
+        )
+    }
+
+    /// Returns the concatenation of all text nodes that are descendants of the node, with a
+    /// separator inserted between text fragments coming from distinct elements.
+    ///
+    /// The separator is only inserted when the start or the end of an element lies between two
+    /// text fragments, so that lists can be turned into lines of plain text. Comments and
+    /// processing instructions are not boundaries, and entity reference nodes are expanded as
+    /// with `text_content()`.
+    ///
+    /// # Arguments
+    /// - `sep`: The separator to insert between text fragments.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<ul><li>a</li><li>b</li></ul>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.inner_text_with_separator("\n"), "a\nb");
+    /// ```
+    #[must_use]
+    pub fn inner_text_with_separator(&self, sep: &str) -> String {
+        if let Some(text) = self.text_fragment() {
+            return text.into_owned();
+        }
+
+        /// Collects the text fragments, noting the element boundaries met between them.
+        struct SeparatedText<'s> {
+            sep: &'s str,
+            result: String,
+            boundary: bool, // An element starts or ends since the last fragment
+        }
+
+        impl SeparatedText<'_> {
+            fn push(&mut self, node: &Node) {
+                if let Some(text) = node.text_fragment() {
+                    if self.boundary && !self.result.is_empty() {
+                        self.result.push_str(self.sep);
+                    }
+                    self.result.push_str(&text);
+                    self.boundary = false;
+                }
+            }
+        }
+
+        impl<'xml> Visitor<'xml> for SeparatedText<'_> {
+            fn enter_element(&mut self, _element: &Node<'xml>) -> WalkAction {
+                self.boundary = true;
+                WalkAction::Continue
+            }
+
+            fn leave_element(&mut self, _element: &Node<'xml>) {
+                self.boundary = true;
+            }
+
+            fn text(&mut self, text: &Node<'xml>) {
+                self.push(text);
+            }
+
+            fn entity_ref(&mut self, entity_ref: &Node<'xml>) {
+                self.push(entity_ref);
+            }
+        }
+
+        let mut text = SeparatedText {
+            sep,
+            result: String::new(),
+            boundary: false,
+        };
+        self.walk(&mut text);
+        text.result
+    }
+
+    /// Returns the text content of the node as readable plain text.
+    ///
+    /// The text fragments are concatenated as with `text_content()`, then ASCII whitespace
+    /// sequences are collapsed into a single space and the result is trimmed.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<p>  Hello\n  <b>world</b> </p>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.strip_to_text(), "Hello world");
+    /// ```
+    #[must_use]
+    pub fn strip_to_text(&self) -> String {
+        Self::collapse_whitespace(&self.text_content())
+    }
+
+    /// Returns the text content of the node as readable plain text, with a space inserted
+    /// between text fragments coming from distinct elements.
+    ///
+    /// This is the same as `strip_to_text()`, but words separated only by element boundaries
+    /// (e.g. `<td>a</td><td>b</td>`) are not glued together.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<tr><td>a</td><td>b</td></tr>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.strip_to_text(), "ab");
+    /// assert_eq!(root_node.strip_to_text_with_boundaries(), "a b");
+    /// ```
+    #[must_use]
+    pub fn strip_to_text_with_boundaries(&self) -> String {
+        Self::collapse_whitespace(&self.inner_text_with_separator(" "))
+    }
+
+    /// Collapses ASCII whitespace sequences into a single space and trims the result.
+    fn collapse_whitespace(text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        for word in text.split_ascii_whitespace() {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(word);
+        }
+        result
+    }
+
+    /// Returns the byte length of the text content of the node, without building it.
+    ///
+    /// This is equal to `text_content().len()`, and permits callers to decide whether to
+    /// process or skip large text regions.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root>Hello <b>World</b>!</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.text_content_len(), 12);
+    /// ```
+    #[must_use]
+    pub fn text_content_len(&self) -> usize {
+        let fragment_len = |node: &Node| {
+            node.text_bytes()
+                .map(<[u8]>::len)
+                .or_else(|| node.text_fragment().map(|text| text.len()))
+        };
+
+        fragment_len(self).unwrap_or_else(|| {
+            self.descendants()
+                .filter_map(|node| fragment_len(&node))
+                .sum()
+        })
+    }
+
+    /// Returns true if the node is the root node, false otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><child/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert!(root_node.is_root());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.idx == 1 // The root node is always at index 1
+    }
+
+    /// Returns true if the node has children, false otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><child/></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert!(root_node.has_children());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_children(&self) -> bool {
+        self.first_child_idx().is_some()
+    }
+
+    /// Returns true if the node is a `NodeType::Element`, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn is_element(&self) -> bool {
+        matches!(self.node_info.node_type(), NodeType::Element { .. })
+    }
+
+    /// Returns true if the node is a `NodeType::Text`, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn is_text(&self) -> bool {
+        matches!(self.node_info.node_type(), NodeType::Text(_))
+    }
+
+    /// Returns true if the node is a `NodeType::Text` holding the content of a CDATA section.
+    ///
+    /// Such nodes are only created when the `keep_cdata` parsing option is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<script>a &amp; <![CDATA[if (a < b && c) {}]]></script>".to_vec();
+    /// let options = ParserOptions::new().keep_cdata(true).parse_escapes(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let script = document.root().unwrap();
+    /// let texts: Vec<(&str, bool)> = script
+    ///     .children()
+    ///     .map(|node| (node.text().unwrap(), node.is_cdata()))
+    ///     .collect();
+    ///
+    /// assert_eq!(texts, [("a & ", false), ("if (a < b && c) {}", true)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_cdata(&self) -> bool {
+        self.is_text() && self.doc.cdata_nodes.binary_search(&self.idx()).is_ok()
+    }
+
+    /// Returns true if the node is a `NodeType::Comment`, false otherwise.
+    ///
+    /// Comment nodes are only created when the `keep_comments` parsing option is enabled.
+    #[inline]
+    #[must_use]
+    pub fn is_comment(&self) -> bool {
+        matches!(self.node_info.node_type(), NodeType::Comment(_))
+    }
+
+    /// Returns true if the node is a `NodeType::ProcessingInstruction`, false otherwise.
+    ///
+    /// Processing instruction nodes are only created when the `keep_processing_instructions`
+    /// parsing option is enabled.
+    #[inline]
+    #[must_use]
+    pub fn is_processing_instruction(&self) -> bool {
+        matches!(
+            self.node_info.node_type(),
+            NodeType::ProcessingInstruction { .. }
+        )
+    }
+
+    /// Returns the target of the processing instruction, if the node is a
+    /// `NodeType::ProcessingInstruction`.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><?page-break  before ?></root>".to_vec();
+    /// let options = ParserOptions::new().keep_processing_instructions(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let pi = document.root().unwrap().first_child().unwrap();
+    ///
+    /// assert!(pi.is_processing_instruction());
+    /// assert_eq!(pi.pi_target(), Some("page-break"));
+    /// assert_eq!(pi.pi_data(), Some("before"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pi_target(&self) -> Option<&'xml str> {
+        match &self.node_info.node_type() {
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::ProcessingInstruction { target, .. } => {
+                Some(self.doc.get_str_from_location(target.clone()))
+            }
+            #[cfg(feature = "use_cstr")]
+            NodeType::ProcessingInstruction { target, .. } => {
+                Some(self.doc.get_str_from_location(*target))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the data of the processing instruction, without its surrounding whitespace, if
+    /// the node is a `NodeType::ProcessingInstruction`.
+    #[inline]
+    #[must_use]
+    pub fn pi_data(&self) -> Option<&'xml str> {
+        match &self.node_info.node_type() {
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::ProcessingInstruction { data, .. } => {
+                Some(self.doc.get_str_from_location(data.clone()))
+            }
+            #[cfg(feature = "use_cstr")]
+            NodeType::ProcessingInstruction { data, .. } => {
+                Some(self.doc.get_str_from_location(*data))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the content of the comment, located between `<!--` and `-->`, if the node is
+    /// a `NodeType::Comment`.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><!-- TODO: review -->Text</root>".to_vec();
+    /// let options = ParserOptions::new().keep_comments(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let comment = document.root().unwrap().first_child().unwrap();
+    ///
+    /// assert!(comment.is_comment());
+    /// assert_eq!(comment.comment(), Some(" TODO: review "));
+    /// assert_eq!(comment.text(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> Option<&'xml str> {
+        match &self.node_info.node_type() {
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::Comment(location) => Some(self.doc.get_str_from_location(location.clone())),
+            #[cfg(feature = "use_cstr")]
+            NodeType::Comment(location) => Some(self.doc.get_str_from_location(*location)),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the node is a `NodeType::EntityRef`, false otherwise.
+    ///
+    /// Entity reference nodes are only created when the `keep_entity_refs` parsing option is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn is_entity_ref(&self) -> bool {
+        matches!(self.node_info.node_type(), NodeType::EntityRef(_))
+    }
+
+    /// Returns the name of the referenced entity, without `&` and `;`, if the node is a
+    /// `NodeType::EntityRef`.
+    #[inline]
+    #[must_use]
+    pub fn entity_name(&self) -> Option<&'xml str> {
+        match &self.node_info.node_type() {
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::EntityRef(location) => Some(self.doc.get_str_from_location(location.clone())),
+            #[cfg(feature = "use_cstr")]
+            NodeType::EntityRef(location) => Some(self.doc.get_str_from_location(*location)),
+            _ => None,
+        }
+    }
+
+    /// Returns the replacement text of the referenced entity, if the node is a
+    /// `NodeType::EntityRef` and the entity is known: a predefined entity, an HTML5 entity
+    /// with the `html5_entities` feature, or a custom entity of the parsing options.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<p>&lt;&product;&unknown;</p>".to_vec();
+    /// let options = ParserOptions::new().keep_entity_refs(true).entity("product", "Widget");
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let values: Vec<_> = document.root().unwrap().children().map(|n| n.entity_value()).collect();
+    ///
+    /// assert_eq!(values, [Some("<"), Some("Widget"), None]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn entity_value(&self) -> Option<&'xml str> {
+        let name = self.entity_name()?;
+        self.doc
+            .named_entity(name.as_bytes())
+            .and_then(|value| std::str::from_utf8(value).ok())
     }
 
     /// Returns the `NodeType` instance associated with this node.
@@ -618,6 +1457,79 @@ impl<'xml> Node<'xml> {
         // None
     }
 
+    /// Returns an iterator over the child elements with the specified tag name.
+    ///
+    /// Where `get_child()` returns the first matching child only, this method yields all of
+    /// them, in document order.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<ul><li>1</li><hr/><li>2</li><li>3</li></ul>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let ul = document.root().unwrap();
+    /// let items: Vec<_> = ul.children_by_name("li").map(|li| li.text_content()).collect();
+    ///
+    /// assert_eq!(items, ["1", "2", "3"]);
+    /// assert_eq!(ul.children_by_name("ol").count(), 0);
+    /// ```
+    #[inline]
+    pub fn children_by_name<'a>(&self, tag_name: &'a str) -> impl Iterator<Item = Node<'xml>> + 'a
+    where
+        'xml: 'a,
+    {
+        self.children()
+            .filter(move |child| child.is_element() && child.is(tag_name))
+    }
+
+    /// Returns an iterator over the descendant elements with the specified tag name, in
+    /// document order.
+    ///
+    /// The descendants are scanned sequentially in the node vector, and the tag names are
+    /// compared as bytes, without allocating.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<body><p>1</p><div><p>2</p><span><p>3</p></span></div></body>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let div = document.find_first("div").unwrap();
+    /// let texts: Vec<_> = div.descendants_named("p").map(|p| p.text_content()).collect();
+    ///
+    /// assert_eq!(texts, ["2", "3"]);
+    /// ```
+    #[inline]
+    pub fn descendants_named<'a>(&self, tag_name: &'a str) -> impl Iterator<Item = Node<'xml>> + 'a
+    where
+        'xml: 'a,
+    {
+        self.descendants()
+            .filter(move |node| node.is_element() && node.is_bytes(tag_name.as_bytes()))
+    }
+
+    /// Returns the text content of the first child element with the specified tag name.
+    ///
+    /// This combines `get_child()` and `text_content()` in a single call.
+    /// If no such child exists, it returns None.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<record><name>John <i>Doe</i></name><age>42</age></record>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let record = document.root().unwrap();
+    ///
+    /// assert_eq!(record.get_child_text("name").unwrap(), "John Doe");
+    /// assert!(record.get_child_text("email").is_none());
+    /// ```
+    #[must_use]
+    pub fn get_child_text(&self, tag_name: &str) -> Option<String> {
+        self.get_child(tag_name).map(|child| child.text_content())
+    }
+
     /// Finds a sibling node with the specified tag name.
     /// If the node has no parent or no siblings, it returns None.
     ///
@@ -686,12 +1598,299 @@ impl<'xml> Node<'xml> {
     #[inline]
     #[must_use]
     pub fn get_attribute(&self, name: &str) -> Option<&'xml str> {
+        self.find_attribute(name.as_bytes())
+            .map(|attr| attr.value())
+    }
+
+    /// Searches for an attribute by name, using the attribute index of the document for the
+    /// elements having many attributes when the `index_attributes` parsing option is enabled.
+    pub(crate) fn find_attribute(&self, name: &[u8]) -> Option<Attribute<'xml>> {
+        if self.doc.options.index_attributes {
+            if let NodeType::Element { attributes, .. } = self.node_info.node_type() {
+                if (attributes.end - attributes.start) as usize >= INDEXED_ATTRIBUTES_MIN {
+                    return self.doc.find_indexed_attribute(attributes, name);
+                }
+            }
+        }
+
+        self.attributes().find(|attr| attr.name_bytes() == name)
+    }
+
+    /// Searches for an attribute by name and returns its value, or `default` if not found.
+    ///
+    /// The default value can be a `'static` literal or any slice living at least as long as the
+    /// returned value.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root name=\"value\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.get_attribute_or("name", "none"), "value");
+    /// assert_eq!(root_node.get_attribute_or("id", "none"), "none");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_attribute_or<'a>(&self, name: &str, default: &'a str) -> &'a str
+    where
+        'xml: 'a,
+    {
+        self.get_attribute(name).unwrap_or(default)
+    }
+
+    /// Returns true if this node has an attribute with the given name.
+    ///
+    /// The names are compared as bytes, and the attribute value is not accessed. The
+    /// attribute index is used as with `get_attribute()`.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root hidden=\"\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert!(root_node.has_attribute("hidden"));
+    /// assert!(!root_node.has_attribute("id"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.find_attribute(name.as_bytes()).is_some()
+    }
+
+    /// Returns the number of attributes of this node.
+    ///
+    /// The count is the length of the attributes range of the node: the attributes are not
+    /// iterated. Nodes other than elements have no attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root name=\"The root\" id=\"1\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.attribute_count(), 2);
+    /// assert_eq!(root_node.first_child().unwrap().attribute_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn attribute_count(&self) -> usize {
+        match self.node_info.node_type() {
+            NodeType::Element { attributes, .. } => (attributes.end - attributes.start) as usize,
+            _ => 0,
+        }
+    }
+
+    /// Transforms each attribute of this node with `f`, returning the results in attribute order.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root name=\"The root\" id=\"1\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let names = root_node.map_attributes(|attr| attr.name().to_uppercase());
+    ///
+    /// assert_eq!(names, vec!["NAME", "ID"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map_attributes<T, F>(&self, f: F) -> Vec<T>
+    where
+        F: FnMut(Attribute<'xml>) -> T,
+    {
+        self.attributes().map(f).collect()
+    }
+
+    /// Transforms each attribute of this node with the fallible `f`, returning the results in
+    /// attribute order, or the first error encountered.
+    ///
+    /// # Errors
+    /// Returns the first error returned by `f`. The remaining attributes are not processed.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root width=\"10\" height=\"20\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    /// let values = root_node.try_map_attributes(|attr| attr.value().parse::<u32>());
+    ///
+    /// assert_eq!(values.unwrap(), vec![10, 20]);
+    /// ```
+    #[inline]
+    pub fn try_map_attributes<T, E, F>(&self, f: F) -> Result<Vec<T>, E>
+    where
+        F: FnMut(Attribute<'xml>) -> Result<T, E>,
+    {
+        self.attributes().map(f).collect()
+    }
+
+    /// Returns the number of attributes of this node that satisfy the given predicate.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root id=\"r\" data-a=\"1\" data-b=\"2\">Text</root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let root_node = document.root().unwrap();
+    ///
+    /// assert_eq!(root_node.attribute_count_matching(|attr| attr.name().starts_with("data-")), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn attribute_count_matching<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&Attribute<'xml>) -> bool,
+    {
+        self.attributes().filter(pred).count()
+    }
+
+    /// Returns the effective language of the node, as declared by the `xml:lang` (or HTML
+    /// `lang`) attribute of the node or of its closest ancestor having one.
+    ///
+    /// An empty declaration (`xml:lang=""`) resets the language to unknown. The language
+    /// scopes of the whole document are computed on the first call and cached, so this method
+    /// can be called for every node of a text extraction loop without climbing the tree.
+    ///
+    /// # Returns
+    /// The language tag, or `None` if no language is declared for the node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html lang=\"en\"><p>Hello</p><p xml:lang=\"fr\">Bonjour</p></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let languages: Vec<_> = document
+    ///     .all_nodes()
+    ///     .filter(|node| node.is_text())
+    ///     .map(|node| (node.text().unwrap(), node.language()))
+    ///     .collect();
+    ///
+    /// assert_eq!(languages, [("Hello", Some("en")), ("Bonjour", Some("fr"))]);
+    /// ```
+    #[must_use]
+    pub fn language(&self) -> Option<&'xml str> {
+        let decl_idx = self.doc.language_declaration(self.idx);
+        if decl_idx == 0 {
+            return None;
+        }
+
+        self.doc
+            .get_node(decl_idx)
+            .ok()
+            .and_then(|node| node.declared_language())
+            .filter(|lang| !lang.is_empty())
+    }
+
+    /// Returns the base URL of the node, built from the `xml:base` attributes of the node and
+    /// of its ancestors.
+    ///
+    /// Relative `xml:base` values are resolved against the base URL of the enclosing elements.
+    /// The scopes of the `xml:base` attributes are computed on the first call and cached.
+    ///
+    /// # Returns
+    /// The base URL, or `None` if no `xml:base` attribute is in scope for the node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<doc xml:base=\"http://a/b/\"><ch xml:base=\"c/\"><img/></ch></doc>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(document.find_first("img").unwrap().base_url().as_deref(), Some("http://a/b/c/"));
+    /// ```
+    #[must_use]
+    pub fn base_url(&self) -> Option<String> {
+        let scopes = self.doc.base_scopes();
+        let mut decl_idx = scopes.declaration(self.idx);
+        let mut bases = Vec::new();
+
+        // Collect the declarations up to the first absolute one
+        while decl_idx != 0 {
+            let Some(base) = self
+                .doc
+                .get_node(decl_idx)
+                .ok()
+                .and_then(|node| node.declared_base())
+            else {
+                break;
+            };
+            bases.push(base);
+            if uri::has_scheme(base) {
+                break;
+            }
+            decl_idx = scopes.outer_declaration(decl_idx);
+        }
+
+        let mut bases = bases.into_iter().rev();
+        let outermost = bases.next()?.to_string();
+        Some(bases.fold(outermost, |base, relative| uri::resolve(&base, relative)))
+    }
+
+    /// Resolves a URL, such as the value of an `href` or `src` attribute, against the base URL
+    /// of the node.
+    ///
+    /// # Arguments
+    /// * `relative` - The URL to resolve
+    ///
+    /// # Returns
+    /// The resolved URL, or `relative` unchanged if no `xml:base` attribute is in scope for
+    /// the node.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<doc xml:base=\"http://a/b/c\"><a href=\"../d#e\"/></doc>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let link = document.find_first("a").unwrap();
+    ///
+    /// assert_eq!(link.resolve_url(link.get_attribute("href").unwrap()), "http://a/d#e");
+    /// ```
+    #[must_use]
+    pub fn resolve_url(&self, relative: &str) -> String {
+        match self.base_url() {
+            Some(base) => uri::resolve(&base, relative),
+            None => relative.to_string(),
+        }
+    }
+
+    /// Returns the value of the `xml:base` attribute of the node. With the namespace removal of
+    /// the `xml` prefix, it is seen as `base`.
+    pub(crate) fn declared_base(&self) -> Option<&'xml str> {
+        let name = if self.doc.options.removes_prefix(b"xml") {
+            "base"
+        } else {
+            "xml:base"
+        };
+        self.get_attribute(name)
+    }
+
+    /// Returns the value of the `xml:lang` attribute of the node, or of its `lang` attribute
+    /// if absent. With the namespace removal, `xml:lang` is seen as `lang`.
+    pub(crate) fn declared_language(&self) -> Option<&'xml str> {
+        let mut lang = None;
         for attr in self.attributes() {
-            if attr.name() == name {
-                return Some(attr.value());
+            match attr.name() {
+                "xml:lang" => return Some(attr.value()),
+                "lang" if lang.is_none() => lang = Some(attr.value()),
+                _ => {}
             }
         }
-        None
+        lang
     }
 
     /// Returns the parent node of this node, if it exists.
@@ -730,12 +1929,154 @@ impl<'xml> Node<'xml> {
         });
     }
 
+    /// Returns an iterator over the ancestors of the node: its parent, grandparent, and so on
+    /// up to the root element.
+    ///
+    /// With the `forward_only` feature, the parent links are not kept: the path from the root
+    /// element to the node is searched on the first call and collected into a temporary `Vec`,
+    /// the descendants of a node having the indexes following it.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root><a><b>Text</b></a></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let b = document.find_first("b").unwrap();
+    /// let names: Vec<_> = b.ancestors().map(|node| node.tag_name().to_string()).collect();
+    ///
+    /// assert_eq!(names, ["a", "root"]);
+    /// assert!(document.root().unwrap().ancestors().next().is_none());
+    /// ```
+    #[inline]
+    pub fn ancestors(&self) -> Ancestors<'xml> {
+        #[cfg(not(feature = "forward_only"))]
+        {
+            Ancestors {
+                next: self.parent(),
+            }
+        }
+
+        #[cfg(feature = "forward_only")]
+        {
+            let mut path = Vec::new();
+            let mut current = self.doc.root();
+            let mut found = false;
+            while let Some(node) = current {
+                if node.idx == self.idx {
+                    found = true;
+                    break;
+                }
+                current = node
+                    .children()
+                    .take_while(|child| child.idx <= self.idx)
+                    .last();
+                path.push(node);
+            }
+            if !found {
+                path.clear(); // The node is not part of the tree
+            }
+            path.reverse();
+
+            Ancestors {
+                path: path.into_iter(),
+            }
+        }
+    }
+
     /// Returns the position of this node in the XML source.
     #[inline]
     #[must_use]
     pub fn position(&self) -> XmlIdx {
         self.node_info.position()
     }
+
+    /// Returns the span of this element in the XML source, from the `<` of its start tag to
+    /// the end of its end tag, or of its self-closing start tag.
+    ///
+    /// The spans are only recorded when the `keep_element_spans` parsing option is enabled.
+    /// They permit tools to highlight or cut whole elements from the original content. An
+    /// element left unclosed when recovering from errors extends to the end of the content.
+    ///
+    /// # Returns
+    /// The span of the element, or `None` if the node is not an element parsed with the
+    /// `keep_element_spans` option.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><a href=\"x\">Link</a><br/></root>".to_vec();
+    /// let options = ParserOptions::new().keep_element_spans(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let a = document.find_first("a").unwrap();
+    ///
+    /// assert_eq!(a.span(), Some(6..26));
+    /// assert_eq!(document.find_first("br").unwrap().span(), Some(26..31));
+    /// assert_eq!(document.root().unwrap().span(), Some(0..38));
+    /// ```
+    #[must_use]
+    pub fn span(&self) -> Option<Range<XmlIdx>> {
+        let spans = &self.doc.element_spans;
+        spans
+            .binary_search_by_key(&self.idx, |(node_idx, _)| *node_idx)
+            .ok()
+            .map(|pos| spans[pos].1.outer.clone())
+    }
+
+    /// Returns the span of the content of this element in the XML source, strictly between
+    /// the `>` of its start tag and the `<` of its end tag.
+    ///
+    /// The original inner markup of the element can then be extracted from the source, for
+    /// example to feed it to a different parser, without serializing the element's children.
+    /// The content of an empty element (`<a/>`) is an empty span at the end of the element.
+    /// An element implicitly closed by another tag has its content ending at that tag.
+    ///
+    /// # Returns
+    /// The span of the element content, or `None` if the node is not an element parsed with
+    /// the `keep_element_spans` option.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<root><p class=\"x\">A <b>bold</b> move</p></root>";
+    /// let options = ParserOptions::new().keep_element_spans(true);
+    /// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+    /// let inner = document.find_first("p").unwrap().inner_span().unwrap();
+    ///
+    /// assert_eq!(&xml_data[inner.start as usize..inner.end as usize], b"A <b>bold</b> move");
+    /// ```
+    #[must_use]
+    pub fn inner_span(&self) -> Option<Range<XmlIdx>> {
+        let spans = &self.doc.element_spans;
+        spans
+            .binary_search_by_key(&self.idx, |(node_idx, _)| *node_idx)
+            .ok()
+            .map(|pos| spans[pos].1.inner.clone())
+    }
+
+    /// Returns the line number, starting at `1`, of this node in the XML source.
+    ///
+    /// This is the line of the node's `position()`: the tag name of an element, or the start of
+    /// a text node. Use `Document::line_col()` to also get the column number.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let document = Document::new(b"<root>\n  <a/>\n  <b/>\n</root>".to_vec()).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().line(), 1);
+    /// assert_eq!(document.find_first("b").unwrap().line(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.doc.line_col(self.position()).0
+    }
 }
 
 impl Eq for Node<'_> {}
@@ -809,3 +2150,31 @@ impl DoubleEndedIterator for NodeChildren<'_> {
         }
     }
 }
+
+/// Iterator over the ancestors of a node, from its parent up to the root element.
+///
+/// Returned by `Node::ancestors()`.
+#[must_use]
+pub struct Ancestors<'a> {
+    #[cfg(not(feature = "forward_only"))]
+    next: Option<Node<'a>>,
+    #[cfg(feature = "forward_only")]
+    path: std::vec::IntoIter<Node<'a>>,
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = Node<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(not(feature = "forward_only"))]
+        {
+            let node = self.next.take();
+            self.next = node.as_ref().and_then(Node::parent);
+            node
+        }
+
+        #[cfg(feature = "forward_only")]
+        self.path.next()
+    }
+}
@@ -107,6 +107,9 @@ impl NodeInfo {
     ///
     /// For Element nodes, this is the start position of the element name.
     /// For Text nodes, this is the start position of the text content.
+    /// For Comment nodes, this is the start position of the comment content.
+    /// For `ProcessingInstruction` nodes, this is the start position of the target.
+    /// For `EntityRef` nodes, this is the start position of the entity name.
     /// For the head node, this is always `0`.
     #[inline]
     #[must_use]
@@ -115,7 +118,12 @@ impl NodeInfo {
         {
             match &self.node_type {
                 NodeType::Element { name, .. } => *name,
-                NodeType::Text(location) => *location,
+                NodeType::Text(location)
+                | NodeType::Comment(location)
+                | NodeType::EntityRef(location)
+                | NodeType::ProcessingInstruction {
+                    target: location, ..
+                } => *location,
                 NodeType::Head => 0,
             }
         }
@@ -123,7 +131,12 @@ impl NodeInfo {
         #[cfg(not(feature = "use_cstr"))]
         match &self.node_type {
             NodeType::Element { name, .. } => name.start,
-            NodeType::Text(location) => location.start,
+            NodeType::Text(location)
+            | NodeType::Comment(location)
+            | NodeType::EntityRef(location)
+            | NodeType::ProcessingInstruction {
+                target: location, ..
+            } => location.start,
             NodeType::Head => 0,
         }
     }
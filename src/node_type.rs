@@ -11,6 +11,16 @@
 ///   - `attributes`: Range information for the element's attributes
 /// * `Text` - A text node containing character data between elements
 ///   - Contains location information for the text content in the source
+/// * `Comment` - A comment located inside the root element, kept when the `keep_comments`
+///   parsing option is enabled
+///   - Contains location information for the comment content (between `<!--` and `-->`)
+/// * `ProcessingInstruction` - A processing instruction located inside the root element, kept
+///   when the `keep_processing_instructions` parsing option is enabled
+///   - `target`: Location information for the target name
+///   - `data`: Location information for the data following the target
+/// * `EntityRef` - A named entity reference located in character data, kept when the
+///   `keep_entity_refs` parsing option is enabled
+///   - Contains location information for the entity name (between `&` and `;`)
 use crate::defs::{AttributeRange, XmlLocation};
 use std::fmt::Debug;
 
@@ -22,6 +32,12 @@ pub enum NodeType {
         attributes: AttributeRange,
     },
     Text(XmlLocation),
+    Comment(XmlLocation),
+    ProcessingInstruction {
+        target: XmlLocation,
+        data: XmlLocation,
+    },
+    EntityRef(XmlLocation),
 }
 
 /// Custom implementation of the `Debug` trait for `NodeType`.
@@ -32,6 +48,9 @@ pub enum NodeType {
 /// - `Root`: Displays as "Root"
 /// - `Element`: Displays as "Element(name: `the_name_range`, attributes: `the_attributes_range`)"
 /// - `Text`: Displays as "`Text(text_content_range)`"
+/// - `Comment`: Displays as "`Comment(comment_content_range)`"
+/// - `ProcessingInstruction`: Displays as "`ProcessingInstruction(target: the_target_range, data: the_data_range)`"
+/// - `EntityRef`: Displays as "`EntityRef(entity_name_range)`"
 ///
 /// This is useful for debugging and logging purposes when working with the
 /// node tree structure.
@@ -43,6 +62,14 @@ impl Debug for NodeType {
                 write!(f, "Element(name: {name:?}, attributes: {attributes:?})")
             }
             NodeType::Text(text) => write!(f, "Text({text:?})"),
+            NodeType::Comment(comment) => write!(f, "Comment({comment:?})"),
+            NodeType::EntityRef(name) => write!(f, "EntityRef({name:?})"),
+            NodeType::ProcessingInstruction { target, data } => {
+                write!(
+                    f,
+                    "ProcessingInstruction(target: {target:?}, data: {data:?})"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,115 @@
+//! Parallel iteration over the nodes of a document.
+//!
+//! This module is only available when the `rayon` feature is enabled. As the nodes of a
+//! document are kept in a flat vector, in document order, the descendants of a node occupy a
+//! contiguous range of node indexes. This range is split across the threads of the rayon pool,
+//! such that CPU-heavy post-processing (text extraction, search indexing, etc.) scales across
+//! cores. The items are produced in document order when collected.
+//!
+//! # Example
+//!
+//! ```
+//! use rayon::prelude::*;
+//! use xhtml_parser::Document;
+//!
+//! let xml_data = b"<book><p>One two</p><p>three</p><p>four five six</p></book>".to_vec();
+//! let document = Document::new(xml_data).unwrap();
+//!
+//! let words: usize = document
+//!     .par_all_nodes()
+//!     .filter_map(|node| node.text())
+//!     .map(|text| text.split_whitespace().count())
+//!     .sum();
+//!
+//! assert_eq!(words, 6);
+//! ```
+
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::defs::NodeIdx;
+use crate::document::Document;
+use crate::node::Node;
+
+#[cfg(feature = "allocator_api")]
+compile_error!(
+    "feature \"rayon\" and feature \"allocator_api\" cannot be enabled at the same time"
+);
+
+impl Document<'_> {
+    /// Returns a parallel iterator over all nodes of the document, in document order.
+    ///
+    /// This is the parallel counterpart of `all_nodes()`.
+    ///
+    /// # Returns
+    /// A parallel iterator yielding the nodes of the document tree.
+    #[inline]
+    #[must_use]
+    pub fn par_all_nodes(&self) -> impl IndexedParallelIterator<Item = Node<'_>> {
+        par_nodes(self, 1..self.last_node_idx() as usize + 1)
+    }
+
+    /// Returns a parallel iterator over the descendants of a given node, in document order.
+    ///
+    /// This is the parallel counterpart of `descendants()`.
+    ///
+    /// # Arguments
+    /// - `node_idx`: The index of the node whose descendants are to be iterated over.
+    ///
+    /// # Returns
+    /// A parallel iterator yielding the descendants of the node, without the node itself.
+    #[inline]
+    #[must_use]
+    pub fn par_descendants(
+        &self,
+        node_idx: NodeIdx,
+    ) -> impl IndexedParallelIterator<Item = Node<'_>> {
+        let first = node_idx as usize + 1;
+        let end = self
+            .last_descendant(node_idx)
+            .map_or(first, |last| last as usize + 1);
+        par_nodes(self, first..end)
+    }
+}
+
+impl<'xml> Node<'xml> {
+    /// Returns a parallel iterator over the descendants of the node, in document order.
+    ///
+    /// This is the parallel counterpart of `descendants()`.
+    ///
+    /// # Returns
+    /// A parallel iterator yielding the descendants of the node, without the node itself.
+    ///
+    /// # Example
+    /// ```
+    /// use rayon::prelude::*;
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><head><title>T</title></head><body><p>A</p><p>B</p></body></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let body = document.find_first("body").unwrap();
+    /// let texts: Vec<&str> = body.par_descendants().filter_map(|node| node.text()).collect();
+    ///
+    /// assert_eq!(texts, ["A", "B"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_descendants(&self) -> impl IndexedParallelIterator<Item = Node<'xml>> {
+        self.doc.par_descendants(self.idx())
+    }
+}
+
+/// Returns a parallel iterator over the nodes of a range of node indexes.
+fn par_nodes<'a>(
+    document: &'a Document<'a>,
+    indexes: Range<usize>,
+) -> impl IndexedParallelIterator<Item = Node<'a>> {
+    indexes.into_par_iter().map(move |idx| {
+        #[cfg(not(feature = "forward_only"))]
+        let node = Node::new(idx as NodeIdx, &document.nodes[idx], document);
+        #[cfg(feature = "forward_only")]
+        let node = Node::new(idx as NodeIdx, 0, &document.nodes[idx], document);
+        node
+    })
+}
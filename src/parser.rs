@@ -6,18 +6,35 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::inline_always)]
 
-use crate::defs::{NodeIdx, ParseXmlError, XmlIdx, XmlLocation};
-use crate::document::Document;
+use crate::defs::{
+    Diagnostic, DiagnosticKind, NodeIdx, ParseXmlError, XmlDeclaration, XmlIdx, XmlLocation,
+};
+use crate::document::{column_number, Document, ElementSpan, FRAGMENT_ROOT_NAME};
 use crate::node_type::NodeType;
 
 use kmp::kmp_find;
 use phf::phf_map;
 
 //use memchr::memchr2;
-use memchr::{memchr, memchr2};
+use memchr::{memchr, memchr2, memchr_iter, memrchr};
 
 use core::ops::Range;
 
+pub mod events;
+pub mod feed;
+mod html;
+#[cfg(feature = "html5_entities")]
+mod html5_entities;
+pub mod options;
+pub mod sax;
+#[cfg(feature = "fast_scan")]
+mod scan;
+
+pub use events::{Event, Tokenizer};
+pub use feed::FeedParser;
+pub use options::{InvalidUtf8, ParseProgress, ParserOptions, PrefixFilter, WhitespaceMode};
+pub use sax::{stream, stream_with_options, SaxAttributes, SaxHandler};
+
 type XmlRange = Range<XmlIdx>;
 
 enum State {
@@ -38,19 +55,17 @@ const EXCLAMATION_MARK: u8 = b'!';
 const QUESTION_MARK: u8 = b'?';
 const AMPERSAND: u8 = b'&';
 const SEMI_COLON: u8 = b';';
+const PERCENT: u8 = b'%';
 const HASH: u8 = b'#';
 const X_CHAR: u8 = b'x';
 const LEFT_BRACKET: u8 = b'[';
 const RIGHT_BRACKET: u8 = b']';
 const SPACE: u8 = b' ';
 
-#[cfg(feature = "namespace_removal")]
 const COLON: u8 = b':';
 
-#[cfg(not(feature = "parse_escapes"))]
 const NEWLINE: u8 = b'\n';
 
-#[cfg(not(feature = "parse_escapes"))]
 const CARRIAGE_RETURN: u8 = b'\r';
 
 #[allow(dead_code)]
@@ -216,7 +231,7 @@ static ENTITIES_MAP: phf::Map<&'static [u8], &'static [u8]> = phf_map! {
     b"permil" => b"\xE2\x80\xB0", // per mille sign, U+2030 ISOtech
     b"lsaquo" => b"\xE2\x80\xB9", // single left-pointing angle quotation mark, U+2039 ISO proposed
     b"rsaquo" => b"\xE2\x80\xBA", // single right-pointing angle quotation mark, U+203A ISO proposed
-    b"euro"   => b"\xE2\x80\xAC", // euro sign, U+20AC NEW
+    b"euro"   => b"\xE2\x82\xAC", // euro sign, U+20AC NEW
 };
 
 macro_rules! search_char {
@@ -229,6 +244,9 @@ macro_rules! search_char {
 struct Parent {
     parent_idx: NodeIdx,
     last_child_idx: NodeIdx,
+    preserve_ws: bool, // The whitespace of the PCData nodes is preserved inside the element
+    #[cfg(feature = "namespace_aware")]
+    ns_decls_len: usize, // Number of in-scope namespace declarations when the element was opened
 }
 
 impl Parent {
@@ -236,32 +254,40 @@ impl Parent {
         Self {
             parent_idx,
             last_child_idx: 0,
+            preserve_ws: false,
+            #[cfg(feature = "namespace_aware")]
+            ns_decls_len: 0,
         }
     }
 }
 
-impl Document {
+#[cfg(feature = "namespace_aware")]
+/// A namespace declaration (`xmlns` or `xmlns:prefix` attribute) in scope during parsing.
+struct NamespaceDecl {
+    prefix: XmlRange, // Empty for the default namespace
+    uri: XmlLocation,
+}
+
+impl Document<'_> {
     /// Skips a specific slice in the XML buffer, returning the next position after the slice.
     ///
     /// This method scans the XML buffer starting from the current pointer position `p`
-    /// and skips over the specified slice.
+    /// and skips over the specified slice, whatever its distance from `p`.
     /// If the slice is not found, it returns `None`.
     ///
     /// # Arguments
     /// * `p` - The current position in the XML buffer
-    /// * `max` - The maximum number of bytes to search for the slice
     /// * `slice` - The slice to skip, represented as a byte slice
     ///
     /// # Returns
     /// `Option<XmlIdx>` - The updated pointer position after skipping the slice,
     /// or `None` if the end of the XML buffer is reached or the slice is not found.
     #[inline(always)]
-    fn skip_after_slice(&self, p: XmlIdx, max: XmlIdx, slice: &[u8]) -> Option<XmlIdx> {
-        if p >= self.xml.len() as XmlIdx {
+    fn skip_after_slice(&self, p: XmlIdx, slice: &[u8]) -> Option<XmlIdx> {
+        if p >= self.content_len {
             None
         } else {
-            let max_pos: XmlIdx = (p + max).min(self.xml.len() as XmlIdx);
-            kmp_find(slice, &self.xml[p as usize..max_pos as usize])
+            kmp_find(slice, &self.xml[p as usize..self.content_len as usize])
                 .map(|pos| p + pos as XmlIdx + slice.len() as XmlIdx)
         }
     }
@@ -281,13 +307,19 @@ impl Document {
     /// or `None` if the end of the XML buffer is reached.
     #[inline(always)]
     fn skip_chartype(&self, p: XmlIdx, chartype: Chartype) -> Option<XmlIdx> {
-        if p >= self.xml.len() as XmlIdx {
+        if p >= self.content_len {
             None
         } else {
-            (self.xml[p as usize..])
+            let bytes = &self.xml[p as usize..self.content_len as usize];
+
+            #[cfg(feature = "fast_scan")]
+            let pos = scan::skip_chartype(bytes, chartype);
+            #[cfg(not(feature = "fast_scan"))]
+            let pos = bytes
                 .iter()
-                .position(|&byte| !Self::is_of_type(byte, chartype))
-                .map(|pos| p + pos as XmlIdx)
+                .position(|&byte| !Self::is_of_type(byte, chartype));
+
+            pos.map(|pos| p + pos as XmlIdx)
         }
     }
 
@@ -306,13 +338,18 @@ impl Document {
     /// or `None` if no such character is found within the specified range.
     #[inline(always)]
     fn scan_range_for_chartype(&self, range: XmlRange, chartype: Chartype) -> Option<XmlIdx> {
-        (self.xml[range.start as usize..range.end as usize])
+        let bytes = &self.xml[range.start as usize..range.end as usize];
+
+        #[cfg(feature = "fast_scan")]
+        let pos = scan::find_chartype(bytes, chartype);
+        #[cfg(not(feature = "fast_scan"))]
+        let pos = bytes
             .iter()
-            .position(|&byte| Self::is_of_type(byte, chartype))
-            .map(|pos| range.start + pos as XmlIdx)
+            .position(|&byte| Self::is_of_type(byte, chartype));
+
+        pos.map(|pos| range.start + pos as XmlIdx)
     }
 
-    #[cfg(not(feature = "parse_escapes"))]
     /// Scans a range in the XML buffer for a specific character and returns the position of the first occurrence.
     ///
     /// This method searches for the first occurrence of the specified character within the given range.
@@ -351,10 +388,14 @@ impl Document {
     /// of the XML buffer.
     #[inline(always)]
     fn scan_until_char(&self, p: XmlIdx, target_char: u8) -> Option<XmlIdx> {
-        if p >= self.xml.len() as XmlIdx {
+        if p >= self.content_len {
             None
         } else {
-            search_char!(target_char, &self.xml[p as usize..]).map(|pos| p + pos as XmlIdx)
+            search_char!(
+                target_char,
+                &self.xml[p as usize..self.content_len as usize]
+            )
+            .map(|pos| p + pos as XmlIdx)
         }
     }
 
@@ -374,13 +415,19 @@ impl Document {
     /// is beyond the end of the XML buffer.
     #[inline(always)]
     fn scan_until_chartype(&self, p: XmlIdx, chartype: Chartype) -> Option<XmlIdx> {
-        if p >= self.xml.len() as XmlIdx {
+        if p >= self.content_len {
             None
         } else {
-            (self.xml[p as usize..])
+            let bytes = &self.xml[p as usize..self.content_len as usize];
+
+            #[cfg(feature = "fast_scan")]
+            let pos = scan::find_chartype(bytes, chartype);
+            #[cfg(not(feature = "fast_scan"))]
+            let pos = bytes
                 .iter()
-                .position(|&byte| Self::is_of_type(byte, chartype))
-                .map(|pos| p + pos as XmlIdx)
+                .position(|&byte| Self::is_of_type(byte, chartype));
+
+            pos.map(|pos| p + pos as XmlIdx)
         }
     }
 
@@ -401,10 +448,15 @@ impl Document {
     /// of the XML buffer.
     #[inline(always)]
     fn scan_until_one_of_2_chars(&self, p: XmlIdx, char1: u8, char2: u8) -> Option<XmlIdx> {
-        if p >= self.xml.len() as XmlIdx {
+        if p >= self.content_len {
             None
         } else {
-            memchr2(char1, char2, &self.xml[p as usize..]).map(|pos| p + pos as XmlIdx)
+            memchr2(
+                char1,
+                char2,
+                &self.xml[p as usize..self.content_len as usize],
+            )
+            .map(|pos| p + pos as XmlIdx)
         }
     }
 
@@ -423,10 +475,10 @@ impl Document {
     fn show_xml_around_error(&self, pos: XmlIdx) -> String {
         let start = pos.saturating_sub(30);
 
-        let end = if (pos + 30) < self.xml.len() as XmlIdx {
+        let end = if (pos + 30) < self.content_len {
             pos + 30
         } else {
-            self.xml.len() as XmlIdx
+            self.content_len
         };
 
         format!(
@@ -440,8 +492,8 @@ impl Document {
     /// Creates a standardized parsing error with context information.
     ///
     /// This helper method generates a `ParseXmlError::InvalidXml` with a descriptive
-    /// message that includes the error position and surrounding XML content for
-    /// better debugging.
+    /// message that includes the error position (line, column and byte offset) and
+    /// surrounding XML content for better debugging.
     ///
     /// # Arguments
     /// * `msg` - The error message describing what went wrong
@@ -451,14 +503,86 @@ impl Document {
     /// A `Result` containing the formatted parsing error
     #[inline]
     fn invalid(&self, msg: &str, pos: XmlIdx) -> Result<(), ParseXmlError> {
+        let (line, column) = self.error_line_col(pos);
+
         Err(ParseXmlError::InvalidXml(format!(
-            "{}. at position {}: {}",
+            "{}. at line {}, column {} (position {}): {}",
             msg,
+            line,
+            column,
             pos,
             self.show_xml_around_error(pos)
         )))
     }
 
+    /// Computes the line and column (1-based) of a position in the XML buffer.
+    ///
+    /// # Arguments
+    /// * `pos` - The byte position in the XML
+    fn error_line_col(&self, pos: XmlIdx) -> (usize, usize) {
+        // The line offsets are not cached, as the buffer is still being modified
+        let before = &self.xml[..(pos as usize).min(self.xml.len())];
+        let line = memchr_iter(b'\n', before).count() + 1;
+        let line_start = memrchr(b'\n', before).map_or(0, |nl| nl + 1);
+
+        (line, column_number(&before[line_start..]))
+    }
+
+    /// Reports a malformed construct found during parsing.
+    ///
+    /// When the document is parsed through `Document::new_recovering()`, the problem is
+    /// recorded as a diagnostic and the caller is expected to resynchronize. Otherwise,
+    /// a parsing error is returned, as with `invalid()`.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of problem
+    /// * `msg` - The message describing what went wrong
+    /// * `pos` - The byte position in the XML where the problem occurred
+    ///
+    /// # Returns
+    /// `Ok(())` if the problem was recorded, or the parsing error otherwise
+    fn recover(
+        &mut self,
+        kind: DiagnosticKind,
+        msg: &str,
+        pos: XmlIdx,
+    ) -> Result<(), ParseXmlError> {
+        if self.diagnostics.is_none() {
+            return self.invalid(msg, pos);
+        }
+
+        let (line, column) = self.error_line_col(pos);
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.push(Diagnostic {
+                kind,
+                message: msg.to_string(),
+                position: pos,
+                line,
+                column,
+            });
+        }
+        Ok(())
+    }
+
+    /// Skips a malformed construct while recovering, up to the next space, `/` or `>`.
+    ///
+    /// # Arguments
+    /// * `i` - The position of the malformed construct
+    ///
+    /// # Returns
+    /// The position of the next space, `/` or `>`, or the end of the XML buffer
+    fn skip_malformed(&self, mut i: XmlIdx) -> XmlIdx {
+        let size = self.content_len;
+        while i < size {
+            let byte = self.xml[i as usize];
+            if byte == SLASH || byte == GREATER_THAN || Self::is_of_type(byte, Chartype::Space) {
+                break;
+            }
+            i += 1;
+        }
+        i
+    }
+
     /// Validates that a closing tag matches its corresponding opening tag.
     ///
     /// This method ensures XML well-formedness by verifying that closing tags
@@ -470,60 +594,203 @@ impl Document {
     /// * `range` - The byte range containing the closing tag name
     ///
     /// # Returns
-    /// `Ok(())` if the tags match, or a parsing error if they don't match
-    /// or if the parent node is not an element
+    /// `Ok(true)` if the tags match, `Ok(false)` if they don't match and the problem was
+    /// recorded as a diagnostic, or a parsing error if they don't match or if the parent
+    /// node is not an element
     #[inline(always)]
     fn check_closing_tag(
-        &self,
+        &mut self,
         parent_idx: NodeIdx,
-        location: XmlLocation,
-    ) -> Result<(), ParseXmlError> {
+        location: &XmlLocation,
+    ) -> Result<bool, ParseXmlError> {
+        #[cfg(feature = "use_cstr")]
+        let (location, position) = (*location, *location);
+
+        #[cfg(not(feature = "use_cstr"))]
+        let (location, position) = (location.clone(), location.start);
+
         if let NodeType::Element { name, .. } = self.nodes[parent_idx as usize].node_type() {
-            #[cfg(not(feature = "use_cstr"))]
-            {
-                let tag_name = &self.xml[name.start as usize..name.end as usize];
-                let closing_tag = &self.xml[location.start as usize..location.end as usize];
-                if tag_name != closing_tag {
-                    return self.invalid(
-                        &format!(
-                            "Closing tag '{}' does not match opening tag '{}'",
-                            self.get_str_from_location(location.clone()),
-                            self.get_str_from_location(name.clone())
-                        ),
-                        location.start,
-                    );
-                }
+            if self.closing_tag_matches(parent_idx, &location)? {
+                return Ok(true);
             }
 
             #[cfg(feature = "use_cstr")]
-            {
-                let tag_name = std::ffi::CStr::from_bytes_until_nul(&self.xml[*name as usize..])
-                    .or_else(|_| Err(ParseXmlError::InternalError))?;
-                let closing_tag =
-                    std::ffi::CStr::from_bytes_until_nul(&self.xml[location as usize..])
-                        .or_else(|_| Err(ParseXmlError::InternalError))?;
-
-                if tag_name != closing_tag {
-                    return self.invalid(
-                        &format!(
-                            "Closing tag '{}' does not match opening tag '{}'",
-                            self.get_str_from_location(location),
-                            self.get_str_from_location(*name)
-                        ),
-                        location,
-                    );
-                }
+            let name = *name;
+
+            #[cfg(not(feature = "use_cstr"))]
+            let name = name.clone();
+
+            let msg = format!(
+                "Closing tag '{}' does not match opening tag '{}'",
+                self.get_str_from_location(location),
+                self.get_str_from_location(name)
+            );
+            self.recover(DiagnosticKind::MismatchedClosingTag, &msg, position)?;
+        } else {
+            self.invalid("Expected an element node for closing tag", position)?;
+        }
+        Ok(false)
+    }
+
+    /// Checks if a closing tag has the same name as an element.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The node index of the element
+    /// * `location` - The location of the closing tag name
+    ///
+    /// # Returns
+    /// `Ok(true)` if the names are the same, `Ok(false)` if they differ or if the node is
+    /// not an element
+    #[inline(always)]
+    fn closing_tag_matches(
+        &self,
+        node_idx: NodeIdx,
+        location: &XmlLocation,
+    ) -> Result<bool, ParseXmlError> {
+        let NodeType::Element { name, .. } = self.nodes[node_idx as usize].node_type() else {
+            return Ok(false);
+        };
+
+        #[cfg(not(feature = "use_cstr"))]
+        {
+            let tag_name = &self.xml[name.start as usize..name.end as usize];
+            let closing_tag = &self.xml[location.start as usize..location.end as usize];
+            Ok(tag_name == closing_tag)
+        }
+
+        #[cfg(feature = "use_cstr")]
+        {
+            let tag_name = std::ffi::CStr::from_bytes_until_nul(&self.xml[*name as usize..])
+                .map_err(|_| ParseXmlError::InternalError)?;
+            let closing_tag = std::ffi::CStr::from_bytes_until_nul(&self.xml[*location as usize..])
+                .map_err(|_| ParseXmlError::InternalError)?;
+            Ok(tag_name == closing_tag)
+        }
+    }
+
+    /// Returns the name of an element, as found in the XML buffer.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The node index of the element
+    ///
+    /// # Returns
+    /// The name of the element, or an empty slice if the node is not an element
+    pub(crate) fn element_name(&self, node_idx: NodeIdx) -> &[u8] {
+        match self.nodes[node_idx as usize].node_type() {
+            #[cfg(not(feature = "use_cstr"))]
+            NodeType::Element { name, .. } => &self.xml[name.start as usize..name.end as usize],
+            #[cfg(feature = "use_cstr")]
+            NodeType::Element { name, .. } => {
+                let name = &self.xml[*name as usize..];
+                &name[..memchr(0, name).unwrap_or(name.len())]
+            }
+            _ => &[],
+        }
+    }
+
+    /// Computes the number of elements closed by a closing tag with the `html_leniency` option.
+    ///
+    /// The closing tag closes the nearest open element with the same name, along with the
+    /// elements opened since, provided their end tag is optional.
+    ///
+    /// # Arguments
+    /// * `parenthood` - The currently open elements, innermost last
+    /// * `location` - The location of the closing tag name
+    ///
+    /// # Returns
+    /// The number of elements closed, or 0 if no element can be closed that way
+    fn html_closed_count(
+        &self,
+        parenthood: &[Parent],
+        location: &XmlLocation,
+    ) -> Result<usize, ParseXmlError> {
+        for (level, parent) in parenthood.iter().enumerate().rev() {
+            if self.closing_tag_matches(parent.parent_idx, location)? {
+                return Ok(parenthood.len() - level);
+            }
+            if !html::has_optional_end_tag(self.element_name(parent.parent_idx)) {
+                break;
             }
+        }
+        Ok(0)
+    }
+
+    /// Checks if a name from the XML buffer, not yet folded to lowercase, is the same as an
+    /// element name.
+    ///
+    /// # Arguments
+    /// * `name` - The name, from the XML buffer
+    /// * `element_name` - The element name, as recorded
+    fn closing_name_matches(&self, name: &[u8], element_name: &[u8]) -> bool {
+        if self.options.lowercase_names {
+            name.eq_ignore_ascii_case(element_name)
         } else {
+            name == element_name
+        }
+    }
+
+    /// Reads the raw text content of a `script` or `style` element, with the `html_raw_text`
+    /// option.
+    ///
+    /// The content, up to the end tag of the element, is added as-is as a text node, unless
+    /// it is empty or, without the `keep_ws_only_pcdata` option, composed of whitespace only.
+    ///
+    /// # Arguments
+    /// * `parent` - The element, as the innermost open element
+    /// * `start` - The position following the end of the element's opening tag
+    ///
+    /// # Returns
+    /// The position of the `<` of the end tag, or `None` if the end tag is not found
+    fn read_raw_text(
+        &mut self,
+        parent: &mut Parent,
+        start: XmlIdx,
+    ) -> Result<Option<XmlIdx>, ParseXmlError> {
+        let name = self.element_name(parent.parent_idx).to_vec();
+        let size = self.content_len as usize;
+
+        let mut end = start as usize;
+        let end = loop {
+            let Some(pos) = self.scan_until_char(end as XmlIdx, LESS_THAN) else {
+                return Ok(None);
+            };
+            end = pos as usize;
+            let tag_end = end + 2 + name.len();
+            if tag_end < size
+                && self.xml[end + 1] == SLASH
+                && self.closing_name_matches(&self.xml[end + 2..tag_end], &name)
+                && Self::is_of_type(self.xml[tag_end], Chartype::ParseCloseTag)
+            {
+                break end as XmlIdx;
+            }
+            end += 1;
+        };
+
+        let content = &self.xml[start as usize..end as usize];
+        let ws_only = content
+            .iter()
+            .all(|&b| Self::is_of_type(b, Chartype::Space));
+        if !content.is_empty()
+            && (!ws_only || self.options.keep_ws_only_pcdata || parent.preserve_ws)
+        {
             #[cfg(feature = "use_cstr")]
-            let position = location;
+            let location = {
+                self.xml[end as usize] = 0; // Null-terminate the string
+                start
+            };
 
             #[cfg(not(feature = "use_cstr"))]
-            let position = location.start;
-
-            return self.invalid("Expected an element node for closing tag", position);
+            let location = start..end;
+
+            let node_idx = self.add_node(
+                parent.parent_idx,
+                parent.last_child_idx,
+                NodeType::Text(location),
+            )?;
+            parent.last_child_idx = node_idx;
         }
-        Ok(())
+
+        Ok(Some(end))
     }
 
     /// Converts a byte slice containing decimal digits to a u32.
@@ -592,24 +859,47 @@ impl Document {
     /// Translates XML escape sequences to their UTF-8 representations.
     ///
     /// This method handles three types of escape sequences:
-    /// - Named entities (e.g., `&amp;`, `&lt;`) using the predefined entities map
+    /// - Named entities (e.g., `&amp;`, `&lt;`) using the predefined entities map, the HTML5
+    ///   entities map with the `html5_entities` feature, then the entities registered with
+    ///   the `entity()` parsing option
     /// - Decimal character references (e.g., `&#65;`)
     /// - Hexadecimal character references (e.g., `&#x41;`)
     ///
     /// The method processes the escape sequence starting after the '&' character
     /// and writes the UTF-8 bytes to the specified position in the XML buffer.
     ///
+    /// When the replacement text is longer than the escape sequence, as for some declared or
+    /// registered entities, it cannot be written in place: the translated part of the region,
+    /// the replacement text and the rest of the region are then moved to the text store,
+    /// past the XML content, where the translation of the region goes on.
+    ///
     /// # Arguments
     /// * `from` - Starting position after the '&' character
     /// * `to` - Position where the translated UTF-8 bytes should be written
+    /// * `region` - The range of the text or attribute value being translated, updated when
+    ///   it is moved to the text store
     ///
     /// # Returns
-    /// `Some((next_from, next_to))` if translation succeeds, where `next_from` is
+    /// `Ok(Some((next_from, next_to)))` if translation succeeds, where `next_from` is
     /// the position after the semicolon and `next_to` is the position after the
-    /// written UTF-8 bytes. Returns `None` if the escape sequence is invalid.
+    /// written UTF-8 bytes. Returns `Ok(None)` if the escape sequence is invalid.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::EntityLimitExceeded`: If the translation exceeds the entity
+    ///   expansion limits of the parsing options.
+    /// - `ParseXmlError::NoMoreSpace`: If the XML buffer would become too large for the
+    ///   selected `xxxx_xml_size` feature when moving the region to the text store.
     #[inline]
-    fn translate_sequence(&mut self, from: XmlIdx, to: XmlIdx) -> Option<(XmlIdx, XmlIdx)> {
-        let end = self.scan_until_char(from, SEMI_COLON)?;
+    fn translate_sequence(
+        &mut self,
+        from: XmlIdx,
+        to: XmlIdx,
+        region: &mut XmlRange,
+    ) -> Result<Option<(XmlIdx, XmlIdx)>, ParseXmlError> {
+        let Some(end) = self.scan_range_for_char(from..region.end, SEMI_COLON) else {
+            return Ok(None);
+        };
+        let reference_len = (end + 2 - from) as usize; // From '&' to ';'
         let mut from = from;
 
         let number = self.xml[from as usize] == HASH;
@@ -623,27 +913,69 @@ impl Document {
 
         if from == end {
             // No content between '&' and ';'
-            return None;
+            return Ok(None);
         }
 
         let from_slice = &self.xml[from as usize..end as usize];
 
         let bytes = if number {
-            char::from_u32(if hex_number {
-                Self::hexadecimal(from_slice)?
+            (if hex_number {
+                Self::hexadecimal(from_slice)
             } else {
-                Self::decimal(from_slice)?
+                Self::decimal(from_slice)
             })
-            .map(|val| val.to_string().into_bytes())?
+            .and_then(char::from_u32)
+            .map(|val| val.to_string().into_bytes())
         } else {
-            ENTITIES_MAP.get(from_slice).map(|entity| entity.to_vec())?
+            self.named_entity(from_slice).map(<[u8]>::to_vec)
         };
+        let Some(bytes) = bytes else {
+            return Ok(None);
+        };
+
+        let len = bytes.len();
+        let mut to = to;
+        let mut next_from = end + 1; // pass the semicolon
+
+        if len > reference_len {
+            let start = self.xml.len();
+            let translated = (to - region.start) as usize;
+            if start + translated + len + (region.end - next_from) as usize + 1
+                > XmlIdx::MAX as usize
+            {
+                return Err(ParseXmlError::NoMoreSpace);
+            }
+
+            let xml = self.xml.to_mut();
+            xml.extend_from_within(region.start as usize..to as usize);
+            xml.extend_from_slice(&bytes);
+            xml.extend_from_within(next_from as usize..region.end as usize);
+            *region = start as XmlIdx..xml.len() as XmlIdx;
+
+            #[cfg(feature = "use_cstr")]
+            xml.push(0); // Room for the null terminator
 
-        let buf = &mut self.xml[to as usize..];
-        let len = bytes.len().min(buf.len());
-        buf[..len].copy_from_slice(&bytes[..len]);
+            to = (start + translated) as XmlIdx;
+            next_from = to + len as XmlIdx;
+        } else {
+            self.xml[to as usize..to as usize + len].copy_from_slice(&bytes);
+        }
+
+        self.entity_expansions += 1;
+        self.entity_expansion_size += len;
+        if self
+            .options
+            .max_entity_expansions
+            .is_some_and(|max| self.entity_expansions > max)
+            || self
+                .options
+                .max_entity_expansion_size
+                .is_some_and(|max| self.entity_expansion_size > max)
+        {
+            return Err(ParseXmlError::EntityLimitExceeded);
+        }
 
-        Some((end + 1, to + len as XmlIdx)) // pass the semicolon
+        Ok(Some((next_from, to + len as XmlIdx)))
     }
 
     /// Processes XML content by translating escape sequences in-place.
@@ -657,35 +989,33 @@ impl Document {
     /// * `range` - The byte range in the XML buffer to process
     ///
     /// # Returns
-    /// `Some((start, end))` where `start` is the original start position and
-    /// `end` is the new end position after sequence translation and compaction.
-    /// Returns `None` if processing fails.
+    /// `Ok(start..end)` where `start` is the original start position and
+    /// `end` is the new end position after sequence translation and compaction. The range
+    /// is located in the text store if an entity is longer than its reference.
+    ///
+    /// # Errors
+    /// The errors of `translate_sequence()`.
     #[inline(always)]
-    fn parse_pcdata(&mut self, range: &XmlRange) -> XmlRange {
-        let end = range.end;
+    fn parse_pcdata(&mut self, range: &XmlRange) -> Result<XmlRange, ParseXmlError> {
+        let mut region = range.clone();
         let mut to = range.start;
         let mut from = range.start;
 
         loop {
-            #[cfg(feature = "parse_escapes")]
-            let next_pos = match self.scan_range_for_chartype(from..end, Chartype::ParsePCData) {
-                Some(pos) => pos,
-                None => end, // No more characters of the specified type found
-            };
-
-            #[cfg(not(feature = "parse_escapes"))]
-            let next_pos = match self.scan_range_for_char(from..end, CARRIAGE_RETURN) {
-                Some(pos) => pos,
-                None => end,
-            };
+            let next_pos = if self.options.parse_escapes && self.options.decode_entities {
+                self.scan_range_for_chartype(from..region.end, Chartype::ParsePCData)
+            } else {
+                self.scan_range_for_char(from..region.end, CARRIAGE_RETURN)
+            }
+            .unwrap_or(region.end); // No more characters of the specified type found
 
-            if next_pos >= end {
+            if next_pos >= region.end {
                 if from != to {
                     // Move the tail content to the `to` position
                     self.xml
-                        .copy_within(from as usize..end as usize, to as usize);
+                        .copy_within(from as usize..region.end as usize, to as usize);
                 }
-                to += end - from;
+                to += region.end - from;
                 break;
             }
 
@@ -698,9 +1028,10 @@ impl Document {
                 to += next_pos - from;
             }
 
-            #[cfg(feature = "parse_escapes")]
             if self.xml[next_pos as usize] == AMPERSAND {
-                if let Some((new_from, new_to)) = self.translate_sequence(next_pos + 1, to) {
+                if let Some((new_from, new_to)) =
+                    self.translate_sequence(next_pos + 1, to, &mut region)?
+                {
                     from = new_from;
                     to = new_to;
                 } else {
@@ -712,148 +1043,609 @@ impl Document {
                     from = next_pos + 1;
                     to += 1;
                 }
+                continue;
             }
 
-            #[cfg(not(feature = "parse_escapes"))]
-            {
-                // This is a carriage return
-                self.xml[to as usize] = NEWLINE; // Replace with a newline character
-                to += 1; // Move the `to` position forward
-                from = next_pos
-                    + if (next_pos + 1) < end && self.xml[(next_pos + 1) as usize] == NEWLINE {
-                        2 // Move past the newline character if present
-                    } else {
-                        1
-                    };
-            }
+            // This is a carriage return
+            self.xml[to as usize] = NEWLINE; // Replace with a newline character
+            to += 1; // Move the `to` position forward
+            from = next_pos
+                + if (next_pos + 1) < region.end && self.xml[(next_pos + 1) as usize] == NEWLINE {
+                    2 // Move past the newline character if present
+                } else {
+                    1
+                };
         }
 
-        range.start..to
+        Ok(region.start..to)
     }
 
-    /// Normalizes attribute values by removing unnecessary whitespace and escape sequences.
-    ///
-    /// This method scans through the specified range, looking for '&' characters
-    /// that indicate escape sequences. It replaces these sequences with their
-    /// UTF-8 representations and ensures that the attribute value is compacted
-    /// without leading or trailing whitespace.
+    /// Copies aside the original content of a text run, before its translation in place, if
+    /// the `keep_raw_text` option is enabled and the translation may modify it.
     ///
     /// # Arguments
-    /// * `range` - The byte range in the XML buffer representing the attribute value
+    /// * `range` - The byte range of the text in the XML buffer
     ///
     /// # Returns
-    /// A new `XmlLocation` representing the normalized attribute value, with leading
-    /// and trailing whitespace removed, and escape sequences translated.
+    /// The range of the copy in the raw text buffer, to be recorded with `record_raw_text()`,
+    /// or `None` if no copy is needed.
+    #[inline]
+    fn save_raw_text(&mut self, range: &XmlRange) -> Option<Range<usize>> {
+        if !self.options.keep_raw_text {
+            return None;
+        }
+
+        let content = &self.xml[range.start as usize..range.end as usize];
+        if self.options.parse_escapes && self.options.decode_entities {
+            memchr2(AMPERSAND, CARRIAGE_RETURN, content)
+        } else {
+            memchr(CARRIAGE_RETURN, content)
+        }?;
+
+        let start = self.raw_text_buffer.len();
+        self.raw_text_buffer.extend_from_slice(content);
+        Some(start..self.raw_text_buffer.len())
+    }
+
+    /// Translates a text run in place and adds it as the last child of an element.
     ///
-    /// # Note
-    /// This method modifies the XML buffer in place, so the original range may be adjusted.
-    #[inline(always)]
-    fn normalize_attribute_value(&mut self, range: &XmlRange) -> XmlRange {
-        let end = range.end;
-        let mut to = range.start;
-        let mut from = range.start;
-        let mut space_added = false;
+    /// # Arguments
+    /// * `parent` - The element, as the innermost open element
+    /// * `range` - The byte range of the text run in the XML buffer
+    ///
+    /// # Errors
+    /// The errors of `parse_pcdata()` and `add_node()`.
+    fn add_text(&mut self, parent: &mut Parent, range: XmlRange) -> Result<(), ParseXmlError> {
+        let raw_text = self.save_raw_text(&range);
+        let text_range = self.parse_pcdata(&range)?;
+
+        #[cfg(feature = "use_cstr")]
+        let location = {
+            self.xml[text_range.end as usize] = 0; // Null-terminate the string
+            text_range.start
+        };
 
-        loop {
-            let next_pos = match self.scan_range_for_chartype(from..end, Chartype::ParseAtrNorm) {
-                Some(pos) => pos,
-                None => end, // No more characters of the specified type found
+        #[cfg(not(feature = "use_cstr"))]
+        let location = text_range;
+
+        let node_idx = self.add_node(
+            parent.parent_idx,
+            parent.last_child_idx,
+            NodeType::Text(location),
+        )?;
+        parent.last_child_idx = node_idx;
+        self.record_raw_text(node_idx, raw_text);
+        Ok(())
+    }
+
+    /// Adds a text run as the last children of an element, with the `keep_entity_refs`
+    /// option: the named entity references become `NodeType::EntityRef` nodes, and the text
+    /// located between them text nodes.
+    ///
+    /// # Arguments
+    /// * `parent` - The element, as the innermost open element
+    /// * `range` - The byte range of the text run in the XML buffer
+    ///
+    /// # Errors
+    /// The errors of `parse_pcdata()` and `add_node()`.
+    fn add_text_with_entity_refs(
+        &mut self,
+        parent: &mut Parent,
+        range: XmlRange,
+    ) -> Result<(), ParseXmlError> {
+        let mut start = range.start;
+        let mut pos = range.start;
+
+        while let Some(ampersand) = self.scan_range_for_char(pos..range.end, AMPERSAND) {
+            pos = ampersand + 1;
+            let Some(name_end) = self.entity_name_end(pos..range.end) else {
+                continue; // Character reference or lone '&', part of the text
             };
 
-            if next_pos >= end {
-                if from != to {
-                    // Move the tail content to the `to` position
-                    self.xml
-                        .copy_within(from as usize..end as usize, to as usize);
-                }
-                to += end - from;
-                if to > range.start && self.xml[(to - 1) as usize] == SPACE {
-                    // If we added a space, we need to adjust the end position
-                    to -= 1; // Remove the last added space
-                }
-                break; // No more '&' or whitespace found
+            if ampersand > start {
+                self.add_text(parent, start..ampersand)?;
             }
 
-            if next_pos > from {
-                // Move the content before the '&' or whitespace to the `to` position
-                self.xml
-                    .copy_within(from as usize..next_pos as usize, to as usize);
-                to += next_pos - from;
-                space_added = false; // Reset space added flag
-            }
-            if self.xml[next_pos as usize] == AMPERSAND {
-                match self.translate_sequence(next_pos + 1, to) {
-                    Some((new_from, new_to)) => {
-                        from = new_from;
-                        to = new_to;
-                    }
-                    None => {
-                        // Invalid escape sequence, just skip the '&'
-                        from += 1;
-                    }
-                }
-                space_added = false; // Reset space added flag
-            } else {
-                // Handle whitespace normalization
-                if !space_added && to != range.start {
-                    // Add a space if not already added
-                    self.xml[to as usize] = SPACE;
-                    to += 1;
-                    space_added = true;
-                }
-                from = next_pos + 1; // Move past the whitespace
-            }
+            #[cfg(feature = "use_cstr")]
+            let location = {
+                self.xml[name_end as usize] = 0; // Null-terminate the name, replacing the ';'
+                pos
+            };
+
+            #[cfg(not(feature = "use_cstr"))]
+            let location = pos..name_end;
+
+            let node_idx = self.add_node(
+                parent.parent_idx,
+                parent.last_child_idx,
+                NodeType::EntityRef(location),
+            )?;
+            parent.last_child_idx = node_idx;
+
+            start = name_end + 1;
+            pos = start;
         }
 
-        range.start..to
+        if range.end > start {
+            self.add_text(parent, start..range.end)?;
+        }
+        Ok(())
     }
 
-    /// Checks if a byte is of a specific character type.
+    /// Returns the position of the `;` ending the name of a named entity reference, if the
+    /// range starts with such a name.
     ///
-    /// This method uses a precomputed table to determine if the byte
-    /// belongs to a specific character type (e.g., whitespace, letter, digit).
+    /// # Arguments
+    /// * `range` - The range following the `&` of the reference
+    fn entity_name_end(&self, range: XmlRange) -> Option<XmlIdx> {
+        if range.start >= range.end
+            || !Self::is_of_type(self.xml[range.start as usize], Chartype::StartSymBol)
+        {
+            return None;
+        }
+        let name_end = (range.start + 1..range.end)
+            .find(|&pos| !Self::is_of_type(self.xml[pos as usize], Chartype::Symbol))?;
+        (self.xml[name_end as usize] == SEMI_COLON).then_some(name_end)
+    }
+
+    /// Returns the replacement text of a named entity: a predefined entity, an entity declared
+    /// in the internal DTD subset, an HTML5 entity with the `html5_entities` feature, or a
+    /// custom entity of the parsing options.
     ///
     /// # Arguments
-    /// * `byte` - The byte to check
-    /// * `chartype` - The character type to check against
+    /// * `name` - The name of the entity, without `&` and `;`
+    pub(crate) fn named_entity(&self, name: &[u8]) -> Option<&[u8]> {
+        let entity = ENTITIES_MAP.get(name).copied().or_else(|| {
+            if self.entity_declarations.is_empty() {
+                return None;
+            }
+            std::str::from_utf8(name)
+                .ok()
+                .and_then(|name| self.entity_declarations.get(name))
+                .map(String::as_bytes)
+        });
+        #[cfg(feature = "html5_entities")]
+        let entity = entity.or_else(|| html5_entities::HTML5_ENTITIES_MAP.get(name).copied());
+
+        entity.or_else(|| self.options.custom_entity(name))
+    }
+
+    /// Appends a text to a buffer, translating its character and entity references. The
+    /// unknown or malformed references are kept as-is.
     ///
-    /// # Returns
-    /// `true` if the byte matches the character type, `false` otherwise
-    #[inline(always)]
-    pub(crate) fn is_of_type(byte: u8, chartype: Chartype) -> bool {
-        (CHARTYPE_TABLE[byte as usize] & chartype as u8) != 0
+    /// # Arguments
+    /// * `text` - The text to translate
+    /// * `buf` - The buffer receiving the translated text
+    pub(crate) fn decode_references_into(&self, text: &str, buf: &mut String) {
+        let mut rest = text;
+
+        while let Some(pos) = rest.find('&') {
+            buf.push_str(&rest[..pos]);
+            rest = &rest[pos..];
+
+            let end = rest.find(';').filter(|&end| end > 1);
+            let translated = end.and_then(|end| {
+                let reference = &rest[1..end];
+                if let Some(number) = reference.strip_prefix('#') {
+                    let value = match number.strip_prefix('x') {
+                        Some(hex) if !hex.is_empty() => Self::hexadecimal(hex.as_bytes()),
+                        None if !number.is_empty() => Self::decimal(number.as_bytes()),
+                        _ => None,
+                    };
+                    value.and_then(char::from_u32).map(|c| buf.push(c))
+                } else {
+                    self.named_entity(reference.as_bytes())
+                        .and_then(|value| std::str::from_utf8(value).ok())
+                        .map(|value| buf.push_str(value))
+                }
+            });
+
+            match (translated, end) {
+                (Some(()), Some(end)) => rest = &rest[end + 1..],
+                _ => {
+                    buf.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        buf.push_str(rest);
     }
 
-    /// Trims trailing whitespace characters from the end of a specified XML range.
+    /// Reads the internal subset of a DOCTYPE declaration, recording its general entity
+    /// declarations. The other markup declarations, the comments, the processing instructions
+    /// and the parameter entity references are skipped.
     ///
-    /// This method iterates backward from the end of the range, removing any
-    /// whitespace characters (spaces, tabs, newlines) until it reaches a non-whitespace
-    /// character or the start of the range. It effectively adjusts the end index
-    /// of the range to exclude trailing whitespace.
     /// # Arguments
-    /// * `range` - The byte range to trim
+    /// * `i` - The position following the `[` opening the internal subset
     ///
     /// # Returns
-    /// The new end index of the range after trimming trailing whitespace
-    #[inline(always)]
-    fn trim_the_ending_whitespaces(&self, range: XmlRange) -> XmlIdx {
-        let mut end = range.end;
-        while end > range.start && Self::is_of_type(self.xml[(end - 1) as usize], Chartype::Space) {
-            end -= 1;
+    /// The position of the `]` closing the internal subset, or `None` if the end of the XML
+    /// buffer is reached.
+    fn parse_internal_subset(&mut self, mut i: XmlIdx) -> Option<XmlIdx> {
+        loop {
+            i = self.skip_chartype(i, Chartype::Space)?;
+            let rest = &self.xml[i as usize..];
+            i = if rest[0] == RIGHT_BRACKET {
+                return Some(i);
+            } else if rest.starts_with(b"<!--") {
+                self.skip_after_slice(i + 4, b"-->".as_slice())?
+            } else if rest.starts_with(b"<?") {
+                self.skip_after_slice(i + 2, b"?>".as_slice())?
+            } else if rest.starts_with(b"<!ENTITY") {
+                self.parse_entity_declaration(i + 8)?
+            } else if rest[0] == PERCENT {
+                // Parameter entity reference
+                self.scan_until_char(i, SEMI_COLON)? + 1
+            } else if rest[0] == LESS_THAN {
+                self.skip_declaration(i)?
+            } else {
+                i + 1
+            };
         }
-        end
     }
 
-    #[cfg(feature = "namespace_removal")]
-    /// Removes the namespace prefix from an XML element or attribute name.
-    ///
-    /// This method searches for a colon (`:`) character within the specified range
-    /// and removes everything before it (including the colon itself), effectively
-    /// stripping the namespace prefix. If no colon is found, the original range
-    /// is returned unchanged.
+    /// Reads an entity declaration of the internal DTD subset, recording the replacement text
+    /// of an internal general entity (`<!ENTITY name "value">`) if it is not already declared.
+    /// Parameter entities and external entities are skipped.
     ///
     /// # Arguments
-    /// * `range` - The byte range containing the name with potential namespace prefix
+    /// * `i` - The position following `<!ENTITY`
+    ///
+    /// # Returns
+    /// The position following the declaration, or `None` if the end of the XML buffer is
+    /// reached.
+    fn parse_entity_declaration(&mut self, i: XmlIdx) -> Option<XmlIdx> {
+        let name_start = self.skip_chartype(i, Chartype::Space)?;
+        let name_end = self.skip_chartype(name_start, Chartype::Symbol)?;
+        let value_start = self.skip_chartype(name_end, Chartype::Space)?;
+        let quote = self.xml[value_start as usize];
+
+        if name_end > name_start
+            && Self::is_of_type(self.xml[name_start as usize], Chartype::StartSymBol)
+            && (quote == b'"' || quote == b'\'')
+        {
+            let value_end = self.scan_until_char(value_start + 1, quote)?;
+            let name = &self.xml[name_start as usize..name_end as usize];
+            if !ENTITIES_MAP.contains_key(name) {
+                let name = String::from_utf8_lossy(name).into_owned();
+                let value = Self::expand_entity_value(
+                    &self.xml[(value_start + 1) as usize..value_end as usize],
+                );
+                self.entity_declarations.entry(name).or_insert(value);
+            }
+        }
+        self.skip_declaration(name_start)
+    }
+
+    /// Expands the character references and the predefined entities of the value of an
+    /// entity declaration. The other entity references are kept as-is.
+    ///
+    /// # Arguments
+    /// * `value` - The value of the declaration, without its quotes
+    fn expand_entity_value(value: &[u8]) -> String {
+        let mut result = Vec::with_capacity(value.len());
+        let mut rest = value;
+
+        while let Some(pos) = memchr(AMPERSAND, rest) {
+            result.extend_from_slice(&rest[..pos]);
+            rest = &rest[pos + 1..];
+
+            let translated = memchr(SEMI_COLON, rest).and_then(|end| {
+                let bytes = match &rest[..end] {
+                    [HASH, X_CHAR, digits @ ..] if !digits.is_empty() => {
+                        Self::hexadecimal(digits).and_then(char::from_u32)
+                    }
+                    [HASH, digits @ ..] if !digits.is_empty() => {
+                        Self::decimal(digits).and_then(char::from_u32)
+                    }
+                    name => {
+                        return ENTITIES_MAP
+                            .get(name)
+                            .map(|value| (value.to_vec(), end + 1));
+                    }
+                }
+                .map(|c| c.to_string().into_bytes())?;
+                Some((bytes, end + 1))
+            });
+
+            if let Some((bytes, len)) = translated {
+                result.extend_from_slice(&bytes);
+                rest = &rest[len..];
+            } else {
+                result.push(AMPERSAND);
+            }
+        }
+        result.extend_from_slice(rest);
+
+        String::from_utf8_lossy(&result).into_owned()
+    }
+
+    /// Skips a markup declaration of the DTD, up to its closing `>`. The `>` characters
+    /// located in quoted strings are ignored.
+    ///
+    /// # Arguments
+    /// * `i` - A position inside the declaration, outside of its quoted strings
+    ///
+    /// # Returns
+    /// The position following the declaration, or `None` if the end of the XML buffer is
+    /// reached.
+    fn skip_declaration(&self, i: XmlIdx) -> Option<XmlIdx> {
+        let mut quote = None;
+        let pos = self.xml[i as usize..]
+            .iter()
+            .position(|&byte| match quote {
+                Some(q) => {
+                    if byte == q {
+                        quote = None;
+                    }
+                    false
+                }
+                None if byte == b'"' || byte == b'\'' => {
+                    quote = Some(byte);
+                    false
+                }
+                None => byte == GREATER_THAN,
+            })?;
+        Some(i + pos as XmlIdx + 1)
+    }
+
+    /// Reports the progress of the parsing to the callback of the `progress` option, if any.
+    ///
+    /// # Arguments
+    /// * `offset` - The byte offset reached in the XML buffer
+    ///
+    /// # Returns
+    /// The offset from which the next progress is to be reported, or `XmlIdx::MAX` if there
+    /// is no callback.
+    fn report_progress(&self, offset: XmlIdx) -> XmlIdx {
+        let Some(progress) = &self.options.progress else {
+            return XmlIdx::MAX;
+        };
+        (progress.callback)(ParseProgress {
+            offset: offset as usize,
+            total: self.content_len as usize,
+            nodes: self.nodes.len() - 1, // Without the head node
+        });
+        (offset as usize)
+            .saturating_add(progress.interval)
+            .min(XmlIdx::MAX as usize) as XmlIdx
+    }
+
+    /// Records the original content of a text node saved by `save_raw_text()`.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The index of the text node
+    /// * `raw_text` - The range of the original content in the raw text buffer, if saved
+    #[inline]
+    fn record_raw_text(&mut self, node_idx: NodeIdx, raw_text: Option<Range<usize>>) {
+        if let Some(range) = raw_text {
+            self.raw_texts.push((node_idx, range));
+        }
+    }
+
+    /// Records the start of the span of an element, when the `keep_element_spans` option is
+    /// enabled. Until the element is closed, its span and its content extend to the end of
+    /// the content.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The index of the element
+    /// * `start` - The position of the `<` of its start tag
+    #[inline]
+    fn start_span(&mut self, node_idx: NodeIdx, start: XmlIdx) {
+        if self.options.keep_element_spans {
+            let end = self.content_len;
+            self.element_spans.push((
+                node_idx,
+                ElementSpan {
+                    outer: start..end,
+                    inner: end..end,
+                },
+            ));
+        }
+    }
+
+    /// Records the start of the content of the element being opened, following the `>` of
+    /// its start tag, when the `keep_element_spans` option is enabled.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The index of the element
+    /// * `start` - The position following the `>` of its start tag
+    #[inline]
+    fn start_span_content(&mut self, node_idx: NodeIdx, start: XmlIdx) {
+        if self.options.keep_element_spans {
+            if let Some((idx, span)) = self.element_spans.last_mut() {
+                if *idx == node_idx {
+                    span.inner.start = start;
+                }
+            }
+        }
+    }
+
+    /// Records the end of the span of an element, when the `keep_element_spans` option is
+    /// enabled. An element closed before the end of its start tag gets an empty content,
+    /// located at the end of its span.
+    ///
+    /// # Arguments
+    /// * `node_idx` - The index of the element
+    /// * `content_end` - The position of the `<` of its end tag, or the end of its span
+    /// * `end` - The position following the `>` of its end tag, or the start of the tag that
+    ///   implicitly closes it
+    #[inline]
+    fn end_span(&mut self, node_idx: NodeIdx, content_end: XmlIdx, end: XmlIdx) {
+        if self.options.keep_element_spans {
+            if let Ok(pos) = self
+                .element_spans
+                .binary_search_by_key(&node_idx, |(idx, _)| *idx)
+            {
+                let span = &mut self.element_spans[pos].1;
+                span.outer.end = end;
+                span.inner.end = content_end;
+                span.inner.start = span.inner.start.min(content_end);
+            }
+        }
+    }
+
+    /// Normalizes attribute values by removing unnecessary whitespace and escape sequences.
+    ///
+    /// This method scans through the specified range, looking for '&' characters
+    /// that indicate escape sequences. When the `decode_entities` option is enabled, it
+    /// replaces these sequences with their UTF-8 representations. It ensures that the
+    /// attribute value is compacted without leading or trailing whitespace.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range in the XML buffer representing the attribute value
+    ///
+    /// # Returns
+    /// A new `XmlLocation` representing the normalized attribute value, with leading
+    /// and trailing whitespace removed, and escape sequences translated.
+    ///
+    /// # Errors
+    /// The errors of `translate_sequence()`.
+    ///
+    /// # Note
+    /// This method modifies the XML buffer in place, so the original range may be adjusted.
+    /// The value is moved to the text store if an entity is longer than its reference.
+    #[inline(always)]
+    fn normalize_attribute_value(&mut self, range: &XmlRange) -> Result<XmlRange, ParseXmlError> {
+        let mut region = range.clone();
+        let mut to = range.start;
+        let mut from = range.start;
+        let mut space_added = false;
+
+        loop {
+            let next_pos =
+                match self.scan_range_for_chartype(from..region.end, Chartype::ParseAtrNorm) {
+                    Some(pos) => pos,
+                    None => region.end, // No more characters of the specified type found
+                };
+
+            if next_pos >= region.end {
+                if from != to {
+                    // Move the tail content to the `to` position
+                    self.xml
+                        .copy_within(from as usize..region.end as usize, to as usize);
+                }
+                to += region.end - from;
+                if to > region.start && self.xml[(to - 1) as usize] == SPACE {
+                    // If we added a space, we need to adjust the end position
+                    to -= 1; // Remove the last added space
+                }
+                break; // No more '&' or whitespace found
+            }
+
+            if next_pos > from {
+                // Move the content before the '&' or whitespace to the `to` position
+                self.xml
+                    .copy_within(from as usize..next_pos as usize, to as usize);
+                to += next_pos - from;
+                space_added = false; // Reset space added flag
+            }
+            if self.xml[next_pos as usize] == AMPERSAND {
+                let translated = if self.options.decode_entities {
+                    self.translate_sequence(next_pos + 1, to, &mut region)?
+                } else {
+                    None
+                };
+                if let Some((new_from, new_to)) = translated {
+                    from = new_from;
+                    to = new_to;
+                } else {
+                    // Not translated, keep the '&'
+                    self.xml[to as usize] = AMPERSAND;
+                    from = next_pos + 1;
+                    to += 1;
+                }
+                space_added = false; // Reset space added flag
+            } else {
+                // Handle whitespace normalization
+                if !space_added && to != region.start {
+                    // Add a space if not already added
+                    self.xml[to as usize] = SPACE;
+                    to += 1;
+                    space_added = true;
+                }
+                from = next_pos + 1; // Move past the whitespace
+            }
+        }
+
+        Ok(region.start..to)
+    }
+
+    /// Checks if a byte is of a specific character type.
+    ///
+    /// This method uses a precomputed table to determine if the byte
+    /// belongs to a specific character type (e.g., whitespace, letter, digit).
+    ///
+    /// # Arguments
+    /// * `byte` - The byte to check
+    /// * `chartype` - The character type to check against
+    ///
+    /// # Returns
+    /// `true` if the byte matches the character type, `false` otherwise
+    #[inline(always)]
+    pub(crate) fn is_of_type(byte: u8, chartype: Chartype) -> bool {
+        (CHARTYPE_TABLE[byte as usize] & chartype as u8) != 0
+    }
+
+    /// Checks that a name supplied to the mutable DOM methods or to the `DocumentBuilder` is
+    /// an element or attribute name the parser would accept.
+    ///
+    /// # Arguments
+    /// * `name` - The name to check
+    /// * `kind` - The kind of name (`element` or `attribute`), for the error message
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the name is empty, does not start with a letter or
+    ///   underscore, or contains characters not allowed in names.
+    pub(crate) fn check_name(name: &str, kind: &str) -> Result<(), ParseXmlError> {
+        match name.as_bytes().split_first() {
+            None => Err(ParseXmlError::InvalidXml(format!(
+                "An {kind} name cannot be empty"
+            ))),
+            Some((&first, rest))
+                if Self::is_of_type(first, Chartype::StartSymBol)
+                    && rest
+                        .iter()
+                        .all(|&byte| Self::is_of_type(byte, Chartype::Symbol)) =>
+            {
+                Ok(())
+            }
+            Some(_) => Err(ParseXmlError::InvalidXml(format!(
+                "Invalid {kind} name: '{name}'"
+            ))),
+        }
+    }
+
+    /// Trims trailing whitespace characters from the end of a specified XML range.
+    ///
+    /// This method iterates backward from the end of the range, removing any
+    /// whitespace characters (spaces, tabs, newlines) until it reaches a non-whitespace
+    /// character or the start of the range. It effectively adjusts the end index
+    /// of the range to exclude trailing whitespace.
+    /// # Arguments
+    /// * `range` - The byte range to trim
+    ///
+    /// # Returns
+    /// The new end index of the range after trimming trailing whitespace
+    #[inline(always)]
+    fn trim_the_ending_whitespaces(&self, range: XmlRange) -> XmlIdx {
+        let mut end = range.end;
+        while end > range.start && Self::is_of_type(self.xml[(end - 1) as usize], Chartype::Space) {
+            end -= 1;
+        }
+        end
+    }
+
+    /// Removes the namespace prefix from an XML element or attribute name.
+    ///
+    /// This method searches for a colon (`:`) character within the specified range
+    /// and removes everything before it (including the colon itself), effectively
+    /// stripping the namespace prefix. If no colon is found, or if the prefix is not
+    /// selected by the prefix filter of the parsing options, the original range is
+    /// returned unchanged.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range containing the name with potential namespace prefix
     ///
     /// # Returns
     /// A new range containing only the local name part (without namespace prefix)
@@ -866,7 +1658,11 @@ impl Document {
         let colon_pos = search_char!(COLON, &self.xml[range.start as usize..range.end as usize])
             .map_or(range.end, |pos| range.start + pos as XmlIdx);
 
-        if colon_pos < range.end {
+        if colon_pos < range.end
+            && self
+                .options
+                .removes_prefix(&self.xml[range.start as usize..colon_pos as usize])
+        {
             // Remove the namespace prefix
             self.xml.copy_within(
                 (colon_pos + 1) as usize..range.end as usize,
@@ -878,6 +1674,167 @@ impl Document {
         }
     }
 
+    /// Splits the content of a processing instruction into its target and data.
+    ///
+    /// # Arguments
+    /// * `start` - The position of the target, just after `<?`
+    /// * `end` - The position just after the closing `?>`
+    ///
+    /// # Returns
+    /// The ranges of the target and of the data, the latter without its surrounding whitespace
+    fn pi_ranges(&self, start: XmlIdx, end: XmlIdx) -> (XmlRange, XmlRange) {
+        let content_end = end - 2;
+        let target_end = self
+            .scan_range_for_chartype(start..content_end, Chartype::Space)
+            .unwrap_or(content_end);
+        let data_start = self
+            .skip_chartype(target_end, Chartype::Space)
+            .map_or(content_end, |pos| pos.min(content_end));
+        let data_end = self.trim_the_ending_whitespaces(data_start..content_end);
+
+        (start..target_end, data_start..data_end)
+    }
+
+    /// Returns the locations of the target and data of a processing instruction, null-terminating
+    /// them with the `use_cstr` feature.
+    ///
+    /// # Arguments
+    /// * `target` - The range of the target
+    /// * `data` - The range of the data
+    #[inline]
+    fn pi_locations(&mut self, target: XmlRange, data: XmlRange) -> (XmlLocation, XmlLocation) {
+        #[cfg(feature = "use_cstr")]
+        {
+            self.xml[target.end as usize] = 0; // Null-terminate the target
+            self.xml[data.end as usize] = 0; // Null-terminate the data
+            (target.start, data.start)
+        }
+
+        #[cfg(not(feature = "use_cstr"))]
+        (target, data)
+    }
+
+    /// Folds an element or attribute name to lowercase in the buffer, when the
+    /// `lowercase_names` option is enabled.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range containing the name
+    #[inline(always)]
+    fn fold_name_case(&mut self, range: XmlRange) {
+        if self.options.lowercase_names {
+            self.xml[range.start as usize..range.end as usize].make_ascii_lowercase();
+        }
+    }
+
+    /// Removes the namespace prefix from an element or attribute name, when the
+    /// `namespace_removal` option is enabled.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range containing the name
+    ///
+    /// # Returns
+    /// The range of the name to record
+    #[inline(always)]
+    fn process_name(&mut self, range: XmlRange) -> XmlRange {
+        if self.options.namespace_removal {
+            self.remove_namespace_prefix(range)
+        } else {
+            range
+        }
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    /// Returns the location of the declared prefix if the attribute name is a namespace declaration.
+    ///
+    /// The returned range is where the prefix will be located once the attribute name has been
+    /// processed (i.e. after the removal of the `xmlns:` part when the `namespace_removal` option is enabled).
+    /// The default namespace declaration (`xmlns`) returns an empty range.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range containing the attribute name
+    fn namespace_declaration_prefix(&self, range: &XmlRange) -> Option<XmlRange> {
+        let name = &self.xml[range.start as usize..range.end as usize];
+
+        if name == b"xmlns" {
+            Some(range.start..range.start)
+        } else if name.starts_with(b"xmlns:") {
+            if self.options.removes_prefix(b"xmlns") {
+                Some(range.start..(range.end - 6))
+            } else {
+                Some((range.start + 6)..range.end)
+            }
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    /// Resolves the namespace URI of an element against the in-scope namespace declarations.
+    ///
+    /// The innermost declaration of the element's prefix is used. Nothing is recorded if the
+    /// prefix is not declared, or if it is bound to an empty URI (`xmlns=""`).
+    ///
+    /// # Arguments
+    /// * `node_idx` - The index of the element node
+    /// * `prefix` - The prefix of the element name, empty if there is none
+    /// * `ns_decls` - The in-scope namespace declarations, innermost last
+    fn resolve_namespace(&mut self, node_idx: NodeIdx, prefix: &[u8], ns_decls: &[NamespaceDecl]) {
+        let Some(decl) = ns_decls
+            .iter()
+            .rev()
+            .find(|decl| &self.xml[decl.prefix.start as usize..decl.prefix.end as usize] == prefix)
+        else {
+            return;
+        };
+
+        #[cfg(feature = "use_cstr")]
+        let (uri, is_empty) = (decl.uri, self.xml[decl.uri as usize] == 0);
+
+        #[cfg(not(feature = "use_cstr"))]
+        let (uri, is_empty) = (decl.uri.clone(), decl.uri.is_empty());
+
+        if !is_empty {
+            self.namespaces.push((node_idx, uri));
+        }
+    }
+
+    /// Checks for content located after the end of the root element.
+    ///
+    /// Whitespace, comments and processing instructions are allowed after the root element.
+    /// Anything else is still parsed, the elements being added as siblings of the root
+    /// element, but recorded so that strict callers can reject the document through
+    /// `Document::ensure_single_root()`.
+    ///
+    /// # Arguments
+    /// * `i` - The position following the end of the root element
+    fn check_trailing_content(&mut self, mut i: XmlIdx) {
+        let size = self.content_len;
+
+        loop {
+            i = match self.skip_chartype(i, Chartype::Space) {
+                Some(new_i) if new_i < size => new_i,
+                _ => return,
+            };
+
+            let rest = &self.xml[i as usize..];
+            let skipped = if rest.starts_with(b"<!--") {
+                self.skip_after_slice(i + 4, b"-->".as_slice())
+            } else if rest.starts_with(b"<?") {
+                self.skip_after_slice(i + 2, b"?>".as_slice())
+            } else {
+                None
+            };
+
+            match skipped {
+                Some(new_i) => i = new_i,
+                None => {
+                    self.had_trailing_content = true;
+                    return;
+                }
+            }
+        }
+    }
+
     /// Parses the XML document and builds the document tree structure.
     ///
     /// This is the main parsing method that implements a state machine to process
@@ -887,8 +1844,8 @@ impl Document {
     /// - Attributes with quoted values
     /// - Text content with entity translation
     /// - Comments and processing instructions (bypass)
-    /// - CDATA sections (bypass)
-    /// - DOCTYPE and DTD declarations (bypass)
+    /// - CDATA sections (bypass, or text nodes with the `keep_cdata` option)
+    /// - DOCTYPE and DTD declarations (bypass, except the entity declarations of the internal subset)
     ///
     /// The parser maintains a current parent node and builds the tree by adding
     /// child nodes as it encounters different XML constructs. It performs
@@ -904,21 +1861,79 @@ impl Document {
     /// - Invalid character sequences
     /// - Malformed attributes
     /// - Unexpected end of document
-    #[allow(clippy::too_many_lines)]
+    #[inline]
     pub(crate) fn parse(&mut self) -> Result<(), ParseXmlError> {
+        self.parse_content(false)
+    }
+
+    /// Parses the XML content, as `parse()`, optionally as a fragment.
+    ///
+    /// A fragment is parsed inside a synthetic root element, opened before the content, and
+    /// closed by the `</fragment>` end tag the content must be followed by in the buffer.
+    ///
+    /// # Arguments
+    /// * `fragment` - True to parse the content as a fragment
+    ///
+    /// # Errors
+    /// The same errors as `parse()`.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn parse_content(&mut self, fragment: bool) -> Result<(), ParseXmlError> {
         let mut parenthood = Vec::<Parent>::with_capacity(20);
         let mut level = 0usize;
 
+        #[cfg(feature = "namespace_aware")]
+        let mut ns_decls = Vec::<NamespaceDecl>::new();
+        #[cfg(feature = "namespace_aware")]
+        let mut elem_prefix = Vec::<u8>::new(); // Prefix of the element being opened
+
         let mut state = State::Start;
         let mut i: XmlIdx = 0 as XmlIdx;
+        let mut tag_start: XmlIdx = 0; // Position of the '<' of the current tag
+        let mut top_level_idx: NodeIdx = 0; // Last element added outside of any element
+
+        self.content_len = self.xml.len() as XmlIdx;
+        let size = self.content_len;
+        let mut next_progress = match &self.options.progress {
+            Some(progress) => progress.interval.min(XmlIdx::MAX as usize) as XmlIdx,
+            None => XmlIdx::MAX,
+        };
+
+        if fragment {
+            // The name of the synthetic root element is the one of its end tag
+            let name_end = size - 1;
+            let name_start = name_end - FRAGMENT_ROOT_NAME.len() as XmlIdx;
 
-        let size = self.xml.len() as XmlIdx;
+            #[cfg(feature = "use_cstr")]
+            let name = name_start; // Null-terminated when the end tag is read
+
+            #[cfg(not(feature = "use_cstr"))]
+            let name = name_start..name_end;
+
+            let node_idx = self.add_node(
+                0,
+                0,
+                NodeType::Element {
+                    name,
+                    attributes: 0..0,
+                },
+            )?;
+            parenthood.push(Parent::new(node_idx));
+            self.start_span(node_idx, 0);
+            self.start_span_content(node_idx, 0);
+            level = 1;
+            state = State::ReadPCData;
+        }
 
         loop {
             state = match state {
                 State::Start => {
                     i = match self.scan_until_char(i, LESS_THAN) {
                         Some(new_i) => new_i,
+                        None if !self.is_empty() => {
+                            // End of the content following the root element
+                            state = State::End;
+                            continue;
+                        }
                         None => break,
                     };
                     i += 1;
@@ -929,6 +1944,10 @@ impl Document {
                     State::ReadStartOfTag
                 }
                 State::ReadStartOfTag => {
+                    tag_start = i - 1;
+                    if tag_start >= next_progress {
+                        next_progress = self.report_progress(tag_start);
+                    }
                     match self.xml[i as usize] {
                         SLASH => {
                             i += 1;
@@ -939,10 +1958,46 @@ impl Document {
                             if i < size {
                                 if self.xml[i as usize..].starts_with(b"--") {
                                     i += 2;
-                                    i = match self.skip_after_slice(i, 5000, b"-->".as_slice()) {
+                                    let start = i;
+                                    i = match self.skip_after_slice(i, b"-->".as_slice()) {
                                         Some(new_i) => new_i,
-                                        None => break,
+                                        None => {
+                                            self.recover(
+                                                DiagnosticKind::UnexpectedEnd,
+                                                "Unterminated comment",
+                                                tag_start,
+                                            )?;
+                                            break;
+                                        }
                                     };
+
+                                    if level == 0 && self.is_empty() {
+                                        // Comment located before the root element
+                                        #[cfg(feature = "use_cstr")]
+                                        {
+                                            self.xml[(i - 3) as usize] = 0; // Null-terminate the string
+                                            self.prolog_comments.push(start);
+                                        }
+
+                                        #[cfg(not(feature = "use_cstr"))]
+                                        self.prolog_comments.push(start..(i - 3));
+                                    } else if level > 0 && self.options.keep_comments {
+                                        #[cfg(feature = "use_cstr")]
+                                        let location = {
+                                            self.xml[(i - 3) as usize] = 0; // Null-terminate the string
+                                            start
+                                        };
+
+                                        #[cfg(not(feature = "use_cstr"))]
+                                        let location = start..(i - 3);
+
+                                        let node_idx = self.add_node(
+                                            parenthood[level - 1].parent_idx,
+                                            parenthood[level - 1].last_child_idx,
+                                            NodeType::Comment(location),
+                                        )?;
+                                        parenthood[level - 1].last_child_idx = node_idx;
+                                    }
                                 } else if self.xml[i as usize..].starts_with(b"DOCTYPE") {
                                     i += 7;
                                     i = match self.scan_until_one_of_2_chars(
@@ -955,9 +2010,16 @@ impl Document {
                                     };
 
                                     if self.xml[i as usize] == LEFT_BRACKET {
-                                        i = match self.scan_until_char(i, RIGHT_BRACKET) {
+                                        i = match self.parse_internal_subset(i + 1) {
                                             Some(new_i) => new_i,
-                                            None => break,
+                                            None => {
+                                                self.recover(
+                                                    DiagnosticKind::UnexpectedEnd,
+                                                    "Unterminated DOCTYPE declaration",
+                                                    tag_start,
+                                                )?;
+                                                break;
+                                            }
                                         };
                                         i += 1; // skip ']'
                                         i = match self.skip_chartype(i, Chartype::Space) {
@@ -965,40 +2027,107 @@ impl Document {
                                             None => break,
                                         };
 
-                                        if self.xml[i as usize] == GREATER_THAN {
-                                            i += 1; // skip '>'
-                                        } else {
-                                            return self.invalid(
+                                        if self.xml[i as usize] != GREATER_THAN {
+                                            self.recover(
+                                                DiagnosticKind::MalformedDeclaration,
                                                 "Expected '>' after DOCTYPE declaration",
                                                 i,
-                                            );
+                                            )?;
+                                            i = match self.scan_until_char(i, GREATER_THAN) {
+                                                Some(new_i) => new_i,
+                                                None => break,
+                                            };
                                         }
                                     }
                                     i += 1; // skip '>'
                                 } else if self.xml[i as usize..].starts_with(b"[CDATA[") {
                                     i += 7;
-                                    i = match self.skip_after_slice(i, 5000, b"]]>".as_slice()) {
+                                    let start = i;
+                                    i = match self.skip_after_slice(i, b"]]>".as_slice()) {
                                         Some(new_i) => new_i,
-                                        None => break,
+                                        None => {
+                                            self.recover(
+                                                DiagnosticKind::UnexpectedEnd,
+                                                "Unterminated CDATA section",
+                                                tag_start,
+                                            )?;
+                                            break;
+                                        }
                                     };
+
+                                    if level > 0 && self.options.keep_cdata && i - 3 > start {
+                                        #[cfg(feature = "use_cstr")]
+                                        let location = {
+                                            self.xml[(i - 3) as usize] = 0; // Null-terminate the string
+                                            start
+                                        };
+
+                                        #[cfg(not(feature = "use_cstr"))]
+                                        let location = start..(i - 3);
+
+                                        let node_idx = self.add_node(
+                                            parenthood[level - 1].parent_idx,
+                                            parenthood[level - 1].last_child_idx,
+                                            NodeType::Text(location),
+                                        )?;
+                                        parenthood[level - 1].last_child_idx = node_idx;
+                                        self.cdata_nodes.push(node_idx);
+                                    }
                                 } else {
                                     break;
                                 }
                             }
                             if i >= size {
                                 State::End
+                            } else if level == 0 && !self.is_empty() {
+                                // The text following the root element is ignored
+                                State::Start
                             } else {
                                 State::ReadPCData
                             }
                         }
                         QUESTION_MARK => {
                             i += 1;
-                            i = match self.skip_after_slice(i, 500, b"?>".as_slice()) {
+                            let start = i;
+                            i = match self.skip_after_slice(i, b"?>".as_slice()) {
                                 Some(new_i) => new_i,
-                                None => break,
+                                None => {
+                                    self.recover(
+                                        DiagnosticKind::UnexpectedEnd,
+                                        "Unterminated processing instruction",
+                                        tag_start,
+                                    )?;
+                                    break;
+                                }
                             };
+
+                            if level > 0 && self.options.keep_processing_instructions {
+                                let (target, data) = self.pi_ranges(start, i);
+                                let (target, data) = self.pi_locations(target, data);
+                                let node_idx = self.add_node(
+                                    parenthood[level - 1].parent_idx,
+                                    parenthood[level - 1].last_child_idx,
+                                    NodeType::ProcessingInstruction { target, data },
+                                )?;
+                                parenthood[level - 1].last_child_idx = node_idx;
+                            } else if level == 0 && self.is_empty() {
+                                // Processing instruction located before the root element
+                                let (target, data) = self.pi_ranges(start, i);
+                                let name = &self.xml[target.start as usize..target.end as usize];
+                                if !name.eq_ignore_ascii_case(b"xml") {
+                                    let locations = self.pi_locations(target, data);
+                                    self.prolog_pis.push(locations);
+                                } else if self.declaration.is_none() {
+                                    let data = &self.xml[data.start as usize..data.end as usize];
+                                    self.declaration = Some(XmlDeclaration::from_data(data));
+                                }
+                            }
+
                             if i >= size {
                                 State::End
+                            } else if level == 0 && !self.is_empty() {
+                                // The text following the root element is ignored
+                                State::Start
                             } else {
                                 State::ReadPCData
                             }
@@ -1009,24 +2138,54 @@ impl Document {
                 State::ReadTagOpen => {
                     let start = i;
                     if !Self::is_of_type(self.xml[i as usize], Chartype::StartSymBol) {
-                        return self.invalid(
+                        self.recover(
+                            DiagnosticKind::InvalidTagName,
                             "Tag name must start with a letter or underscore",
                             i as XmlIdx,
-                        );
+                        )?;
+                        // The '<' is ignored
+                        state = if level == 0 {
+                            State::Start
+                        } else {
+                            State::ReadPCData
+                        };
+                        continue;
                     }
                     i += 1; // skip first char of tag name
                     i = match self.skip_chartype(i, Chartype::Symbol) {
                         Some(new_i) => new_i,
                         None => break,
                     };
+                    self.fold_name_case(start..i);
 
-                    #[cfg(feature = "namespace_removal")]
-                    // Remove namespace prefix from attribute name
-                    let name_range = self.remove_namespace_prefix(start..i);
+                    #[cfg(feature = "namespace_aware")]
+                    {
+                        // Keep the prefix, as it may be removed from the buffer
+                        elem_prefix.clear();
+                        let name = &self.xml[start as usize..i as usize];
+                        if let Some(pos) = search_char!(COLON, name) {
+                            elem_prefix.extend_from_slice(&name[..pos]);
+                        }
+                    }
 
-                    #[cfg(not(feature = "namespace_removal"))]
-                    // If namespace removal is not enabled, use the original range
-                    let name_range = start..i;
+                    // Remove namespace prefix from the name, if enabled
+                    let name_range = self.process_name(start..i);
+
+                    if self.options.html_leniency {
+                        // Close the open elements the new element cannot be nested in
+                        while level > 1
+                            && html::closes_on_open(
+                                self.element_name(parenthood[level - 1].parent_idx),
+                                &self.xml[name_range.start as usize..name_range.end as usize],
+                            )
+                        {
+                            #[cfg(feature = "namespace_aware")]
+                            ns_decls.truncate(parenthood[level - 1].ns_decls_len);
+                            self.end_span(parenthood[level - 1].parent_idx, tag_start, tag_start);
+                            parenthood.pop();
+                            level -= 1;
+                        }
+                    }
 
                     #[cfg(feature = "use_cstr")]
                     {
@@ -1035,10 +2194,10 @@ impl Document {
 
                         self.xml[name_range.end as usize] = 0; // Null-terminate the string
                         let node_idx = if level == 0 {
-                            // If this is the root element, we set the root node index
+                            // The root element, or an element following it
                             self.add_node(
                                 0,
-                                0,
+                                top_level_idx,
                                 NodeType::Element {
                                     name: name_range.start,
                                     attributes: 0..0, // Placeholder for attributes range
@@ -1056,22 +2215,50 @@ impl Document {
                         };
                         if level > 0 {
                             parenthood[level - 1].last_child_idx = node_idx;
+                        } else {
+                            top_level_idx = node_idx;
                         }
                         parenthood.push(Parent::new(node_idx));
+                        parenthood[level].preserve_ws = (level > 0
+                            && parenthood[level - 1].preserve_ws)
+                            || self
+                                .options
+                                .preserves_whitespace(self.element_name(node_idx));
+                        self.start_span(node_idx, tag_start);
+                        #[cfg(feature = "namespace_aware")]
+                        {
+                            parenthood[level].ns_decls_len = ns_decls.len();
+                        }
                         level += 1;
 
                         i += 1; // skip the null terminator (or not if there was a removed namespace prefix)
 
+                        #[cfg(feature = "namespace_aware")]
+                        if byte == SLASH || byte == GREATER_THAN {
+                            // No attribute, the namespace can be resolved now
+                            self.resolve_namespace(node_idx, &elem_prefix, &ns_decls);
+                        }
+
                         if byte == SLASH {
                             if i >= size || self.xml[i as usize] != GREATER_THAN {
-                                return self
-                                    .invalid("Expected '>' after '/' in self-closing tag", i);
+                                self.recover(
+                                    DiagnosticKind::MalformedTag,
+                                    "Expected '>' after '/' in self-closing tag",
+                                    i,
+                                )?;
+                                state = State::ReadAttribute;
+                                continue;
                             }
+                            #[cfg(feature = "namespace_aware")]
+                            ns_decls.truncate(parenthood[level - 1].ns_decls_len);
+                            self.end_span(node_idx, i + 1, i + 1);
                             parenthood.pop();
                             level -= 1;
 
                             if level == 0 {
-                                state = State::End;
+                                i += 1;
+                                self.check_trailing_content(i);
+                                state = State::Start;
                                 continue;
                             }
 
@@ -1082,6 +2269,31 @@ impl Document {
                             state = State::ReadPCData;
                             continue;
                         } else if byte == GREATER_THAN {
+                            self.start_span_content(node_idx, i);
+                            if self.options.html_void_elements
+                                && html::is_void_element(self.element_name(node_idx))
+                            {
+                                #[cfg(feature = "namespace_aware")]
+                                ns_decls.truncate(parenthood[level - 1].ns_decls_len);
+                                self.end_span(node_idx, i, i);
+                                parenthood.pop();
+                                level -= 1;
+
+                                if level == 0 {
+                                    self.check_trailing_content(i);
+                                    state = State::Start;
+                                    continue;
+                                }
+                            } else if self.options.html_raw_text
+                                && html::is_raw_text_element(self.element_name(node_idx))
+                            {
+                                i = match self.read_raw_text(&mut parenthood[level - 1], i)? {
+                                    Some(new_i) => new_i + 1,
+                                    None => break,
+                                };
+                                state = State::ReadStartOfTag;
+                                continue;
+                            }
                             if i >= size {
                                 break;
                             }
@@ -1093,9 +2305,10 @@ impl Document {
                     #[cfg(not(feature = "use_cstr"))]
                     {
                         let node_idx = if level == 0 {
+                            // The root element, or an element following it
                             self.add_node(
                                 0,
-                                0,
+                                top_level_idx,
                                 NodeType::Element {
                                     name: name_range,
                                     attributes: 0..0, // Placeholder for attributes range
@@ -1113,8 +2326,20 @@ impl Document {
                         };
                         if level > 0 {
                             parenthood[level - 1].last_child_idx = node_idx;
+                        } else {
+                            top_level_idx = node_idx;
                         }
                         parenthood.push(Parent::new(node_idx));
+                        parenthood[level].preserve_ws = (level > 0
+                            && parenthood[level - 1].preserve_ws)
+                            || self
+                                .options
+                                .preserves_whitespace(self.element_name(node_idx));
+                        self.start_span(node_idx, tag_start);
+                        #[cfg(feature = "namespace_aware")]
+                        {
+                            parenthood[level].ns_decls_len = ns_decls.len();
+                        }
                         level += 1;
                     }
 
@@ -1129,44 +2354,91 @@ impl Document {
                     };
 
                     if i == start {
-                        return self.invalid("Expected tag name after '</'", i);
+                        self.recover(
+                            DiagnosticKind::MalformedTag,
+                            "Expected tag name after '</'",
+                            i,
+                        )?;
+                        i = match self.scan_until_char(i, GREATER_THAN) {
+                            Some(new_i) => new_i + 1,
+                            None => break,
+                        };
+                        state = if level == 0 {
+                            State::Start
+                        } else {
+                            State::ReadPCData
+                        };
+                        continue;
                     }
 
-                    #[cfg(feature = "namespace_removal")]
-                    // Remove namespace prefix from attribute name
-                    let name_range = self.remove_namespace_prefix(start..i);
+                    self.fold_name_case(start..i);
 
-                    #[cfg(not(feature = "namespace_removal"))]
-                    // If namespace removal is not enabled, use the original range
-                    let name_range = start..i;
+                    // Remove namespace prefix from the name, if enabled
+                    let name_range = self.process_name(start..i);
 
                     let is_greater_than = self.xml[i as usize] == GREATER_THAN;
 
+                    // The end tags of void elements are ignored, the elements being already closed
+                    let is_void = self.options.html_void_elements
+                        && html::is_void_element(
+                            &self.xml[name_range.start as usize..name_range.end as usize],
+                        );
+
                     #[cfg(feature = "use_cstr")]
-                    {
+                    let location = {
                         self.xml[name_range.end as usize] = 0; // Null-terminate the string
-                        if level > 0 {
-                            self.check_closing_tag(
-                                parenthood[level - 1].parent_idx,
-                                name_range.start,
-                            )?;
-                        } else {
-                            return self.invalid("No opening tag for closing tag", i);
-                        }
-                    }
+                        name_range.start
+                    };
 
                     #[cfg(not(feature = "use_cstr"))]
-                    {
-                        if level > 0 {
-                            self.check_closing_tag(parenthood[level - 1].parent_idx, name_range)?;
-                        } else {
-                            return self.invalid("No opening tag for closing tag", i);
+                    let location = name_range;
+
+                    // Number of elements implicitly closed with the tag's element
+                    let html_closed = if level > 0 && self.options.html_leniency {
+                        self.html_closed_count(&parenthood, &location)?
+                    } else {
+                        0
+                    };
+
+                    // Number of elements closed by the tag
+                    let closed = if level == 0 {
+                        self.recover(
+                            DiagnosticKind::UnmatchedClosingTag,
+                            "No opening tag for closing tag",
+                            i,
+                        )?;
+                        0
+                    } else if is_void {
+                        0
+                    } else if html_closed > 0 {
+                        html_closed
+                    } else if self.check_closing_tag(parenthood[level - 1].parent_idx, &location)? {
+                        1
+                    } else {
+                        // Recovering: if an enclosing element has the same name, the elements
+                        // opened since are implicitly closed, otherwise the tag is ignored
+                        let mut closed = 0;
+                        for l in (0..level - 1).rev() {
+                            if self.closing_tag_matches(parenthood[l].parent_idx, &location)? {
+                                closed = level - l;
+                                break;
+                            }
                         }
-                    }
+                        closed
+                    };
 
-                    if level > 0 {
-                        parenthood.pop();
-                        level -= 1;
+                    // The element closed by the tag, the elements it implicitly closes ending
+                    // at the tag
+                    let mut closed_idx = 0;
+                    if closed > 0 {
+                        level -= closed;
+                        closed_idx = parenthood[level].parent_idx;
+                        for parent in &parenthood[level + 1..] {
+                            self.end_span(parent.parent_idx, tag_start, tag_start);
+                        }
+                        #[cfg(feature = "namespace_aware")]
+                        ns_decls.truncate(parenthood[level].ns_decls_len);
+                        parenthood.truncate(level);
                     }
 
                     if !is_greater_than {
@@ -1177,9 +2449,18 @@ impl Document {
                     }
 
                     i += 1;
-                    if i >= size
-                    /* || level == 0 */
-                    {
+                    if closed > 0 {
+                        self.end_span(closed_idx, tag_start, i);
+                    }
+                    if level == 0 {
+                        if closed > 0 {
+                            // A top-level element is closed
+                            self.check_trailing_content(i);
+                            State::Start
+                        } else {
+                            State::Start
+                        }
+                    } else if i >= size {
                         State::End
                     } else {
                         State::ReadPCData
@@ -1197,14 +2478,28 @@ impl Document {
                                 break;
                             }
                             if self.xml[i as usize] != GREATER_THAN {
-                                return self
-                                    .invalid("Expected '>' after '/' in self-closing tag", i);
+                                self.recover(
+                                    DiagnosticKind::MalformedTag,
+                                    "Expected '>' after '/' in self-closing tag",
+                                    i,
+                                )?;
+                                state = State::ReadAttribute;
+                                continue;
+                            }
+                            #[cfg(feature = "namespace_aware")]
+                            {
+                                let node_idx = parenthood[level - 1].parent_idx;
+                                self.resolve_namespace(node_idx, &elem_prefix, &ns_decls);
+                                ns_decls.truncate(parenthood[level - 1].ns_decls_len);
                             }
+                            self.end_span(parenthood[level - 1].parent_idx, i + 1, i + 1);
                             parenthood.pop();
                             level -= 1;
 
                             if level == 0 {
-                                State::End
+                                i += 1;
+                                self.check_trailing_content(i);
+                                State::Start
                             } else {
                                 i += 1;
                                 if i >= size {
@@ -1214,7 +2509,36 @@ impl Document {
                             }
                         }
                         GREATER_THAN => {
+                            let node_idx = parenthood[level - 1].parent_idx;
+                            #[cfg(feature = "namespace_aware")]
+                            self.resolve_namespace(node_idx, &elem_prefix, &ns_decls);
                             i += 1;
+                            self.start_span_content(node_idx, i);
+
+                            if self.options.html_void_elements
+                                && html::is_void_element(self.element_name(node_idx))
+                            {
+                                #[cfg(feature = "namespace_aware")]
+                                ns_decls.truncate(parenthood[level - 1].ns_decls_len);
+                                self.end_span(node_idx, i, i);
+                                parenthood.pop();
+                                level -= 1;
+
+                                if level == 0 {
+                                    self.check_trailing_content(i);
+                                    state = State::Start;
+                                    continue;
+                                }
+                            } else if self.options.html_raw_text
+                                && html::is_raw_text_element(self.element_name(node_idx))
+                            {
+                                i = match self.read_raw_text(&mut parenthood[level - 1], i)? {
+                                    Some(new_i) => new_i + 1,
+                                    None => break,
+                                };
+                                state = State::ReadStartOfTag;
+                                continue;
+                            }
                             if i >= size {
                                 break;
                             }
@@ -1223,10 +2547,14 @@ impl Document {
                         _ => {
                             let start = i;
                             if !Self::is_of_type(self.xml[i as usize], Chartype::StartSymBol) {
-                                return self.invalid(
+                                self.recover(
+                                    DiagnosticKind::InvalidAttribute,
                                     "Attribute name must start with a letter or underscore",
                                     i,
-                                );
+                                )?;
+                                i = self.skip_malformed(i);
+                                state = State::ReadAttribute;
+                                continue;
                             }
                             i += 1;
                             i = match self.skip_chartype(i, Chartype::Symbol) {
@@ -1235,17 +2563,31 @@ impl Document {
                             };
 
                             if self.xml[i as usize] != EQUAL {
-                                return self.invalid("Attribute must have an '=' sign", i);
+                                self.recover(
+                                    DiagnosticKind::InvalidAttribute,
+                                    "Attribute must have an '=' sign",
+                                    i,
+                                )?;
+                                i = self.skip_malformed(i);
+                                state = State::ReadAttribute;
+                                continue;
                             }
                             let end = i;
+                            self.fold_name_case(start..end);
                             i += 1; //skip =
                             if i >= size {
                                 break;
                             }
                             let quote = self.xml[i as usize];
                             if (quote != b'\'') && (quote != b'"') {
-                                return self
-                                    .invalid("Attribute value must be enclosed in quotes", i);
+                                self.recover(
+                                    DiagnosticKind::InvalidAttribute,
+                                    "Attribute value must be enclosed in quotes",
+                                    i,
+                                )?;
+                                i = self.skip_malformed(i);
+                                state = State::ReadAttribute;
+                                continue;
                             }
                             i += 1;
                             let value_start = i;
@@ -1254,15 +2596,21 @@ impl Document {
                                 None => break,
                             };
 
-                            let value_range = self.normalize_attribute_value(&(value_start..i));
+                            let value_range = self.normalize_attribute_value(&(value_start..i))?;
+
+                            #[cfg(feature = "namespace_aware")]
+                            if let Some(prefix) = self.namespace_declaration_prefix(&(start..end)) {
+                                #[cfg(feature = "use_cstr")]
+                                let uri = value_range.start;
 
-                            #[cfg(feature = "namespace_removal")]
-                            // Remove namespace prefix from attribute name
-                            let name_range = self.remove_namespace_prefix(start..end);
+                                #[cfg(not(feature = "use_cstr"))]
+                                let uri = value_range.clone();
 
-                            #[cfg(not(feature = "namespace_removal"))]
-                            // If namespace removal is not enabled, use the original range
-                            let name_range = start..end;
+                                ns_decls.push(NamespaceDecl { prefix, uri });
+                            }
+
+                            // Remove namespace prefix from the name, if enabled
+                            let name_range = self.process_name(start..end);
 
                             #[cfg(feature = "use_cstr")]
                             {
@@ -1297,6 +2645,7 @@ impl Document {
                 }
                 State::ReadPCData => {
                     let space_start = i; // in case we must keep whitespaces
+                    let preserve_ws = level > 0 && parenthood[level - 1].preserve_ws;
                     match self.skip_chartype(i, Chartype::Space) {
                         Some(new_i) => {
                             i = new_i;
@@ -1312,43 +2661,28 @@ impl Document {
                                 if i > start {
                                     let mut the_end = i;
 
-                                    if cfg!(feature = "trim_pcdata") {
+                                    if self.options.trim_pcdata && !preserve_ws {
                                         the_end = self.trim_the_ending_whitespaces(start..the_end);
                                     } else {
                                         start = space_start; // Reset start to space_start if not trimming
                                     }
 
-                                    let text_range = self.parse_pcdata(&(start..the_end));
-
-                                    #[cfg(feature = "use_cstr")]
-                                    {
-                                        self.xml[text_range.end as usize] = 0; // Null-terminate the string
-                                        if level > 0 {
-                                            let node_idx = self.add_node(
-                                                parenthood[level - 1].parent_idx,
-                                                parenthood[level - 1].last_child_idx,
-                                                NodeType::Text(text_range.start),
-                                            )?;
-                                            parenthood[level - 1].last_child_idx = node_idx;
-                                        } else {
-                                            break;
-                                        }
+                                    if level == 0 {
+                                        break;
                                     }
-
-                                    #[cfg(not(feature = "use_cstr"))]
-                                    if level > 0 {
-                                        let node_idx = self.add_node(
-                                            parenthood[level - 1].parent_idx,
-                                            parenthood[level - 1].last_child_idx,
-                                            NodeType::Text(text_range),
+                                    if self.options.keep_entity_refs {
+                                        self.add_text_with_entity_refs(
+                                            &mut parenthood[level - 1],
+                                            start..the_end,
                                         )?;
-                                        parenthood[level - 1].last_child_idx = node_idx;
                                     } else {
-                                        break;
+                                        self.add_text(&mut parenthood[level - 1], start..the_end)?;
                                     }
                                 } else {
-                                    #[cfg(feature = "keep_ws_only_pcdata")]
-                                    if i > space_start && level != 0 {
+                                    if (self.options.keep_ws_only_pcdata || preserve_ws)
+                                        && i > space_start
+                                        && level != 0
+                                    {
                                         #[cfg(feature = "use_cstr")]
                                         {
                                             self.xml[i as usize] = 0; // Null-terminate the string
@@ -1390,11 +2724,32 @@ impl Document {
                     }
                 }
                 State::End => {
+                    if level > 0 && self.diagnostics.is_some() {
+                        // Recovering: the elements still open are reported below
+                        break;
+                    }
+                    self.report_progress(size);
                     return Ok(());
                 }
             };
         }
 
+        if self.diagnostics.is_some() {
+            let msg = match parenthood
+                .last()
+                .and_then(|p| self.get_node(p.parent_idx).ok())
+            {
+                Some(node) => format!(
+                    "Unexpected end of XML document, element '{}' not closed",
+                    node.tag_name()
+                ),
+                None => "Unexpected end of XML document".to_string(),
+            };
+            self.recover(DiagnosticKind::UnexpectedEnd, &msg, i.min(size))?;
+            self.report_progress(size);
+            return Ok(());
+        }
+
         Err(ParseXmlError::InvalidXml(
             "Unexpected end of XML document.".to_string(),
         ))
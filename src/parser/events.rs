@@ -0,0 +1,563 @@
+//! Pull-parser for XML/XHTML documents.
+//!
+//! This module provides the `Tokenizer`, which scans the XML content lazily and returns its
+//! constructs one `Event` at a time. The caller drives the parsing: it can stop at any point,
+//! skip the content of an element with `Tokenizer::skip_subtree()`, or feed the events to its
+//! own tree builder. The `stream()` SAX function is built on top of it.
+//!
+//! The same in-place processing as the tree-based parser is used: entity translation in
+//! text and attribute values, attribute value normalization, closing tag validation, and
+//! the `namespace_removal`, `parse_escapes` and `trim_pcdata` parsing options.
+//!
+//! As the events borrow the string slices from the XML buffer owned by the tokenizer, they
+//! are obtained with `Tokenizer::next_event()` instead of through the `Iterator` trait. An
+//! event must be dropped before the next one is requested.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::parser::{Event, ParserOptions, Tokenizer};
+//!
+//! let xml_data = b"<root><skip><a>Not this</a></skip><title>Fish &amp; Chips</title></root>".to_vec();
+//! let options = ParserOptions::new().parse_escapes(true);
+//! let mut tokenizer = Tokenizer::new_with_options(xml_data, options).unwrap();
+//! let mut title = String::new();
+//!
+//! loop {
+//!     match tokenizer.next_event().unwrap() {
+//!         Event::StartTag { name: "skip", .. } => tokenizer.skip_subtree().unwrap(),
+//!         Event::Text(text) => {
+//!             title.push_str(text);
+//!             break;
+//!         }
+//!         Event::Eof => break,
+//!         _ => {}
+//!     }
+//! }
+//!
+//! assert_eq!(title, "Fish & Chips");
+//! ```
+
+use crate::defs::{ParseXmlError, XmlIdx};
+use crate::document::Document;
+use crate::encoding;
+
+use super::{
+    Chartype, ParserOptions, SaxAttributes, XmlRange, EQUAL, EXCLAMATION_MARK, GREATER_THAN,
+    LEFT_BRACKET, LESS_THAN, QUESTION_MARK, SLASH,
+};
+
+/// A construct of the XML content, as returned by `Tokenizer::next_event()`.
+#[derive(Debug, Clone)]
+pub enum Event<'a> {
+    /// A start tag, with its attributes. Self-closing elements are followed by an `EndTag`.
+    StartTag {
+        /// The tag name
+        name: &'a str,
+        /// The attributes of the tag, normalized and with their entities translated
+        attributes: SaxAttributes<'a>,
+    },
+    /// An end tag, also returned after the `StartTag` of self-closing elements.
+    EndTag {
+        /// The tag name
+        name: &'a str,
+    },
+    /// The text content of an element, with entities translated.
+    Text(&'a str),
+    /// A text run composed of whitespace only, such as the indentation between elements.
+    Whitespace(&'a str),
+    /// The content of a CDATA section located inside an element, as-is.
+    CData(&'a str),
+    /// The content of a comment, located between `<!--` and `-->`.
+    Comment(&'a str),
+    /// A processing instruction (`<?target data?>`). The XML declaration is returned as a
+    /// processing instruction with the `xml` target.
+    ProcessingInstruction {
+        /// The target of the processing instruction
+        target: &'a str,
+        /// The data following the target, without the surrounding whitespace
+        data: &'a str,
+    },
+    /// The end of the XML content has been reached. It is returned by every subsequent call.
+    Eof,
+}
+
+/// The constructs found by the scanner, as ranges in the XML buffer. Character data runs
+/// are only turned into `Text` when they are returned, so skipped text is left untouched.
+enum Token {
+    StartTag(XmlRange),
+    EndTag(XmlRange),
+    CharData(XmlRange),
+    Text(XmlRange),
+    CData(XmlRange),
+    Comment(XmlRange),
+    Pi(XmlRange, XmlRange),
+    Eof,
+}
+
+/// A pull-parser returning the constructs of the XML content as `Event`s.
+///
+/// No nodes vector is built: the memory used is limited to the XML buffer and a stack of
+/// the currently open elements. Elements must be properly nested and closed, as with
+/// `Document::new()`. Text and elements located outside of any element are not returned.
+///
+/// Once an error has been returned, the tokenizer only returns `Event::Eof`.
+pub struct Tokenizer {
+    pub(super) doc: Document<'static>,
+    pub(super) pos: XmlIdx,
+    /// The names of the open elements, kept out of the XML buffer so that the content
+    /// already returned can be discarded by the `FeedParser`
+    open_names: Vec<u8>,
+    /// The start of each open element name in `open_names`
+    open_tags: Vec<usize>,
+    attributes: Vec<(XmlRange, XmlRange)>,
+    pending_end: Option<XmlRange>,
+    /// True if the XML buffer holds only part of the content, ending at a construct
+    /// boundary, such that open elements are not an error at its end
+    pub(super) partial: bool,
+}
+
+/// Returns the string located at `range` in the XML buffer.
+#[inline]
+pub(super) fn range_str<'a>(xml: &'a [u8], range: &XmlRange) -> &'a str {
+    std::str::from_utf8(&xml[range.start as usize..range.end as usize]).unwrap_or("non valid utf-8")
+}
+
+/// Returns the error reported when the XML content ends inside a construct or an element.
+#[inline]
+pub(super) fn unexpected_end() -> ParseXmlError {
+    ParseXmlError::InvalidXml("Unexpected end of XML document.".to_string())
+}
+
+impl Tokenizer {
+    /// Creates a new tokenizer for the given XML content.
+    ///
+    /// # Arguments
+    /// * `xml` - A byte vector containing the XML content to be parsed. It is modified in place.
+    ///
+    /// # Returns
+    /// A `Result` containing the tokenizer, or a `ParseXmlError` if the XML is too large
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is too large.
+    pub fn new(xml: Vec<u8>) -> Result<Self, ParseXmlError> {
+        Self::new_with_options(xml, ParserOptions::default())
+    }
+
+    /// Creates a new tokenizer for the given XML content, using the given parsing options.
+    ///
+    /// The `keep_ws_only_pcdata` option is not used, as whitespace-only text runs are always
+    /// returned as `Event::Whitespace`.
+    ///
+    /// # Arguments
+    /// * `xml` - A byte vector containing the XML content to be parsed. It is modified in place.
+    /// * `options` - The parsing options to use
+    ///
+    /// # Returns
+    /// A `Result` containing the tokenizer, or a `ParseXmlError` if the XML is too large
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is too large.
+    /// - `ParseXmlError::LimitExceeded`: If the XML content is larger than the `max_input_len` option.
+    pub fn new_with_options(xml: Vec<u8>, options: ParserOptions) -> Result<Self, ParseXmlError> {
+        if xml.len() > XmlIdx::MAX as usize {
+            return Err(ParseXmlError::InvalidXml(
+                "XML document is too large!".to_string(),
+            ));
+        }
+        options.check_input_len(xml.len())?;
+
+        let mut doc = Document::with_buffer(encoding::to_utf8(xml.into(), &options)?, 0, 0);
+        doc.options = options;
+
+        Ok(Tokenizer {
+            doc,
+            pos: 0,
+            open_names: Vec::with_capacity(200),
+            open_tags: Vec::with_capacity(20),
+            attributes: Vec::new(),
+            pending_end: None,
+            partial: false,
+        })
+    }
+
+    /// Creates a tokenizer without XML content, for the `FeedParser`, that replaces the
+    /// XML buffer with each complete part of the content.
+    ///
+    /// # Arguments
+    /// * `options` - The parsing options to use
+    pub(super) fn new_partial(options: ParserOptions) -> Self {
+        let mut doc = Document::with_buffer(Vec::new().into(), 0, 0);
+        doc.options = options;
+
+        Tokenizer {
+            doc,
+            pos: 0,
+            open_names: Vec::with_capacity(200),
+            open_tags: Vec::with_capacity(20),
+            attributes: Vec::new(),
+            pending_end: None,
+            partial: true,
+        }
+    }
+
+    /// Returns the next construct of the XML content.
+    ///
+    /// # Returns
+    /// A `Result` containing the next event, `Event::Eof` once the end of the XML content
+    /// has been reached, or a `ParseXmlError` if the XML is malformed
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::parser::{Event, Tokenizer};
+    ///
+    /// let mut tokenizer = Tokenizer::new(b"<root a=\"1\"><br/></root>".to_vec()).unwrap();
+    /// let mut names = Vec::new();
+    ///
+    /// loop {
+    ///     match tokenizer.next_event().unwrap() {
+    ///         Event::StartTag { name, .. } => names.push(format!("+{name}")),
+    ///         Event::EndTag { name } => names.push(format!("-{name}")),
+    ///         Event::Eof => break,
+    ///         _ => {}
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(names, ["+root", "+br", "-br", "-root"]);
+    /// ```
+    pub fn next_event(&mut self) -> Result<Event<'_>, ParseXmlError> {
+        let mut token = self.next_token()?;
+        if let Token::CharData(range) = &token {
+            if !self.doc.is_whitespace_only(range) {
+                token = Token::Text(self.doc.stream_text(range.clone())?);
+            }
+        }
+
+        let xml = &self.doc.xml;
+
+        Ok(match token {
+            Token::StartTag(name) => Event::StartTag {
+                name: range_str(xml, &name),
+                attributes: SaxAttributes {
+                    xml,
+                    attrs: self.attributes.iter(),
+                },
+            },
+            Token::EndTag(name) => Event::EndTag {
+                name: range_str(xml, &name),
+            },
+            Token::CharData(range) => Event::Whitespace(range_str(xml, &range)),
+            Token::Text(range) => Event::Text(range_str(xml, &range)),
+            Token::CData(range) => Event::CData(range_str(xml, &range)),
+            Token::Comment(range) => Event::Comment(range_str(xml, &range)),
+            Token::Pi(target, data) => Event::ProcessingInstruction {
+                target: range_str(xml, &target),
+                data: range_str(xml, &data),
+            },
+            Token::Eof => Event::Eof,
+        })
+    }
+
+    /// Skips the content of the element whose `StartTag` event was just returned, up to and
+    /// including its `EndTag` event.
+    ///
+    /// The skipped content is still checked for well-formedness, but its text is not
+    /// processed. If no element is open, the rest of the XML content is skipped.
+    ///
+    /// # Returns
+    /// `Ok(())` once the element has been skipped, or a `ParseXmlError` if the XML is malformed
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the skipped content is not well-formed.
+    pub fn skip_subtree(&mut self) -> Result<(), ParseXmlError> {
+        if self.pending_end.take().is_some() {
+            return Ok(());
+        }
+
+        let depth = self.open_tags.len();
+        while self.open_tags.len() >= depth {
+            if let Token::Eof = self.next_token()? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of elements currently open.
+    ///
+    /// After a `StartTag` event, the count includes the started element. After an `EndTag`
+    /// event, it no longer includes the ended element.
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.open_tags.len() + usize::from(self.pending_end.is_some())
+    }
+
+    /// Returns the next token, leaving the tokenizer at the end of the XML content on error.
+    fn next_token(&mut self) -> Result<Token, ParseXmlError> {
+        self.scan().inspect_err(|_| {
+            self.pos = self.doc.content_len;
+            self.open_tags.clear();
+            self.open_names.clear();
+            self.pending_end = None;
+        })
+    }
+
+    /// Returns an `InvalidXml` error with the given message and position.
+    #[inline]
+    fn fail<T>(&self, msg: &str, pos: XmlIdx) -> Result<T, ParseXmlError> {
+        self.doc.invalid(msg, pos).map(|()| unreachable!())
+    }
+
+    /// Scans the XML buffer from the current position up to the next construct to return.
+    ///
+    /// This is the pull counterpart of `Document::parse()`. Only the names of the open
+    /// elements are kept, to validate the closing tags.
+    #[allow(clippy::too_many_lines)]
+    fn scan(&mut self) -> Result<Token, ParseXmlError> {
+        if let Some(name) = self.pending_end.take() {
+            return Ok(Token::EndTag(name));
+        }
+
+        let size = self.doc.content_len;
+
+        loop {
+            if self.pos >= size {
+                return if self.open_tags.is_empty() || self.partial {
+                    Ok(Token::Eof)
+                } else {
+                    Err(unexpected_end())
+                };
+            }
+
+            let next_tag = self
+                .doc
+                .scan_until_char(self.pos, LESS_THAN)
+                .unwrap_or(size);
+            if next_tag > self.pos && !self.open_tags.is_empty() {
+                let range = self.pos..next_tag;
+                self.pos = next_tag;
+                return Ok(Token::CharData(range));
+            }
+
+            let mut i = next_tag + 1;
+            if i >= size {
+                self.pos = size;
+                continue;
+            }
+
+            match self.doc.xml[i as usize] {
+                SLASH => {
+                    let start = i + 1;
+                    let end = self
+                        .doc
+                        .scan_until_chartype(start, Chartype::ParseCloseTag)
+                        .ok_or_else(unexpected_end)?;
+                    if end == start {
+                        return self.fail("Expected tag name after '</'", end);
+                    }
+
+                    let name_range = self.doc.process_name(start..end);
+                    let Some(open_start) = self.open_tags.pop() else {
+                        return self.fail("No opening tag for closing tag", end);
+                    };
+
+                    let xml = &self.doc.xml;
+                    let opening_tag = &self.open_names[open_start..];
+                    let closing_tag = &xml[name_range.start as usize..name_range.end as usize];
+                    if opening_tag != closing_tag {
+                        return self.fail(
+                            &format!(
+                                "Closing tag '{}' does not match opening tag '{}'",
+                                range_str(xml, &name_range),
+                                String::from_utf8_lossy(opening_tag)
+                            ),
+                            name_range.start,
+                        );
+                    }
+                    self.open_names.truncate(open_start);
+
+                    let end = self
+                        .doc
+                        .scan_until_char(end, GREATER_THAN)
+                        .ok_or_else(unexpected_end)?;
+                    self.pos = end + 1;
+                    return Ok(Token::EndTag(name_range));
+                }
+                EXCLAMATION_MARK => {
+                    i += 1;
+                    if self.doc.xml[i as usize..].starts_with(b"--") {
+                        let start = i + 2;
+                        let Some(end) = self.doc.skip_after_slice(start, b"-->".as_slice()) else {
+                            return self.fail("Unterminated comment", next_tag);
+                        };
+                        self.pos = end;
+                        return Ok(Token::Comment(start..(end - 3)));
+                    } else if self.doc.xml[i as usize..].starts_with(b"[CDATA[") {
+                        let start = i + 7;
+                        let Some(end) = self.doc.skip_after_slice(start, b"]]>".as_slice()) else {
+                            return self.fail("Unterminated CDATA section", next_tag);
+                        };
+                        self.pos = end;
+                        if !self.open_tags.is_empty() && end - 3 > start {
+                            return Ok(Token::CData(start..(end - 3)));
+                        }
+                    } else if self.doc.xml[i as usize..].starts_with(b"DOCTYPE") {
+                        i = self
+                            .doc
+                            .scan_until_one_of_2_chars(i + 7, GREATER_THAN, LEFT_BRACKET)
+                            .ok_or_else(unexpected_end)?;
+
+                        if self.doc.xml[i as usize] == LEFT_BRACKET {
+                            let Some(end) = self.doc.parse_internal_subset(i + 1) else {
+                                return self.fail("Unterminated DOCTYPE declaration", next_tag);
+                            };
+                            i = self
+                                .doc
+                                .skip_chartype(end + 1, Chartype::Space)
+                                .ok_or_else(unexpected_end)?;
+                            if self.doc.xml[i as usize] != GREATER_THAN {
+                                return self.fail("Expected '>' after DOCTYPE declaration", i);
+                            }
+                        }
+                        self.pos = i + 1; // skip '>'
+                    } else {
+                        return self.fail("Invalid markup declaration", next_tag);
+                    }
+                }
+                QUESTION_MARK => {
+                    let start = i + 1;
+                    let Some(end) = self.doc.skip_after_slice(start, b"?>".as_slice()) else {
+                        return self.fail("Unterminated processing instruction", next_tag);
+                    };
+                    let (target, data) = self.doc.pi_ranges(start, end);
+
+                    self.pos = end;
+                    return Ok(Token::Pi(target, data));
+                }
+                _ => return self.scan_start_tag(i),
+            }
+        }
+    }
+
+    /// Scans a start tag and its attributes, starting at the first character of its name.
+    fn scan_start_tag(&mut self, mut i: XmlIdx) -> Result<Token, ParseXmlError> {
+        let size = self.doc.content_len;
+        let start = i;
+        if !Document::is_of_type(self.doc.xml[i as usize], Chartype::StartSymBol) {
+            return self.fail("Tag name must start with a letter or underscore", i);
+        }
+        i = self
+            .doc
+            .skip_chartype(i + 1, Chartype::Symbol)
+            .ok_or_else(unexpected_end)?;
+        let name_range = self.doc.process_name(start..i);
+
+        self.attributes.clear();
+        let self_closing = loop {
+            i = self
+                .doc
+                .skip_chartype(i, Chartype::Space)
+                .ok_or_else(unexpected_end)?;
+
+            match self.doc.xml[i as usize] {
+                SLASH => {
+                    i += 1;
+                    if i >= size || self.doc.xml[i as usize] != GREATER_THAN {
+                        return self.fail("Expected '>' after '/' in self-closing tag", i);
+                    }
+                    i += 1;
+                    break true;
+                }
+                GREATER_THAN => {
+                    i += 1;
+                    break false;
+                }
+                _ => {
+                    let attr_start = i;
+                    if !Document::is_of_type(self.doc.xml[i as usize], Chartype::StartSymBol) {
+                        return self
+                            .fail("Attribute name must start with a letter or underscore", i);
+                    }
+                    i = self
+                        .doc
+                        .skip_chartype(i + 1, Chartype::Symbol)
+                        .ok_or_else(unexpected_end)?;
+                    if self.doc.xml[i as usize] != EQUAL {
+                        return self.fail("Attribute must have an '=' sign", i);
+                    }
+                    let attr_end = i;
+
+                    i += 1; // skip '='
+                    if i >= size {
+                        return Err(unexpected_end());
+                    }
+                    let quote = self.doc.xml[i as usize];
+                    if (quote != b'\'') && (quote != b'"') {
+                        return self.fail("Attribute value must be enclosed in quotes", i);
+                    }
+                    let value_start = i + 1;
+                    i = self
+                        .doc
+                        .scan_until_char(value_start, quote)
+                        .ok_or_else(unexpected_end)?;
+
+                    let value_range = self.doc.normalize_attribute_value(&(value_start..i))?;
+                    let attr_name_range = self.doc.process_name(attr_start..attr_end);
+                    self.attributes.push((attr_name_range, value_range));
+
+                    i += 1; // skip the closing quote
+                }
+            }
+        };
+
+        self.pos = i;
+        if self_closing {
+            self.pending_end = Some(name_range.clone());
+        } else {
+            self.open_tags.push(self.open_names.len());
+            self.open_names.extend_from_slice(
+                &self.doc.xml[name_range.start as usize..name_range.end as usize],
+            );
+        }
+
+        Ok(Token::StartTag(name_range))
+    }
+}
+
+impl Document<'_> {
+    /// Returns true if the character data run is composed of whitespace only.
+    #[inline]
+    fn is_whitespace_only(&self, range: &XmlRange) -> bool {
+        self.skip_chartype(range.start, Chartype::Space)
+            .is_none_or(|pos| pos >= range.end)
+    }
+
+    /// Processes a character data run containing text in place, translating its entities.
+    ///
+    /// # Arguments
+    /// * `range` - The byte range of the character data in the XML buffer
+    ///
+    /// # Returns
+    /// The byte range of the processed text
+    ///
+    /// # Errors
+    /// Returns `ParseXmlError::EntityLimitExceeded` if the entity expansion limits of the
+    /// parsing options are exceeded.
+    fn stream_text(&mut self, range: XmlRange) -> Result<XmlRange, ParseXmlError> {
+        let range = if self.options.trim_pcdata {
+            let text_start = self
+                .skip_chartype(range.start, Chartype::Space)
+                .map_or(range.end, |pos| pos.min(range.end));
+            text_start..self.trim_the_ending_whitespaces(text_start..range.end)
+        } else {
+            range
+        };
+
+        self.parse_pcdata(&range)
+    }
+}
@@ -0,0 +1,340 @@
+//! Incremental parsing of XML/XHTML content received in chunks.
+//!
+//! This module provides the `FeedParser`, which accepts the XML content in chunks of any
+//! size, as produced by a network connection or a decompressor, and reports its constructs
+//! to a `SaxHandler` as soon as they are complete. Only the incomplete construct located at
+//! the end of a chunk is kept, until the following chunks complete it: the whole content is
+//! never concatenated.
+//!
+//! The same processing as the `stream()` function is used. The content must be encoded in
+//! UTF-8, or in one of the legacy encodings accepted with the `legacy_encodings` parsing
+//! option. As the content already reported is discarded, the line and column numbers of the
+//! error messages are relative to the part of the content being parsed.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::parser::{FeedParser, ParserOptions, SaxAttributes, SaxHandler};
+//!
+//! #[derive(Default)]
+//! struct Titles(Vec<String>);
+//!
+//! impl SaxHandler for Titles {
+//!     fn text(&mut self, text: &str) {
+//!         self.0.push(text.to_string());
+//!     }
+//! }
+//!
+//! let xml_data = b"<book><title>Fish &amp; Chips</title><title>Tea</title></book>";
+//! let options = ParserOptions::new().parse_escapes(true);
+//! let mut parser = FeedParser::new_with_options(Titles::default(), options);
+//!
+//! for chunk in xml_data.chunks(5) {
+//!     parser.feed(chunk).unwrap();
+//! }
+//! let titles = parser.finish().unwrap();
+//!
+//! assert_eq!(titles.0, ["Fish & Chips", "Tea"]);
+//! ```
+
+use std::mem;
+
+use memchr::{memchr, memchr2};
+
+use crate::defs::{ParseXmlError, XmlIdx};
+use crate::encoding::{self, Encoding};
+use crate::xml_buffer::XmlBuffer;
+
+use super::sax::report_events;
+use super::{
+    ParserOptions, SaxHandler, Tokenizer, GREATER_THAN, LEFT_BRACKET, LESS_THAN, RIGHT_BRACKET,
+};
+
+/// The beginnings of the constructs starting with `<!`.
+const DECLARATIONS: [&[u8]; 3] = [b"<!--", b"<![CDATA[", b"<!DOCTYPE"];
+
+/// A resumable parser, reporting the constructs of XML content received in chunks to a
+/// `SaxHandler`.
+///
+/// The chunks are given with `feed()`, and the end of the content is signaled with
+/// `finish()`, which returns the handler. Elements must be properly nested and closed, as
+/// with `Document::new()`. Text and elements located outside of any element are not reported.
+///
+/// Once an error has been returned, the parser rejects any further content.
+pub struct FeedParser<H: SaxHandler> {
+    tokenizer: Tokenizer,
+    handler: H,
+    pending: Vec<u8>,
+    encoding: Option<Encoding>,
+    fed_len: usize,
+    failed: bool,
+}
+
+impl<H: SaxHandler> FeedParser<H> {
+    /// Creates a new parser reporting to the given handler.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler receiving the parsing events
+    pub fn new(handler: H) -> Self {
+        Self::new_with_options(handler, ParserOptions::default())
+    }
+
+    /// Creates a new parser reporting to the given handler, using the given parsing options.
+    ///
+    /// As for the `Tokenizer`, the `keep_ws_only_pcdata` option is not used. The
+    /// `max_input_len` option limits the total size of the chunks.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler receiving the parsing events
+    /// * `options` - The parsing options to use
+    pub fn new_with_options(handler: H, options: ParserOptions) -> Self {
+        FeedParser {
+            tokenizer: Tokenizer::new_partial(options),
+            handler,
+            pending: Vec::new(),
+            encoding: None,
+            fed_len: 0,
+            failed: false,
+        }
+    }
+
+    /// Parses the next chunk of the XML content.
+    ///
+    /// The constructs completed by the chunk are reported to the handler. The incomplete
+    /// construct located at its end is kept until the following chunks complete it.
+    ///
+    /// # Arguments
+    /// * `bytes` - The next chunk of the XML content
+    ///
+    /// # Returns
+    /// `Ok(())` if the chunk was accepted, or a `ParseXmlError` if the XML is malformed
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed, if a single
+    ///   construct is too large, or if the parser already failed.
+    /// - `ParseXmlError::InvalidEncoding`: If the encoding of the content is not supported.
+    /// - `ParseXmlError::LimitExceeded`: If the content is larger than the `max_input_len` option.
+    /// - `ParseXmlError::EntityLimitExceeded`: If the entity expansion limits are exceeded.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), ParseXmlError> {
+        self.check_not_failed()?;
+
+        self.fed_len += bytes.len();
+        if let Err(err) = self.tokenizer.doc.options.check_input_len(self.fed_len) {
+            return Err(self.fail(err));
+        }
+
+        self.pending.extend_from_slice(bytes);
+        let len = complete_len(&self.pending);
+        if len == 0 {
+            return Ok(());
+        }
+
+        let incomplete = self.pending[len..].to_vec();
+        self.pending.truncate(len);
+        let complete = mem::replace(&mut self.pending, incomplete);
+        self.parse(complete, true)
+    }
+
+    /// Parses the remaining content, and ends the parsing.
+    ///
+    /// # Returns
+    /// A `Result` containing the handler, or a `ParseXmlError` if the XML is malformed
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXml`: If the XML content ends inside a construct or an
+    ///   element, is not well-formed, or if the parser already failed.
+    /// - `ParseXmlError::InvalidEncoding`: If the encoding of the content is not supported.
+    /// - `ParseXmlError::EntityLimitExceeded`: If the entity expansion limits are exceeded.
+    pub fn finish(mut self) -> Result<H, ParseXmlError> {
+        self.check_not_failed()?;
+
+        let remaining = mem::take(&mut self.pending);
+        self.parse(remaining, false)?;
+        Ok(self.handler)
+    }
+
+    /// Returns the handler receiving the parsing events.
+    #[inline]
+    #[must_use]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Returns the handler receiving the parsing events, as mutable.
+    #[inline]
+    #[must_use]
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Returns the number of bytes kept until the following chunks complete the construct
+    /// located at the end of the content.
+    #[inline]
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns an error if the parser already failed.
+    fn check_not_failed(&self) -> Result<(), ParseXmlError> {
+        if self.failed {
+            Err(ParseXmlError::InvalidXml(
+                "The parser has already failed.".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Marks the parser as failed, discarding the content kept, and returns the error.
+    fn fail(&mut self, err: ParseXmlError) -> ParseXmlError {
+        self.failed = true;
+        self.pending = Vec::new();
+        err
+    }
+
+    /// Parses a part of the content, reporting its constructs to the handler.
+    ///
+    /// # Arguments
+    /// * `xml` - The part of the content, ending at a construct boundary if `partial` is true
+    /// * `partial` - True if more content is expected
+    fn parse(&mut self, xml: Vec<u8>, partial: bool) -> Result<(), ParseXmlError> {
+        self.parse_part(xml, partial).map_err(|err| self.fail(err))
+    }
+
+    /// Parses a part of the content, without marking the parser as failed on error.
+    fn parse_part(&mut self, xml: Vec<u8>, partial: bool) -> Result<(), ParseXmlError> {
+        let xml = self.decode(xml)?;
+        if xml.len() > XmlIdx::MAX as usize {
+            return Err(ParseXmlError::InvalidXml(
+                "XML construct is too large!".to_string(),
+            ));
+        }
+
+        self.tokenizer.doc.content_len = xml.len() as XmlIdx;
+        self.tokenizer.doc.xml = xml;
+        self.tokenizer.pos = 0;
+        self.tokenizer.partial = partial;
+        report_events(&mut self.tokenizer, &mut self.handler)
+    }
+
+    /// Converts a part of the content to UTF-8. The encoding is detected with the first part,
+    /// which holds the byte order mark and the XML declaration, if any.
+    ///
+    /// # Arguments
+    /// * `xml` - The part of the content
+    ///
+    /// # Errors
+    /// Returns `ParseXmlError::InvalidEncoding` if the encoding is not supported.
+    fn decode(&mut self, xml: Vec<u8>) -> Result<XmlBuffer<'static>, ParseXmlError> {
+        let options = &self.tokenizer.doc.options;
+        let Some(encoding) = self.encoding else {
+            let encoding = encoding::detect(&xml)?;
+            if let Encoding::Utf16Le | Encoding::Utf16Be = encoding {
+                return Err(ParseXmlError::InvalidEncoding(
+                    "UTF-16 content is not supported by the FeedParser".to_string(),
+                ));
+            }
+            self.encoding = Some(encoding);
+            return encoding::to_utf8(xml.into(), options);
+        };
+
+        match encoding {
+            Encoding::Latin1 | Encoding::Windows1252 => {
+                Ok(encoding::decode_single_byte(&xml, encoding))
+            }
+            _ => encoding::to_utf8(xml.into(), options),
+        }
+    }
+}
+
+/// Returns the length of the leading part of the content composed of complete constructs.
+/// The trailing text is considered incomplete, as it may continue in the next chunk.
+///
+/// # Arguments
+/// * `xml` - The content
+fn complete_len(xml: &[u8]) -> usize {
+    let mut len = 0;
+    while let Some(offset) = memchr(LESS_THAN, &xml[len..]) {
+        let start = len + offset;
+        match construct_len(&xml[start..]) {
+            Some(construct_len) => len = start + construct_len,
+            None => return start,
+        }
+    }
+    len
+}
+
+/// Returns the length of the construct starting with `<`, or `None` if it is incomplete.
+///
+/// Malformed constructs are delimited as well as possible: they are reported by the
+/// tokenizer.
+///
+/// # Arguments
+/// * `bytes` - The content, starting with the `<` of the construct
+fn construct_len(bytes: &[u8]) -> Option<usize> {
+    let after = |from: usize, pattern: &[u8]| {
+        bytes[from..]
+            .windows(pattern.len())
+            .position(|window| window == pattern)
+            .map(|pos| from + pos + pattern.len())
+    };
+
+    if bytes.starts_with(b"<!--") {
+        after(4, b"-->")
+    } else if bytes.starts_with(b"<![CDATA[") {
+        after(9, b"]]>")
+    } else if bytes.starts_with(b"<!DOCTYPE") {
+        let mut i = 9 + memchr2(GREATER_THAN, LEFT_BRACKET, &bytes[9..])?;
+        if bytes[i] == LEFT_BRACKET {
+            i = internal_subset_end(bytes, i + 1)?;
+            i += memchr(GREATER_THAN, &bytes[i..])?;
+        }
+        Some(i + 1)
+    } else if bytes.starts_with(b"<?") {
+        after(2, b"?>")
+    } else if DECLARATIONS
+        .iter()
+        .any(|declaration| bytes.len() < declaration.len() && declaration.starts_with(bytes))
+    {
+        None
+    } else {
+        tag_len(bytes)
+    }
+}
+
+/// Returns the position of the `]` closing the internal subset of a DOCTYPE declaration, or
+/// `None` if it is incomplete. The comments, processing instructions and markup declarations
+/// of the subset are skipped as constructs, so that a `]` located in them is ignored.
+///
+/// # Arguments
+/// * `bytes` - The content, starting with the `<` of the DOCTYPE declaration
+/// * `i` - The position following the `[` opening the internal subset
+fn internal_subset_end(bytes: &[u8], mut i: usize) -> Option<usize> {
+    loop {
+        i += match *bytes.get(i)? {
+            RIGHT_BRACKET => return Some(i),
+            LESS_THAN => construct_len(&bytes[i..])?,
+            _ => 1,
+        };
+    }
+}
+
+/// Returns the length of the start or end tag, up to its `>` located outside of the
+/// attribute values, or `None` if it is incomplete.
+///
+/// # Arguments
+/// * `bytes` - The content, starting with the `<` of the tag
+fn tag_len(bytes: &[u8]) -> Option<usize> {
+    let mut quote = None;
+    for (i, &byte) in bytes.iter().enumerate().skip(1) {
+        match quote {
+            Some(q) if byte == q => quote = None,
+            Some(_) => {}
+            None if byte == b'"' || byte == b'\'' => quote = Some(byte),
+            None if byte == GREATER_THAN => return Some(i + 1),
+            None => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,112 @@
+//! HTML leniency rules.
+//!
+//! This module holds the tables used by the `html_leniency` parsing option to close the
+//! elements whose end tag is optional in HTML (`p`, `li`, `td`, etc.), and by the
+//! `html_void_elements` parsing option to recognize the elements that have no content
+//! (`br`, `img`, etc.), and by the `html_raw_text` parsing option to recognize the
+//! elements with a raw text content (`script` and `style`). Tag names are compared
+//! ignoring ASCII case.
+
+/// The void elements, that have no end tag.
+const VOID_ELEMENTS: &[&[u8]] = &[
+    b"area", b"base", b"br", b"col", b"embed", b"hr", b"img", b"input", b"link", b"meta", b"param",
+    b"source", b"track", b"wbr",
+];
+
+/// The elements whose content is raw text, up to their end tag.
+const RAW_TEXT_ELEMENTS: &[&[u8]] = &[b"script", b"style"];
+
+/// Opening tags that implicitly close an open `p` element.
+const CLOSING_P: &[&[u8]] = &[
+    b"address",
+    b"article",
+    b"aside",
+    b"blockquote",
+    b"details",
+    b"div",
+    b"dl",
+    b"fieldset",
+    b"figcaption",
+    b"figure",
+    b"footer",
+    b"form",
+    b"h1",
+    b"h2",
+    b"h3",
+    b"h4",
+    b"h5",
+    b"h6",
+    b"header",
+    b"hgroup",
+    b"hr",
+    b"main",
+    b"menu",
+    b"nav",
+    b"ol",
+    b"p",
+    b"pre",
+    b"section",
+    b"table",
+    b"ul",
+];
+
+/// The open elements with an optional end tag, and the opening tags that implicitly close them.
+const IMPLICIT_CLOSING_RULES: &[(&[u8], &[&[u8]])] = &[
+    (b"p", CLOSING_P),
+    (b"li", &[b"li"]),
+    (b"dt", &[b"dt", b"dd"]),
+    (b"dd", &[b"dt", b"dd"]),
+    (b"option", &[b"option", b"optgroup"]),
+    (b"optgroup", &[b"optgroup"]),
+    (b"rt", &[b"rt", b"rp"]),
+    (b"rp", &[b"rt", b"rp"]),
+    (b"thead", &[b"tbody", b"tfoot"]),
+    (b"tbody", &[b"tbody", b"tfoot"]),
+    (b"tfoot", &[b"tbody"]),
+    (b"tr", &[b"tr", b"tbody", b"tfoot"]),
+    (b"td", &[b"td", b"th", b"tr", b"tbody", b"tfoot"]),
+    (b"th", &[b"td", b"th", b"tr", b"tbody", b"tfoot"]),
+];
+
+/// Returns true if the opening of an element implicitly closes the currently open element.
+///
+/// # Arguments
+/// * `open` - The name of the currently open element
+/// * `opening` - The name of the element being opened
+pub(crate) fn closes_on_open(open: &[u8], opening: &[u8]) -> bool {
+    IMPLICIT_CLOSING_RULES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(open))
+        .is_some_and(|(_, closing)| closing.iter().any(|tag| tag.eq_ignore_ascii_case(opening)))
+}
+
+/// Returns true if the end tag of an element is optional, the element being implicitly closed
+/// by the end tag of an enclosing element.
+///
+/// # Arguments
+/// * `name` - The name of the element
+pub(crate) fn has_optional_end_tag(name: &[u8]) -> bool {
+    IMPLICIT_CLOSING_RULES
+        .iter()
+        .any(|(tag, _)| tag.eq_ignore_ascii_case(name))
+}
+
+/// Returns true if an element is a void element, that has no content and no end tag.
+///
+/// # Arguments
+/// * `name` - The name of the element
+pub(crate) fn is_void_element(name: &[u8]) -> bool {
+    VOID_ELEMENTS
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case(name))
+}
+
+/// Returns true if the content of an element is raw text, that is not parsed as markup.
+///
+/// # Arguments
+/// * `name` - The name of the element
+pub(crate) fn is_raw_text_element(name: &[u8]) -> bool {
+    RAW_TEXT_ELEMENTS
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case(name))
+}
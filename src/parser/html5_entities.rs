@@ -0,0 +1,2007 @@
+//! The HTML5 named character references.
+//!
+//! This module holds the named character references of the WHATWG HTML specification that
+//! are not part of the XHTML 1.0 entities of `ENTITIES_MAP`. It is only available when the
+//! `html5_entities` feature is enabled. The `&nGt;` and `&nLt;` references are left out, as
+//! their replacement text is longer than the reference itself and cannot be translated in
+//! place.
+
+use phf::phf_map;
+
+#[rustfmt::skip]
+pub(super) static HTML5_ENTITIES_MAP: phf::Map<&'static [u8], &'static [u8]> = phf_map! {
+    b"Abreve"                         => b"\xC4\x82",               // latin capital letter a with breve, U+0102
+    b"abreve"                         => b"\xC4\x83",               // latin small letter a with breve, U+0103
+    b"ac"                             => b"\xE2\x88\xBE",           // inverted lazy s, U+223E
+    b"acd"                            => b"\xE2\x88\xBF",           // sine wave, U+223F
+    b"acE"                            => b"\xE2\x88\xBE\xCC\xB3",   // inverted lazy s, U+223E + combining double low line, U+0333
+    b"Acy"                            => b"\xD0\x90",               // cyrillic capital letter a, U+0410
+    b"acy"                            => b"\xD0\xB0",               // cyrillic small letter a, U+0430
+    b"af"                             => b"\xE2\x81\xA1",           // function application, U+2061
+    b"Afr"                            => b"\xF0\x9D\x94\x84",       // mathematical fraktur capital a, U+1D504
+    b"afr"                            => b"\xF0\x9D\x94\x9E",       // mathematical fraktur small a, U+1D51E
+    b"alefsym"                        => b"\xE2\x84\xB5",           // alef symbol, U+2135
+    b"aleph"                          => b"\xE2\x84\xB5",           // alef symbol, U+2135
+    b"Alpha"                          => b"\xCE\x91",               // greek capital letter alpha, U+0391
+    b"alpha"                          => b"\xCE\xB1",               // greek small letter alpha, U+03B1
+    b"Amacr"                          => b"\xC4\x80",               // latin capital letter a with macron, U+0100
+    b"amacr"                          => b"\xC4\x81",               // latin small letter a with macron, U+0101
+    b"amalg"                          => b"\xE2\xA8\xBF",           // amalgamation or coproduct, U+2A3F
+    b"AMP"                            => b"\x26",                   // ampersand, U+0026
+    b"And"                            => b"\xE2\xA9\x93",           // double logical and, U+2A53
+    b"and"                            => b"\xE2\x88\xA7",           // logical and, U+2227
+    b"andand"                         => b"\xE2\xA9\x95",           // two intersecting logical and, U+2A55
+    b"andd"                           => b"\xE2\xA9\x9C",           // logical and with horizontal dash, U+2A5C
+    b"andslope"                       => b"\xE2\xA9\x98",           // sloping large and, U+2A58
+    b"andv"                           => b"\xE2\xA9\x9A",           // logical and with middle stem, U+2A5A
+    b"ang"                            => b"\xE2\x88\xA0",           // angle, U+2220
+    b"ange"                           => b"\xE2\xA6\xA4",           // angle with underbar, U+29A4
+    b"angle"                          => b"\xE2\x88\xA0",           // angle, U+2220
+    b"angmsd"                         => b"\xE2\x88\xA1",           // measured angle, U+2221
+    b"angmsdaa"                       => b"\xE2\xA6\xA8",           // measured angle with open arm ending in arrow pointing up and right, U+29A8
+    b"angmsdab"                       => b"\xE2\xA6\xA9",           // measured angle with open arm ending in arrow pointing up and left, U+29A9
+    b"angmsdac"                       => b"\xE2\xA6\xAA",           // measured angle with open arm ending in arrow pointing down and right, U+29AA
+    b"angmsdad"                       => b"\xE2\xA6\xAB",           // measured angle with open arm ending in arrow pointing down and left, U+29AB
+    b"angmsdae"                       => b"\xE2\xA6\xAC",           // measured angle with open arm ending in arrow pointing right and up, U+29AC
+    b"angmsdaf"                       => b"\xE2\xA6\xAD",           // measured angle with open arm ending in arrow pointing left and up, U+29AD
+    b"angmsdag"                       => b"\xE2\xA6\xAE",           // measured angle with open arm ending in arrow pointing right and down, U+29AE
+    b"angmsdah"                       => b"\xE2\xA6\xAF",           // measured angle with open arm ending in arrow pointing left and down, U+29AF
+    b"angrt"                          => b"\xE2\x88\x9F",           // right angle, U+221F
+    b"angrtvb"                        => b"\xE2\x8A\xBE",           // right angle with arc, U+22BE
+    b"angrtvbd"                       => b"\xE2\xA6\x9D",           // measured right angle with dot, U+299D
+    b"angsph"                         => b"\xE2\x88\xA2",           // spherical angle, U+2222
+    b"angst"                          => b"\xC3\x85",               // latin capital letter a with ring above, U+00C5
+    b"angzarr"                        => b"\xE2\x8D\xBC",           // right angle with downwards zigzag arrow, U+237C
+    b"Aogon"                          => b"\xC4\x84",               // latin capital letter a with ogonek, U+0104
+    b"aogon"                          => b"\xC4\x85",               // latin small letter a with ogonek, U+0105
+    b"Aopf"                           => b"\xF0\x9D\x94\xB8",       // mathematical double-struck capital a, U+1D538
+    b"aopf"                           => b"\xF0\x9D\x95\x92",       // mathematical double-struck small a, U+1D552
+    b"ap"                             => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"apacir"                         => b"\xE2\xA9\xAF",           // almost equal to with circumflex accent, U+2A6F
+    b"apE"                            => b"\xE2\xA9\xB0",           // approximately equal or equal to, U+2A70
+    b"ape"                            => b"\xE2\x89\x8A",           // almost equal or equal to, U+224A
+    b"apid"                           => b"\xE2\x89\x8B",           // triple tilde, U+224B
+    b"ApplyFunction"                  => b"\xE2\x81\xA1",           // function application, U+2061
+    b"approx"                         => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"approxeq"                       => b"\xE2\x89\x8A",           // almost equal or equal to, U+224A
+    b"Ascr"                           => b"\xF0\x9D\x92\x9C",       // mathematical script capital a, U+1D49C
+    b"ascr"                           => b"\xF0\x9D\x92\xB6",       // mathematical script small a, U+1D4B6
+    b"Assign"                         => b"\xE2\x89\x94",           // colon equals, U+2254
+    b"ast"                            => b"\x2A",                   // asterisk, U+002A
+    b"asymp"                          => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"asympeq"                        => b"\xE2\x89\x8D",           // equivalent to, U+224D
+    b"awconint"                       => b"\xE2\x88\xB3",           // anticlockwise contour integral, U+2233
+    b"awint"                          => b"\xE2\xA8\x91",           // anticlockwise integration, U+2A11
+    b"backcong"                       => b"\xE2\x89\x8C",           // all equal to, U+224C
+    b"backepsilon"                    => b"\xCF\xB6",               // greek reversed lunate epsilon symbol, U+03F6
+    b"backprime"                      => b"\xE2\x80\xB5",           // reversed prime, U+2035
+    b"backsim"                        => b"\xE2\x88\xBD",           // reversed tilde, U+223D
+    b"backsimeq"                      => b"\xE2\x8B\x8D",           // reversed tilde equals, U+22CD
+    b"Backslash"                      => b"\xE2\x88\x96",           // set minus, U+2216
+    b"Barv"                           => b"\xE2\xAB\xA7",           // short down tack with overbar, U+2AE7
+    b"barvee"                         => b"\xE2\x8A\xBD",           // nor, U+22BD
+    b"Barwed"                         => b"\xE2\x8C\x86",           // perspective, U+2306
+    b"barwed"                         => b"\xE2\x8C\x85",           // projective, U+2305
+    b"barwedge"                       => b"\xE2\x8C\x85",           // projective, U+2305
+    b"bbrk"                           => b"\xE2\x8E\xB5",           // bottom square bracket, U+23B5
+    b"bbrktbrk"                       => b"\xE2\x8E\xB6",           // bottom square bracket over top square bracket, U+23B6
+    b"bcong"                          => b"\xE2\x89\x8C",           // all equal to, U+224C
+    b"Bcy"                            => b"\xD0\x91",               // cyrillic capital letter be, U+0411
+    b"bcy"                            => b"\xD0\xB1",               // cyrillic small letter be, U+0431
+    b"becaus"                         => b"\xE2\x88\xB5",           // because, U+2235
+    b"Because"                        => b"\xE2\x88\xB5",           // because, U+2235
+    b"because"                        => b"\xE2\x88\xB5",           // because, U+2235
+    b"bemptyv"                        => b"\xE2\xA6\xB0",           // reversed empty set, U+29B0
+    b"bepsi"                          => b"\xCF\xB6",               // greek reversed lunate epsilon symbol, U+03F6
+    b"bernou"                         => b"\xE2\x84\xAC",           // script capital b, U+212C
+    b"Bernoullis"                     => b"\xE2\x84\xAC",           // script capital b, U+212C
+    b"Beta"                           => b"\xCE\x92",               // greek capital letter beta, U+0392
+    b"beta"                           => b"\xCE\xB2",               // greek small letter beta, U+03B2
+    b"beth"                           => b"\xE2\x84\xB6",           // bet symbol, U+2136
+    b"between"                        => b"\xE2\x89\xAC",           // between, U+226C
+    b"Bfr"                            => b"\xF0\x9D\x94\x85",       // mathematical fraktur capital b, U+1D505
+    b"bfr"                            => b"\xF0\x9D\x94\x9F",       // mathematical fraktur small b, U+1D51F
+    b"bigcap"                         => b"\xE2\x8B\x82",           // n-ary intersection, U+22C2
+    b"bigcirc"                        => b"\xE2\x97\xAF",           // large circle, U+25EF
+    b"bigcup"                         => b"\xE2\x8B\x83",           // n-ary union, U+22C3
+    b"bigodot"                        => b"\xE2\xA8\x80",           // n-ary circled dot operator, U+2A00
+    b"bigoplus"                       => b"\xE2\xA8\x81",           // n-ary circled plus operator, U+2A01
+    b"bigotimes"                      => b"\xE2\xA8\x82",           // n-ary circled times operator, U+2A02
+    b"bigsqcup"                       => b"\xE2\xA8\x86",           // n-ary square union operator, U+2A06
+    b"bigstar"                        => b"\xE2\x98\x85",           // black star, U+2605
+    b"bigtriangledown"                => b"\xE2\x96\xBD",           // white down-pointing triangle, U+25BD
+    b"bigtriangleup"                  => b"\xE2\x96\xB3",           // white up-pointing triangle, U+25B3
+    b"biguplus"                       => b"\xE2\xA8\x84",           // n-ary union operator with plus, U+2A04
+    b"bigvee"                         => b"\xE2\x8B\x81",           // n-ary logical or, U+22C1
+    b"bigwedge"                       => b"\xE2\x8B\x80",           // n-ary logical and, U+22C0
+    b"bkarow"                         => b"\xE2\xA4\x8D",           // rightwards double dash arrow, U+290D
+    b"blacklozenge"                   => b"\xE2\xA7\xAB",           // black lozenge, U+29EB
+    b"blacksquare"                    => b"\xE2\x96\xAA",           // black small square, U+25AA
+    b"blacktriangle"                  => b"\xE2\x96\xB4",           // black up-pointing small triangle, U+25B4
+    b"blacktriangledown"              => b"\xE2\x96\xBE",           // black down-pointing small triangle, U+25BE
+    b"blacktriangleleft"              => b"\xE2\x97\x82",           // black left-pointing small triangle, U+25C2
+    b"blacktriangleright"             => b"\xE2\x96\xB8",           // black right-pointing small triangle, U+25B8
+    b"blank"                          => b"\xE2\x90\xA3",           // open box, U+2423
+    b"blk12"                          => b"\xE2\x96\x92",           // medium shade, U+2592
+    b"blk14"                          => b"\xE2\x96\x91",           // light shade, U+2591
+    b"blk34"                          => b"\xE2\x96\x93",           // dark shade, U+2593
+    b"block"                          => b"\xE2\x96\x88",           // full block, U+2588
+    b"bne"                            => b"\x3D\xE2\x83\xA5",       // equals sign, U+003D + combining reverse solidus overlay, U+20E5
+    b"bnequiv"                        => b"\xE2\x89\xA1\xE2\x83\xA5",// identical to, U+2261 + combining reverse solidus overlay, U+20E5
+    b"bNot"                           => b"\xE2\xAB\xAD",           // reversed double stroke not sign, U+2AED
+    b"bnot"                           => b"\xE2\x8C\x90",           // reversed not sign, U+2310
+    b"Bopf"                           => b"\xF0\x9D\x94\xB9",       // mathematical double-struck capital b, U+1D539
+    b"bopf"                           => b"\xF0\x9D\x95\x93",       // mathematical double-struck small b, U+1D553
+    b"bot"                            => b"\xE2\x8A\xA5",           // up tack, U+22A5
+    b"bottom"                         => b"\xE2\x8A\xA5",           // up tack, U+22A5
+    b"bowtie"                         => b"\xE2\x8B\x88",           // bowtie, U+22C8
+    b"boxbox"                         => b"\xE2\xA7\x89",           // two joined squares, U+29C9
+    b"boxDL"                          => b"\xE2\x95\x97",           // box drawings double down and left, U+2557
+    b"boxDl"                          => b"\xE2\x95\x96",           // box drawings down double and left single, U+2556
+    b"boxdL"                          => b"\xE2\x95\x95",           // box drawings down single and left double, U+2555
+    b"boxdl"                          => b"\xE2\x94\x90",           // box drawings light down and left, U+2510
+    b"boxDR"                          => b"\xE2\x95\x94",           // box drawings double down and right, U+2554
+    b"boxDr"                          => b"\xE2\x95\x93",           // box drawings down double and right single, U+2553
+    b"boxdR"                          => b"\xE2\x95\x92",           // box drawings down single and right double, U+2552
+    b"boxdr"                          => b"\xE2\x94\x8C",           // box drawings light down and right, U+250C
+    b"boxH"                           => b"\xE2\x95\x90",           // box drawings double horizontal, U+2550
+    b"boxh"                           => b"\xE2\x94\x80",           // box drawings light horizontal, U+2500
+    b"boxHD"                          => b"\xE2\x95\xA6",           // box drawings double down and horizontal, U+2566
+    b"boxHd"                          => b"\xE2\x95\xA4",           // box drawings down single and horizontal double, U+2564
+    b"boxhD"                          => b"\xE2\x95\xA5",           // box drawings down double and horizontal single, U+2565
+    b"boxhd"                          => b"\xE2\x94\xAC",           // box drawings light down and horizontal, U+252C
+    b"boxHU"                          => b"\xE2\x95\xA9",           // box drawings double up and horizontal, U+2569
+    b"boxHu"                          => b"\xE2\x95\xA7",           // box drawings up single and horizontal double, U+2567
+    b"boxhU"                          => b"\xE2\x95\xA8",           // box drawings up double and horizontal single, U+2568
+    b"boxhu"                          => b"\xE2\x94\xB4",           // box drawings light up and horizontal, U+2534
+    b"boxminus"                       => b"\xE2\x8A\x9F",           // squared minus, U+229F
+    b"boxplus"                        => b"\xE2\x8A\x9E",           // squared plus, U+229E
+    b"boxtimes"                       => b"\xE2\x8A\xA0",           // squared times, U+22A0
+    b"boxUL"                          => b"\xE2\x95\x9D",           // box drawings double up and left, U+255D
+    b"boxUl"                          => b"\xE2\x95\x9C",           // box drawings up double and left single, U+255C
+    b"boxuL"                          => b"\xE2\x95\x9B",           // box drawings up single and left double, U+255B
+    b"boxul"                          => b"\xE2\x94\x98",           // box drawings light up and left, U+2518
+    b"boxUR"                          => b"\xE2\x95\x9A",           // box drawings double up and right, U+255A
+    b"boxUr"                          => b"\xE2\x95\x99",           // box drawings up double and right single, U+2559
+    b"boxuR"                          => b"\xE2\x95\x98",           // box drawings up single and right double, U+2558
+    b"boxur"                          => b"\xE2\x94\x94",           // box drawings light up and right, U+2514
+    b"boxV"                           => b"\xE2\x95\x91",           // box drawings double vertical, U+2551
+    b"boxv"                           => b"\xE2\x94\x82",           // box drawings light vertical, U+2502
+    b"boxVH"                          => b"\xE2\x95\xAC",           // box drawings double vertical and horizontal, U+256C
+    b"boxVh"                          => b"\xE2\x95\xAB",           // box drawings vertical double and horizontal single, U+256B
+    b"boxvH"                          => b"\xE2\x95\xAA",           // box drawings vertical single and horizontal double, U+256A
+    b"boxvh"                          => b"\xE2\x94\xBC",           // box drawings light vertical and horizontal, U+253C
+    b"boxVL"                          => b"\xE2\x95\xA3",           // box drawings double vertical and left, U+2563
+    b"boxVl"                          => b"\xE2\x95\xA2",           // box drawings vertical double and left single, U+2562
+    b"boxvL"                          => b"\xE2\x95\xA1",           // box drawings vertical single and left double, U+2561
+    b"boxvl"                          => b"\xE2\x94\xA4",           // box drawings light vertical and left, U+2524
+    b"boxVR"                          => b"\xE2\x95\xA0",           // box drawings double vertical and right, U+2560
+    b"boxVr"                          => b"\xE2\x95\x9F",           // box drawings vertical double and right single, U+255F
+    b"boxvR"                          => b"\xE2\x95\x9E",           // box drawings vertical single and right double, U+255E
+    b"boxvr"                          => b"\xE2\x94\x9C",           // box drawings light vertical and right, U+251C
+    b"bprime"                         => b"\xE2\x80\xB5",           // reversed prime, U+2035
+    b"Breve"                          => b"\xCB\x98",               // breve, U+02D8
+    b"breve"                          => b"\xCB\x98",               // breve, U+02D8
+    b"Bscr"                           => b"\xE2\x84\xAC",           // script capital b, U+212C
+    b"bscr"                           => b"\xF0\x9D\x92\xB7",       // mathematical script small b, U+1D4B7
+    b"bsemi"                          => b"\xE2\x81\x8F",           // reversed semicolon, U+204F
+    b"bsim"                           => b"\xE2\x88\xBD",           // reversed tilde, U+223D
+    b"bsime"                          => b"\xE2\x8B\x8D",           // reversed tilde equals, U+22CD
+    b"bsol"                           => b"\x5C",                   // reverse solidus, U+005C
+    b"bsolb"                          => b"\xE2\xA7\x85",           // squared falling diagonal slash, U+29C5
+    b"bsolhsub"                       => b"\xE2\x9F\x88",           // reverse solidus preceding subset, U+27C8
+    b"bull"                           => b"\xE2\x80\xA2",           // bullet, U+2022
+    b"bullet"                         => b"\xE2\x80\xA2",           // bullet, U+2022
+    b"bump"                           => b"\xE2\x89\x8E",           // geometrically equivalent to, U+224E
+    b"bumpE"                          => b"\xE2\xAA\xAE",           // equals sign with bumpy above, U+2AAE
+    b"bumpe"                          => b"\xE2\x89\x8F",           // difference between, U+224F
+    b"Bumpeq"                         => b"\xE2\x89\x8E",           // geometrically equivalent to, U+224E
+    b"bumpeq"                         => b"\xE2\x89\x8F",           // difference between, U+224F
+    b"Cacute"                         => b"\xC4\x86",               // latin capital letter c with acute, U+0106
+    b"cacute"                         => b"\xC4\x87",               // latin small letter c with acute, U+0107
+    b"Cap"                            => b"\xE2\x8B\x92",           // double intersection, U+22D2
+    b"cap"                            => b"\xE2\x88\xA9",           // intersection, U+2229
+    b"capand"                         => b"\xE2\xA9\x84",           // intersection with logical and, U+2A44
+    b"capbrcup"                       => b"\xE2\xA9\x89",           // intersection above bar above union, U+2A49
+    b"capcap"                         => b"\xE2\xA9\x8B",           // intersection beside and joined with intersection, U+2A4B
+    b"capcup"                         => b"\xE2\xA9\x87",           // intersection above union, U+2A47
+    b"capdot"                         => b"\xE2\xA9\x80",           // intersection with dot, U+2A40
+    b"CapitalDifferentialD"           => b"\xE2\x85\x85",           // double-struck italic capital d, U+2145
+    b"caps"                           => b"\xE2\x88\xA9\xEF\xB8\x80",// intersection, U+2229 + variation selector-1, U+FE00
+    b"caret"                          => b"\xE2\x81\x81",           // caret insertion point, U+2041
+    b"caron"                          => b"\xCB\x87",               // caron, U+02C7
+    b"Cayleys"                        => b"\xE2\x84\xAD",           // black-letter capital c, U+212D
+    b"ccaps"                          => b"\xE2\xA9\x8D",           // closed intersection with serifs, U+2A4D
+    b"Ccaron"                         => b"\xC4\x8C",               // latin capital letter c with caron, U+010C
+    b"ccaron"                         => b"\xC4\x8D",               // latin small letter c with caron, U+010D
+    b"Ccirc"                          => b"\xC4\x88",               // latin capital letter c with circumflex, U+0108
+    b"ccirc"                          => b"\xC4\x89",               // latin small letter c with circumflex, U+0109
+    b"Cconint"                        => b"\xE2\x88\xB0",           // volume integral, U+2230
+    b"ccups"                          => b"\xE2\xA9\x8C",           // closed union with serifs, U+2A4C
+    b"ccupssm"                        => b"\xE2\xA9\x90",           // closed union with serifs and smash product, U+2A50
+    b"Cdot"                           => b"\xC4\x8A",               // latin capital letter c with dot above, U+010A
+    b"cdot"                           => b"\xC4\x8B",               // latin small letter c with dot above, U+010B
+    b"Cedilla"                        => b"\xC2\xB8",               // cedilla, U+00B8
+    b"cemptyv"                        => b"\xE2\xA6\xB2",           // empty set with small circle above, U+29B2
+    b"CenterDot"                      => b"\xC2\xB7",               // middle dot, U+00B7
+    b"centerdot"                      => b"\xC2\xB7",               // middle dot, U+00B7
+    b"Cfr"                            => b"\xE2\x84\xAD",           // black-letter capital c, U+212D
+    b"cfr"                            => b"\xF0\x9D\x94\xA0",       // mathematical fraktur small c, U+1D520
+    b"CHcy"                           => b"\xD0\xA7",               // cyrillic capital letter che, U+0427
+    b"chcy"                           => b"\xD1\x87",               // cyrillic small letter che, U+0447
+    b"check"                          => b"\xE2\x9C\x93",           // check mark, U+2713
+    b"checkmark"                      => b"\xE2\x9C\x93",           // check mark, U+2713
+    b"Chi"                            => b"\xCE\xA7",               // greek capital letter chi, U+03A7
+    b"chi"                            => b"\xCF\x87",               // greek small letter chi, U+03C7
+    b"cir"                            => b"\xE2\x97\x8B",           // white circle, U+25CB
+    b"circeq"                         => b"\xE2\x89\x97",           // ring equal to, U+2257
+    b"circlearrowleft"                => b"\xE2\x86\xBA",           // anticlockwise open circle arrow, U+21BA
+    b"circlearrowright"               => b"\xE2\x86\xBB",           // clockwise open circle arrow, U+21BB
+    b"circledast"                     => b"\xE2\x8A\x9B",           // circled asterisk operator, U+229B
+    b"circledcirc"                    => b"\xE2\x8A\x9A",           // circled ring operator, U+229A
+    b"circleddash"                    => b"\xE2\x8A\x9D",           // circled dash, U+229D
+    b"CircleDot"                      => b"\xE2\x8A\x99",           // circled dot operator, U+2299
+    b"circledR"                       => b"\xC2\xAE",               // registered sign, U+00AE
+    b"circledS"                       => b"\xE2\x93\x88",           // circled latin capital letter s, U+24C8
+    b"CircleMinus"                    => b"\xE2\x8A\x96",           // circled minus, U+2296
+    b"CirclePlus"                     => b"\xE2\x8A\x95",           // circled plus, U+2295
+    b"CircleTimes"                    => b"\xE2\x8A\x97",           // circled times, U+2297
+    b"cirE"                           => b"\xE2\xA7\x83",           // circle with two horizontal strokes to the right, U+29C3
+    b"cire"                           => b"\xE2\x89\x97",           // ring equal to, U+2257
+    b"cirfnint"                       => b"\xE2\xA8\x90",           // circulation function, U+2A10
+    b"cirmid"                         => b"\xE2\xAB\xAF",           // vertical line with circle above, U+2AEF
+    b"cirscir"                        => b"\xE2\xA7\x82",           // circle with small circle to the right, U+29C2
+    b"ClockwiseContourIntegral"       => b"\xE2\x88\xB2",           // clockwise contour integral, U+2232
+    b"CloseCurlyDoubleQuote"          => b"\xE2\x80\x9D",           // right double quotation mark, U+201D
+    b"CloseCurlyQuote"                => b"\xE2\x80\x99",           // right single quotation mark, U+2019
+    b"clubs"                          => b"\xE2\x99\xA3",           // black club suit, U+2663
+    b"clubsuit"                       => b"\xE2\x99\xA3",           // black club suit, U+2663
+    b"Colon"                          => b"\xE2\x88\xB7",           // proportion, U+2237
+    b"colon"                          => b"\x3A",                   // colon, U+003A
+    b"Colone"                         => b"\xE2\xA9\xB4",           // double colon equal, U+2A74
+    b"colone"                         => b"\xE2\x89\x94",           // colon equals, U+2254
+    b"coloneq"                        => b"\xE2\x89\x94",           // colon equals, U+2254
+    b"comma"                          => b"\x2C",                   // comma, U+002C
+    b"commat"                         => b"\x40",                   // commercial at, U+0040
+    b"comp"                           => b"\xE2\x88\x81",           // complement, U+2201
+    b"compfn"                         => b"\xE2\x88\x98",           // ring operator, U+2218
+    b"complement"                     => b"\xE2\x88\x81",           // complement, U+2201
+    b"complexes"                      => b"\xE2\x84\x82",           // double-struck capital c, U+2102
+    b"cong"                           => b"\xE2\x89\x85",           // approximately equal to, U+2245
+    b"congdot"                        => b"\xE2\xA9\xAD",           // congruent with dot above, U+2A6D
+    b"Congruent"                      => b"\xE2\x89\xA1",           // identical to, U+2261
+    b"Conint"                         => b"\xE2\x88\xAF",           // surface integral, U+222F
+    b"conint"                         => b"\xE2\x88\xAE",           // contour integral, U+222E
+    b"ContourIntegral"                => b"\xE2\x88\xAE",           // contour integral, U+222E
+    b"Copf"                           => b"\xE2\x84\x82",           // double-struck capital c, U+2102
+    b"copf"                           => b"\xF0\x9D\x95\x94",       // mathematical double-struck small c, U+1D554
+    b"coprod"                         => b"\xE2\x88\x90",           // n-ary coproduct, U+2210
+    b"Coproduct"                      => b"\xE2\x88\x90",           // n-ary coproduct, U+2210
+    b"COPY"                           => b"\xC2\xA9",               // copyright sign, U+00A9
+    b"copysr"                         => b"\xE2\x84\x97",           // sound recording copyright, U+2117
+    b"CounterClockwiseContourIntegral"=> b"\xE2\x88\xB3",           // anticlockwise contour integral, U+2233
+    b"crarr"                          => b"\xE2\x86\xB5",           // downwards arrow with corner leftwards, U+21B5
+    b"Cross"                          => b"\xE2\xA8\xAF",           // vector or cross product, U+2A2F
+    b"cross"                          => b"\xE2\x9C\x97",           // ballot x, U+2717
+    b"Cscr"                           => b"\xF0\x9D\x92\x9E",       // mathematical script capital c, U+1D49E
+    b"cscr"                           => b"\xF0\x9D\x92\xB8",       // mathematical script small c, U+1D4B8
+    b"csub"                           => b"\xE2\xAB\x8F",           // closed subset, U+2ACF
+    b"csube"                          => b"\xE2\xAB\x91",           // closed subset or equal to, U+2AD1
+    b"csup"                           => b"\xE2\xAB\x90",           // closed superset, U+2AD0
+    b"csupe"                          => b"\xE2\xAB\x92",           // closed superset or equal to, U+2AD2
+    b"ctdot"                          => b"\xE2\x8B\xAF",           // midline horizontal ellipsis, U+22EF
+    b"cudarrl"                        => b"\xE2\xA4\xB8",           // right-side arc clockwise arrow, U+2938
+    b"cudarrr"                        => b"\xE2\xA4\xB5",           // arrow pointing rightwards then curving downwards, U+2935
+    b"cuepr"                          => b"\xE2\x8B\x9E",           // equal to or precedes, U+22DE
+    b"cuesc"                          => b"\xE2\x8B\x9F",           // equal to or succeeds, U+22DF
+    b"cularr"                         => b"\xE2\x86\xB6",           // anticlockwise top semicircle arrow, U+21B6
+    b"cularrp"                        => b"\xE2\xA4\xBD",           // top arc anticlockwise arrow with plus, U+293D
+    b"Cup"                            => b"\xE2\x8B\x93",           // double union, U+22D3
+    b"cup"                            => b"\xE2\x88\xAA",           // union, U+222A
+    b"cupbrcap"                       => b"\xE2\xA9\x88",           // union above bar above intersection, U+2A48
+    b"CupCap"                         => b"\xE2\x89\x8D",           // equivalent to, U+224D
+    b"cupcap"                         => b"\xE2\xA9\x86",           // union above intersection, U+2A46
+    b"cupcup"                         => b"\xE2\xA9\x8A",           // union beside and joined with union, U+2A4A
+    b"cupdot"                         => b"\xE2\x8A\x8D",           // multiset multiplication, U+228D
+    b"cupor"                          => b"\xE2\xA9\x85",           // union with logical or, U+2A45
+    b"cups"                           => b"\xE2\x88\xAA\xEF\xB8\x80",// union, U+222A + variation selector-1, U+FE00
+    b"curarr"                         => b"\xE2\x86\xB7",           // clockwise top semicircle arrow, U+21B7
+    b"curarrm"                        => b"\xE2\xA4\xBC",           // top arc clockwise arrow with minus, U+293C
+    b"curlyeqprec"                    => b"\xE2\x8B\x9E",           // equal to or precedes, U+22DE
+    b"curlyeqsucc"                    => b"\xE2\x8B\x9F",           // equal to or succeeds, U+22DF
+    b"curlyvee"                       => b"\xE2\x8B\x8E",           // curly logical or, U+22CE
+    b"curlywedge"                     => b"\xE2\x8B\x8F",           // curly logical and, U+22CF
+    b"curvearrowleft"                 => b"\xE2\x86\xB6",           // anticlockwise top semicircle arrow, U+21B6
+    b"curvearrowright"                => b"\xE2\x86\xB7",           // clockwise top semicircle arrow, U+21B7
+    b"cuvee"                          => b"\xE2\x8B\x8E",           // curly logical or, U+22CE
+    b"cuwed"                          => b"\xE2\x8B\x8F",           // curly logical and, U+22CF
+    b"cwconint"                       => b"\xE2\x88\xB2",           // clockwise contour integral, U+2232
+    b"cwint"                          => b"\xE2\x88\xB1",           // clockwise integral, U+2231
+    b"cylcty"                         => b"\xE2\x8C\xAD",           // cylindricity, U+232D
+    b"daleth"                         => b"\xE2\x84\xB8",           // dalet symbol, U+2138
+    b"Darr"                           => b"\xE2\x86\xA1",           // downwards two headed arrow, U+21A1
+    b"dArr"                           => b"\xE2\x87\x93",           // downwards double arrow, U+21D3
+    b"darr"                           => b"\xE2\x86\x93",           // downwards arrow, U+2193
+    b"dash"                           => b"\xE2\x80\x90",           // hyphen, U+2010
+    b"Dashv"                          => b"\xE2\xAB\xA4",           // vertical bar double left turnstile, U+2AE4
+    b"dashv"                          => b"\xE2\x8A\xA3",           // left tack, U+22A3
+    b"dbkarow"                        => b"\xE2\xA4\x8F",           // rightwards triple dash arrow, U+290F
+    b"dblac"                          => b"\xCB\x9D",               // double acute accent, U+02DD
+    b"Dcaron"                         => b"\xC4\x8E",               // latin capital letter d with caron, U+010E
+    b"dcaron"                         => b"\xC4\x8F",               // latin small letter d with caron, U+010F
+    b"Dcy"                            => b"\xD0\x94",               // cyrillic capital letter de, U+0414
+    b"dcy"                            => b"\xD0\xB4",               // cyrillic small letter de, U+0434
+    b"DD"                             => b"\xE2\x85\x85",           // double-struck italic capital d, U+2145
+    b"dd"                             => b"\xE2\x85\x86",           // double-struck italic small d, U+2146
+    b"ddagger"                        => b"\xE2\x80\xA1",           // double dagger, U+2021
+    b"ddarr"                          => b"\xE2\x87\x8A",           // downwards paired arrows, U+21CA
+    b"DDotrahd"                       => b"\xE2\xA4\x91",           // rightwards arrow with dotted stem, U+2911
+    b"ddotseq"                        => b"\xE2\xA9\xB7",           // equals sign with two dots above and two dots below, U+2A77
+    b"Del"                            => b"\xE2\x88\x87",           // nabla, U+2207
+    b"Delta"                          => b"\xCE\x94",               // greek capital letter delta, U+0394
+    b"delta"                          => b"\xCE\xB4",               // greek small letter delta, U+03B4
+    b"demptyv"                        => b"\xE2\xA6\xB1",           // empty set with overbar, U+29B1
+    b"dfisht"                         => b"\xE2\xA5\xBF",           // down fish tail, U+297F
+    b"Dfr"                            => b"\xF0\x9D\x94\x87",       // mathematical fraktur capital d, U+1D507
+    b"dfr"                            => b"\xF0\x9D\x94\xA1",       // mathematical fraktur small d, U+1D521
+    b"dHar"                           => b"\xE2\xA5\xA5",           // downwards harpoon with barb left beside downwards harpoon with barb right, U+2965
+    b"dharl"                          => b"\xE2\x87\x83",           // downwards harpoon with barb leftwards, U+21C3
+    b"dharr"                          => b"\xE2\x87\x82",           // downwards harpoon with barb rightwards, U+21C2
+    b"DiacriticalAcute"               => b"\xC2\xB4",               // acute accent, U+00B4
+    b"DiacriticalDot"                 => b"\xCB\x99",               // dot above, U+02D9
+    b"DiacriticalDoubleAcute"         => b"\xCB\x9D",               // double acute accent, U+02DD
+    b"DiacriticalGrave"               => b"\x60",                   // grave accent, U+0060
+    b"DiacriticalTilde"               => b"\xCB\x9C",               // small tilde, U+02DC
+    b"diam"                           => b"\xE2\x8B\x84",           // diamond operator, U+22C4
+    b"Diamond"                        => b"\xE2\x8B\x84",           // diamond operator, U+22C4
+    b"diamond"                        => b"\xE2\x8B\x84",           // diamond operator, U+22C4
+    b"diamondsuit"                    => b"\xE2\x99\xA6",           // black diamond suit, U+2666
+    b"diams"                          => b"\xE2\x99\xA6",           // black diamond suit, U+2666
+    b"die"                            => b"\xC2\xA8",               // diaeresis, U+00A8
+    b"DifferentialD"                  => b"\xE2\x85\x86",           // double-struck italic small d, U+2146
+    b"digamma"                        => b"\xCF\x9D",               // greek small letter digamma, U+03DD
+    b"disin"                          => b"\xE2\x8B\xB2",           // element of with long horizontal stroke, U+22F2
+    b"div"                            => b"\xC3\xB7",               // division sign, U+00F7
+    b"divideontimes"                  => b"\xE2\x8B\x87",           // division times, U+22C7
+    b"divonx"                         => b"\xE2\x8B\x87",           // division times, U+22C7
+    b"DJcy"                           => b"\xD0\x82",               // cyrillic capital letter dje, U+0402
+    b"djcy"                           => b"\xD1\x92",               // cyrillic small letter dje, U+0452
+    b"dlcorn"                         => b"\xE2\x8C\x9E",           // bottom left corner, U+231E
+    b"dlcrop"                         => b"\xE2\x8C\x8D",           // bottom left crop, U+230D
+    b"dollar"                         => b"\x24",                   // dollar sign, U+0024
+    b"Dopf"                           => b"\xF0\x9D\x94\xBB",       // mathematical double-struck capital d, U+1D53B
+    b"dopf"                           => b"\xF0\x9D\x95\x95",       // mathematical double-struck small d, U+1D555
+    b"Dot"                            => b"\xC2\xA8",               // diaeresis, U+00A8
+    b"dot"                            => b"\xCB\x99",               // dot above, U+02D9
+    b"DotDot"                         => b"\xE2\x83\x9C",           // combining four dots above, U+20DC
+    b"doteq"                          => b"\xE2\x89\x90",           // approaches the limit, U+2250
+    b"doteqdot"                       => b"\xE2\x89\x91",           // geometrically equal to, U+2251
+    b"DotEqual"                       => b"\xE2\x89\x90",           // approaches the limit, U+2250
+    b"dotminus"                       => b"\xE2\x88\xB8",           // dot minus, U+2238
+    b"dotplus"                        => b"\xE2\x88\x94",           // dot plus, U+2214
+    b"dotsquare"                      => b"\xE2\x8A\xA1",           // squared dot operator, U+22A1
+    b"doublebarwedge"                 => b"\xE2\x8C\x86",           // perspective, U+2306
+    b"DoubleContourIntegral"          => b"\xE2\x88\xAF",           // surface integral, U+222F
+    b"DoubleDot"                      => b"\xC2\xA8",               // diaeresis, U+00A8
+    b"DoubleDownArrow"                => b"\xE2\x87\x93",           // downwards double arrow, U+21D3
+    b"DoubleLeftArrow"                => b"\xE2\x87\x90",           // leftwards double arrow, U+21D0
+    b"DoubleLeftRightArrow"           => b"\xE2\x87\x94",           // left right double arrow, U+21D4
+    b"DoubleLeftTee"                  => b"\xE2\xAB\xA4",           // vertical bar double left turnstile, U+2AE4
+    b"DoubleLongLeftArrow"            => b"\xE2\x9F\xB8",           // long leftwards double arrow, U+27F8
+    b"DoubleLongLeftRightArrow"       => b"\xE2\x9F\xBA",           // long left right double arrow, U+27FA
+    b"DoubleLongRightArrow"           => b"\xE2\x9F\xB9",           // long rightwards double arrow, U+27F9
+    b"DoubleRightArrow"               => b"\xE2\x87\x92",           // rightwards double arrow, U+21D2
+    b"DoubleRightTee"                 => b"\xE2\x8A\xA8",           // true, U+22A8
+    b"DoubleUpArrow"                  => b"\xE2\x87\x91",           // upwards double arrow, U+21D1
+    b"DoubleUpDownArrow"              => b"\xE2\x87\x95",           // up down double arrow, U+21D5
+    b"DoubleVerticalBar"              => b"\xE2\x88\xA5",           // parallel to, U+2225
+    b"DownArrow"                      => b"\xE2\x86\x93",           // downwards arrow, U+2193
+    b"Downarrow"                      => b"\xE2\x87\x93",           // downwards double arrow, U+21D3
+    b"downarrow"                      => b"\xE2\x86\x93",           // downwards arrow, U+2193
+    b"DownArrowBar"                   => b"\xE2\xA4\x93",           // downwards arrow to bar, U+2913
+    b"DownArrowUpArrow"               => b"\xE2\x87\xB5",           // downwards arrow leftwards of upwards arrow, U+21F5
+    b"DownBreve"                      => b"\xCC\x91",               // combining inverted breve, U+0311
+    b"downdownarrows"                 => b"\xE2\x87\x8A",           // downwards paired arrows, U+21CA
+    b"downharpoonleft"                => b"\xE2\x87\x83",           // downwards harpoon with barb leftwards, U+21C3
+    b"downharpoonright"               => b"\xE2\x87\x82",           // downwards harpoon with barb rightwards, U+21C2
+    b"DownLeftRightVector"            => b"\xE2\xA5\x90",           // left barb down right barb down harpoon, U+2950
+    b"DownLeftTeeVector"              => b"\xE2\xA5\x9E",           // leftwards harpoon with barb down from bar, U+295E
+    b"DownLeftVector"                 => b"\xE2\x86\xBD",           // leftwards harpoon with barb downwards, U+21BD
+    b"DownLeftVectorBar"              => b"\xE2\xA5\x96",           // leftwards harpoon with barb down to bar, U+2956
+    b"DownRightTeeVector"             => b"\xE2\xA5\x9F",           // rightwards harpoon with barb down from bar, U+295F
+    b"DownRightVector"                => b"\xE2\x87\x81",           // rightwards harpoon with barb downwards, U+21C1
+    b"DownRightVectorBar"             => b"\xE2\xA5\x97",           // rightwards harpoon with barb down to bar, U+2957
+    b"DownTee"                        => b"\xE2\x8A\xA4",           // down tack, U+22A4
+    b"DownTeeArrow"                   => b"\xE2\x86\xA7",           // downwards arrow from bar, U+21A7
+    b"drbkarow"                       => b"\xE2\xA4\x90",           // rightwards two-headed triple dash arrow, U+2910
+    b"drcorn"                         => b"\xE2\x8C\x9F",           // bottom right corner, U+231F
+    b"drcrop"                         => b"\xE2\x8C\x8C",           // bottom right crop, U+230C
+    b"Dscr"                           => b"\xF0\x9D\x92\x9F",       // mathematical script capital d, U+1D49F
+    b"dscr"                           => b"\xF0\x9D\x92\xB9",       // mathematical script small d, U+1D4B9
+    b"DScy"                           => b"\xD0\x85",               // cyrillic capital letter dze, U+0405
+    b"dscy"                           => b"\xD1\x95",               // cyrillic small letter dze, U+0455
+    b"dsol"                           => b"\xE2\xA7\xB6",           // solidus with overbar, U+29F6
+    b"Dstrok"                         => b"\xC4\x90",               // latin capital letter d with stroke, U+0110
+    b"dstrok"                         => b"\xC4\x91",               // latin small letter d with stroke, U+0111
+    b"dtdot"                          => b"\xE2\x8B\xB1",           // down right diagonal ellipsis, U+22F1
+    b"dtri"                           => b"\xE2\x96\xBF",           // white down-pointing small triangle, U+25BF
+    b"dtrif"                          => b"\xE2\x96\xBE",           // black down-pointing small triangle, U+25BE
+    b"duarr"                          => b"\xE2\x87\xB5",           // downwards arrow leftwards of upwards arrow, U+21F5
+    b"duhar"                          => b"\xE2\xA5\xAF",           // downwards harpoon with barb left beside upwards harpoon with barb right, U+296F
+    b"dwangle"                        => b"\xE2\xA6\xA6",           // oblique angle opening up, U+29A6
+    b"DZcy"                           => b"\xD0\x8F",               // cyrillic capital letter dzhe, U+040F
+    b"dzcy"                           => b"\xD1\x9F",               // cyrillic small letter dzhe, U+045F
+    b"dzigrarr"                       => b"\xE2\x9F\xBF",           // long rightwards squiggle arrow, U+27FF
+    b"easter"                         => b"\xE2\xA9\xAE",           // equals with asterisk, U+2A6E
+    b"Ecaron"                         => b"\xC4\x9A",               // latin capital letter e with caron, U+011A
+    b"ecaron"                         => b"\xC4\x9B",               // latin small letter e with caron, U+011B
+    b"ecir"                           => b"\xE2\x89\x96",           // ring in equal to, U+2256
+    b"ecolon"                         => b"\xE2\x89\x95",           // equals colon, U+2255
+    b"Ecy"                            => b"\xD0\xAD",               // cyrillic capital letter e, U+042D
+    b"ecy"                            => b"\xD1\x8D",               // cyrillic small letter e, U+044D
+    b"eDDot"                          => b"\xE2\xA9\xB7",           // equals sign with two dots above and two dots below, U+2A77
+    b"Edot"                           => b"\xC4\x96",               // latin capital letter e with dot above, U+0116
+    b"eDot"                           => b"\xE2\x89\x91",           // geometrically equal to, U+2251
+    b"edot"                           => b"\xC4\x97",               // latin small letter e with dot above, U+0117
+    b"ee"                             => b"\xE2\x85\x87",           // double-struck italic small e, U+2147
+    b"efDot"                          => b"\xE2\x89\x92",           // approximately equal to or the image of, U+2252
+    b"Efr"                            => b"\xF0\x9D\x94\x88",       // mathematical fraktur capital e, U+1D508
+    b"efr"                            => b"\xF0\x9D\x94\xA2",       // mathematical fraktur small e, U+1D522
+    b"eg"                             => b"\xE2\xAA\x9A",           // double-line equal to or greater-than, U+2A9A
+    b"egs"                            => b"\xE2\xAA\x96",           // slanted equal to or greater-than, U+2A96
+    b"egsdot"                         => b"\xE2\xAA\x98",           // slanted equal to or greater-than with dot inside, U+2A98
+    b"el"                             => b"\xE2\xAA\x99",           // double-line equal to or less-than, U+2A99
+    b"Element"                        => b"\xE2\x88\x88",           // element of, U+2208
+    b"elinters"                       => b"\xE2\x8F\xA7",           // electrical intersection, U+23E7
+    b"ell"                            => b"\xE2\x84\x93",           // script small l, U+2113
+    b"els"                            => b"\xE2\xAA\x95",           // slanted equal to or less-than, U+2A95
+    b"elsdot"                         => b"\xE2\xAA\x97",           // slanted equal to or less-than with dot inside, U+2A97
+    b"Emacr"                          => b"\xC4\x92",               // latin capital letter e with macron, U+0112
+    b"emacr"                          => b"\xC4\x93",               // latin small letter e with macron, U+0113
+    b"empty"                          => b"\xE2\x88\x85",           // empty set, U+2205
+    b"emptyset"                       => b"\xE2\x88\x85",           // empty set, U+2205
+    b"EmptySmallSquare"               => b"\xE2\x97\xBB",           // white medium square, U+25FB
+    b"emptyv"                         => b"\xE2\x88\x85",           // empty set, U+2205
+    b"EmptyVerySmallSquare"           => b"\xE2\x96\xAB",           // white small square, U+25AB
+    b"emsp13"                         => b"\xE2\x80\x84",           // three-per-em space, U+2004
+    b"emsp14"                         => b"\xE2\x80\x85",           // four-per-em space, U+2005
+    b"ENG"                            => b"\xC5\x8A",               // latin capital letter eng, U+014A
+    b"eng"                            => b"\xC5\x8B",               // latin small letter eng, U+014B
+    b"Eogon"                          => b"\xC4\x98",               // latin capital letter e with ogonek, U+0118
+    b"eogon"                          => b"\xC4\x99",               // latin small letter e with ogonek, U+0119
+    b"Eopf"                           => b"\xF0\x9D\x94\xBC",       // mathematical double-struck capital e, U+1D53C
+    b"eopf"                           => b"\xF0\x9D\x95\x96",       // mathematical double-struck small e, U+1D556
+    b"epar"                           => b"\xE2\x8B\x95",           // equal and parallel to, U+22D5
+    b"eparsl"                         => b"\xE2\xA7\xA3",           // equals sign and slanted parallel, U+29E3
+    b"eplus"                          => b"\xE2\xA9\xB1",           // equals sign above plus sign, U+2A71
+    b"epsi"                           => b"\xCE\xB5",               // greek small letter epsilon, U+03B5
+    b"Epsilon"                        => b"\xCE\x95",               // greek capital letter epsilon, U+0395
+    b"epsilon"                        => b"\xCE\xB5",               // greek small letter epsilon, U+03B5
+    b"epsiv"                          => b"\xCF\xB5",               // greek lunate epsilon symbol, U+03F5
+    b"eqcirc"                         => b"\xE2\x89\x96",           // ring in equal to, U+2256
+    b"eqcolon"                        => b"\xE2\x89\x95",           // equals colon, U+2255
+    b"eqsim"                          => b"\xE2\x89\x82",           // minus tilde, U+2242
+    b"eqslantgtr"                     => b"\xE2\xAA\x96",           // slanted equal to or greater-than, U+2A96
+    b"eqslantless"                    => b"\xE2\xAA\x95",           // slanted equal to or less-than, U+2A95
+    b"Equal"                          => b"\xE2\xA9\xB5",           // two consecutive equals signs, U+2A75
+    b"equals"                         => b"\x3D",                   // equals sign, U+003D
+    b"EqualTilde"                     => b"\xE2\x89\x82",           // minus tilde, U+2242
+    b"equest"                         => b"\xE2\x89\x9F",           // questioned equal to, U+225F
+    b"Equilibrium"                    => b"\xE2\x87\x8C",           // rightwards harpoon over leftwards harpoon, U+21CC
+    b"equiv"                          => b"\xE2\x89\xA1",           // identical to, U+2261
+    b"equivDD"                        => b"\xE2\xA9\xB8",           // equivalent with four dots above, U+2A78
+    b"eqvparsl"                       => b"\xE2\xA7\xA5",           // identical to and slanted parallel, U+29E5
+    b"erarr"                          => b"\xE2\xA5\xB1",           // equals sign above rightwards arrow, U+2971
+    b"erDot"                          => b"\xE2\x89\x93",           // image of or approximately equal to, U+2253
+    b"Escr"                           => b"\xE2\x84\xB0",           // script capital e, U+2130
+    b"escr"                           => b"\xE2\x84\xAF",           // script small e, U+212F
+    b"esdot"                          => b"\xE2\x89\x90",           // approaches the limit, U+2250
+    b"Esim"                           => b"\xE2\xA9\xB3",           // equals sign above tilde operator, U+2A73
+    b"esim"                           => b"\xE2\x89\x82",           // minus tilde, U+2242
+    b"Eta"                            => b"\xCE\x97",               // greek capital letter eta, U+0397
+    b"eta"                            => b"\xCE\xB7",               // greek small letter eta, U+03B7
+    b"excl"                           => b"\x21",                   // exclamation mark, U+0021
+    b"exist"                          => b"\xE2\x88\x83",           // there exists, U+2203
+    b"Exists"                         => b"\xE2\x88\x83",           // there exists, U+2203
+    b"expectation"                    => b"\xE2\x84\xB0",           // script capital e, U+2130
+    b"ExponentialE"                   => b"\xE2\x85\x87",           // double-struck italic small e, U+2147
+    b"exponentiale"                   => b"\xE2\x85\x87",           // double-struck italic small e, U+2147
+    b"fallingdotseq"                  => b"\xE2\x89\x92",           // approximately equal to or the image of, U+2252
+    b"Fcy"                            => b"\xD0\xA4",               // cyrillic capital letter ef, U+0424
+    b"fcy"                            => b"\xD1\x84",               // cyrillic small letter ef, U+0444
+    b"female"                         => b"\xE2\x99\x80",           // female sign, U+2640
+    b"ffilig"                         => b"\xEF\xAC\x83",           // latin small ligature ffi, U+FB03
+    b"fflig"                          => b"\xEF\xAC\x80",           // latin small ligature ff, U+FB00
+    b"ffllig"                         => b"\xEF\xAC\x84",           // latin small ligature ffl, U+FB04
+    b"Ffr"                            => b"\xF0\x9D\x94\x89",       // mathematical fraktur capital f, U+1D509
+    b"ffr"                            => b"\xF0\x9D\x94\xA3",       // mathematical fraktur small f, U+1D523
+    b"filig"                          => b"\xEF\xAC\x81",           // latin small ligature fi, U+FB01
+    b"FilledSmallSquare"              => b"\xE2\x97\xBC",           // black medium square, U+25FC
+    b"FilledVerySmallSquare"          => b"\xE2\x96\xAA",           // black small square, U+25AA
+    b"fjlig"                          => b"\x66\x6A",               // latin small letter f, U+0066 + latin small letter j, U+006A
+    b"flat"                           => b"\xE2\x99\xAD",           // music flat sign, U+266D
+    b"fllig"                          => b"\xEF\xAC\x82",           // latin small ligature fl, U+FB02
+    b"fltns"                          => b"\xE2\x96\xB1",           // white parallelogram, U+25B1
+    b"fnof"                           => b"\xC6\x92",               // latin small letter f with hook, U+0192
+    b"Fopf"                           => b"\xF0\x9D\x94\xBD",       // mathematical double-struck capital f, U+1D53D
+    b"fopf"                           => b"\xF0\x9D\x95\x97",       // mathematical double-struck small f, U+1D557
+    b"ForAll"                         => b"\xE2\x88\x80",           // for all, U+2200
+    b"forall"                         => b"\xE2\x88\x80",           // for all, U+2200
+    b"fork"                           => b"\xE2\x8B\x94",           // pitchfork, U+22D4
+    b"forkv"                          => b"\xE2\xAB\x99",           // element of opening downwards, U+2AD9
+    b"Fouriertrf"                     => b"\xE2\x84\xB1",           // script capital f, U+2131
+    b"fpartint"                       => b"\xE2\xA8\x8D",           // finite part integral, U+2A0D
+    b"frac13"                         => b"\xE2\x85\x93",           // vulgar fraction one third, U+2153
+    b"frac15"                         => b"\xE2\x85\x95",           // vulgar fraction one fifth, U+2155
+    b"frac16"                         => b"\xE2\x85\x99",           // vulgar fraction one sixth, U+2159
+    b"frac18"                         => b"\xE2\x85\x9B",           // vulgar fraction one eighth, U+215B
+    b"frac23"                         => b"\xE2\x85\x94",           // vulgar fraction two thirds, U+2154
+    b"frac25"                         => b"\xE2\x85\x96",           // vulgar fraction two fifths, U+2156
+    b"frac35"                         => b"\xE2\x85\x97",           // vulgar fraction three fifths, U+2157
+    b"frac38"                         => b"\xE2\x85\x9C",           // vulgar fraction three eighths, U+215C
+    b"frac45"                         => b"\xE2\x85\x98",           // vulgar fraction four fifths, U+2158
+    b"frac56"                         => b"\xE2\x85\x9A",           // vulgar fraction five sixths, U+215A
+    b"frac58"                         => b"\xE2\x85\x9D",           // vulgar fraction five eighths, U+215D
+    b"frac78"                         => b"\xE2\x85\x9E",           // vulgar fraction seven eighths, U+215E
+    b"frasl"                          => b"\xE2\x81\x84",           // fraction slash, U+2044
+    b"frown"                          => b"\xE2\x8C\xA2",           // frown, U+2322
+    b"Fscr"                           => b"\xE2\x84\xB1",           // script capital f, U+2131
+    b"fscr"                           => b"\xF0\x9D\x92\xBB",       // mathematical script small f, U+1D4BB
+    b"gacute"                         => b"\xC7\xB5",               // latin small letter g with acute, U+01F5
+    b"Gamma"                          => b"\xCE\x93",               // greek capital letter gamma, U+0393
+    b"gamma"                          => b"\xCE\xB3",               // greek small letter gamma, U+03B3
+    b"Gammad"                         => b"\xCF\x9C",               // greek letter digamma, U+03DC
+    b"gammad"                         => b"\xCF\x9D",               // greek small letter digamma, U+03DD
+    b"gap"                            => b"\xE2\xAA\x86",           // greater-than or approximate, U+2A86
+    b"Gbreve"                         => b"\xC4\x9E",               // latin capital letter g with breve, U+011E
+    b"gbreve"                         => b"\xC4\x9F",               // latin small letter g with breve, U+011F
+    b"Gcedil"                         => b"\xC4\xA2",               // latin capital letter g with cedilla, U+0122
+    b"Gcirc"                          => b"\xC4\x9C",               // latin capital letter g with circumflex, U+011C
+    b"gcirc"                          => b"\xC4\x9D",               // latin small letter g with circumflex, U+011D
+    b"Gcy"                            => b"\xD0\x93",               // cyrillic capital letter ghe, U+0413
+    b"gcy"                            => b"\xD0\xB3",               // cyrillic small letter ghe, U+0433
+    b"Gdot"                           => b"\xC4\xA0",               // latin capital letter g with dot above, U+0120
+    b"gdot"                           => b"\xC4\xA1",               // latin small letter g with dot above, U+0121
+    b"gE"                             => b"\xE2\x89\xA7",           // greater-than over equal to, U+2267
+    b"ge"                             => b"\xE2\x89\xA5",           // greater-than or equal to, U+2265
+    b"gEl"                            => b"\xE2\xAA\x8C",           // greater-than above double-line equal above less-than, U+2A8C
+    b"gel"                            => b"\xE2\x8B\x9B",           // greater-than equal to or less-than, U+22DB
+    b"geq"                            => b"\xE2\x89\xA5",           // greater-than or equal to, U+2265
+    b"geqq"                           => b"\xE2\x89\xA7",           // greater-than over equal to, U+2267
+    b"geqslant"                       => b"\xE2\xA9\xBE",           // greater-than or slanted equal to, U+2A7E
+    b"ges"                            => b"\xE2\xA9\xBE",           // greater-than or slanted equal to, U+2A7E
+    b"gescc"                          => b"\xE2\xAA\xA9",           // greater-than closed by curve above slanted equal, U+2AA9
+    b"gesdot"                         => b"\xE2\xAA\x80",           // greater-than or slanted equal to with dot inside, U+2A80
+    b"gesdoto"                        => b"\xE2\xAA\x82",           // greater-than or slanted equal to with dot above, U+2A82
+    b"gesdotol"                       => b"\xE2\xAA\x84",           // greater-than or slanted equal to with dot above left, U+2A84
+    b"gesl"                           => b"\xE2\x8B\x9B\xEF\xB8\x80",// greater-than equal to or less-than, U+22DB + variation selector-1, U+FE00
+    b"gesles"                         => b"\xE2\xAA\x94",           // greater-than above slanted equal above less-than above slanted equal, U+2A94
+    b"Gfr"                            => b"\xF0\x9D\x94\x8A",       // mathematical fraktur capital g, U+1D50A
+    b"gfr"                            => b"\xF0\x9D\x94\xA4",       // mathematical fraktur small g, U+1D524
+    b"Gg"                             => b"\xE2\x8B\x99",           // very much greater-than, U+22D9
+    b"gg"                             => b"\xE2\x89\xAB",           // much greater-than, U+226B
+    b"ggg"                            => b"\xE2\x8B\x99",           // very much greater-than, U+22D9
+    b"gimel"                          => b"\xE2\x84\xB7",           // gimel symbol, U+2137
+    b"GJcy"                           => b"\xD0\x83",               // cyrillic capital letter gje, U+0403
+    b"gjcy"                           => b"\xD1\x93",               // cyrillic small letter gje, U+0453
+    b"gl"                             => b"\xE2\x89\xB7",           // greater-than or less-than, U+2277
+    b"gla"                            => b"\xE2\xAA\xA5",           // greater-than beside less-than, U+2AA5
+    b"glE"                            => b"\xE2\xAA\x92",           // greater-than above less-than above double-line equal, U+2A92
+    b"glj"                            => b"\xE2\xAA\xA4",           // greater-than overlapping less-than, U+2AA4
+    b"gnap"                           => b"\xE2\xAA\x8A",           // greater-than and not approximate, U+2A8A
+    b"gnapprox"                       => b"\xE2\xAA\x8A",           // greater-than and not approximate, U+2A8A
+    b"gnE"                            => b"\xE2\x89\xA9",           // greater-than but not equal to, U+2269
+    b"gne"                            => b"\xE2\xAA\x88",           // greater-than and single-line not equal to, U+2A88
+    b"gneq"                           => b"\xE2\xAA\x88",           // greater-than and single-line not equal to, U+2A88
+    b"gneqq"                          => b"\xE2\x89\xA9",           // greater-than but not equal to, U+2269
+    b"gnsim"                          => b"\xE2\x8B\xA7",           // greater-than but not equivalent to, U+22E7
+    b"Gopf"                           => b"\xF0\x9D\x94\xBE",       // mathematical double-struck capital g, U+1D53E
+    b"gopf"                           => b"\xF0\x9D\x95\x98",       // mathematical double-struck small g, U+1D558
+    b"grave"                          => b"\x60",                   // grave accent, U+0060
+    b"GreaterEqual"                   => b"\xE2\x89\xA5",           // greater-than or equal to, U+2265
+    b"GreaterEqualLess"               => b"\xE2\x8B\x9B",           // greater-than equal to or less-than, U+22DB
+    b"GreaterFullEqual"               => b"\xE2\x89\xA7",           // greater-than over equal to, U+2267
+    b"GreaterGreater"                 => b"\xE2\xAA\xA2",           // double nested greater-than, U+2AA2
+    b"GreaterLess"                    => b"\xE2\x89\xB7",           // greater-than or less-than, U+2277
+    b"GreaterSlantEqual"              => b"\xE2\xA9\xBE",           // greater-than or slanted equal to, U+2A7E
+    b"GreaterTilde"                   => b"\xE2\x89\xB3",           // greater-than or equivalent to, U+2273
+    b"Gscr"                           => b"\xF0\x9D\x92\xA2",       // mathematical script capital g, U+1D4A2
+    b"gscr"                           => b"\xE2\x84\x8A",           // script small g, U+210A
+    b"gsim"                           => b"\xE2\x89\xB3",           // greater-than or equivalent to, U+2273
+    b"gsime"                          => b"\xE2\xAA\x8E",           // greater-than above similar or equal, U+2A8E
+    b"gsiml"                          => b"\xE2\xAA\x90",           // greater-than above similar above less-than, U+2A90
+    b"GT"                             => b"\x3E",                   // greater-than sign, U+003E
+    b"Gt"                             => b"\xE2\x89\xAB",           // much greater-than, U+226B
+    b"gtcc"                           => b"\xE2\xAA\xA7",           // greater-than closed by curve, U+2AA7
+    b"gtcir"                          => b"\xE2\xA9\xBA",           // greater-than with circle inside, U+2A7A
+    b"gtdot"                          => b"\xE2\x8B\x97",           // greater-than with dot, U+22D7
+    b"gtlPar"                         => b"\xE2\xA6\x95",           // double left arc greater-than bracket, U+2995
+    b"gtquest"                        => b"\xE2\xA9\xBC",           // greater-than with question mark above, U+2A7C
+    b"gtrapprox"                      => b"\xE2\xAA\x86",           // greater-than or approximate, U+2A86
+    b"gtrarr"                         => b"\xE2\xA5\xB8",           // greater-than above rightwards arrow, U+2978
+    b"gtrdot"                         => b"\xE2\x8B\x97",           // greater-than with dot, U+22D7
+    b"gtreqless"                      => b"\xE2\x8B\x9B",           // greater-than equal to or less-than, U+22DB
+    b"gtreqqless"                     => b"\xE2\xAA\x8C",           // greater-than above double-line equal above less-than, U+2A8C
+    b"gtrless"                        => b"\xE2\x89\xB7",           // greater-than or less-than, U+2277
+    b"gtrsim"                         => b"\xE2\x89\xB3",           // greater-than or equivalent to, U+2273
+    b"gvertneqq"                      => b"\xE2\x89\xA9\xEF\xB8\x80",// greater-than but not equal to, U+2269 + variation selector-1, U+FE00
+    b"gvnE"                           => b"\xE2\x89\xA9\xEF\xB8\x80",// greater-than but not equal to, U+2269 + variation selector-1, U+FE00
+    b"Hacek"                          => b"\xCB\x87",               // caron, U+02C7
+    b"hairsp"                         => b"\xE2\x80\x8A",           // hair space, U+200A
+    b"half"                           => b"\xC2\xBD",               // vulgar fraction one half, U+00BD
+    b"hamilt"                         => b"\xE2\x84\x8B",           // script capital h, U+210B
+    b"HARDcy"                         => b"\xD0\xAA",               // cyrillic capital letter hard sign, U+042A
+    b"hardcy"                         => b"\xD1\x8A",               // cyrillic small letter hard sign, U+044A
+    b"hArr"                           => b"\xE2\x87\x94",           // left right double arrow, U+21D4
+    b"harr"                           => b"\xE2\x86\x94",           // left right arrow, U+2194
+    b"harrcir"                        => b"\xE2\xA5\x88",           // left right arrow through small circle, U+2948
+    b"harrw"                          => b"\xE2\x86\xAD",           // left right wave arrow, U+21AD
+    b"Hat"                            => b"\x5E",                   // circumflex accent, U+005E
+    b"hbar"                           => b"\xE2\x84\x8F",           // planck constant over two pi, U+210F
+    b"Hcirc"                          => b"\xC4\xA4",               // latin capital letter h with circumflex, U+0124
+    b"hcirc"                          => b"\xC4\xA5",               // latin small letter h with circumflex, U+0125
+    b"hearts"                         => b"\xE2\x99\xA5",           // black heart suit, U+2665
+    b"heartsuit"                      => b"\xE2\x99\xA5",           // black heart suit, U+2665
+    b"hellip"                         => b"\xE2\x80\xA6",           // horizontal ellipsis, U+2026
+    b"hercon"                         => b"\xE2\x8A\xB9",           // hermitian conjugate matrix, U+22B9
+    b"Hfr"                            => b"\xE2\x84\x8C",           // black-letter capital h, U+210C
+    b"hfr"                            => b"\xF0\x9D\x94\xA5",       // mathematical fraktur small h, U+1D525
+    b"HilbertSpace"                   => b"\xE2\x84\x8B",           // script capital h, U+210B
+    b"hksearow"                       => b"\xE2\xA4\xA5",           // south east arrow with hook, U+2925
+    b"hkswarow"                       => b"\xE2\xA4\xA6",           // south west arrow with hook, U+2926
+    b"hoarr"                          => b"\xE2\x87\xBF",           // left right open-headed arrow, U+21FF
+    b"homtht"                         => b"\xE2\x88\xBB",           // homothetic, U+223B
+    b"hookleftarrow"                  => b"\xE2\x86\xA9",           // leftwards arrow with hook, U+21A9
+    b"hookrightarrow"                 => b"\xE2\x86\xAA",           // rightwards arrow with hook, U+21AA
+    b"Hopf"                           => b"\xE2\x84\x8D",           // double-struck capital h, U+210D
+    b"hopf"                           => b"\xF0\x9D\x95\x99",       // mathematical double-struck small h, U+1D559
+    b"horbar"                         => b"\xE2\x80\x95",           // horizontal bar, U+2015
+    b"HorizontalLine"                 => b"\xE2\x94\x80",           // box drawings light horizontal, U+2500
+    b"Hscr"                           => b"\xE2\x84\x8B",           // script capital h, U+210B
+    b"hscr"                           => b"\xF0\x9D\x92\xBD",       // mathematical script small h, U+1D4BD
+    b"hslash"                         => b"\xE2\x84\x8F",           // planck constant over two pi, U+210F
+    b"Hstrok"                         => b"\xC4\xA6",               // latin capital letter h with stroke, U+0126
+    b"hstrok"                         => b"\xC4\xA7",               // latin small letter h with stroke, U+0127
+    b"HumpDownHump"                   => b"\xE2\x89\x8E",           // geometrically equivalent to, U+224E
+    b"HumpEqual"                      => b"\xE2\x89\x8F",           // difference between, U+224F
+    b"hybull"                         => b"\xE2\x81\x83",           // hyphen bullet, U+2043
+    b"hyphen"                         => b"\xE2\x80\x90",           // hyphen, U+2010
+    b"ic"                             => b"\xE2\x81\xA3",           // invisible separator, U+2063
+    b"Icy"                            => b"\xD0\x98",               // cyrillic capital letter i, U+0418
+    b"icy"                            => b"\xD0\xB8",               // cyrillic small letter i, U+0438
+    b"Idot"                           => b"\xC4\xB0",               // latin capital letter i with dot above, U+0130
+    b"IEcy"                           => b"\xD0\x95",               // cyrillic capital letter ie, U+0415
+    b"iecy"                           => b"\xD0\xB5",               // cyrillic small letter ie, U+0435
+    b"iff"                            => b"\xE2\x87\x94",           // left right double arrow, U+21D4
+    b"Ifr"                            => b"\xE2\x84\x91",           // black-letter capital i, U+2111
+    b"ifr"                            => b"\xF0\x9D\x94\xA6",       // mathematical fraktur small i, U+1D526
+    b"ii"                             => b"\xE2\x85\x88",           // double-struck italic small i, U+2148
+    b"iiiint"                         => b"\xE2\xA8\x8C",           // quadruple integral operator, U+2A0C
+    b"iiint"                          => b"\xE2\x88\xAD",           // triple integral, U+222D
+    b"iinfin"                         => b"\xE2\xA7\x9C",           // incomplete infinity, U+29DC
+    b"iiota"                          => b"\xE2\x84\xA9",           // turned greek small letter iota, U+2129
+    b"IJlig"                          => b"\xC4\xB2",               // latin capital ligature ij, U+0132
+    b"ijlig"                          => b"\xC4\xB3",               // latin small ligature ij, U+0133
+    b"Im"                             => b"\xE2\x84\x91",           // black-letter capital i, U+2111
+    b"Imacr"                          => b"\xC4\xAA",               // latin capital letter i with macron, U+012A
+    b"imacr"                          => b"\xC4\xAB",               // latin small letter i with macron, U+012B
+    b"image"                          => b"\xE2\x84\x91",           // black-letter capital i, U+2111
+    b"ImaginaryI"                     => b"\xE2\x85\x88",           // double-struck italic small i, U+2148
+    b"imagline"                       => b"\xE2\x84\x90",           // script capital i, U+2110
+    b"imagpart"                       => b"\xE2\x84\x91",           // black-letter capital i, U+2111
+    b"imath"                          => b"\xC4\xB1",               // latin small letter dotless i, U+0131
+    b"imof"                           => b"\xE2\x8A\xB7",           // image of, U+22B7
+    b"imped"                          => b"\xC6\xB5",               // latin capital letter z with stroke, U+01B5
+    b"Implies"                        => b"\xE2\x87\x92",           // rightwards double arrow, U+21D2
+    b"in"                             => b"\xE2\x88\x88",           // element of, U+2208
+    b"incare"                         => b"\xE2\x84\x85",           // care of, U+2105
+    b"infin"                          => b"\xE2\x88\x9E",           // infinity, U+221E
+    b"infintie"                       => b"\xE2\xA7\x9D",           // tie over infinity, U+29DD
+    b"inodot"                         => b"\xC4\xB1",               // latin small letter dotless i, U+0131
+    b"Int"                            => b"\xE2\x88\xAC",           // double integral, U+222C
+    b"int"                            => b"\xE2\x88\xAB",           // integral, U+222B
+    b"intcal"                         => b"\xE2\x8A\xBA",           // intercalate, U+22BA
+    b"integers"                       => b"\xE2\x84\xA4",           // double-struck capital z, U+2124
+    b"Integral"                       => b"\xE2\x88\xAB",           // integral, U+222B
+    b"intercal"                       => b"\xE2\x8A\xBA",           // intercalate, U+22BA
+    b"Intersection"                   => b"\xE2\x8B\x82",           // n-ary intersection, U+22C2
+    b"intlarhk"                       => b"\xE2\xA8\x97",           // integral with leftwards arrow with hook, U+2A17
+    b"intprod"                        => b"\xE2\xA8\xBC",           // interior product, U+2A3C
+    b"InvisibleComma"                 => b"\xE2\x81\xA3",           // invisible separator, U+2063
+    b"InvisibleTimes"                 => b"\xE2\x81\xA2",           // invisible times, U+2062
+    b"IOcy"                           => b"\xD0\x81",               // cyrillic capital letter io, U+0401
+    b"iocy"                           => b"\xD1\x91",               // cyrillic small letter io, U+0451
+    b"Iogon"                          => b"\xC4\xAE",               // latin capital letter i with ogonek, U+012E
+    b"iogon"                          => b"\xC4\xAF",               // latin small letter i with ogonek, U+012F
+    b"Iopf"                           => b"\xF0\x9D\x95\x80",       // mathematical double-struck capital i, U+1D540
+    b"iopf"                           => b"\xF0\x9D\x95\x9A",       // mathematical double-struck small i, U+1D55A
+    b"Iota"                           => b"\xCE\x99",               // greek capital letter iota, U+0399
+    b"iota"                           => b"\xCE\xB9",               // greek small letter iota, U+03B9
+    b"iprod"                          => b"\xE2\xA8\xBC",           // interior product, U+2A3C
+    b"Iscr"                           => b"\xE2\x84\x90",           // script capital i, U+2110
+    b"iscr"                           => b"\xF0\x9D\x92\xBE",       // mathematical script small i, U+1D4BE
+    b"isin"                           => b"\xE2\x88\x88",           // element of, U+2208
+    b"isindot"                        => b"\xE2\x8B\xB5",           // element of with dot above, U+22F5
+    b"isinE"                          => b"\xE2\x8B\xB9",           // element of with two horizontal strokes, U+22F9
+    b"isins"                          => b"\xE2\x8B\xB4",           // small element of with vertical bar at end of horizontal stroke, U+22F4
+    b"isinsv"                         => b"\xE2\x8B\xB3",           // element of with vertical bar at end of horizontal stroke, U+22F3
+    b"isinv"                          => b"\xE2\x88\x88",           // element of, U+2208
+    b"it"                             => b"\xE2\x81\xA2",           // invisible times, U+2062
+    b"Itilde"                         => b"\xC4\xA8",               // latin capital letter i with tilde, U+0128
+    b"itilde"                         => b"\xC4\xA9",               // latin small letter i with tilde, U+0129
+    b"Iukcy"                          => b"\xD0\x86",               // cyrillic capital letter byelorussian-ukrainian i, U+0406
+    b"iukcy"                          => b"\xD1\x96",               // cyrillic small letter byelorussian-ukrainian i, U+0456
+    b"Jcirc"                          => b"\xC4\xB4",               // latin capital letter j with circumflex, U+0134
+    b"jcirc"                          => b"\xC4\xB5",               // latin small letter j with circumflex, U+0135
+    b"Jcy"                            => b"\xD0\x99",               // cyrillic capital letter short i, U+0419
+    b"jcy"                            => b"\xD0\xB9",               // cyrillic small letter short i, U+0439
+    b"Jfr"                            => b"\xF0\x9D\x94\x8D",       // mathematical fraktur capital j, U+1D50D
+    b"jfr"                            => b"\xF0\x9D\x94\xA7",       // mathematical fraktur small j, U+1D527
+    b"jmath"                          => b"\xC8\xB7",               // latin small letter dotless j, U+0237
+    b"Jopf"                           => b"\xF0\x9D\x95\x81",       // mathematical double-struck capital j, U+1D541
+    b"jopf"                           => b"\xF0\x9D\x95\x9B",       // mathematical double-struck small j, U+1D55B
+    b"Jscr"                           => b"\xF0\x9D\x92\xA5",       // mathematical script capital j, U+1D4A5
+    b"jscr"                           => b"\xF0\x9D\x92\xBF",       // mathematical script small j, U+1D4BF
+    b"Jsercy"                         => b"\xD0\x88",               // cyrillic capital letter je, U+0408
+    b"jsercy"                         => b"\xD1\x98",               // cyrillic small letter je, U+0458
+    b"Jukcy"                          => b"\xD0\x84",               // cyrillic capital letter ukrainian ie, U+0404
+    b"jukcy"                          => b"\xD1\x94",               // cyrillic small letter ukrainian ie, U+0454
+    b"Kappa"                          => b"\xCE\x9A",               // greek capital letter kappa, U+039A
+    b"kappa"                          => b"\xCE\xBA",               // greek small letter kappa, U+03BA
+    b"kappav"                         => b"\xCF\xB0",               // greek kappa symbol, U+03F0
+    b"Kcedil"                         => b"\xC4\xB6",               // latin capital letter k with cedilla, U+0136
+    b"kcedil"                         => b"\xC4\xB7",               // latin small letter k with cedilla, U+0137
+    b"Kcy"                            => b"\xD0\x9A",               // cyrillic capital letter ka, U+041A
+    b"kcy"                            => b"\xD0\xBA",               // cyrillic small letter ka, U+043A
+    b"Kfr"                            => b"\xF0\x9D\x94\x8E",       // mathematical fraktur capital k, U+1D50E
+    b"kfr"                            => b"\xF0\x9D\x94\xA8",       // mathematical fraktur small k, U+1D528
+    b"kgreen"                         => b"\xC4\xB8",               // latin small letter kra, U+0138
+    b"KHcy"                           => b"\xD0\xA5",               // cyrillic capital letter ha, U+0425
+    b"khcy"                           => b"\xD1\x85",               // cyrillic small letter ha, U+0445
+    b"KJcy"                           => b"\xD0\x8C",               // cyrillic capital letter kje, U+040C
+    b"kjcy"                           => b"\xD1\x9C",               // cyrillic small letter kje, U+045C
+    b"Kopf"                           => b"\xF0\x9D\x95\x82",       // mathematical double-struck capital k, U+1D542
+    b"kopf"                           => b"\xF0\x9D\x95\x9C",       // mathematical double-struck small k, U+1D55C
+    b"Kscr"                           => b"\xF0\x9D\x92\xA6",       // mathematical script capital k, U+1D4A6
+    b"kscr"                           => b"\xF0\x9D\x93\x80",       // mathematical script small k, U+1D4C0
+    b"lAarr"                          => b"\xE2\x87\x9A",           // leftwards triple arrow, U+21DA
+    b"Lacute"                         => b"\xC4\xB9",               // latin capital letter l with acute, U+0139
+    b"lacute"                         => b"\xC4\xBA",               // latin small letter l with acute, U+013A
+    b"laemptyv"                       => b"\xE2\xA6\xB4",           // empty set with left arrow above, U+29B4
+    b"lagran"                         => b"\xE2\x84\x92",           // script capital l, U+2112
+    b"Lambda"                         => b"\xCE\x9B",               // greek capital letter lamda, U+039B
+    b"lambda"                         => b"\xCE\xBB",               // greek small letter lamda, U+03BB
+    b"Lang"                           => b"\xE2\x9F\xAA",           // mathematical left double angle bracket, U+27EA
+    b"lang"                           => b"\xE2\x9F\xA8",           // mathematical left angle bracket, U+27E8
+    b"langd"                          => b"\xE2\xA6\x91",           // left angle bracket with dot, U+2991
+    b"langle"                         => b"\xE2\x9F\xA8",           // mathematical left angle bracket, U+27E8
+    b"lap"                            => b"\xE2\xAA\x85",           // less-than or approximate, U+2A85
+    b"Laplacetrf"                     => b"\xE2\x84\x92",           // script capital l, U+2112
+    b"Larr"                           => b"\xE2\x86\x9E",           // leftwards two headed arrow, U+219E
+    b"lArr"                           => b"\xE2\x87\x90",           // leftwards double arrow, U+21D0
+    b"larr"                           => b"\xE2\x86\x90",           // leftwards arrow, U+2190
+    b"larrb"                          => b"\xE2\x87\xA4",           // leftwards arrow to bar, U+21E4
+    b"larrbfs"                        => b"\xE2\xA4\x9F",           // leftwards arrow from bar to black diamond, U+291F
+    b"larrfs"                         => b"\xE2\xA4\x9D",           // leftwards arrow to black diamond, U+291D
+    b"larrhk"                         => b"\xE2\x86\xA9",           // leftwards arrow with hook, U+21A9
+    b"larrlp"                         => b"\xE2\x86\xAB",           // leftwards arrow with loop, U+21AB
+    b"larrpl"                         => b"\xE2\xA4\xB9",           // left-side arc anticlockwise arrow, U+2939
+    b"larrsim"                        => b"\xE2\xA5\xB3",           // leftwards arrow above tilde operator, U+2973
+    b"larrtl"                         => b"\xE2\x86\xA2",           // leftwards arrow with tail, U+21A2
+    b"lat"                            => b"\xE2\xAA\xAB",           // larger than, U+2AAB
+    b"lAtail"                         => b"\xE2\xA4\x9B",           // leftwards double arrow-tail, U+291B
+    b"latail"                         => b"\xE2\xA4\x99",           // leftwards arrow-tail, U+2919
+    b"late"                           => b"\xE2\xAA\xAD",           // larger than or equal to, U+2AAD
+    b"lates"                          => b"\xE2\xAA\xAD\xEF\xB8\x80",// larger than or equal to, U+2AAD + variation selector-1, U+FE00
+    b"lBarr"                          => b"\xE2\xA4\x8E",           // leftwards triple dash arrow, U+290E
+    b"lbarr"                          => b"\xE2\xA4\x8C",           // leftwards double dash arrow, U+290C
+    b"lbbrk"                          => b"\xE2\x9D\xB2",           // light left tortoise shell bracket ornament, U+2772
+    b"lbrace"                         => b"\x7B",                   // left curly bracket, U+007B
+    b"lbrack"                         => b"\x5B",                   // left square bracket, U+005B
+    b"lbrke"                          => b"\xE2\xA6\x8B",           // left square bracket with underbar, U+298B
+    b"lbrksld"                        => b"\xE2\xA6\x8F",           // left square bracket with tick in bottom corner, U+298F
+    b"lbrkslu"                        => b"\xE2\xA6\x8D",           // left square bracket with tick in top corner, U+298D
+    b"Lcaron"                         => b"\xC4\xBD",               // latin capital letter l with caron, U+013D
+    b"lcaron"                         => b"\xC4\xBE",               // latin small letter l with caron, U+013E
+    b"Lcedil"                         => b"\xC4\xBB",               // latin capital letter l with cedilla, U+013B
+    b"lcedil"                         => b"\xC4\xBC",               // latin small letter l with cedilla, U+013C
+    b"lceil"                          => b"\xE2\x8C\x88",           // left ceiling, U+2308
+    b"lcub"                           => b"\x7B",                   // left curly bracket, U+007B
+    b"Lcy"                            => b"\xD0\x9B",               // cyrillic capital letter el, U+041B
+    b"lcy"                            => b"\xD0\xBB",               // cyrillic small letter el, U+043B
+    b"ldca"                           => b"\xE2\xA4\xB6",           // arrow pointing downwards then curving leftwards, U+2936
+    b"ldquor"                         => b"\xE2\x80\x9E",           // double low-9 quotation mark, U+201E
+    b"ldrdhar"                        => b"\xE2\xA5\xA7",           // leftwards harpoon with barb down above rightwards harpoon with barb down, U+2967
+    b"ldrushar"                       => b"\xE2\xA5\x8B",           // left barb down right barb up harpoon, U+294B
+    b"ldsh"                           => b"\xE2\x86\xB2",           // downwards arrow with tip leftwards, U+21B2
+    b"lE"                             => b"\xE2\x89\xA6",           // less-than over equal to, U+2266
+    b"le"                             => b"\xE2\x89\xA4",           // less-than or equal to, U+2264
+    b"LeftAngleBracket"               => b"\xE2\x9F\xA8",           // mathematical left angle bracket, U+27E8
+    b"LeftArrow"                      => b"\xE2\x86\x90",           // leftwards arrow, U+2190
+    b"Leftarrow"                      => b"\xE2\x87\x90",           // leftwards double arrow, U+21D0
+    b"leftarrow"                      => b"\xE2\x86\x90",           // leftwards arrow, U+2190
+    b"LeftArrowBar"                   => b"\xE2\x87\xA4",           // leftwards arrow to bar, U+21E4
+    b"LeftArrowRightArrow"            => b"\xE2\x87\x86",           // leftwards arrow over rightwards arrow, U+21C6
+    b"leftarrowtail"                  => b"\xE2\x86\xA2",           // leftwards arrow with tail, U+21A2
+    b"LeftCeiling"                    => b"\xE2\x8C\x88",           // left ceiling, U+2308
+    b"LeftDoubleBracket"              => b"\xE2\x9F\xA6",           // mathematical left white square bracket, U+27E6
+    b"LeftDownTeeVector"              => b"\xE2\xA5\xA1",           // downwards harpoon with barb left from bar, U+2961
+    b"LeftDownVector"                 => b"\xE2\x87\x83",           // downwards harpoon with barb leftwards, U+21C3
+    b"LeftDownVectorBar"              => b"\xE2\xA5\x99",           // downwards harpoon with barb left to bar, U+2959
+    b"LeftFloor"                      => b"\xE2\x8C\x8A",           // left floor, U+230A
+    b"leftharpoondown"                => b"\xE2\x86\xBD",           // leftwards harpoon with barb downwards, U+21BD
+    b"leftharpoonup"                  => b"\xE2\x86\xBC",           // leftwards harpoon with barb upwards, U+21BC
+    b"leftleftarrows"                 => b"\xE2\x87\x87",           // leftwards paired arrows, U+21C7
+    b"LeftRightArrow"                 => b"\xE2\x86\x94",           // left right arrow, U+2194
+    b"Leftrightarrow"                 => b"\xE2\x87\x94",           // left right double arrow, U+21D4
+    b"leftrightarrow"                 => b"\xE2\x86\x94",           // left right arrow, U+2194
+    b"leftrightarrows"                => b"\xE2\x87\x86",           // leftwards arrow over rightwards arrow, U+21C6
+    b"leftrightharpoons"              => b"\xE2\x87\x8B",           // leftwards harpoon over rightwards harpoon, U+21CB
+    b"leftrightsquigarrow"            => b"\xE2\x86\xAD",           // left right wave arrow, U+21AD
+    b"LeftRightVector"                => b"\xE2\xA5\x8E",           // left barb up right barb up harpoon, U+294E
+    b"LeftTee"                        => b"\xE2\x8A\xA3",           // left tack, U+22A3
+    b"LeftTeeArrow"                   => b"\xE2\x86\xA4",           // leftwards arrow from bar, U+21A4
+    b"LeftTeeVector"                  => b"\xE2\xA5\x9A",           // leftwards harpoon with barb up from bar, U+295A
+    b"leftthreetimes"                 => b"\xE2\x8B\x8B",           // left semidirect product, U+22CB
+    b"LeftTriangle"                   => b"\xE2\x8A\xB2",           // normal subgroup of, U+22B2
+    b"LeftTriangleBar"                => b"\xE2\xA7\x8F",           // left triangle beside vertical bar, U+29CF
+    b"LeftTriangleEqual"              => b"\xE2\x8A\xB4",           // normal subgroup of or equal to, U+22B4
+    b"LeftUpDownVector"               => b"\xE2\xA5\x91",           // up barb left down barb left harpoon, U+2951
+    b"LeftUpTeeVector"                => b"\xE2\xA5\xA0",           // upwards harpoon with barb left from bar, U+2960
+    b"LeftUpVector"                   => b"\xE2\x86\xBF",           // upwards harpoon with barb leftwards, U+21BF
+    b"LeftUpVectorBar"                => b"\xE2\xA5\x98",           // upwards harpoon with barb left to bar, U+2958
+    b"LeftVector"                     => b"\xE2\x86\xBC",           // leftwards harpoon with barb upwards, U+21BC
+    b"LeftVectorBar"                  => b"\xE2\xA5\x92",           // leftwards harpoon with barb up to bar, U+2952
+    b"lEg"                            => b"\xE2\xAA\x8B",           // less-than above double-line equal above greater-than, U+2A8B
+    b"leg"                            => b"\xE2\x8B\x9A",           // less-than equal to or greater-than, U+22DA
+    b"leq"                            => b"\xE2\x89\xA4",           // less-than or equal to, U+2264
+    b"leqq"                           => b"\xE2\x89\xA6",           // less-than over equal to, U+2266
+    b"leqslant"                       => b"\xE2\xA9\xBD",           // less-than or slanted equal to, U+2A7D
+    b"les"                            => b"\xE2\xA9\xBD",           // less-than or slanted equal to, U+2A7D
+    b"lescc"                          => b"\xE2\xAA\xA8",           // less-than closed by curve above slanted equal, U+2AA8
+    b"lesdot"                         => b"\xE2\xA9\xBF",           // less-than or slanted equal to with dot inside, U+2A7F
+    b"lesdoto"                        => b"\xE2\xAA\x81",           // less-than or slanted equal to with dot above, U+2A81
+    b"lesdotor"                       => b"\xE2\xAA\x83",           // less-than or slanted equal to with dot above right, U+2A83
+    b"lesg"                           => b"\xE2\x8B\x9A\xEF\xB8\x80",// less-than equal to or greater-than, U+22DA + variation selector-1, U+FE00
+    b"lesges"                         => b"\xE2\xAA\x93",           // less-than above slanted equal above greater-than above slanted equal, U+2A93
+    b"lessapprox"                     => b"\xE2\xAA\x85",           // less-than or approximate, U+2A85
+    b"lessdot"                        => b"\xE2\x8B\x96",           // less-than with dot, U+22D6
+    b"lesseqgtr"                      => b"\xE2\x8B\x9A",           // less-than equal to or greater-than, U+22DA
+    b"lesseqqgtr"                     => b"\xE2\xAA\x8B",           // less-than above double-line equal above greater-than, U+2A8B
+    b"LessEqualGreater"               => b"\xE2\x8B\x9A",           // less-than equal to or greater-than, U+22DA
+    b"LessFullEqual"                  => b"\xE2\x89\xA6",           // less-than over equal to, U+2266
+    b"LessGreater"                    => b"\xE2\x89\xB6",           // less-than or greater-than, U+2276
+    b"lessgtr"                        => b"\xE2\x89\xB6",           // less-than or greater-than, U+2276
+    b"LessLess"                       => b"\xE2\xAA\xA1",           // double nested less-than, U+2AA1
+    b"lesssim"                        => b"\xE2\x89\xB2",           // less-than or equivalent to, U+2272
+    b"LessSlantEqual"                 => b"\xE2\xA9\xBD",           // less-than or slanted equal to, U+2A7D
+    b"LessTilde"                      => b"\xE2\x89\xB2",           // less-than or equivalent to, U+2272
+    b"lfisht"                         => b"\xE2\xA5\xBC",           // left fish tail, U+297C
+    b"lfloor"                         => b"\xE2\x8C\x8A",           // left floor, U+230A
+    b"Lfr"                            => b"\xF0\x9D\x94\x8F",       // mathematical fraktur capital l, U+1D50F
+    b"lfr"                            => b"\xF0\x9D\x94\xA9",       // mathematical fraktur small l, U+1D529
+    b"lg"                             => b"\xE2\x89\xB6",           // less-than or greater-than, U+2276
+    b"lgE"                            => b"\xE2\xAA\x91",           // less-than above greater-than above double-line equal, U+2A91
+    b"lHar"                           => b"\xE2\xA5\xA2",           // leftwards harpoon with barb up above leftwards harpoon with barb down, U+2962
+    b"lhard"                          => b"\xE2\x86\xBD",           // leftwards harpoon with barb downwards, U+21BD
+    b"lharu"                          => b"\xE2\x86\xBC",           // leftwards harpoon with barb upwards, U+21BC
+    b"lharul"                         => b"\xE2\xA5\xAA",           // leftwards harpoon with barb up above long dash, U+296A
+    b"lhblk"                          => b"\xE2\x96\x84",           // lower half block, U+2584
+    b"LJcy"                           => b"\xD0\x89",               // cyrillic capital letter lje, U+0409
+    b"ljcy"                           => b"\xD1\x99",               // cyrillic small letter lje, U+0459
+    b"Ll"                             => b"\xE2\x8B\x98",           // very much less-than, U+22D8
+    b"ll"                             => b"\xE2\x89\xAA",           // much less-than, U+226A
+    b"llarr"                          => b"\xE2\x87\x87",           // leftwards paired arrows, U+21C7
+    b"llcorner"                       => b"\xE2\x8C\x9E",           // bottom left corner, U+231E
+    b"Lleftarrow"                     => b"\xE2\x87\x9A",           // leftwards triple arrow, U+21DA
+    b"llhard"                         => b"\xE2\xA5\xAB",           // leftwards harpoon with barb down below long dash, U+296B
+    b"lltri"                          => b"\xE2\x97\xBA",           // lower left triangle, U+25FA
+    b"Lmidot"                         => b"\xC4\xBF",               // latin capital letter l with middle dot, U+013F
+    b"lmidot"                         => b"\xC5\x80",               // latin small letter l with middle dot, U+0140
+    b"lmoust"                         => b"\xE2\x8E\xB0",           // upper left or lower right curly bracket section, U+23B0
+    b"lmoustache"                     => b"\xE2\x8E\xB0",           // upper left or lower right curly bracket section, U+23B0
+    b"lnap"                           => b"\xE2\xAA\x89",           // less-than and not approximate, U+2A89
+    b"lnapprox"                       => b"\xE2\xAA\x89",           // less-than and not approximate, U+2A89
+    b"lnE"                            => b"\xE2\x89\xA8",           // less-than but not equal to, U+2268
+    b"lne"                            => b"\xE2\xAA\x87",           // less-than and single-line not equal to, U+2A87
+    b"lneq"                           => b"\xE2\xAA\x87",           // less-than and single-line not equal to, U+2A87
+    b"lneqq"                          => b"\xE2\x89\xA8",           // less-than but not equal to, U+2268
+    b"lnsim"                          => b"\xE2\x8B\xA6",           // less-than but not equivalent to, U+22E6
+    b"loang"                          => b"\xE2\x9F\xAC",           // mathematical left white tortoise shell bracket, U+27EC
+    b"loarr"                          => b"\xE2\x87\xBD",           // leftwards open-headed arrow, U+21FD
+    b"lobrk"                          => b"\xE2\x9F\xA6",           // mathematical left white square bracket, U+27E6
+    b"LongLeftArrow"                  => b"\xE2\x9F\xB5",           // long leftwards arrow, U+27F5
+    b"Longleftarrow"                  => b"\xE2\x9F\xB8",           // long leftwards double arrow, U+27F8
+    b"longleftarrow"                  => b"\xE2\x9F\xB5",           // long leftwards arrow, U+27F5
+    b"LongLeftRightArrow"             => b"\xE2\x9F\xB7",           // long left right arrow, U+27F7
+    b"Longleftrightarrow"             => b"\xE2\x9F\xBA",           // long left right double arrow, U+27FA
+    b"longleftrightarrow"             => b"\xE2\x9F\xB7",           // long left right arrow, U+27F7
+    b"longmapsto"                     => b"\xE2\x9F\xBC",           // long rightwards arrow from bar, U+27FC
+    b"LongRightArrow"                 => b"\xE2\x9F\xB6",           // long rightwards arrow, U+27F6
+    b"Longrightarrow"                 => b"\xE2\x9F\xB9",           // long rightwards double arrow, U+27F9
+    b"longrightarrow"                 => b"\xE2\x9F\xB6",           // long rightwards arrow, U+27F6
+    b"looparrowleft"                  => b"\xE2\x86\xAB",           // leftwards arrow with loop, U+21AB
+    b"looparrowright"                 => b"\xE2\x86\xAC",           // rightwards arrow with loop, U+21AC
+    b"lopar"                          => b"\xE2\xA6\x85",           // left white parenthesis, U+2985
+    b"Lopf"                           => b"\xF0\x9D\x95\x83",       // mathematical double-struck capital l, U+1D543
+    b"lopf"                           => b"\xF0\x9D\x95\x9D",       // mathematical double-struck small l, U+1D55D
+    b"loplus"                         => b"\xE2\xA8\xAD",           // plus sign in left half circle, U+2A2D
+    b"lotimes"                        => b"\xE2\xA8\xB4",           // multiplication sign in left half circle, U+2A34
+    b"lowast"                         => b"\xE2\x88\x97",           // asterisk operator, U+2217
+    b"lowbar"                         => b"\x5F",                   // low line, U+005F
+    b"LowerLeftArrow"                 => b"\xE2\x86\x99",           // south west arrow, U+2199
+    b"LowerRightArrow"                => b"\xE2\x86\x98",           // south east arrow, U+2198
+    b"loz"                            => b"\xE2\x97\x8A",           // lozenge, U+25CA
+    b"lozenge"                        => b"\xE2\x97\x8A",           // lozenge, U+25CA
+    b"lozf"                           => b"\xE2\xA7\xAB",           // black lozenge, U+29EB
+    b"lpar"                           => b"\x28",                   // left parenthesis, U+0028
+    b"lparlt"                         => b"\xE2\xA6\x93",           // left arc less-than bracket, U+2993
+    b"lrarr"                          => b"\xE2\x87\x86",           // leftwards arrow over rightwards arrow, U+21C6
+    b"lrcorner"                       => b"\xE2\x8C\x9F",           // bottom right corner, U+231F
+    b"lrhar"                          => b"\xE2\x87\x8B",           // leftwards harpoon over rightwards harpoon, U+21CB
+    b"lrhard"                         => b"\xE2\xA5\xAD",           // rightwards harpoon with barb down below long dash, U+296D
+    b"lrtri"                          => b"\xE2\x8A\xBF",           // right triangle, U+22BF
+    b"Lscr"                           => b"\xE2\x84\x92",           // script capital l, U+2112
+    b"lscr"                           => b"\xF0\x9D\x93\x81",       // mathematical script small l, U+1D4C1
+    b"Lsh"                            => b"\xE2\x86\xB0",           // upwards arrow with tip leftwards, U+21B0
+    b"lsh"                            => b"\xE2\x86\xB0",           // upwards arrow with tip leftwards, U+21B0
+    b"lsim"                           => b"\xE2\x89\xB2",           // less-than or equivalent to, U+2272
+    b"lsime"                          => b"\xE2\xAA\x8D",           // less-than above similar or equal, U+2A8D
+    b"lsimg"                          => b"\xE2\xAA\x8F",           // less-than above similar above greater-than, U+2A8F
+    b"lsqb"                           => b"\x5B",                   // left square bracket, U+005B
+    b"lsquor"                         => b"\xE2\x80\x9A",           // single low-9 quotation mark, U+201A
+    b"Lstrok"                         => b"\xC5\x81",               // latin capital letter l with stroke, U+0141
+    b"lstrok"                         => b"\xC5\x82",               // latin small letter l with stroke, U+0142
+    b"LT"                             => b"\x3C",                   // less-than sign, U+003C
+    b"Lt"                             => b"\xE2\x89\xAA",           // much less-than, U+226A
+    b"ltcc"                           => b"\xE2\xAA\xA6",           // less-than closed by curve, U+2AA6
+    b"ltcir"                          => b"\xE2\xA9\xB9",           // less-than with circle inside, U+2A79
+    b"ltdot"                          => b"\xE2\x8B\x96",           // less-than with dot, U+22D6
+    b"lthree"                         => b"\xE2\x8B\x8B",           // left semidirect product, U+22CB
+    b"ltimes"                         => b"\xE2\x8B\x89",           // left normal factor semidirect product, U+22C9
+    b"ltlarr"                         => b"\xE2\xA5\xB6",           // less-than above leftwards arrow, U+2976
+    b"ltquest"                        => b"\xE2\xA9\xBB",           // less-than with question mark above, U+2A7B
+    b"ltri"                           => b"\xE2\x97\x83",           // white left-pointing small triangle, U+25C3
+    b"ltrie"                          => b"\xE2\x8A\xB4",           // normal subgroup of or equal to, U+22B4
+    b"ltrif"                          => b"\xE2\x97\x82",           // black left-pointing small triangle, U+25C2
+    b"ltrPar"                         => b"\xE2\xA6\x96",           // double right arc less-than bracket, U+2996
+    b"lurdshar"                       => b"\xE2\xA5\x8A",           // left barb up right barb down harpoon, U+294A
+    b"luruhar"                        => b"\xE2\xA5\xA6",           // leftwards harpoon with barb up above rightwards harpoon with barb up, U+2966
+    b"lvertneqq"                      => b"\xE2\x89\xA8\xEF\xB8\x80",// less-than but not equal to, U+2268 + variation selector-1, U+FE00
+    b"lvnE"                           => b"\xE2\x89\xA8\xEF\xB8\x80",// less-than but not equal to, U+2268 + variation selector-1, U+FE00
+    b"male"                           => b"\xE2\x99\x82",           // male sign, U+2642
+    b"malt"                           => b"\xE2\x9C\xA0",           // maltese cross, U+2720
+    b"maltese"                        => b"\xE2\x9C\xA0",           // maltese cross, U+2720
+    b"Map"                            => b"\xE2\xA4\x85",           // rightwards two-headed arrow from bar, U+2905
+    b"map"                            => b"\xE2\x86\xA6",           // rightwards arrow from bar, U+21A6
+    b"mapsto"                         => b"\xE2\x86\xA6",           // rightwards arrow from bar, U+21A6
+    b"mapstodown"                     => b"\xE2\x86\xA7",           // downwards arrow from bar, U+21A7
+    b"mapstoleft"                     => b"\xE2\x86\xA4",           // leftwards arrow from bar, U+21A4
+    b"mapstoup"                       => b"\xE2\x86\xA5",           // upwards arrow from bar, U+21A5
+    b"marker"                         => b"\xE2\x96\xAE",           // black vertical rectangle, U+25AE
+    b"mcomma"                         => b"\xE2\xA8\xA9",           // minus sign with comma above, U+2A29
+    b"Mcy"                            => b"\xD0\x9C",               // cyrillic capital letter em, U+041C
+    b"mcy"                            => b"\xD0\xBC",               // cyrillic small letter em, U+043C
+    b"mDDot"                          => b"\xE2\x88\xBA",           // geometric proportion, U+223A
+    b"measuredangle"                  => b"\xE2\x88\xA1",           // measured angle, U+2221
+    b"MediumSpace"                    => b"\xE2\x81\x9F",           // medium mathematical space, U+205F
+    b"Mellintrf"                      => b"\xE2\x84\xB3",           // script capital m, U+2133
+    b"Mfr"                            => b"\xF0\x9D\x94\x90",       // mathematical fraktur capital m, U+1D510
+    b"mfr"                            => b"\xF0\x9D\x94\xAA",       // mathematical fraktur small m, U+1D52A
+    b"mho"                            => b"\xE2\x84\xA7",           // inverted ohm sign, U+2127
+    b"mid"                            => b"\xE2\x88\xA3",           // divides, U+2223
+    b"midast"                         => b"\x2A",                   // asterisk, U+002A
+    b"midcir"                         => b"\xE2\xAB\xB0",           // vertical line with circle below, U+2AF0
+    b"minus"                          => b"\xE2\x88\x92",           // minus sign, U+2212
+    b"minusb"                         => b"\xE2\x8A\x9F",           // squared minus, U+229F
+    b"minusd"                         => b"\xE2\x88\xB8",           // dot minus, U+2238
+    b"minusdu"                        => b"\xE2\xA8\xAA",           // minus sign with dot below, U+2A2A
+    b"MinusPlus"                      => b"\xE2\x88\x93",           // minus-or-plus sign, U+2213
+    b"mlcp"                           => b"\xE2\xAB\x9B",           // transversal intersection, U+2ADB
+    b"mldr"                           => b"\xE2\x80\xA6",           // horizontal ellipsis, U+2026
+    b"mnplus"                         => b"\xE2\x88\x93",           // minus-or-plus sign, U+2213
+    b"models"                         => b"\xE2\x8A\xA7",           // models, U+22A7
+    b"Mopf"                           => b"\xF0\x9D\x95\x84",       // mathematical double-struck capital m, U+1D544
+    b"mopf"                           => b"\xF0\x9D\x95\x9E",       // mathematical double-struck small m, U+1D55E
+    b"mp"                             => b"\xE2\x88\x93",           // minus-or-plus sign, U+2213
+    b"Mscr"                           => b"\xE2\x84\xB3",           // script capital m, U+2133
+    b"mscr"                           => b"\xF0\x9D\x93\x82",       // mathematical script small m, U+1D4C2
+    b"mstpos"                         => b"\xE2\x88\xBE",           // inverted lazy s, U+223E
+    b"Mu"                             => b"\xCE\x9C",               // greek capital letter mu, U+039C
+    b"mu"                             => b"\xCE\xBC",               // greek small letter mu, U+03BC
+    b"multimap"                       => b"\xE2\x8A\xB8",           // multimap, U+22B8
+    b"mumap"                          => b"\xE2\x8A\xB8",           // multimap, U+22B8
+    b"nabla"                          => b"\xE2\x88\x87",           // nabla, U+2207
+    b"Nacute"                         => b"\xC5\x83",               // latin capital letter n with acute, U+0143
+    b"nacute"                         => b"\xC5\x84",               // latin small letter n with acute, U+0144
+    b"nang"                           => b"\xE2\x88\xA0\xE2\x83\x92",// angle, U+2220 + combining long vertical line overlay, U+20D2
+    b"nap"                            => b"\xE2\x89\x89",           // not almost equal to, U+2249
+    b"napE"                           => b"\xE2\xA9\xB0\xCC\xB8",   // approximately equal or equal to, U+2A70 + combining long solidus overlay, U+0338
+    b"napid"                          => b"\xE2\x89\x8B\xCC\xB8",   // triple tilde, U+224B + combining long solidus overlay, U+0338
+    b"napos"                          => b"\xC5\x89",               // latin small letter n preceded by apostrophe, U+0149
+    b"napprox"                        => b"\xE2\x89\x89",           // not almost equal to, U+2249
+    b"natur"                          => b"\xE2\x99\xAE",           // music natural sign, U+266E
+    b"natural"                        => b"\xE2\x99\xAE",           // music natural sign, U+266E
+    b"naturals"                       => b"\xE2\x84\x95",           // double-struck capital n, U+2115
+    b"nbump"                          => b"\xE2\x89\x8E\xCC\xB8",   // geometrically equivalent to, U+224E + combining long solidus overlay, U+0338
+    b"nbumpe"                         => b"\xE2\x89\x8F\xCC\xB8",   // difference between, U+224F + combining long solidus overlay, U+0338
+    b"ncap"                           => b"\xE2\xA9\x83",           // intersection with overbar, U+2A43
+    b"Ncaron"                         => b"\xC5\x87",               // latin capital letter n with caron, U+0147
+    b"ncaron"                         => b"\xC5\x88",               // latin small letter n with caron, U+0148
+    b"Ncedil"                         => b"\xC5\x85",               // latin capital letter n with cedilla, U+0145
+    b"ncedil"                         => b"\xC5\x86",               // latin small letter n with cedilla, U+0146
+    b"ncong"                          => b"\xE2\x89\x87",           // neither approximately nor actually equal to, U+2247
+    b"ncongdot"                       => b"\xE2\xA9\xAD\xCC\xB8",   // congruent with dot above, U+2A6D + combining long solidus overlay, U+0338
+    b"ncup"                           => b"\xE2\xA9\x82",           // union with overbar, U+2A42
+    b"Ncy"                            => b"\xD0\x9D",               // cyrillic capital letter en, U+041D
+    b"ncy"                            => b"\xD0\xBD",               // cyrillic small letter en, U+043D
+    b"ne"                             => b"\xE2\x89\xA0",           // not equal to, U+2260
+    b"nearhk"                         => b"\xE2\xA4\xA4",           // north east arrow with hook, U+2924
+    b"neArr"                          => b"\xE2\x87\x97",           // north east double arrow, U+21D7
+    b"nearr"                          => b"\xE2\x86\x97",           // north east arrow, U+2197
+    b"nearrow"                        => b"\xE2\x86\x97",           // north east arrow, U+2197
+    b"nedot"                          => b"\xE2\x89\x90\xCC\xB8",   // approaches the limit, U+2250 + combining long solidus overlay, U+0338
+    b"NegativeMediumSpace"            => b"\xE2\x80\x8B",           // zero width space, U+200B
+    b"NegativeThickSpace"             => b"\xE2\x80\x8B",           // zero width space, U+200B
+    b"NegativeThinSpace"              => b"\xE2\x80\x8B",           // zero width space, U+200B
+    b"NegativeVeryThinSpace"          => b"\xE2\x80\x8B",           // zero width space, U+200B
+    b"nequiv"                         => b"\xE2\x89\xA2",           // not identical to, U+2262
+    b"nesear"                         => b"\xE2\xA4\xA8",           // north east arrow and south east arrow, U+2928
+    b"nesim"                          => b"\xE2\x89\x82\xCC\xB8",   // minus tilde, U+2242 + combining long solidus overlay, U+0338
+    b"NestedGreaterGreater"           => b"\xE2\x89\xAB",           // much greater-than, U+226B
+    b"NestedLessLess"                 => b"\xE2\x89\xAA",           // much less-than, U+226A
+    b"NewLine"                        => b"\x0A",                   // <control>, U+000A
+    b"nexist"                         => b"\xE2\x88\x84",           // there does not exist, U+2204
+    b"nexists"                        => b"\xE2\x88\x84",           // there does not exist, U+2204
+    b"Nfr"                            => b"\xF0\x9D\x94\x91",       // mathematical fraktur capital n, U+1D511
+    b"nfr"                            => b"\xF0\x9D\x94\xAB",       // mathematical fraktur small n, U+1D52B
+    b"ngE"                            => b"\xE2\x89\xA7\xCC\xB8",   // greater-than over equal to, U+2267 + combining long solidus overlay, U+0338
+    b"nge"                            => b"\xE2\x89\xB1",           // neither greater-than nor equal to, U+2271
+    b"ngeq"                           => b"\xE2\x89\xB1",           // neither greater-than nor equal to, U+2271
+    b"ngeqq"                          => b"\xE2\x89\xA7\xCC\xB8",   // greater-than over equal to, U+2267 + combining long solidus overlay, U+0338
+    b"ngeqslant"                      => b"\xE2\xA9\xBE\xCC\xB8",   // greater-than or slanted equal to, U+2A7E + combining long solidus overlay, U+0338
+    b"nges"                           => b"\xE2\xA9\xBE\xCC\xB8",   // greater-than or slanted equal to, U+2A7E + combining long solidus overlay, U+0338
+    b"nGg"                            => b"\xE2\x8B\x99\xCC\xB8",   // very much greater-than, U+22D9 + combining long solidus overlay, U+0338
+    b"ngsim"                          => b"\xE2\x89\xB5",           // neither greater-than nor equivalent to, U+2275
+    b"ngt"                            => b"\xE2\x89\xAF",           // not greater-than, U+226F
+    b"ngtr"                           => b"\xE2\x89\xAF",           // not greater-than, U+226F
+    b"nGtv"                           => b"\xE2\x89\xAB\xCC\xB8",   // much greater-than, U+226B + combining long solidus overlay, U+0338
+    b"nhArr"                          => b"\xE2\x87\x8E",           // left right double arrow with stroke, U+21CE
+    b"nharr"                          => b"\xE2\x86\xAE",           // left right arrow with stroke, U+21AE
+    b"nhpar"                          => b"\xE2\xAB\xB2",           // parallel with horizontal stroke, U+2AF2
+    b"ni"                             => b"\xE2\x88\x8B",           // contains as member, U+220B
+    b"nis"                            => b"\xE2\x8B\xBC",           // small contains with vertical bar at end of horizontal stroke, U+22FC
+    b"nisd"                           => b"\xE2\x8B\xBA",           // contains with long horizontal stroke, U+22FA
+    b"niv"                            => b"\xE2\x88\x8B",           // contains as member, U+220B
+    b"NJcy"                           => b"\xD0\x8A",               // cyrillic capital letter nje, U+040A
+    b"njcy"                           => b"\xD1\x9A",               // cyrillic small letter nje, U+045A
+    b"nlArr"                          => b"\xE2\x87\x8D",           // leftwards double arrow with stroke, U+21CD
+    b"nlarr"                          => b"\xE2\x86\x9A",           // leftwards arrow with stroke, U+219A
+    b"nldr"                           => b"\xE2\x80\xA5",           // two dot leader, U+2025
+    b"nlE"                            => b"\xE2\x89\xA6\xCC\xB8",   // less-than over equal to, U+2266 + combining long solidus overlay, U+0338
+    b"nle"                            => b"\xE2\x89\xB0",           // neither less-than nor equal to, U+2270
+    b"nLeftarrow"                     => b"\xE2\x87\x8D",           // leftwards double arrow with stroke, U+21CD
+    b"nleftarrow"                     => b"\xE2\x86\x9A",           // leftwards arrow with stroke, U+219A
+    b"nLeftrightarrow"                => b"\xE2\x87\x8E",           // left right double arrow with stroke, U+21CE
+    b"nleftrightarrow"                => b"\xE2\x86\xAE",           // left right arrow with stroke, U+21AE
+    b"nleq"                           => b"\xE2\x89\xB0",           // neither less-than nor equal to, U+2270
+    b"nleqq"                          => b"\xE2\x89\xA6\xCC\xB8",   // less-than over equal to, U+2266 + combining long solidus overlay, U+0338
+    b"nleqslant"                      => b"\xE2\xA9\xBD\xCC\xB8",   // less-than or slanted equal to, U+2A7D + combining long solidus overlay, U+0338
+    b"nles"                           => b"\xE2\xA9\xBD\xCC\xB8",   // less-than or slanted equal to, U+2A7D + combining long solidus overlay, U+0338
+    b"nless"                          => b"\xE2\x89\xAE",           // not less-than, U+226E
+    b"nLl"                            => b"\xE2\x8B\x98\xCC\xB8",   // very much less-than, U+22D8 + combining long solidus overlay, U+0338
+    b"nlsim"                          => b"\xE2\x89\xB4",           // neither less-than nor equivalent to, U+2274
+    b"nlt"                            => b"\xE2\x89\xAE",           // not less-than, U+226E
+    b"nltri"                          => b"\xE2\x8B\xAA",           // not normal subgroup of, U+22EA
+    b"nltrie"                         => b"\xE2\x8B\xAC",           // not normal subgroup of or equal to, U+22EC
+    b"nLtv"                           => b"\xE2\x89\xAA\xCC\xB8",   // much less-than, U+226A + combining long solidus overlay, U+0338
+    b"nmid"                           => b"\xE2\x88\xA4",           // does not divide, U+2224
+    b"NoBreak"                        => b"\xE2\x81\xA0",           // word joiner, U+2060
+    b"NonBreakingSpace"               => b"\xC2\xA0",               // no-break space, U+00A0
+    b"Nopf"                           => b"\xE2\x84\x95",           // double-struck capital n, U+2115
+    b"nopf"                           => b"\xF0\x9D\x95\x9F",       // mathematical double-struck small n, U+1D55F
+    b"Not"                            => b"\xE2\xAB\xAC",           // double stroke not sign, U+2AEC
+    b"NotCongruent"                   => b"\xE2\x89\xA2",           // not identical to, U+2262
+    b"NotCupCap"                      => b"\xE2\x89\xAD",           // not equivalent to, U+226D
+    b"NotDoubleVerticalBar"           => b"\xE2\x88\xA6",           // not parallel to, U+2226
+    b"NotElement"                     => b"\xE2\x88\x89",           // not an element of, U+2209
+    b"NotEqual"                       => b"\xE2\x89\xA0",           // not equal to, U+2260
+    b"NotEqualTilde"                  => b"\xE2\x89\x82\xCC\xB8",   // minus tilde, U+2242 + combining long solidus overlay, U+0338
+    b"NotExists"                      => b"\xE2\x88\x84",           // there does not exist, U+2204
+    b"NotGreater"                     => b"\xE2\x89\xAF",           // not greater-than, U+226F
+    b"NotGreaterEqual"                => b"\xE2\x89\xB1",           // neither greater-than nor equal to, U+2271
+    b"NotGreaterFullEqual"            => b"\xE2\x89\xA7\xCC\xB8",   // greater-than over equal to, U+2267 + combining long solidus overlay, U+0338
+    b"NotGreaterGreater"              => b"\xE2\x89\xAB\xCC\xB8",   // much greater-than, U+226B + combining long solidus overlay, U+0338
+    b"NotGreaterLess"                 => b"\xE2\x89\xB9",           // neither greater-than nor less-than, U+2279
+    b"NotGreaterSlantEqual"           => b"\xE2\xA9\xBE\xCC\xB8",   // greater-than or slanted equal to, U+2A7E + combining long solidus overlay, U+0338
+    b"NotGreaterTilde"                => b"\xE2\x89\xB5",           // neither greater-than nor equivalent to, U+2275
+    b"NotHumpDownHump"                => b"\xE2\x89\x8E\xCC\xB8",   // geometrically equivalent to, U+224E + combining long solidus overlay, U+0338
+    b"NotHumpEqual"                   => b"\xE2\x89\x8F\xCC\xB8",   // difference between, U+224F + combining long solidus overlay, U+0338
+    b"notin"                          => b"\xE2\x88\x89",           // not an element of, U+2209
+    b"notindot"                       => b"\xE2\x8B\xB5\xCC\xB8",   // element of with dot above, U+22F5 + combining long solidus overlay, U+0338
+    b"notinE"                         => b"\xE2\x8B\xB9\xCC\xB8",   // element of with two horizontal strokes, U+22F9 + combining long solidus overlay, U+0338
+    b"notinva"                        => b"\xE2\x88\x89",           // not an element of, U+2209
+    b"notinvb"                        => b"\xE2\x8B\xB7",           // small element of with overbar, U+22F7
+    b"notinvc"                        => b"\xE2\x8B\xB6",           // element of with overbar, U+22F6
+    b"NotLeftTriangle"                => b"\xE2\x8B\xAA",           // not normal subgroup of, U+22EA
+    b"NotLeftTriangleBar"             => b"\xE2\xA7\x8F\xCC\xB8",   // left triangle beside vertical bar, U+29CF + combining long solidus overlay, U+0338
+    b"NotLeftTriangleEqual"           => b"\xE2\x8B\xAC",           // not normal subgroup of or equal to, U+22EC
+    b"NotLess"                        => b"\xE2\x89\xAE",           // not less-than, U+226E
+    b"NotLessEqual"                   => b"\xE2\x89\xB0",           // neither less-than nor equal to, U+2270
+    b"NotLessGreater"                 => b"\xE2\x89\xB8",           // neither less-than nor greater-than, U+2278
+    b"NotLessLess"                    => b"\xE2\x89\xAA\xCC\xB8",   // much less-than, U+226A + combining long solidus overlay, U+0338
+    b"NotLessSlantEqual"              => b"\xE2\xA9\xBD\xCC\xB8",   // less-than or slanted equal to, U+2A7D + combining long solidus overlay, U+0338
+    b"NotLessTilde"                   => b"\xE2\x89\xB4",           // neither less-than nor equivalent to, U+2274
+    b"NotNestedGreaterGreater"        => b"\xE2\xAA\xA2\xCC\xB8",   // double nested greater-than, U+2AA2 + combining long solidus overlay, U+0338
+    b"NotNestedLessLess"              => b"\xE2\xAA\xA1\xCC\xB8",   // double nested less-than, U+2AA1 + combining long solidus overlay, U+0338
+    b"notni"                          => b"\xE2\x88\x8C",           // does not contain as member, U+220C
+    b"notniva"                        => b"\xE2\x88\x8C",           // does not contain as member, U+220C
+    b"notnivb"                        => b"\xE2\x8B\xBE",           // small contains with overbar, U+22FE
+    b"notnivc"                        => b"\xE2\x8B\xBD",           // contains with overbar, U+22FD
+    b"NotPrecedes"                    => b"\xE2\x8A\x80",           // does not precede, U+2280
+    b"NotPrecedesEqual"               => b"\xE2\xAA\xAF\xCC\xB8",   // precedes above single-line equals sign, U+2AAF + combining long solidus overlay, U+0338
+    b"NotPrecedesSlantEqual"          => b"\xE2\x8B\xA0",           // does not precede or equal, U+22E0
+    b"NotReverseElement"              => b"\xE2\x88\x8C",           // does not contain as member, U+220C
+    b"NotRightTriangle"               => b"\xE2\x8B\xAB",           // does not contain as normal subgroup, U+22EB
+    b"NotRightTriangleBar"            => b"\xE2\xA7\x90\xCC\xB8",   // vertical bar beside right triangle, U+29D0 + combining long solidus overlay, U+0338
+    b"NotRightTriangleEqual"          => b"\xE2\x8B\xAD",           // does not contain as normal subgroup or equal, U+22ED
+    b"NotSquareSubset"                => b"\xE2\x8A\x8F\xCC\xB8",   // square image of, U+228F + combining long solidus overlay, U+0338
+    b"NotSquareSubsetEqual"           => b"\xE2\x8B\xA2",           // not square image of or equal to, U+22E2
+    b"NotSquareSuperset"              => b"\xE2\x8A\x90\xCC\xB8",   // square original of, U+2290 + combining long solidus overlay, U+0338
+    b"NotSquareSupersetEqual"         => b"\xE2\x8B\xA3",           // not square original of or equal to, U+22E3
+    b"NotSubset"                      => b"\xE2\x8A\x82\xE2\x83\x92",// subset of, U+2282 + combining long vertical line overlay, U+20D2
+    b"NotSubsetEqual"                 => b"\xE2\x8A\x88",           // neither a subset of nor equal to, U+2288
+    b"NotSucceeds"                    => b"\xE2\x8A\x81",           // does not succeed, U+2281
+    b"NotSucceedsEqual"               => b"\xE2\xAA\xB0\xCC\xB8",   // succeeds above single-line equals sign, U+2AB0 + combining long solidus overlay, U+0338
+    b"NotSucceedsSlantEqual"          => b"\xE2\x8B\xA1",           // does not succeed or equal, U+22E1
+    b"NotSucceedsTilde"               => b"\xE2\x89\xBF\xCC\xB8",   // succeeds or equivalent to, U+227F + combining long solidus overlay, U+0338
+    b"NotSuperset"                    => b"\xE2\x8A\x83\xE2\x83\x92",// superset of, U+2283 + combining long vertical line overlay, U+20D2
+    b"NotSupersetEqual"               => b"\xE2\x8A\x89",           // neither a superset of nor equal to, U+2289
+    b"NotTilde"                       => b"\xE2\x89\x81",           // not tilde, U+2241
+    b"NotTildeEqual"                  => b"\xE2\x89\x84",           // not asymptotically equal to, U+2244
+    b"NotTildeFullEqual"              => b"\xE2\x89\x87",           // neither approximately nor actually equal to, U+2247
+    b"NotTildeTilde"                  => b"\xE2\x89\x89",           // not almost equal to, U+2249
+    b"NotVerticalBar"                 => b"\xE2\x88\xA4",           // does not divide, U+2224
+    b"npar"                           => b"\xE2\x88\xA6",           // not parallel to, U+2226
+    b"nparallel"                      => b"\xE2\x88\xA6",           // not parallel to, U+2226
+    b"nparsl"                         => b"\xE2\xAB\xBD\xE2\x83\xA5",// double solidus operator, U+2AFD + combining reverse solidus overlay, U+20E5
+    b"npart"                          => b"\xE2\x88\x82\xCC\xB8",   // partial differential, U+2202 + combining long solidus overlay, U+0338
+    b"npolint"                        => b"\xE2\xA8\x94",           // line integration not including the pole, U+2A14
+    b"npr"                            => b"\xE2\x8A\x80",           // does not precede, U+2280
+    b"nprcue"                         => b"\xE2\x8B\xA0",           // does not precede or equal, U+22E0
+    b"npre"                           => b"\xE2\xAA\xAF\xCC\xB8",   // precedes above single-line equals sign, U+2AAF + combining long solidus overlay, U+0338
+    b"nprec"                          => b"\xE2\x8A\x80",           // does not precede, U+2280
+    b"npreceq"                        => b"\xE2\xAA\xAF\xCC\xB8",   // precedes above single-line equals sign, U+2AAF + combining long solidus overlay, U+0338
+    b"nrArr"                          => b"\xE2\x87\x8F",           // rightwards double arrow with stroke, U+21CF
+    b"nrarr"                          => b"\xE2\x86\x9B",           // rightwards arrow with stroke, U+219B
+    b"nrarrc"                         => b"\xE2\xA4\xB3\xCC\xB8",   // wave arrow pointing directly right, U+2933 + combining long solidus overlay, U+0338
+    b"nrarrw"                         => b"\xE2\x86\x9D\xCC\xB8",   // rightwards wave arrow, U+219D + combining long solidus overlay, U+0338
+    b"nRightarrow"                    => b"\xE2\x87\x8F",           // rightwards double arrow with stroke, U+21CF
+    b"nrightarrow"                    => b"\xE2\x86\x9B",           // rightwards arrow with stroke, U+219B
+    b"nrtri"                          => b"\xE2\x8B\xAB",           // does not contain as normal subgroup, U+22EB
+    b"nrtrie"                         => b"\xE2\x8B\xAD",           // does not contain as normal subgroup or equal, U+22ED
+    b"nsc"                            => b"\xE2\x8A\x81",           // does not succeed, U+2281
+    b"nsccue"                         => b"\xE2\x8B\xA1",           // does not succeed or equal, U+22E1
+    b"nsce"                           => b"\xE2\xAA\xB0\xCC\xB8",   // succeeds above single-line equals sign, U+2AB0 + combining long solidus overlay, U+0338
+    b"Nscr"                           => b"\xF0\x9D\x92\xA9",       // mathematical script capital n, U+1D4A9
+    b"nscr"                           => b"\xF0\x9D\x93\x83",       // mathematical script small n, U+1D4C3
+    b"nshortmid"                      => b"\xE2\x88\xA4",           // does not divide, U+2224
+    b"nshortparallel"                 => b"\xE2\x88\xA6",           // not parallel to, U+2226
+    b"nsim"                           => b"\xE2\x89\x81",           // not tilde, U+2241
+    b"nsime"                          => b"\xE2\x89\x84",           // not asymptotically equal to, U+2244
+    b"nsimeq"                         => b"\xE2\x89\x84",           // not asymptotically equal to, U+2244
+    b"nsmid"                          => b"\xE2\x88\xA4",           // does not divide, U+2224
+    b"nspar"                          => b"\xE2\x88\xA6",           // not parallel to, U+2226
+    b"nsqsube"                        => b"\xE2\x8B\xA2",           // not square image of or equal to, U+22E2
+    b"nsqsupe"                        => b"\xE2\x8B\xA3",           // not square original of or equal to, U+22E3
+    b"nsub"                           => b"\xE2\x8A\x84",           // not a subset of, U+2284
+    b"nsubE"                          => b"\xE2\xAB\x85\xCC\xB8",   // subset of above equals sign, U+2AC5 + combining long solidus overlay, U+0338
+    b"nsube"                          => b"\xE2\x8A\x88",           // neither a subset of nor equal to, U+2288
+    b"nsubset"                        => b"\xE2\x8A\x82\xE2\x83\x92",// subset of, U+2282 + combining long vertical line overlay, U+20D2
+    b"nsubseteq"                      => b"\xE2\x8A\x88",           // neither a subset of nor equal to, U+2288
+    b"nsubseteqq"                     => b"\xE2\xAB\x85\xCC\xB8",   // subset of above equals sign, U+2AC5 + combining long solidus overlay, U+0338
+    b"nsucc"                          => b"\xE2\x8A\x81",           // does not succeed, U+2281
+    b"nsucceq"                        => b"\xE2\xAA\xB0\xCC\xB8",   // succeeds above single-line equals sign, U+2AB0 + combining long solidus overlay, U+0338
+    b"nsup"                           => b"\xE2\x8A\x85",           // not a superset of, U+2285
+    b"nsupE"                          => b"\xE2\xAB\x86\xCC\xB8",   // superset of above equals sign, U+2AC6 + combining long solidus overlay, U+0338
+    b"nsupe"                          => b"\xE2\x8A\x89",           // neither a superset of nor equal to, U+2289
+    b"nsupset"                        => b"\xE2\x8A\x83\xE2\x83\x92",// superset of, U+2283 + combining long vertical line overlay, U+20D2
+    b"nsupseteq"                      => b"\xE2\x8A\x89",           // neither a superset of nor equal to, U+2289
+    b"nsupseteqq"                     => b"\xE2\xAB\x86\xCC\xB8",   // superset of above equals sign, U+2AC6 + combining long solidus overlay, U+0338
+    b"ntgl"                           => b"\xE2\x89\xB9",           // neither greater-than nor less-than, U+2279
+    b"ntlg"                           => b"\xE2\x89\xB8",           // neither less-than nor greater-than, U+2278
+    b"ntriangleleft"                  => b"\xE2\x8B\xAA",           // not normal subgroup of, U+22EA
+    b"ntrianglelefteq"                => b"\xE2\x8B\xAC",           // not normal subgroup of or equal to, U+22EC
+    b"ntriangleright"                 => b"\xE2\x8B\xAB",           // does not contain as normal subgroup, U+22EB
+    b"ntrianglerighteq"               => b"\xE2\x8B\xAD",           // does not contain as normal subgroup or equal, U+22ED
+    b"Nu"                             => b"\xCE\x9D",               // greek capital letter nu, U+039D
+    b"nu"                             => b"\xCE\xBD",               // greek small letter nu, U+03BD
+    b"num"                            => b"\x23",                   // number sign, U+0023
+    b"numero"                         => b"\xE2\x84\x96",           // numero sign, U+2116
+    b"numsp"                          => b"\xE2\x80\x87",           // figure space, U+2007
+    b"nvap"                           => b"\xE2\x89\x8D\xE2\x83\x92",// equivalent to, U+224D + combining long vertical line overlay, U+20D2
+    b"nVDash"                         => b"\xE2\x8A\xAF",           // negated double vertical bar double right turnstile, U+22AF
+    b"nVdash"                         => b"\xE2\x8A\xAE",           // does not force, U+22AE
+    b"nvDash"                         => b"\xE2\x8A\xAD",           // not true, U+22AD
+    b"nvdash"                         => b"\xE2\x8A\xAC",           // does not prove, U+22AC
+    b"nvge"                           => b"\xE2\x89\xA5\xE2\x83\x92",// greater-than or equal to, U+2265 + combining long vertical line overlay, U+20D2
+    b"nvgt"                           => b"\x3E\xE2\x83\x92",       // greater-than sign, U+003E + combining long vertical line overlay, U+20D2
+    b"nvHarr"                         => b"\xE2\xA4\x84",           // left right double arrow with vertical stroke, U+2904
+    b"nvinfin"                        => b"\xE2\xA7\x9E",           // infinity negated with vertical bar, U+29DE
+    b"nvlArr"                         => b"\xE2\xA4\x82",           // leftwards double arrow with vertical stroke, U+2902
+    b"nvle"                           => b"\xE2\x89\xA4\xE2\x83\x92",// less-than or equal to, U+2264 + combining long vertical line overlay, U+20D2
+    b"nvlt"                           => b"\x3C\xE2\x83\x92",       // less-than sign, U+003C + combining long vertical line overlay, U+20D2
+    b"nvltrie"                        => b"\xE2\x8A\xB4\xE2\x83\x92",// normal subgroup of or equal to, U+22B4 + combining long vertical line overlay, U+20D2
+    b"nvrArr"                         => b"\xE2\xA4\x83",           // rightwards double arrow with vertical stroke, U+2903
+    b"nvrtrie"                        => b"\xE2\x8A\xB5\xE2\x83\x92",// contains as normal subgroup or equal to, U+22B5 + combining long vertical line overlay, U+20D2
+    b"nvsim"                          => b"\xE2\x88\xBC\xE2\x83\x92",// tilde operator, U+223C + combining long vertical line overlay, U+20D2
+    b"nwarhk"                         => b"\xE2\xA4\xA3",           // north west arrow with hook, U+2923
+    b"nwArr"                          => b"\xE2\x87\x96",           // north west double arrow, U+21D6
+    b"nwarr"                          => b"\xE2\x86\x96",           // north west arrow, U+2196
+    b"nwarrow"                        => b"\xE2\x86\x96",           // north west arrow, U+2196
+    b"nwnear"                         => b"\xE2\xA4\xA7",           // north west arrow and north east arrow, U+2927
+    b"oast"                           => b"\xE2\x8A\x9B",           // circled asterisk operator, U+229B
+    b"ocir"                           => b"\xE2\x8A\x9A",           // circled ring operator, U+229A
+    b"Ocy"                            => b"\xD0\x9E",               // cyrillic capital letter o, U+041E
+    b"ocy"                            => b"\xD0\xBE",               // cyrillic small letter o, U+043E
+    b"odash"                          => b"\xE2\x8A\x9D",           // circled dash, U+229D
+    b"Odblac"                         => b"\xC5\x90",               // latin capital letter o with double acute, U+0150
+    b"odblac"                         => b"\xC5\x91",               // latin small letter o with double acute, U+0151
+    b"odiv"                           => b"\xE2\xA8\xB8",           // circled division sign, U+2A38
+    b"odot"                           => b"\xE2\x8A\x99",           // circled dot operator, U+2299
+    b"odsold"                         => b"\xE2\xA6\xBC",           // circled anticlockwise-rotated division sign, U+29BC
+    b"ofcir"                          => b"\xE2\xA6\xBF",           // circled bullet, U+29BF
+    b"Ofr"                            => b"\xF0\x9D\x94\x92",       // mathematical fraktur capital o, U+1D512
+    b"ofr"                            => b"\xF0\x9D\x94\xAC",       // mathematical fraktur small o, U+1D52C
+    b"ogon"                           => b"\xCB\x9B",               // ogonek, U+02DB
+    b"ogt"                            => b"\xE2\xA7\x81",           // circled greater-than, U+29C1
+    b"ohbar"                          => b"\xE2\xA6\xB5",           // circle with horizontal bar, U+29B5
+    b"ohm"                            => b"\xCE\xA9",               // greek capital letter omega, U+03A9
+    b"oint"                           => b"\xE2\x88\xAE",           // contour integral, U+222E
+    b"olarr"                          => b"\xE2\x86\xBA",           // anticlockwise open circle arrow, U+21BA
+    b"olcir"                          => b"\xE2\xA6\xBE",           // circled white bullet, U+29BE
+    b"olcross"                        => b"\xE2\xA6\xBB",           // circle with superimposed x, U+29BB
+    b"oline"                          => b"\xE2\x80\xBE",           // overline, U+203E
+    b"olt"                            => b"\xE2\xA7\x80",           // circled less-than, U+29C0
+    b"Omacr"                          => b"\xC5\x8C",               // latin capital letter o with macron, U+014C
+    b"omacr"                          => b"\xC5\x8D",               // latin small letter o with macron, U+014D
+    b"Omega"                          => b"\xCE\xA9",               // greek capital letter omega, U+03A9
+    b"omega"                          => b"\xCF\x89",               // greek small letter omega, U+03C9
+    b"Omicron"                        => b"\xCE\x9F",               // greek capital letter omicron, U+039F
+    b"omicron"                        => b"\xCE\xBF",               // greek small letter omicron, U+03BF
+    b"omid"                           => b"\xE2\xA6\xB6",           // circled vertical bar, U+29B6
+    b"ominus"                         => b"\xE2\x8A\x96",           // circled minus, U+2296
+    b"Oopf"                           => b"\xF0\x9D\x95\x86",       // mathematical double-struck capital o, U+1D546
+    b"oopf"                           => b"\xF0\x9D\x95\xA0",       // mathematical double-struck small o, U+1D560
+    b"opar"                           => b"\xE2\xA6\xB7",           // circled parallel, U+29B7
+    b"OpenCurlyDoubleQuote"           => b"\xE2\x80\x9C",           // left double quotation mark, U+201C
+    b"OpenCurlyQuote"                 => b"\xE2\x80\x98",           // left single quotation mark, U+2018
+    b"operp"                          => b"\xE2\xA6\xB9",           // circled perpendicular, U+29B9
+    b"oplus"                          => b"\xE2\x8A\x95",           // circled plus, U+2295
+    b"Or"                             => b"\xE2\xA9\x94",           // double logical or, U+2A54
+    b"or"                             => b"\xE2\x88\xA8",           // logical or, U+2228
+    b"orarr"                          => b"\xE2\x86\xBB",           // clockwise open circle arrow, U+21BB
+    b"ord"                            => b"\xE2\xA9\x9D",           // logical or with horizontal dash, U+2A5D
+    b"order"                          => b"\xE2\x84\xB4",           // script small o, U+2134
+    b"orderof"                        => b"\xE2\x84\xB4",           // script small o, U+2134
+    b"origof"                         => b"\xE2\x8A\xB6",           // original of, U+22B6
+    b"oror"                           => b"\xE2\xA9\x96",           // two intersecting logical or, U+2A56
+    b"orslope"                        => b"\xE2\xA9\x97",           // sloping large or, U+2A57
+    b"orv"                            => b"\xE2\xA9\x9B",           // logical or with middle stem, U+2A5B
+    b"oS"                             => b"\xE2\x93\x88",           // circled latin capital letter s, U+24C8
+    b"Oscr"                           => b"\xF0\x9D\x92\xAA",       // mathematical script capital o, U+1D4AA
+    b"oscr"                           => b"\xE2\x84\xB4",           // script small o, U+2134
+    b"osol"                           => b"\xE2\x8A\x98",           // circled division slash, U+2298
+    b"Otimes"                         => b"\xE2\xA8\xB7",           // multiplication sign in double circle, U+2A37
+    b"otimes"                         => b"\xE2\x8A\x97",           // circled times, U+2297
+    b"otimesas"                       => b"\xE2\xA8\xB6",           // circled multiplication sign with circumflex accent, U+2A36
+    b"ovbar"                          => b"\xE2\x8C\xBD",           // apl functional symbol circle stile, U+233D
+    b"OverBar"                        => b"\xE2\x80\xBE",           // overline, U+203E
+    b"OverBrace"                      => b"\xE2\x8F\x9E",           // top curly bracket, U+23DE
+    b"OverBracket"                    => b"\xE2\x8E\xB4",           // top square bracket, U+23B4
+    b"OverParenthesis"                => b"\xE2\x8F\x9C",           // top parenthesis, U+23DC
+    b"par"                            => b"\xE2\x88\xA5",           // parallel to, U+2225
+    b"parallel"                       => b"\xE2\x88\xA5",           // parallel to, U+2225
+    b"parsim"                         => b"\xE2\xAB\xB3",           // parallel with tilde operator, U+2AF3
+    b"parsl"                          => b"\xE2\xAB\xBD",           // double solidus operator, U+2AFD
+    b"part"                           => b"\xE2\x88\x82",           // partial differential, U+2202
+    b"PartialD"                       => b"\xE2\x88\x82",           // partial differential, U+2202
+    b"Pcy"                            => b"\xD0\x9F",               // cyrillic capital letter pe, U+041F
+    b"pcy"                            => b"\xD0\xBF",               // cyrillic small letter pe, U+043F
+    b"percnt"                         => b"\x25",                   // percent sign, U+0025
+    b"period"                         => b"\x2E",                   // full stop, U+002E
+    b"perp"                           => b"\xE2\x8A\xA5",           // up tack, U+22A5
+    b"pertenk"                        => b"\xE2\x80\xB1",           // per ten thousand sign, U+2031
+    b"Pfr"                            => b"\xF0\x9D\x94\x93",       // mathematical fraktur capital p, U+1D513
+    b"pfr"                            => b"\xF0\x9D\x94\xAD",       // mathematical fraktur small p, U+1D52D
+    b"Phi"                            => b"\xCE\xA6",               // greek capital letter phi, U+03A6
+    b"phi"                            => b"\xCF\x86",               // greek small letter phi, U+03C6
+    b"phiv"                           => b"\xCF\x95",               // greek phi symbol, U+03D5
+    b"phmmat"                         => b"\xE2\x84\xB3",           // script capital m, U+2133
+    b"phone"                          => b"\xE2\x98\x8E",           // black telephone, U+260E
+    b"Pi"                             => b"\xCE\xA0",               // greek capital letter pi, U+03A0
+    b"pi"                             => b"\xCF\x80",               // greek small letter pi, U+03C0
+    b"pitchfork"                      => b"\xE2\x8B\x94",           // pitchfork, U+22D4
+    b"piv"                            => b"\xCF\x96",               // greek pi symbol, U+03D6
+    b"planck"                         => b"\xE2\x84\x8F",           // planck constant over two pi, U+210F
+    b"planckh"                        => b"\xE2\x84\x8E",           // planck constant, U+210E
+    b"plankv"                         => b"\xE2\x84\x8F",           // planck constant over two pi, U+210F
+    b"plus"                           => b"\x2B",                   // plus sign, U+002B
+    b"plusacir"                       => b"\xE2\xA8\xA3",           // plus sign with circumflex accent above, U+2A23
+    b"plusb"                          => b"\xE2\x8A\x9E",           // squared plus, U+229E
+    b"pluscir"                        => b"\xE2\xA8\xA2",           // plus sign with small circle above, U+2A22
+    b"plusdo"                         => b"\xE2\x88\x94",           // dot plus, U+2214
+    b"plusdu"                         => b"\xE2\xA8\xA5",           // plus sign with dot below, U+2A25
+    b"pluse"                          => b"\xE2\xA9\xB2",           // plus sign above equals sign, U+2A72
+    b"PlusMinus"                      => b"\xC2\xB1",               // plus-minus sign, U+00B1
+    b"plussim"                        => b"\xE2\xA8\xA6",           // plus sign with tilde below, U+2A26
+    b"plustwo"                        => b"\xE2\xA8\xA7",           // plus sign with subscript two, U+2A27
+    b"pm"                             => b"\xC2\xB1",               // plus-minus sign, U+00B1
+    b"Poincareplane"                  => b"\xE2\x84\x8C",           // black-letter capital h, U+210C
+    b"pointint"                       => b"\xE2\xA8\x95",           // integral around a point operator, U+2A15
+    b"Popf"                           => b"\xE2\x84\x99",           // double-struck capital p, U+2119
+    b"popf"                           => b"\xF0\x9D\x95\xA1",       // mathematical double-struck small p, U+1D561
+    b"Pr"                             => b"\xE2\xAA\xBB",           // double precedes, U+2ABB
+    b"pr"                             => b"\xE2\x89\xBA",           // precedes, U+227A
+    b"prap"                           => b"\xE2\xAA\xB7",           // precedes above almost equal to, U+2AB7
+    b"prcue"                          => b"\xE2\x89\xBC",           // precedes or equal to, U+227C
+    b"prE"                            => b"\xE2\xAA\xB3",           // precedes above equals sign, U+2AB3
+    b"pre"                            => b"\xE2\xAA\xAF",           // precedes above single-line equals sign, U+2AAF
+    b"prec"                           => b"\xE2\x89\xBA",           // precedes, U+227A
+    b"precapprox"                     => b"\xE2\xAA\xB7",           // precedes above almost equal to, U+2AB7
+    b"preccurlyeq"                    => b"\xE2\x89\xBC",           // precedes or equal to, U+227C
+    b"Precedes"                       => b"\xE2\x89\xBA",           // precedes, U+227A
+    b"PrecedesEqual"                  => b"\xE2\xAA\xAF",           // precedes above single-line equals sign, U+2AAF
+    b"PrecedesSlantEqual"             => b"\xE2\x89\xBC",           // precedes or equal to, U+227C
+    b"PrecedesTilde"                  => b"\xE2\x89\xBE",           // precedes or equivalent to, U+227E
+    b"preceq"                         => b"\xE2\xAA\xAF",           // precedes above single-line equals sign, U+2AAF
+    b"precnapprox"                    => b"\xE2\xAA\xB9",           // precedes above not almost equal to, U+2AB9
+    b"precneqq"                       => b"\xE2\xAA\xB5",           // precedes above not equal to, U+2AB5
+    b"precnsim"                       => b"\xE2\x8B\xA8",           // precedes but not equivalent to, U+22E8
+    b"precsim"                        => b"\xE2\x89\xBE",           // precedes or equivalent to, U+227E
+    b"Prime"                          => b"\xE2\x80\xB3",           // double prime, U+2033
+    b"prime"                          => b"\xE2\x80\xB2",           // prime, U+2032
+    b"primes"                         => b"\xE2\x84\x99",           // double-struck capital p, U+2119
+    b"prnap"                          => b"\xE2\xAA\xB9",           // precedes above not almost equal to, U+2AB9
+    b"prnE"                           => b"\xE2\xAA\xB5",           // precedes above not equal to, U+2AB5
+    b"prnsim"                         => b"\xE2\x8B\xA8",           // precedes but not equivalent to, U+22E8
+    b"prod"                           => b"\xE2\x88\x8F",           // n-ary product, U+220F
+    b"Product"                        => b"\xE2\x88\x8F",           // n-ary product, U+220F
+    b"profalar"                       => b"\xE2\x8C\xAE",           // all around-profile, U+232E
+    b"profline"                       => b"\xE2\x8C\x92",           // arc, U+2312
+    b"profsurf"                       => b"\xE2\x8C\x93",           // segment, U+2313
+    b"prop"                           => b"\xE2\x88\x9D",           // proportional to, U+221D
+    b"Proportion"                     => b"\xE2\x88\xB7",           // proportion, U+2237
+    b"Proportional"                   => b"\xE2\x88\x9D",           // proportional to, U+221D
+    b"propto"                         => b"\xE2\x88\x9D",           // proportional to, U+221D
+    b"prsim"                          => b"\xE2\x89\xBE",           // precedes or equivalent to, U+227E
+    b"prurel"                         => b"\xE2\x8A\xB0",           // precedes under relation, U+22B0
+    b"Pscr"                           => b"\xF0\x9D\x92\xAB",       // mathematical script capital p, U+1D4AB
+    b"pscr"                           => b"\xF0\x9D\x93\x85",       // mathematical script small p, U+1D4C5
+    b"Psi"                            => b"\xCE\xA8",               // greek capital letter psi, U+03A8
+    b"psi"                            => b"\xCF\x88",               // greek small letter psi, U+03C8
+    b"puncsp"                         => b"\xE2\x80\x88",           // punctuation space, U+2008
+    b"Qfr"                            => b"\xF0\x9D\x94\x94",       // mathematical fraktur capital q, U+1D514
+    b"qfr"                            => b"\xF0\x9D\x94\xAE",       // mathematical fraktur small q, U+1D52E
+    b"qint"                           => b"\xE2\xA8\x8C",           // quadruple integral operator, U+2A0C
+    b"Qopf"                           => b"\xE2\x84\x9A",           // double-struck capital q, U+211A
+    b"qopf"                           => b"\xF0\x9D\x95\xA2",       // mathematical double-struck small q, U+1D562
+    b"qprime"                         => b"\xE2\x81\x97",           // quadruple prime, U+2057
+    b"Qscr"                           => b"\xF0\x9D\x92\xAC",       // mathematical script capital q, U+1D4AC
+    b"qscr"                           => b"\xF0\x9D\x93\x86",       // mathematical script small q, U+1D4C6
+    b"quaternions"                    => b"\xE2\x84\x8D",           // double-struck capital h, U+210D
+    b"quatint"                        => b"\xE2\xA8\x96",           // quaternion integral operator, U+2A16
+    b"quest"                          => b"\x3F",                   // question mark, U+003F
+    b"questeq"                        => b"\xE2\x89\x9F",           // questioned equal to, U+225F
+    b"QUOT"                           => b"\x22",                   // quotation mark, U+0022
+    b"rAarr"                          => b"\xE2\x87\x9B",           // rightwards triple arrow, U+21DB
+    b"race"                           => b"\xE2\x88\xBD\xCC\xB1",   // reversed tilde, U+223D + combining macron below, U+0331
+    b"Racute"                         => b"\xC5\x94",               // latin capital letter r with acute, U+0154
+    b"racute"                         => b"\xC5\x95",               // latin small letter r with acute, U+0155
+    b"radic"                          => b"\xE2\x88\x9A",           // square root, U+221A
+    b"raemptyv"                       => b"\xE2\xA6\xB3",           // empty set with right arrow above, U+29B3
+    b"Rang"                           => b"\xE2\x9F\xAB",           // mathematical right double angle bracket, U+27EB
+    b"rang"                           => b"\xE2\x9F\xA9",           // mathematical right angle bracket, U+27E9
+    b"rangd"                          => b"\xE2\xA6\x92",           // right angle bracket with dot, U+2992
+    b"range"                          => b"\xE2\xA6\xA5",           // reversed angle with underbar, U+29A5
+    b"rangle"                         => b"\xE2\x9F\xA9",           // mathematical right angle bracket, U+27E9
+    b"Rarr"                           => b"\xE2\x86\xA0",           // rightwards two headed arrow, U+21A0
+    b"rArr"                           => b"\xE2\x87\x92",           // rightwards double arrow, U+21D2
+    b"rarr"                           => b"\xE2\x86\x92",           // rightwards arrow, U+2192
+    b"rarrap"                         => b"\xE2\xA5\xB5",           // rightwards arrow above almost equal to, U+2975
+    b"rarrb"                          => b"\xE2\x87\xA5",           // rightwards arrow to bar, U+21E5
+    b"rarrbfs"                        => b"\xE2\xA4\xA0",           // rightwards arrow from bar to black diamond, U+2920
+    b"rarrc"                          => b"\xE2\xA4\xB3",           // wave arrow pointing directly right, U+2933
+    b"rarrfs"                         => b"\xE2\xA4\x9E",           // rightwards arrow to black diamond, U+291E
+    b"rarrhk"                         => b"\xE2\x86\xAA",           // rightwards arrow with hook, U+21AA
+    b"rarrlp"                         => b"\xE2\x86\xAC",           // rightwards arrow with loop, U+21AC
+    b"rarrpl"                         => b"\xE2\xA5\x85",           // rightwards arrow with plus below, U+2945
+    b"rarrsim"                        => b"\xE2\xA5\xB4",           // rightwards arrow above tilde operator, U+2974
+    b"Rarrtl"                         => b"\xE2\xA4\x96",           // rightwards two-headed arrow with tail, U+2916
+    b"rarrtl"                         => b"\xE2\x86\xA3",           // rightwards arrow with tail, U+21A3
+    b"rarrw"                          => b"\xE2\x86\x9D",           // rightwards wave arrow, U+219D
+    b"rAtail"                         => b"\xE2\xA4\x9C",           // rightwards double arrow-tail, U+291C
+    b"ratail"                         => b"\xE2\xA4\x9A",           // rightwards arrow-tail, U+291A
+    b"ratio"                          => b"\xE2\x88\xB6",           // ratio, U+2236
+    b"rationals"                      => b"\xE2\x84\x9A",           // double-struck capital q, U+211A
+    b"RBarr"                          => b"\xE2\xA4\x90",           // rightwards two-headed triple dash arrow, U+2910
+    b"rBarr"                          => b"\xE2\xA4\x8F",           // rightwards triple dash arrow, U+290F
+    b"rbarr"                          => b"\xE2\xA4\x8D",           // rightwards double dash arrow, U+290D
+    b"rbbrk"                          => b"\xE2\x9D\xB3",           // light right tortoise shell bracket ornament, U+2773
+    b"rbrace"                         => b"\x7D",                   // right curly bracket, U+007D
+    b"rbrack"                         => b"\x5D",                   // right square bracket, U+005D
+    b"rbrke"                          => b"\xE2\xA6\x8C",           // right square bracket with underbar, U+298C
+    b"rbrksld"                        => b"\xE2\xA6\x8E",           // right square bracket with tick in bottom corner, U+298E
+    b"rbrkslu"                        => b"\xE2\xA6\x90",           // right square bracket with tick in top corner, U+2990
+    b"Rcaron"                         => b"\xC5\x98",               // latin capital letter r with caron, U+0158
+    b"rcaron"                         => b"\xC5\x99",               // latin small letter r with caron, U+0159
+    b"Rcedil"                         => b"\xC5\x96",               // latin capital letter r with cedilla, U+0156
+    b"rcedil"                         => b"\xC5\x97",               // latin small letter r with cedilla, U+0157
+    b"rceil"                          => b"\xE2\x8C\x89",           // right ceiling, U+2309
+    b"rcub"                           => b"\x7D",                   // right curly bracket, U+007D
+    b"Rcy"                            => b"\xD0\xA0",               // cyrillic capital letter er, U+0420
+    b"rcy"                            => b"\xD1\x80",               // cyrillic small letter er, U+0440
+    b"rdca"                           => b"\xE2\xA4\xB7",           // arrow pointing downwards then curving rightwards, U+2937
+    b"rdldhar"                        => b"\xE2\xA5\xA9",           // rightwards harpoon with barb down above leftwards harpoon with barb down, U+2969
+    b"rdquor"                         => b"\xE2\x80\x9D",           // right double quotation mark, U+201D
+    b"rdsh"                           => b"\xE2\x86\xB3",           // downwards arrow with tip rightwards, U+21B3
+    b"Re"                             => b"\xE2\x84\x9C",           // black-letter capital r, U+211C
+    b"real"                           => b"\xE2\x84\x9C",           // black-letter capital r, U+211C
+    b"realine"                        => b"\xE2\x84\x9B",           // script capital r, U+211B
+    b"realpart"                       => b"\xE2\x84\x9C",           // black-letter capital r, U+211C
+    b"reals"                          => b"\xE2\x84\x9D",           // double-struck capital r, U+211D
+    b"rect"                           => b"\xE2\x96\xAD",           // white rectangle, U+25AD
+    b"REG"                            => b"\xC2\xAE",               // registered sign, U+00AE
+    b"ReverseElement"                 => b"\xE2\x88\x8B",           // contains as member, U+220B
+    b"ReverseEquilibrium"             => b"\xE2\x87\x8B",           // leftwards harpoon over rightwards harpoon, U+21CB
+    b"ReverseUpEquilibrium"           => b"\xE2\xA5\xAF",           // downwards harpoon with barb left beside upwards harpoon with barb right, U+296F
+    b"rfisht"                         => b"\xE2\xA5\xBD",           // right fish tail, U+297D
+    b"rfloor"                         => b"\xE2\x8C\x8B",           // right floor, U+230B
+    b"Rfr"                            => b"\xE2\x84\x9C",           // black-letter capital r, U+211C
+    b"rfr"                            => b"\xF0\x9D\x94\xAF",       // mathematical fraktur small r, U+1D52F
+    b"rHar"                           => b"\xE2\xA5\xA4",           // rightwards harpoon with barb up above rightwards harpoon with barb down, U+2964
+    b"rhard"                          => b"\xE2\x87\x81",           // rightwards harpoon with barb downwards, U+21C1
+    b"rharu"                          => b"\xE2\x87\x80",           // rightwards harpoon with barb upwards, U+21C0
+    b"rharul"                         => b"\xE2\xA5\xAC",           // rightwards harpoon with barb up above long dash, U+296C
+    b"Rho"                            => b"\xCE\xA1",               // greek capital letter rho, U+03A1
+    b"rho"                            => b"\xCF\x81",               // greek small letter rho, U+03C1
+    b"rhov"                           => b"\xCF\xB1",               // greek rho symbol, U+03F1
+    b"RightAngleBracket"              => b"\xE2\x9F\xA9",           // mathematical right angle bracket, U+27E9
+    b"RightArrow"                     => b"\xE2\x86\x92",           // rightwards arrow, U+2192
+    b"Rightarrow"                     => b"\xE2\x87\x92",           // rightwards double arrow, U+21D2
+    b"rightarrow"                     => b"\xE2\x86\x92",           // rightwards arrow, U+2192
+    b"RightArrowBar"                  => b"\xE2\x87\xA5",           // rightwards arrow to bar, U+21E5
+    b"RightArrowLeftArrow"            => b"\xE2\x87\x84",           // rightwards arrow over leftwards arrow, U+21C4
+    b"rightarrowtail"                 => b"\xE2\x86\xA3",           // rightwards arrow with tail, U+21A3
+    b"RightCeiling"                   => b"\xE2\x8C\x89",           // right ceiling, U+2309
+    b"RightDoubleBracket"             => b"\xE2\x9F\xA7",           // mathematical right white square bracket, U+27E7
+    b"RightDownTeeVector"             => b"\xE2\xA5\x9D",           // downwards harpoon with barb right from bar, U+295D
+    b"RightDownVector"                => b"\xE2\x87\x82",           // downwards harpoon with barb rightwards, U+21C2
+    b"RightDownVectorBar"             => b"\xE2\xA5\x95",           // downwards harpoon with barb right to bar, U+2955
+    b"RightFloor"                     => b"\xE2\x8C\x8B",           // right floor, U+230B
+    b"rightharpoondown"               => b"\xE2\x87\x81",           // rightwards harpoon with barb downwards, U+21C1
+    b"rightharpoonup"                 => b"\xE2\x87\x80",           // rightwards harpoon with barb upwards, U+21C0
+    b"rightleftarrows"                => b"\xE2\x87\x84",           // rightwards arrow over leftwards arrow, U+21C4
+    b"rightleftharpoons"              => b"\xE2\x87\x8C",           // rightwards harpoon over leftwards harpoon, U+21CC
+    b"rightrightarrows"               => b"\xE2\x87\x89",           // rightwards paired arrows, U+21C9
+    b"rightsquigarrow"                => b"\xE2\x86\x9D",           // rightwards wave arrow, U+219D
+    b"RightTee"                       => b"\xE2\x8A\xA2",           // right tack, U+22A2
+    b"RightTeeArrow"                  => b"\xE2\x86\xA6",           // rightwards arrow from bar, U+21A6
+    b"RightTeeVector"                 => b"\xE2\xA5\x9B",           // rightwards harpoon with barb up from bar, U+295B
+    b"rightthreetimes"                => b"\xE2\x8B\x8C",           // right semidirect product, U+22CC
+    b"RightTriangle"                  => b"\xE2\x8A\xB3",           // contains as normal subgroup, U+22B3
+    b"RightTriangleBar"               => b"\xE2\xA7\x90",           // vertical bar beside right triangle, U+29D0
+    b"RightTriangleEqual"             => b"\xE2\x8A\xB5",           // contains as normal subgroup or equal to, U+22B5
+    b"RightUpDownVector"              => b"\xE2\xA5\x8F",           // up barb right down barb right harpoon, U+294F
+    b"RightUpTeeVector"               => b"\xE2\xA5\x9C",           // upwards harpoon with barb right from bar, U+295C
+    b"RightUpVector"                  => b"\xE2\x86\xBE",           // upwards harpoon with barb rightwards, U+21BE
+    b"RightUpVectorBar"               => b"\xE2\xA5\x94",           // upwards harpoon with barb right to bar, U+2954
+    b"RightVector"                    => b"\xE2\x87\x80",           // rightwards harpoon with barb upwards, U+21C0
+    b"RightVectorBar"                 => b"\xE2\xA5\x93",           // rightwards harpoon with barb up to bar, U+2953
+    b"ring"                           => b"\xCB\x9A",               // ring above, U+02DA
+    b"risingdotseq"                   => b"\xE2\x89\x93",           // image of or approximately equal to, U+2253
+    b"rlarr"                          => b"\xE2\x87\x84",           // rightwards arrow over leftwards arrow, U+21C4
+    b"rlhar"                          => b"\xE2\x87\x8C",           // rightwards harpoon over leftwards harpoon, U+21CC
+    b"rmoust"                         => b"\xE2\x8E\xB1",           // upper right or lower left curly bracket section, U+23B1
+    b"rmoustache"                     => b"\xE2\x8E\xB1",           // upper right or lower left curly bracket section, U+23B1
+    b"rnmid"                          => b"\xE2\xAB\xAE",           // does not divide with reversed negation slash, U+2AEE
+    b"roang"                          => b"\xE2\x9F\xAD",           // mathematical right white tortoise shell bracket, U+27ED
+    b"roarr"                          => b"\xE2\x87\xBE",           // rightwards open-headed arrow, U+21FE
+    b"robrk"                          => b"\xE2\x9F\xA7",           // mathematical right white square bracket, U+27E7
+    b"ropar"                          => b"\xE2\xA6\x86",           // right white parenthesis, U+2986
+    b"Ropf"                           => b"\xE2\x84\x9D",           // double-struck capital r, U+211D
+    b"ropf"                           => b"\xF0\x9D\x95\xA3",       // mathematical double-struck small r, U+1D563
+    b"roplus"                         => b"\xE2\xA8\xAE",           // plus sign in right half circle, U+2A2E
+    b"rotimes"                        => b"\xE2\xA8\xB5",           // multiplication sign in right half circle, U+2A35
+    b"RoundImplies"                   => b"\xE2\xA5\xB0",           // right double arrow with rounded head, U+2970
+    b"rpar"                           => b"\x29",                   // right parenthesis, U+0029
+    b"rpargt"                         => b"\xE2\xA6\x94",           // right arc greater-than bracket, U+2994
+    b"rppolint"                       => b"\xE2\xA8\x92",           // line integration with rectangular path around pole, U+2A12
+    b"rrarr"                          => b"\xE2\x87\x89",           // rightwards paired arrows, U+21C9
+    b"Rrightarrow"                    => b"\xE2\x87\x9B",           // rightwards triple arrow, U+21DB
+    b"Rscr"                           => b"\xE2\x84\x9B",           // script capital r, U+211B
+    b"rscr"                           => b"\xF0\x9D\x93\x87",       // mathematical script small r, U+1D4C7
+    b"Rsh"                            => b"\xE2\x86\xB1",           // upwards arrow with tip rightwards, U+21B1
+    b"rsh"                            => b"\xE2\x86\xB1",           // upwards arrow with tip rightwards, U+21B1
+    b"rsqb"                           => b"\x5D",                   // right square bracket, U+005D
+    b"rsquor"                         => b"\xE2\x80\x99",           // right single quotation mark, U+2019
+    b"rthree"                         => b"\xE2\x8B\x8C",           // right semidirect product, U+22CC
+    b"rtimes"                         => b"\xE2\x8B\x8A",           // right normal factor semidirect product, U+22CA
+    b"rtri"                           => b"\xE2\x96\xB9",           // white right-pointing small triangle, U+25B9
+    b"rtrie"                          => b"\xE2\x8A\xB5",           // contains as normal subgroup or equal to, U+22B5
+    b"rtrif"                          => b"\xE2\x96\xB8",           // black right-pointing small triangle, U+25B8
+    b"rtriltri"                       => b"\xE2\xA7\x8E",           // right triangle above left triangle, U+29CE
+    b"RuleDelayed"                    => b"\xE2\xA7\xB4",           // rule-delayed, U+29F4
+    b"ruluhar"                        => b"\xE2\xA5\xA8",           // rightwards harpoon with barb up above leftwards harpoon with barb up, U+2968
+    b"rx"                             => b"\xE2\x84\x9E",           // prescription take, U+211E
+    b"Sacute"                         => b"\xC5\x9A",               // latin capital letter s with acute, U+015A
+    b"sacute"                         => b"\xC5\x9B",               // latin small letter s with acute, U+015B
+    b"Sc"                             => b"\xE2\xAA\xBC",           // double succeeds, U+2ABC
+    b"sc"                             => b"\xE2\x89\xBB",           // succeeds, U+227B
+    b"scap"                           => b"\xE2\xAA\xB8",           // succeeds above almost equal to, U+2AB8
+    b"sccue"                          => b"\xE2\x89\xBD",           // succeeds or equal to, U+227D
+    b"scE"                            => b"\xE2\xAA\xB4",           // succeeds above equals sign, U+2AB4
+    b"sce"                            => b"\xE2\xAA\xB0",           // succeeds above single-line equals sign, U+2AB0
+    b"Scedil"                         => b"\xC5\x9E",               // latin capital letter s with cedilla, U+015E
+    b"scedil"                         => b"\xC5\x9F",               // latin small letter s with cedilla, U+015F
+    b"Scirc"                          => b"\xC5\x9C",               // latin capital letter s with circumflex, U+015C
+    b"scirc"                          => b"\xC5\x9D",               // latin small letter s with circumflex, U+015D
+    b"scnap"                          => b"\xE2\xAA\xBA",           // succeeds above not almost equal to, U+2ABA
+    b"scnE"                           => b"\xE2\xAA\xB6",           // succeeds above not equal to, U+2AB6
+    b"scnsim"                         => b"\xE2\x8B\xA9",           // succeeds but not equivalent to, U+22E9
+    b"scpolint"                       => b"\xE2\xA8\x93",           // line integration with semicircular path around pole, U+2A13
+    b"scsim"                          => b"\xE2\x89\xBF",           // succeeds or equivalent to, U+227F
+    b"Scy"                            => b"\xD0\xA1",               // cyrillic capital letter es, U+0421
+    b"scy"                            => b"\xD1\x81",               // cyrillic small letter es, U+0441
+    b"sdot"                           => b"\xE2\x8B\x85",           // dot operator, U+22C5
+    b"sdotb"                          => b"\xE2\x8A\xA1",           // squared dot operator, U+22A1
+    b"sdote"                          => b"\xE2\xA9\xA6",           // equals sign with dot below, U+2A66
+    b"searhk"                         => b"\xE2\xA4\xA5",           // south east arrow with hook, U+2925
+    b"seArr"                          => b"\xE2\x87\x98",           // south east double arrow, U+21D8
+    b"searr"                          => b"\xE2\x86\x98",           // south east arrow, U+2198
+    b"searrow"                        => b"\xE2\x86\x98",           // south east arrow, U+2198
+    b"semi"                           => b"\x3B",                   // semicolon, U+003B
+    b"seswar"                         => b"\xE2\xA4\xA9",           // south east arrow and south west arrow, U+2929
+    b"setminus"                       => b"\xE2\x88\x96",           // set minus, U+2216
+    b"setmn"                          => b"\xE2\x88\x96",           // set minus, U+2216
+    b"sext"                           => b"\xE2\x9C\xB6",           // six pointed black star, U+2736
+    b"Sfr"                            => b"\xF0\x9D\x94\x96",       // mathematical fraktur capital s, U+1D516
+    b"sfr"                            => b"\xF0\x9D\x94\xB0",       // mathematical fraktur small s, U+1D530
+    b"sfrown"                         => b"\xE2\x8C\xA2",           // frown, U+2322
+    b"sharp"                          => b"\xE2\x99\xAF",           // music sharp sign, U+266F
+    b"SHCHcy"                         => b"\xD0\xA9",               // cyrillic capital letter shcha, U+0429
+    b"shchcy"                         => b"\xD1\x89",               // cyrillic small letter shcha, U+0449
+    b"SHcy"                           => b"\xD0\xA8",               // cyrillic capital letter sha, U+0428
+    b"shcy"                           => b"\xD1\x88",               // cyrillic small letter sha, U+0448
+    b"ShortDownArrow"                 => b"\xE2\x86\x93",           // downwards arrow, U+2193
+    b"ShortLeftArrow"                 => b"\xE2\x86\x90",           // leftwards arrow, U+2190
+    b"shortmid"                       => b"\xE2\x88\xA3",           // divides, U+2223
+    b"shortparallel"                  => b"\xE2\x88\xA5",           // parallel to, U+2225
+    b"ShortRightArrow"                => b"\xE2\x86\x92",           // rightwards arrow, U+2192
+    b"ShortUpArrow"                   => b"\xE2\x86\x91",           // upwards arrow, U+2191
+    b"Sigma"                          => b"\xCE\xA3",               // greek capital letter sigma, U+03A3
+    b"sigma"                          => b"\xCF\x83",               // greek small letter sigma, U+03C3
+    b"sigmaf"                         => b"\xCF\x82",               // greek small letter final sigma, U+03C2
+    b"sigmav"                         => b"\xCF\x82",               // greek small letter final sigma, U+03C2
+    b"sim"                            => b"\xE2\x88\xBC",           // tilde operator, U+223C
+    b"simdot"                         => b"\xE2\xA9\xAA",           // tilde operator with dot above, U+2A6A
+    b"sime"                           => b"\xE2\x89\x83",           // asymptotically equal to, U+2243
+    b"simeq"                          => b"\xE2\x89\x83",           // asymptotically equal to, U+2243
+    b"simg"                           => b"\xE2\xAA\x9E",           // similar or greater-than, U+2A9E
+    b"simgE"                          => b"\xE2\xAA\xA0",           // similar above greater-than above equals sign, U+2AA0
+    b"siml"                           => b"\xE2\xAA\x9D",           // similar or less-than, U+2A9D
+    b"simlE"                          => b"\xE2\xAA\x9F",           // similar above less-than above equals sign, U+2A9F
+    b"simne"                          => b"\xE2\x89\x86",           // approximately but not actually equal to, U+2246
+    b"simplus"                        => b"\xE2\xA8\xA4",           // plus sign with tilde above, U+2A24
+    b"simrarr"                        => b"\xE2\xA5\xB2",           // tilde operator above rightwards arrow, U+2972
+    b"slarr"                          => b"\xE2\x86\x90",           // leftwards arrow, U+2190
+    b"SmallCircle"                    => b"\xE2\x88\x98",           // ring operator, U+2218
+    b"smallsetminus"                  => b"\xE2\x88\x96",           // set minus, U+2216
+    b"smashp"                         => b"\xE2\xA8\xB3",           // smash product, U+2A33
+    b"smeparsl"                       => b"\xE2\xA7\xA4",           // equals sign and slanted parallel with tilde above, U+29E4
+    b"smid"                           => b"\xE2\x88\xA3",           // divides, U+2223
+    b"smile"                          => b"\xE2\x8C\xA3",           // smile, U+2323
+    b"smt"                            => b"\xE2\xAA\xAA",           // smaller than, U+2AAA
+    b"smte"                           => b"\xE2\xAA\xAC",           // smaller than or equal to, U+2AAC
+    b"smtes"                          => b"\xE2\xAA\xAC\xEF\xB8\x80",// smaller than or equal to, U+2AAC + variation selector-1, U+FE00
+    b"SOFTcy"                         => b"\xD0\xAC",               // cyrillic capital letter soft sign, U+042C
+    b"softcy"                         => b"\xD1\x8C",               // cyrillic small letter soft sign, U+044C
+    b"sol"                            => b"\x2F",                   // solidus, U+002F
+    b"solb"                           => b"\xE2\xA7\x84",           // squared rising diagonal slash, U+29C4
+    b"solbar"                         => b"\xE2\x8C\xBF",           // apl functional symbol slash bar, U+233F
+    b"Sopf"                           => b"\xF0\x9D\x95\x8A",       // mathematical double-struck capital s, U+1D54A
+    b"sopf"                           => b"\xF0\x9D\x95\xA4",       // mathematical double-struck small s, U+1D564
+    b"spades"                         => b"\xE2\x99\xA0",           // black spade suit, U+2660
+    b"spadesuit"                      => b"\xE2\x99\xA0",           // black spade suit, U+2660
+    b"spar"                           => b"\xE2\x88\xA5",           // parallel to, U+2225
+    b"sqcap"                          => b"\xE2\x8A\x93",           // square cap, U+2293
+    b"sqcaps"                         => b"\xE2\x8A\x93\xEF\xB8\x80",// square cap, U+2293 + variation selector-1, U+FE00
+    b"sqcup"                          => b"\xE2\x8A\x94",           // square cup, U+2294
+    b"sqcups"                         => b"\xE2\x8A\x94\xEF\xB8\x80",// square cup, U+2294 + variation selector-1, U+FE00
+    b"Sqrt"                           => b"\xE2\x88\x9A",           // square root, U+221A
+    b"sqsub"                          => b"\xE2\x8A\x8F",           // square image of, U+228F
+    b"sqsube"                         => b"\xE2\x8A\x91",           // square image of or equal to, U+2291
+    b"sqsubset"                       => b"\xE2\x8A\x8F",           // square image of, U+228F
+    b"sqsubseteq"                     => b"\xE2\x8A\x91",           // square image of or equal to, U+2291
+    b"sqsup"                          => b"\xE2\x8A\x90",           // square original of, U+2290
+    b"sqsupe"                         => b"\xE2\x8A\x92",           // square original of or equal to, U+2292
+    b"sqsupset"                       => b"\xE2\x8A\x90",           // square original of, U+2290
+    b"sqsupseteq"                     => b"\xE2\x8A\x92",           // square original of or equal to, U+2292
+    b"squ"                            => b"\xE2\x96\xA1",           // white square, U+25A1
+    b"Square"                         => b"\xE2\x96\xA1",           // white square, U+25A1
+    b"square"                         => b"\xE2\x96\xA1",           // white square, U+25A1
+    b"SquareIntersection"             => b"\xE2\x8A\x93",           // square cap, U+2293
+    b"SquareSubset"                   => b"\xE2\x8A\x8F",           // square image of, U+228F
+    b"SquareSubsetEqual"              => b"\xE2\x8A\x91",           // square image of or equal to, U+2291
+    b"SquareSuperset"                 => b"\xE2\x8A\x90",           // square original of, U+2290
+    b"SquareSupersetEqual"            => b"\xE2\x8A\x92",           // square original of or equal to, U+2292
+    b"SquareUnion"                    => b"\xE2\x8A\x94",           // square cup, U+2294
+    b"squarf"                         => b"\xE2\x96\xAA",           // black small square, U+25AA
+    b"squf"                           => b"\xE2\x96\xAA",           // black small square, U+25AA
+    b"srarr"                          => b"\xE2\x86\x92",           // rightwards arrow, U+2192
+    b"Sscr"                           => b"\xF0\x9D\x92\xAE",       // mathematical script capital s, U+1D4AE
+    b"sscr"                           => b"\xF0\x9D\x93\x88",       // mathematical script small s, U+1D4C8
+    b"ssetmn"                         => b"\xE2\x88\x96",           // set minus, U+2216
+    b"ssmile"                         => b"\xE2\x8C\xA3",           // smile, U+2323
+    b"sstarf"                         => b"\xE2\x8B\x86",           // star operator, U+22C6
+    b"Star"                           => b"\xE2\x8B\x86",           // star operator, U+22C6
+    b"star"                           => b"\xE2\x98\x86",           // white star, U+2606
+    b"starf"                          => b"\xE2\x98\x85",           // black star, U+2605
+    b"straightepsilon"                => b"\xCF\xB5",               // greek lunate epsilon symbol, U+03F5
+    b"straightphi"                    => b"\xCF\x95",               // greek phi symbol, U+03D5
+    b"strns"                          => b"\xC2\xAF",               // macron, U+00AF
+    b"Sub"                            => b"\xE2\x8B\x90",           // double subset, U+22D0
+    b"sub"                            => b"\xE2\x8A\x82",           // subset of, U+2282
+    b"subdot"                         => b"\xE2\xAA\xBD",           // subset with dot, U+2ABD
+    b"subE"                           => b"\xE2\xAB\x85",           // subset of above equals sign, U+2AC5
+    b"sube"                           => b"\xE2\x8A\x86",           // subset of or equal to, U+2286
+    b"subedot"                        => b"\xE2\xAB\x83",           // subset of or equal to with dot above, U+2AC3
+    b"submult"                        => b"\xE2\xAB\x81",           // subset with multiplication sign below, U+2AC1
+    b"subnE"                          => b"\xE2\xAB\x8B",           // subset of above not equal to, U+2ACB
+    b"subne"                          => b"\xE2\x8A\x8A",           // subset of with not equal to, U+228A
+    b"subplus"                        => b"\xE2\xAA\xBF",           // subset with plus sign below, U+2ABF
+    b"subrarr"                        => b"\xE2\xA5\xB9",           // subset above rightwards arrow, U+2979
+    b"Subset"                         => b"\xE2\x8B\x90",           // double subset, U+22D0
+    b"subset"                         => b"\xE2\x8A\x82",           // subset of, U+2282
+    b"subseteq"                       => b"\xE2\x8A\x86",           // subset of or equal to, U+2286
+    b"subseteqq"                      => b"\xE2\xAB\x85",           // subset of above equals sign, U+2AC5
+    b"SubsetEqual"                    => b"\xE2\x8A\x86",           // subset of or equal to, U+2286
+    b"subsetneq"                      => b"\xE2\x8A\x8A",           // subset of with not equal to, U+228A
+    b"subsetneqq"                     => b"\xE2\xAB\x8B",           // subset of above not equal to, U+2ACB
+    b"subsim"                         => b"\xE2\xAB\x87",           // subset of above tilde operator, U+2AC7
+    b"subsub"                         => b"\xE2\xAB\x95",           // subset above subset, U+2AD5
+    b"subsup"                         => b"\xE2\xAB\x93",           // subset above superset, U+2AD3
+    b"succ"                           => b"\xE2\x89\xBB",           // succeeds, U+227B
+    b"succapprox"                     => b"\xE2\xAA\xB8",           // succeeds above almost equal to, U+2AB8
+    b"succcurlyeq"                    => b"\xE2\x89\xBD",           // succeeds or equal to, U+227D
+    b"Succeeds"                       => b"\xE2\x89\xBB",           // succeeds, U+227B
+    b"SucceedsEqual"                  => b"\xE2\xAA\xB0",           // succeeds above single-line equals sign, U+2AB0
+    b"SucceedsSlantEqual"             => b"\xE2\x89\xBD",           // succeeds or equal to, U+227D
+    b"SucceedsTilde"                  => b"\xE2\x89\xBF",           // succeeds or equivalent to, U+227F
+    b"succeq"                         => b"\xE2\xAA\xB0",           // succeeds above single-line equals sign, U+2AB0
+    b"succnapprox"                    => b"\xE2\xAA\xBA",           // succeeds above not almost equal to, U+2ABA
+    b"succneqq"                       => b"\xE2\xAA\xB6",           // succeeds above not equal to, U+2AB6
+    b"succnsim"                       => b"\xE2\x8B\xA9",           // succeeds but not equivalent to, U+22E9
+    b"succsim"                        => b"\xE2\x89\xBF",           // succeeds or equivalent to, U+227F
+    b"SuchThat"                       => b"\xE2\x88\x8B",           // contains as member, U+220B
+    b"Sum"                            => b"\xE2\x88\x91",           // n-ary summation, U+2211
+    b"sum"                            => b"\xE2\x88\x91",           // n-ary summation, U+2211
+    b"sung"                           => b"\xE2\x99\xAA",           // eighth note, U+266A
+    b"Sup"                            => b"\xE2\x8B\x91",           // double superset, U+22D1
+    b"sup"                            => b"\xE2\x8A\x83",           // superset of, U+2283
+    b"supdot"                         => b"\xE2\xAA\xBE",           // superset with dot, U+2ABE
+    b"supdsub"                        => b"\xE2\xAB\x98",           // superset beside and joined by dash with subset, U+2AD8
+    b"supE"                           => b"\xE2\xAB\x86",           // superset of above equals sign, U+2AC6
+    b"supe"                           => b"\xE2\x8A\x87",           // superset of or equal to, U+2287
+    b"supedot"                        => b"\xE2\xAB\x84",           // superset of or equal to with dot above, U+2AC4
+    b"Superset"                       => b"\xE2\x8A\x83",           // superset of, U+2283
+    b"SupersetEqual"                  => b"\xE2\x8A\x87",           // superset of or equal to, U+2287
+    b"suphsol"                        => b"\xE2\x9F\x89",           // superset preceding solidus, U+27C9
+    b"suphsub"                        => b"\xE2\xAB\x97",           // superset beside subset, U+2AD7
+    b"suplarr"                        => b"\xE2\xA5\xBB",           // superset above leftwards arrow, U+297B
+    b"supmult"                        => b"\xE2\xAB\x82",           // superset with multiplication sign below, U+2AC2
+    b"supnE"                          => b"\xE2\xAB\x8C",           // superset of above not equal to, U+2ACC
+    b"supne"                          => b"\xE2\x8A\x8B",           // superset of with not equal to, U+228B
+    b"supplus"                        => b"\xE2\xAB\x80",           // superset with plus sign below, U+2AC0
+    b"Supset"                         => b"\xE2\x8B\x91",           // double superset, U+22D1
+    b"supset"                         => b"\xE2\x8A\x83",           // superset of, U+2283
+    b"supseteq"                       => b"\xE2\x8A\x87",           // superset of or equal to, U+2287
+    b"supseteqq"                      => b"\xE2\xAB\x86",           // superset of above equals sign, U+2AC6
+    b"supsetneq"                      => b"\xE2\x8A\x8B",           // superset of with not equal to, U+228B
+    b"supsetneqq"                     => b"\xE2\xAB\x8C",           // superset of above not equal to, U+2ACC
+    b"supsim"                         => b"\xE2\xAB\x88",           // superset of above tilde operator, U+2AC8
+    b"supsub"                         => b"\xE2\xAB\x94",           // superset above subset, U+2AD4
+    b"supsup"                         => b"\xE2\xAB\x96",           // superset above superset, U+2AD6
+    b"swarhk"                         => b"\xE2\xA4\xA6",           // south west arrow with hook, U+2926
+    b"swArr"                          => b"\xE2\x87\x99",           // south west double arrow, U+21D9
+    b"swarr"                          => b"\xE2\x86\x99",           // south west arrow, U+2199
+    b"swarrow"                        => b"\xE2\x86\x99",           // south west arrow, U+2199
+    b"swnwar"                         => b"\xE2\xA4\xAA",           // south west arrow and north west arrow, U+292A
+    b"Tab"                            => b"\x09",                   // <control>, U+0009
+    b"target"                         => b"\xE2\x8C\x96",           // position indicator, U+2316
+    b"Tau"                            => b"\xCE\xA4",               // greek capital letter tau, U+03A4
+    b"tau"                            => b"\xCF\x84",               // greek small letter tau, U+03C4
+    b"tbrk"                           => b"\xE2\x8E\xB4",           // top square bracket, U+23B4
+    b"Tcaron"                         => b"\xC5\xA4",               // latin capital letter t with caron, U+0164
+    b"tcaron"                         => b"\xC5\xA5",               // latin small letter t with caron, U+0165
+    b"Tcedil"                         => b"\xC5\xA2",               // latin capital letter t with cedilla, U+0162
+    b"tcedil"                         => b"\xC5\xA3",               // latin small letter t with cedilla, U+0163
+    b"Tcy"                            => b"\xD0\xA2",               // cyrillic capital letter te, U+0422
+    b"tcy"                            => b"\xD1\x82",               // cyrillic small letter te, U+0442
+    b"tdot"                           => b"\xE2\x83\x9B",           // combining three dots above, U+20DB
+    b"telrec"                         => b"\xE2\x8C\x95",           // telephone recorder, U+2315
+    b"Tfr"                            => b"\xF0\x9D\x94\x97",       // mathematical fraktur capital t, U+1D517
+    b"tfr"                            => b"\xF0\x9D\x94\xB1",       // mathematical fraktur small t, U+1D531
+    b"there4"                         => b"\xE2\x88\xB4",           // therefore, U+2234
+    b"Therefore"                      => b"\xE2\x88\xB4",           // therefore, U+2234
+    b"therefore"                      => b"\xE2\x88\xB4",           // therefore, U+2234
+    b"Theta"                          => b"\xCE\x98",               // greek capital letter theta, U+0398
+    b"theta"                          => b"\xCE\xB8",               // greek small letter theta, U+03B8
+    b"thetasym"                       => b"\xCF\x91",               // greek theta symbol, U+03D1
+    b"thetav"                         => b"\xCF\x91",               // greek theta symbol, U+03D1
+    b"thickapprox"                    => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"thicksim"                       => b"\xE2\x88\xBC",           // tilde operator, U+223C
+    b"ThickSpace"                     => b"\xE2\x81\x9F\xE2\x80\x8A",// medium mathematical space, U+205F + hair space, U+200A
+    b"ThinSpace"                      => b"\xE2\x80\x89",           // thin space, U+2009
+    b"thkap"                          => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"thksim"                         => b"\xE2\x88\xBC",           // tilde operator, U+223C
+    b"Tilde"                          => b"\xE2\x88\xBC",           // tilde operator, U+223C
+    b"TildeEqual"                     => b"\xE2\x89\x83",           // asymptotically equal to, U+2243
+    b"TildeFullEqual"                 => b"\xE2\x89\x85",           // approximately equal to, U+2245
+    b"TildeTilde"                     => b"\xE2\x89\x88",           // almost equal to, U+2248
+    b"timesb"                         => b"\xE2\x8A\xA0",           // squared times, U+22A0
+    b"timesbar"                       => b"\xE2\xA8\xB1",           // multiplication sign with underbar, U+2A31
+    b"timesd"                         => b"\xE2\xA8\xB0",           // multiplication sign with dot above, U+2A30
+    b"tint"                           => b"\xE2\x88\xAD",           // triple integral, U+222D
+    b"toea"                           => b"\xE2\xA4\xA8",           // north east arrow and south east arrow, U+2928
+    b"top"                            => b"\xE2\x8A\xA4",           // down tack, U+22A4
+    b"topbot"                         => b"\xE2\x8C\xB6",           // apl functional symbol i-beam, U+2336
+    b"topcir"                         => b"\xE2\xAB\xB1",           // down tack with circle below, U+2AF1
+    b"Topf"                           => b"\xF0\x9D\x95\x8B",       // mathematical double-struck capital t, U+1D54B
+    b"topf"                           => b"\xF0\x9D\x95\xA5",       // mathematical double-struck small t, U+1D565
+    b"topfork"                        => b"\xE2\xAB\x9A",           // pitchfork with tee top, U+2ADA
+    b"tosa"                           => b"\xE2\xA4\xA9",           // south east arrow and south west arrow, U+2929
+    b"tprime"                         => b"\xE2\x80\xB4",           // triple prime, U+2034
+    b"TRADE"                          => b"\xE2\x84\xA2",           // trade mark sign, U+2122
+    b"trade"                          => b"\xE2\x84\xA2",           // trade mark sign, U+2122
+    b"triangle"                       => b"\xE2\x96\xB5",           // white up-pointing small triangle, U+25B5
+    b"triangledown"                   => b"\xE2\x96\xBF",           // white down-pointing small triangle, U+25BF
+    b"triangleleft"                   => b"\xE2\x97\x83",           // white left-pointing small triangle, U+25C3
+    b"trianglelefteq"                 => b"\xE2\x8A\xB4",           // normal subgroup of or equal to, U+22B4
+    b"triangleq"                      => b"\xE2\x89\x9C",           // delta equal to, U+225C
+    b"triangleright"                  => b"\xE2\x96\xB9",           // white right-pointing small triangle, U+25B9
+    b"trianglerighteq"                => b"\xE2\x8A\xB5",           // contains as normal subgroup or equal to, U+22B5
+    b"tridot"                         => b"\xE2\x97\xAC",           // white up-pointing triangle with dot, U+25EC
+    b"trie"                           => b"\xE2\x89\x9C",           // delta equal to, U+225C
+    b"triminus"                       => b"\xE2\xA8\xBA",           // minus sign in triangle, U+2A3A
+    b"TripleDot"                      => b"\xE2\x83\x9B",           // combining three dots above, U+20DB
+    b"triplus"                        => b"\xE2\xA8\xB9",           // plus sign in triangle, U+2A39
+    b"trisb"                          => b"\xE2\xA7\x8D",           // triangle with serifs at bottom, U+29CD
+    b"tritime"                        => b"\xE2\xA8\xBB",           // multiplication sign in triangle, U+2A3B
+    b"trpezium"                       => b"\xE2\x8F\xA2",           // white trapezium, U+23E2
+    b"Tscr"                           => b"\xF0\x9D\x92\xAF",       // mathematical script capital t, U+1D4AF
+    b"tscr"                           => b"\xF0\x9D\x93\x89",       // mathematical script small t, U+1D4C9
+    b"TScy"                           => b"\xD0\xA6",               // cyrillic capital letter tse, U+0426
+    b"tscy"                           => b"\xD1\x86",               // cyrillic small letter tse, U+0446
+    b"TSHcy"                          => b"\xD0\x8B",               // cyrillic capital letter tshe, U+040B
+    b"tshcy"                          => b"\xD1\x9B",               // cyrillic small letter tshe, U+045B
+    b"Tstrok"                         => b"\xC5\xA6",               // latin capital letter t with stroke, U+0166
+    b"tstrok"                         => b"\xC5\xA7",               // latin small letter t with stroke, U+0167
+    b"twixt"                          => b"\xE2\x89\xAC",           // between, U+226C
+    b"twoheadleftarrow"               => b"\xE2\x86\x9E",           // leftwards two headed arrow, U+219E
+    b"twoheadrightarrow"              => b"\xE2\x86\xA0",           // rightwards two headed arrow, U+21A0
+    b"Uarr"                           => b"\xE2\x86\x9F",           // upwards two headed arrow, U+219F
+    b"uArr"                           => b"\xE2\x87\x91",           // upwards double arrow, U+21D1
+    b"uarr"                           => b"\xE2\x86\x91",           // upwards arrow, U+2191
+    b"Uarrocir"                       => b"\xE2\xA5\x89",           // upwards two-headed arrow from small circle, U+2949
+    b"Ubrcy"                          => b"\xD0\x8E",               // cyrillic capital letter short u, U+040E
+    b"ubrcy"                          => b"\xD1\x9E",               // cyrillic small letter short u, U+045E
+    b"Ubreve"                         => b"\xC5\xAC",               // latin capital letter u with breve, U+016C
+    b"ubreve"                         => b"\xC5\xAD",               // latin small letter u with breve, U+016D
+    b"Ucy"                            => b"\xD0\xA3",               // cyrillic capital letter u, U+0423
+    b"ucy"                            => b"\xD1\x83",               // cyrillic small letter u, U+0443
+    b"udarr"                          => b"\xE2\x87\x85",           // upwards arrow leftwards of downwards arrow, U+21C5
+    b"Udblac"                         => b"\xC5\xB0",               // latin capital letter u with double acute, U+0170
+    b"udblac"                         => b"\xC5\xB1",               // latin small letter u with double acute, U+0171
+    b"udhar"                          => b"\xE2\xA5\xAE",           // upwards harpoon with barb left beside downwards harpoon with barb right, U+296E
+    b"ufisht"                         => b"\xE2\xA5\xBE",           // up fish tail, U+297E
+    b"Ufr"                            => b"\xF0\x9D\x94\x98",       // mathematical fraktur capital u, U+1D518
+    b"ufr"                            => b"\xF0\x9D\x94\xB2",       // mathematical fraktur small u, U+1D532
+    b"uHar"                           => b"\xE2\xA5\xA3",           // upwards harpoon with barb left beside upwards harpoon with barb right, U+2963
+    b"uharl"                          => b"\xE2\x86\xBF",           // upwards harpoon with barb leftwards, U+21BF
+    b"uharr"                          => b"\xE2\x86\xBE",           // upwards harpoon with barb rightwards, U+21BE
+    b"uhblk"                          => b"\xE2\x96\x80",           // upper half block, U+2580
+    b"ulcorn"                         => b"\xE2\x8C\x9C",           // top left corner, U+231C
+    b"ulcorner"                       => b"\xE2\x8C\x9C",           // top left corner, U+231C
+    b"ulcrop"                         => b"\xE2\x8C\x8F",           // top left crop, U+230F
+    b"ultri"                          => b"\xE2\x97\xB8",           // upper left triangle, U+25F8
+    b"Umacr"                          => b"\xC5\xAA",               // latin capital letter u with macron, U+016A
+    b"umacr"                          => b"\xC5\xAB",               // latin small letter u with macron, U+016B
+    b"UnderBar"                       => b"\x5F",                   // low line, U+005F
+    b"UnderBrace"                     => b"\xE2\x8F\x9F",           // bottom curly bracket, U+23DF
+    b"UnderBracket"                   => b"\xE2\x8E\xB5",           // bottom square bracket, U+23B5
+    b"UnderParenthesis"               => b"\xE2\x8F\x9D",           // bottom parenthesis, U+23DD
+    b"Union"                          => b"\xE2\x8B\x83",           // n-ary union, U+22C3
+    b"UnionPlus"                      => b"\xE2\x8A\x8E",           // multiset union, U+228E
+    b"Uogon"                          => b"\xC5\xB2",               // latin capital letter u with ogonek, U+0172
+    b"uogon"                          => b"\xC5\xB3",               // latin small letter u with ogonek, U+0173
+    b"Uopf"                           => b"\xF0\x9D\x95\x8C",       // mathematical double-struck capital u, U+1D54C
+    b"uopf"                           => b"\xF0\x9D\x95\xA6",       // mathematical double-struck small u, U+1D566
+    b"UpArrow"                        => b"\xE2\x86\x91",           // upwards arrow, U+2191
+    b"Uparrow"                        => b"\xE2\x87\x91",           // upwards double arrow, U+21D1
+    b"uparrow"                        => b"\xE2\x86\x91",           // upwards arrow, U+2191
+    b"UpArrowBar"                     => b"\xE2\xA4\x92",           // upwards arrow to bar, U+2912
+    b"UpArrowDownArrow"               => b"\xE2\x87\x85",           // upwards arrow leftwards of downwards arrow, U+21C5
+    b"UpDownArrow"                    => b"\xE2\x86\x95",           // up down arrow, U+2195
+    b"Updownarrow"                    => b"\xE2\x87\x95",           // up down double arrow, U+21D5
+    b"updownarrow"                    => b"\xE2\x86\x95",           // up down arrow, U+2195
+    b"UpEquilibrium"                  => b"\xE2\xA5\xAE",           // upwards harpoon with barb left beside downwards harpoon with barb right, U+296E
+    b"upharpoonleft"                  => b"\xE2\x86\xBF",           // upwards harpoon with barb leftwards, U+21BF
+    b"upharpoonright"                 => b"\xE2\x86\xBE",           // upwards harpoon with barb rightwards, U+21BE
+    b"uplus"                          => b"\xE2\x8A\x8E",           // multiset union, U+228E
+    b"UpperLeftArrow"                 => b"\xE2\x86\x96",           // north west arrow, U+2196
+    b"UpperRightArrow"                => b"\xE2\x86\x97",           // north east arrow, U+2197
+    b"Upsi"                           => b"\xCF\x92",               // greek upsilon with hook symbol, U+03D2
+    b"upsi"                           => b"\xCF\x85",               // greek small letter upsilon, U+03C5
+    b"upsih"                          => b"\xCF\x92",               // greek upsilon with hook symbol, U+03D2
+    b"Upsilon"                        => b"\xCE\xA5",               // greek capital letter upsilon, U+03A5
+    b"upsilon"                        => b"\xCF\x85",               // greek small letter upsilon, U+03C5
+    b"UpTee"                          => b"\xE2\x8A\xA5",           // up tack, U+22A5
+    b"UpTeeArrow"                     => b"\xE2\x86\xA5",           // upwards arrow from bar, U+21A5
+    b"upuparrows"                     => b"\xE2\x87\x88",           // upwards paired arrows, U+21C8
+    b"urcorn"                         => b"\xE2\x8C\x9D",           // top right corner, U+231D
+    b"urcorner"                       => b"\xE2\x8C\x9D",           // top right corner, U+231D
+    b"urcrop"                         => b"\xE2\x8C\x8E",           // top right crop, U+230E
+    b"Uring"                          => b"\xC5\xAE",               // latin capital letter u with ring above, U+016E
+    b"uring"                          => b"\xC5\xAF",               // latin small letter u with ring above, U+016F
+    b"urtri"                          => b"\xE2\x97\xB9",           // upper right triangle, U+25F9
+    b"Uscr"                           => b"\xF0\x9D\x92\xB0",       // mathematical script capital u, U+1D4B0
+    b"uscr"                           => b"\xF0\x9D\x93\x8A",       // mathematical script small u, U+1D4CA
+    b"utdot"                          => b"\xE2\x8B\xB0",           // up right diagonal ellipsis, U+22F0
+    b"Utilde"                         => b"\xC5\xA8",               // latin capital letter u with tilde, U+0168
+    b"utilde"                         => b"\xC5\xA9",               // latin small letter u with tilde, U+0169
+    b"utri"                           => b"\xE2\x96\xB5",           // white up-pointing small triangle, U+25B5
+    b"utrif"                          => b"\xE2\x96\xB4",           // black up-pointing small triangle, U+25B4
+    b"uuarr"                          => b"\xE2\x87\x88",           // upwards paired arrows, U+21C8
+    b"uwangle"                        => b"\xE2\xA6\xA7",           // oblique angle opening down, U+29A7
+    b"vangrt"                         => b"\xE2\xA6\x9C",           // right angle variant with square, U+299C
+    b"varepsilon"                     => b"\xCF\xB5",               // greek lunate epsilon symbol, U+03F5
+    b"varkappa"                       => b"\xCF\xB0",               // greek kappa symbol, U+03F0
+    b"varnothing"                     => b"\xE2\x88\x85",           // empty set, U+2205
+    b"varphi"                         => b"\xCF\x95",               // greek phi symbol, U+03D5
+    b"varpi"                          => b"\xCF\x96",               // greek pi symbol, U+03D6
+    b"varpropto"                      => b"\xE2\x88\x9D",           // proportional to, U+221D
+    b"vArr"                           => b"\xE2\x87\x95",           // up down double arrow, U+21D5
+    b"varr"                           => b"\xE2\x86\x95",           // up down arrow, U+2195
+    b"varrho"                         => b"\xCF\xB1",               // greek rho symbol, U+03F1
+    b"varsigma"                       => b"\xCF\x82",               // greek small letter final sigma, U+03C2
+    b"varsubsetneq"                   => b"\xE2\x8A\x8A\xEF\xB8\x80",// subset of with not equal to, U+228A + variation selector-1, U+FE00
+    b"varsubsetneqq"                  => b"\xE2\xAB\x8B\xEF\xB8\x80",// subset of above not equal to, U+2ACB + variation selector-1, U+FE00
+    b"varsupsetneq"                   => b"\xE2\x8A\x8B\xEF\xB8\x80",// superset of with not equal to, U+228B + variation selector-1, U+FE00
+    b"varsupsetneqq"                  => b"\xE2\xAB\x8C\xEF\xB8\x80",// superset of above not equal to, U+2ACC + variation selector-1, U+FE00
+    b"vartheta"                       => b"\xCF\x91",               // greek theta symbol, U+03D1
+    b"vartriangleleft"                => b"\xE2\x8A\xB2",           // normal subgroup of, U+22B2
+    b"vartriangleright"               => b"\xE2\x8A\xB3",           // contains as normal subgroup, U+22B3
+    b"Vbar"                           => b"\xE2\xAB\xAB",           // double up tack, U+2AEB
+    b"vBar"                           => b"\xE2\xAB\xA8",           // short up tack with underbar, U+2AE8
+    b"vBarv"                          => b"\xE2\xAB\xA9",           // short up tack above short down tack, U+2AE9
+    b"Vcy"                            => b"\xD0\x92",               // cyrillic capital letter ve, U+0412
+    b"vcy"                            => b"\xD0\xB2",               // cyrillic small letter ve, U+0432
+    b"VDash"                          => b"\xE2\x8A\xAB",           // double vertical bar double right turnstile, U+22AB
+    b"Vdash"                          => b"\xE2\x8A\xA9",           // forces, U+22A9
+    b"vDash"                          => b"\xE2\x8A\xA8",           // true, U+22A8
+    b"vdash"                          => b"\xE2\x8A\xA2",           // right tack, U+22A2
+    b"Vdashl"                         => b"\xE2\xAB\xA6",           // long dash from left member of double vertical, U+2AE6
+    b"Vee"                            => b"\xE2\x8B\x81",           // n-ary logical or, U+22C1
+    b"vee"                            => b"\xE2\x88\xA8",           // logical or, U+2228
+    b"veebar"                         => b"\xE2\x8A\xBB",           // xor, U+22BB
+    b"veeeq"                          => b"\xE2\x89\x9A",           // equiangular to, U+225A
+    b"vellip"                         => b"\xE2\x8B\xAE",           // vertical ellipsis, U+22EE
+    b"Verbar"                         => b"\xE2\x80\x96",           // double vertical line, U+2016
+    b"verbar"                         => b"\x7C",                   // vertical line, U+007C
+    b"Vert"                           => b"\xE2\x80\x96",           // double vertical line, U+2016
+    b"vert"                           => b"\x7C",                   // vertical line, U+007C
+    b"VerticalBar"                    => b"\xE2\x88\xA3",           // divides, U+2223
+    b"VerticalLine"                   => b"\x7C",                   // vertical line, U+007C
+    b"VerticalSeparator"              => b"\xE2\x9D\x98",           // light vertical bar, U+2758
+    b"VerticalTilde"                  => b"\xE2\x89\x80",           // wreath product, U+2240
+    b"VeryThinSpace"                  => b"\xE2\x80\x8A",           // hair space, U+200A
+    b"Vfr"                            => b"\xF0\x9D\x94\x99",       // mathematical fraktur capital v, U+1D519
+    b"vfr"                            => b"\xF0\x9D\x94\xB3",       // mathematical fraktur small v, U+1D533
+    b"vltri"                          => b"\xE2\x8A\xB2",           // normal subgroup of, U+22B2
+    b"vnsub"                          => b"\xE2\x8A\x82\xE2\x83\x92",// subset of, U+2282 + combining long vertical line overlay, U+20D2
+    b"vnsup"                          => b"\xE2\x8A\x83\xE2\x83\x92",// superset of, U+2283 + combining long vertical line overlay, U+20D2
+    b"Vopf"                           => b"\xF0\x9D\x95\x8D",       // mathematical double-struck capital v, U+1D54D
+    b"vopf"                           => b"\xF0\x9D\x95\xA7",       // mathematical double-struck small v, U+1D567
+    b"vprop"                          => b"\xE2\x88\x9D",           // proportional to, U+221D
+    b"vrtri"                          => b"\xE2\x8A\xB3",           // contains as normal subgroup, U+22B3
+    b"Vscr"                           => b"\xF0\x9D\x92\xB1",       // mathematical script capital v, U+1D4B1
+    b"vscr"                           => b"\xF0\x9D\x93\x8B",       // mathematical script small v, U+1D4CB
+    b"vsubnE"                         => b"\xE2\xAB\x8B\xEF\xB8\x80",// subset of above not equal to, U+2ACB + variation selector-1, U+FE00
+    b"vsubne"                         => b"\xE2\x8A\x8A\xEF\xB8\x80",// subset of with not equal to, U+228A + variation selector-1, U+FE00
+    b"vsupnE"                         => b"\xE2\xAB\x8C\xEF\xB8\x80",// superset of above not equal to, U+2ACC + variation selector-1, U+FE00
+    b"vsupne"                         => b"\xE2\x8A\x8B\xEF\xB8\x80",// superset of with not equal to, U+228B + variation selector-1, U+FE00
+    b"Vvdash"                         => b"\xE2\x8A\xAA",           // triple vertical bar right turnstile, U+22AA
+    b"vzigzag"                        => b"\xE2\xA6\x9A",           // vertical zigzag line, U+299A
+    b"Wcirc"                          => b"\xC5\xB4",               // latin capital letter w with circumflex, U+0174
+    b"wcirc"                          => b"\xC5\xB5",               // latin small letter w with circumflex, U+0175
+    b"wedbar"                         => b"\xE2\xA9\x9F",           // logical and with underbar, U+2A5F
+    b"Wedge"                          => b"\xE2\x8B\x80",           // n-ary logical and, U+22C0
+    b"wedge"                          => b"\xE2\x88\xA7",           // logical and, U+2227
+    b"wedgeq"                         => b"\xE2\x89\x99",           // estimates, U+2259
+    b"weierp"                         => b"\xE2\x84\x98",           // script capital p, U+2118
+    b"Wfr"                            => b"\xF0\x9D\x94\x9A",       // mathematical fraktur capital w, U+1D51A
+    b"wfr"                            => b"\xF0\x9D\x94\xB4",       // mathematical fraktur small w, U+1D534
+    b"Wopf"                           => b"\xF0\x9D\x95\x8E",       // mathematical double-struck capital w, U+1D54E
+    b"wopf"                           => b"\xF0\x9D\x95\xA8",       // mathematical double-struck small w, U+1D568
+    b"wp"                             => b"\xE2\x84\x98",           // script capital p, U+2118
+    b"wr"                             => b"\xE2\x89\x80",           // wreath product, U+2240
+    b"wreath"                         => b"\xE2\x89\x80",           // wreath product, U+2240
+    b"Wscr"                           => b"\xF0\x9D\x92\xB2",       // mathematical script capital w, U+1D4B2
+    b"wscr"                           => b"\xF0\x9D\x93\x8C",       // mathematical script small w, U+1D4CC
+    b"xcap"                           => b"\xE2\x8B\x82",           // n-ary intersection, U+22C2
+    b"xcirc"                          => b"\xE2\x97\xAF",           // large circle, U+25EF
+    b"xcup"                           => b"\xE2\x8B\x83",           // n-ary union, U+22C3
+    b"xdtri"                          => b"\xE2\x96\xBD",           // white down-pointing triangle, U+25BD
+    b"Xfr"                            => b"\xF0\x9D\x94\x9B",       // mathematical fraktur capital x, U+1D51B
+    b"xfr"                            => b"\xF0\x9D\x94\xB5",       // mathematical fraktur small x, U+1D535
+    b"xhArr"                          => b"\xE2\x9F\xBA",           // long left right double arrow, U+27FA
+    b"xharr"                          => b"\xE2\x9F\xB7",           // long left right arrow, U+27F7
+    b"Xi"                             => b"\xCE\x9E",               // greek capital letter xi, U+039E
+    b"xi"                             => b"\xCE\xBE",               // greek small letter xi, U+03BE
+    b"xlArr"                          => b"\xE2\x9F\xB8",           // long leftwards double arrow, U+27F8
+    b"xlarr"                          => b"\xE2\x9F\xB5",           // long leftwards arrow, U+27F5
+    b"xmap"                           => b"\xE2\x9F\xBC",           // long rightwards arrow from bar, U+27FC
+    b"xnis"                           => b"\xE2\x8B\xBB",           // contains with vertical bar at end of horizontal stroke, U+22FB
+    b"xodot"                          => b"\xE2\xA8\x80",           // n-ary circled dot operator, U+2A00
+    b"Xopf"                           => b"\xF0\x9D\x95\x8F",       // mathematical double-struck capital x, U+1D54F
+    b"xopf"                           => b"\xF0\x9D\x95\xA9",       // mathematical double-struck small x, U+1D569
+    b"xoplus"                         => b"\xE2\xA8\x81",           // n-ary circled plus operator, U+2A01
+    b"xotime"                         => b"\xE2\xA8\x82",           // n-ary circled times operator, U+2A02
+    b"xrArr"                          => b"\xE2\x9F\xB9",           // long rightwards double arrow, U+27F9
+    b"xrarr"                          => b"\xE2\x9F\xB6",           // long rightwards arrow, U+27F6
+    b"Xscr"                           => b"\xF0\x9D\x92\xB3",       // mathematical script capital x, U+1D4B3
+    b"xscr"                           => b"\xF0\x9D\x93\x8D",       // mathematical script small x, U+1D4CD
+    b"xsqcup"                         => b"\xE2\xA8\x86",           // n-ary square union operator, U+2A06
+    b"xuplus"                         => b"\xE2\xA8\x84",           // n-ary union operator with plus, U+2A04
+    b"xutri"                          => b"\xE2\x96\xB3",           // white up-pointing triangle, U+25B3
+    b"xvee"                           => b"\xE2\x8B\x81",           // n-ary logical or, U+22C1
+    b"xwedge"                         => b"\xE2\x8B\x80",           // n-ary logical and, U+22C0
+    b"YAcy"                           => b"\xD0\xAF",               // cyrillic capital letter ya, U+042F
+    b"yacy"                           => b"\xD1\x8F",               // cyrillic small letter ya, U+044F
+    b"Ycirc"                          => b"\xC5\xB6",               // latin capital letter y with circumflex, U+0176
+    b"ycirc"                          => b"\xC5\xB7",               // latin small letter y with circumflex, U+0177
+    b"Ycy"                            => b"\xD0\xAB",               // cyrillic capital letter yeru, U+042B
+    b"ycy"                            => b"\xD1\x8B",               // cyrillic small letter yeru, U+044B
+    b"Yfr"                            => b"\xF0\x9D\x94\x9C",       // mathematical fraktur capital y, U+1D51C
+    b"yfr"                            => b"\xF0\x9D\x94\xB6",       // mathematical fraktur small y, U+1D536
+    b"YIcy"                           => b"\xD0\x87",               // cyrillic capital letter yi, U+0407
+    b"yicy"                           => b"\xD1\x97",               // cyrillic small letter yi, U+0457
+    b"Yopf"                           => b"\xF0\x9D\x95\x90",       // mathematical double-struck capital y, U+1D550
+    b"yopf"                           => b"\xF0\x9D\x95\xAA",       // mathematical double-struck small y, U+1D56A
+    b"Yscr"                           => b"\xF0\x9D\x92\xB4",       // mathematical script capital y, U+1D4B4
+    b"yscr"                           => b"\xF0\x9D\x93\x8E",       // mathematical script small y, U+1D4CE
+    b"YUcy"                           => b"\xD0\xAE",               // cyrillic capital letter yu, U+042E
+    b"yucy"                           => b"\xD1\x8E",               // cyrillic small letter yu, U+044E
+    b"Zacute"                         => b"\xC5\xB9",               // latin capital letter z with acute, U+0179
+    b"zacute"                         => b"\xC5\xBA",               // latin small letter z with acute, U+017A
+    b"Zcaron"                         => b"\xC5\xBD",               // latin capital letter z with caron, U+017D
+    b"zcaron"                         => b"\xC5\xBE",               // latin small letter z with caron, U+017E
+    b"Zcy"                            => b"\xD0\x97",               // cyrillic capital letter ze, U+0417
+    b"zcy"                            => b"\xD0\xB7",               // cyrillic small letter ze, U+0437
+    b"Zdot"                           => b"\xC5\xBB",               // latin capital letter z with dot above, U+017B
+    b"zdot"                           => b"\xC5\xBC",               // latin small letter z with dot above, U+017C
+    b"zeetrf"                         => b"\xE2\x84\xA8",           // black-letter capital z, U+2128
+    b"ZeroWidthSpace"                 => b"\xE2\x80\x8B",           // zero width space, U+200B
+    b"Zeta"                           => b"\xCE\x96",               // greek capital letter zeta, U+0396
+    b"zeta"                           => b"\xCE\xB6",               // greek small letter zeta, U+03B6
+    b"Zfr"                            => b"\xE2\x84\xA8",           // black-letter capital z, U+2128
+    b"zfr"                            => b"\xF0\x9D\x94\xB7",       // mathematical fraktur small z, U+1D537
+    b"ZHcy"                           => b"\xD0\x96",               // cyrillic capital letter zhe, U+0416
+    b"zhcy"                           => b"\xD0\xB6",               // cyrillic small letter zhe, U+0436
+    b"zigrarr"                        => b"\xE2\x87\x9D",           // rightwards squiggle arrow, U+21DD
+    b"Zopf"                           => b"\xE2\x84\xA4",           // double-struck capital z, U+2124
+    b"zopf"                           => b"\xF0\x9D\x95\xAB",       // mathematical double-struck small z, U+1D56B
+    b"Zscr"                           => b"\xF0\x9D\x92\xB5",       // mathematical script capital z, U+1D4B5
+    b"zscr"                           => b"\xF0\x9D\x93\x8F",       // mathematical script small z, U+1D4CF
+};
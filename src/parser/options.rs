@@ -0,0 +1,1190 @@
+//! Runtime parsing options.
+//!
+//! This module provides the `ParserOptions` builder, used with `Document::new_with_options()`
+//! to select the parsing behaviors at runtime. The default value of each option comes from the
+//! corresponding cargo feature, so `Document::new()` keeps behaving as configured at compile time.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::defs::ParseXmlError;
+
+/// Parsing behaviors that can be selected at runtime.
+///
+/// Each option defaults to the state of the cargo feature of the same name:
+///
+/// | Option                | Cargo feature         | Effect when enabled                                           |
+/// |-----------------------|-----------------------|---------------------------------------------------------------|
+/// | `namespace_removal`   | `namespace_removal`   | Removes the namespace prefix from tag and attribute names     |
+/// | `parse_escapes`       | `parse_escapes`       | Translates the escape sequences (`&..;`) of `PCData` nodes    |
+/// | `trim_pcdata`         | `trim_pcdata`         | Trims the whitespaces at beginning and end of `PCData` nodes  |
+/// | `keep_ws_only_pcdata` | `keep_ws_only_pcdata` | Keeps the `PCData` nodes composed of whitespace only          |
+///
+/// The `whitespace_mode` option sets both `trim_pcdata` and `keep_ws_only_pcdata` from a
+/// `WhitespaceMode`. Inside the elements listed by the `preserve_whitespace_tags` option,
+/// the whitespace is always preserved.
+///
+/// The `keep_comments`, `keep_processing_instructions`, `keep_cdata`, `keep_entity_refs`, `keep_raw_text`, `keep_element_spans`, `html_leniency`,
+/// `html_void_elements`, `html_raw_text`, `lowercase_names`, `legacy_encodings` and `index_attributes` options,
+/// disabled by default,
+/// have no associated cargo feature, as the `decode_entities` option, enabled by default. When `namespace_removal` is enabled, a
+/// `PrefixFilter` selects which prefixes are removed (all of them by default).
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::parser::ParserOptions;
+///
+/// let options = ParserOptions::new()
+///     .namespace_removal(false)
+///     .trim_pcdata(true);
+///
+/// let xml_data = b"<x:root xmlns:x=\"urn:x\"><x:a>  Text  </x:a></x:root>".to_vec();
+/// let document = Document::new_with_options(xml_data, options).unwrap();
+/// let root = document.root().unwrap();
+///
+/// assert_eq!(root.tag_name(), "x:root");
+/// assert_eq!(root.first_child().unwrap().first_child().unwrap().text(), Some("Text"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParserOptions {
+    pub(crate) namespace_removal: bool,
+    pub(crate) parse_escapes: bool,
+    pub(crate) decode_entities: bool,
+    pub(crate) trim_pcdata: bool,
+    pub(crate) keep_ws_only_pcdata: bool,
+    pub(crate) keep_comments: bool,
+    pub(crate) keep_processing_instructions: bool,
+    pub(crate) keep_cdata: bool,
+    pub(crate) keep_entity_refs: bool,
+    pub(crate) keep_raw_text: bool,
+    pub(crate) keep_element_spans: bool,
+    pub(crate) html_leniency: bool,
+    pub(crate) html_void_elements: bool,
+    pub(crate) html_raw_text: bool,
+    pub(crate) lowercase_names: bool,
+    pub(crate) legacy_encodings: bool,
+    pub(crate) index_attributes: bool,
+    pub(crate) invalid_utf8: InvalidUtf8,
+    pub(crate) max_entity_expansions: Option<usize>,
+    pub(crate) max_entity_expansion_size: Option<usize>,
+    pub(crate) max_nodes: Option<usize>,
+    pub(crate) max_attributes: Option<usize>,
+    pub(crate) max_input_len: Option<usize>,
+    pub(crate) prefix_filter: PrefixFilter,
+    pub(crate) entities: HashMap<String, String>,
+    pub(crate) preserve_whitespace_tags: Vec<String>,
+    pub(crate) progress: Option<ProgressCallback>,
+}
+
+/// The elements inside which the whitespace of the `PCData` nodes is preserved by default.
+pub const DEFAULT_PRESERVE_WHITESPACE_TAGS: [&str; 4] = ["pre", "textarea", "script", "style"];
+
+/// Selects the namespace prefixes removed by the `namespace_removal` option.
+///
+/// The prefixes are compared as-is, without the colon. The `xmlns` prefix of namespace
+/// declarations is handled like any other prefix.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::parser::{ParserOptions, PrefixFilter};
+///
+/// let options = ParserOptions::new()
+///     .namespace_removal(true)
+///     .namespace_prefix_filter(PrefixFilter::Keep(vec!["epub".into()]));
+///
+/// let xml_data = b"<h:p xmlns:h=\"urn:h\" epub:type=\"note\"/>".to_vec();
+/// let document = Document::new_with_options(xml_data, options).unwrap();
+/// let p = document.root().unwrap();
+///
+/// assert_eq!(p.tag_name(), "p");
+/// assert_eq!(p.get_attribute("epub:type"), Some("note"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PrefixFilter {
+    /// All prefixes are removed.
+    #[default]
+    All,
+    /// All prefixes are removed, except the listed ones.
+    Keep(Vec<String>),
+    /// Only the listed prefixes are removed.
+    Only(Vec<String>),
+}
+
+/// Selects how the `invalid_utf8` option handles the content that is not valid UTF-8.
+///
+/// The policy is applied once, before parsing, to the content detected as UTF-8.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::parser::{InvalidUtf8, ParserOptions};
+///
+/// let options = ParserOptions::new().invalid_utf8(InvalidUtf8::Replace);
+///
+/// let xml_data = b"<a>caf\xE9</a>".to_vec();
+/// let document = Document::new_with_options(xml_data, options).unwrap();
+///
+/// assert_eq!(document.root().unwrap().first_child().unwrap().text(), Some("caf\u{FFFD}"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvalidUtf8 {
+    /// The bytes are kept as-is. The string accessors return "non valid utf-8" for the
+    /// strings containing invalid sequences, while the byte slice accessors return the bytes.
+    #[default]
+    PassThrough,
+    /// Parsing fails with `ParseXmlError::InvalidEncoding`.
+    Reject,
+    /// Each invalid sequence is replaced with the U+FFFD replacement character. The content
+    /// is then copied into an owned buffer, as the replacement may be longer than the sequence.
+    Replace,
+}
+
+/// Selects how the `whitespace_mode` option handles the whitespace of the `PCData` nodes.
+///
+/// Each mode is a combination of the `trim_pcdata` and `keep_ws_only_pcdata` options, so a
+/// single application can select the handling appropriate to each parsed document.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::parser::{ParserOptions, WhitespaceMode};
+///
+/// let xml_data = b"<p> <b>a</b>  b </p>";
+///
+/// let options = ParserOptions::new().whitespace_mode(WhitespaceMode::Preserve);
+/// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+/// assert_eq!(document.root().unwrap().text_content(), " a  b ");
+///
+/// let options = ParserOptions::new().whitespace_mode(WhitespaceMode::Trim);
+/// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+/// assert_eq!(document.root().unwrap().text_content(), "ab");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitespaceMode {
+    /// The text is kept as-is, including the `PCData` nodes composed of whitespace only.
+    Preserve,
+    /// The whitespaces at beginning and end of `PCData` nodes are removed, and the nodes
+    /// composed of whitespace only are dropped.
+    Trim,
+    /// The `PCData` nodes composed of whitespace only are dropped, the other ones are kept
+    /// as-is.
+    DropWsOnly,
+}
+
+/// The progress of a parsing, reported to the callback registered with the `progress`
+/// option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseProgress {
+    /// The byte offset reached in the XML content
+    pub offset: usize,
+    /// The length of the XML content, in bytes
+    pub total: usize,
+    /// The number of nodes created so far
+    pub nodes: usize,
+}
+
+impl ParseProgress {
+    /// Returns the parsed part of the XML content, from 0.0 to 1.0.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.offset as f32 / self.total as f32
+        }
+    }
+}
+
+/// The callback of the `progress` option, with its reporting interval.
+#[derive(Clone)]
+pub(crate) struct ProgressCallback {
+    pub(crate) interval: usize,
+    pub(crate) callback: Arc<dyn Fn(ParseProgress) + Send + Sync>,
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressCallback")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+// Two callbacks are equal if they are the same closure
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        self.interval == other.interval && Arc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
+impl Eq for ProgressCallback {}
+
+impl PrefixFilter {
+    /// Returns true if the given prefix is to be removed.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix, without the colon
+    #[must_use]
+    pub fn removes(&self, prefix: &[u8]) -> bool {
+        let listed = |prefixes: &[String]| prefixes.iter().any(|p| p.as_bytes() == prefix);
+
+        match self {
+            PrefixFilter::All => true,
+            PrefixFilter::Keep(prefixes) => !listed(prefixes),
+            PrefixFilter::Only(prefixes) => listed(prefixes),
+        }
+    }
+}
+
+impl Default for ParserOptions {
+    #[inline]
+    fn default() -> Self {
+        ParserOptions {
+            namespace_removal: cfg!(feature = "namespace_removal"),
+            parse_escapes: cfg!(feature = "parse_escapes"),
+            decode_entities: true,
+            trim_pcdata: cfg!(feature = "trim_pcdata"),
+            keep_ws_only_pcdata: cfg!(feature = "keep_ws_only_pcdata"),
+            keep_comments: false,
+            keep_processing_instructions: false,
+            keep_cdata: false,
+            keep_entity_refs: false,
+            keep_raw_text: false,
+            keep_element_spans: false,
+            html_leniency: false,
+            html_void_elements: false,
+            html_raw_text: false,
+            lowercase_names: false,
+            legacy_encodings: false,
+            index_attributes: false,
+            invalid_utf8: InvalidUtf8::PassThrough,
+            max_entity_expansions: None,
+            max_entity_expansion_size: None,
+            max_nodes: None,
+            max_attributes: None,
+            max_input_len: None,
+            prefix_filter: PrefixFilter::All,
+            entities: HashMap::new(),
+            preserve_whitespace_tags: DEFAULT_PRESERVE_WHITESPACE_TAGS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            progress: None,
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Creates the options, with the defaults coming from the enabled cargo features.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects if the namespace prefix is removed from tag and attribute names.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to remove the namespace prefixes
+    #[inline]
+    pub fn namespace_removal(mut self, enabled: bool) -> Self {
+        self.namespace_removal = enabled;
+        self
+    }
+
+    /// Selects if the escape sequences (`&..;`) of `PCData` nodes are translated.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to translate the escape sequences
+    #[inline]
+    pub fn parse_escapes(mut self, enabled: bool) -> Self {
+        self.parse_escapes = enabled;
+        self
+    }
+
+    /// Selects if the escape sequences (`&..;`) are decoded at all, in `PCData` nodes and
+    /// attribute values.
+    ///
+    /// When disabled, the escape sequences are kept as-is in the text and attribute values of
+    /// this parse, whatever the `parse_escapes` option, as needed by round-trip and diff tools.
+    /// The serialization of the document then writes them back unchanged, as well as the `&`
+    /// of the text and attribute values later set through the mutable DOM methods. Enabled by
+    /// default.
+    ///
+    /// # Arguments
+    /// * `enabled` - False to keep all the escape sequences as-is
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<a title=\"&lt;b&gt;\">Fish &amp; Chips</a>";
+    ///
+    /// let options = ParserOptions::new().decode_entities(false);
+    /// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+    /// let a = document.root().unwrap();
+    ///
+    /// assert_eq!(a.get_attribute("title"), Some("&lt;b&gt;"));
+    /// assert_eq!(a.first_child().unwrap().text(), Some("Fish &amp; Chips"));
+    /// assert_eq!(document.to_xml().as_bytes(), xml_data);
+    ///
+    /// let document = Document::new(xml_data.to_vec()).unwrap();
+    /// assert_eq!(document.root().unwrap().get_attribute("title"), Some("<b>"));
+    /// ```
+    #[inline]
+    pub fn decode_entities(mut self, enabled: bool) -> Self {
+        self.decode_entities = enabled;
+        self
+    }
+
+    /// Selects if the whitespaces at beginning and end of `PCData` nodes are trimmed.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to trim the `PCData` nodes
+    #[inline]
+    pub fn trim_pcdata(mut self, enabled: bool) -> Self {
+        self.trim_pcdata = enabled;
+        self
+    }
+
+    /// Selects if the `PCData` nodes composed of whitespace only are kept.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the whitespace-only `PCData` nodes
+    #[inline]
+    pub fn keep_ws_only_pcdata(mut self, enabled: bool) -> Self {
+        self.keep_ws_only_pcdata = enabled;
+        self
+    }
+
+    /// Selects the handling of the whitespace of the `PCData` nodes, setting both the
+    /// `trim_pcdata` and `keep_ws_only_pcdata` options.
+    ///
+    /// # Arguments
+    /// * `mode` - The whitespace handling
+    #[inline]
+    pub fn whitespace_mode(mut self, mode: WhitespaceMode) -> Self {
+        self.trim_pcdata = mode == WhitespaceMode::Trim;
+        self.keep_ws_only_pcdata = mode == WhitespaceMode::Preserve;
+        self
+    }
+
+    /// Selects the elements inside which the whitespace of the `PCData` nodes is preserved.
+    ///
+    /// Inside these elements and their descendants, the `PCData` nodes are neither trimmed by
+    /// the `trim_pcdata` option, nor dropped when composed of whitespace only, so code
+    /// listings keep their layout. The names are compared without considering the ASCII case,
+    /// after the removal of the namespace prefix. Defaults to the
+    /// `DEFAULT_PRESERVE_WHITESPACE_TAGS`: `pre`, `textarea`, `script` and `style`. An empty
+    /// list gives the same whitespace handling to all elements.
+    ///
+    /// # Arguments
+    /// * `names` - The element names, replacing the current list
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::{ParserOptions, WhitespaceMode};
+    ///
+    /// let xml_data = b"<body><p>  Text  </p><pre>  let a = 1;\n  <b>let b = 2;</b>\n</pre></body>";
+    ///
+    /// let options = ParserOptions::new().whitespace_mode(WhitespaceMode::Trim);
+    /// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+    /// let body = document.root().unwrap();
+    /// assert_eq!(body.first_child().unwrap().text_content(), "Text");
+    /// assert_eq!(body.last_child().unwrap().text_content(), "  let a = 1;\n  let b = 2;\n");
+    ///
+    /// let options = ParserOptions::new()
+    ///     .whitespace_mode(WhitespaceMode::Trim)
+    ///     .preserve_whitespace_tags(["p"]);
+    /// let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+    /// let body = document.root().unwrap();
+    /// assert_eq!(body.first_child().unwrap().text_content(), "  Text  ");
+    /// assert_eq!(body.last_child().unwrap().text_content(), "let a = 1;let b = 2;");
+    /// ```
+    #[inline]
+    pub fn preserve_whitespace_tags<S>(mut self, names: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.preserve_whitespace_tags = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Selects if the comments located inside the root element are kept as
+    /// `NodeType::Comment` nodes. The comments located before the root element are
+    /// available through `Document::prolog_comments()`.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the comments
+    #[inline]
+    pub fn keep_comments(mut self, enabled: bool) -> Self {
+        self.keep_comments = enabled;
+        self
+    }
+
+    /// Selects if the processing instructions located inside the root element are kept as
+    /// `NodeType::ProcessingInstruction` nodes. The processing instructions located before
+    /// the root element are available through `Document::prolog_processing_instructions()`.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the processing instructions
+    #[inline]
+    pub fn keep_processing_instructions(mut self, enabled: bool) -> Self {
+        self.keep_processing_instructions = enabled;
+        self
+    }
+
+    /// Selects if the content of the CDATA sections located inside the root element is kept as
+    /// `NodeType::Text` nodes. The content is kept as-is, without entity translation or
+    /// trimming, and these nodes are flagged by `Node::is_cdata()`. Empty sections are ignored.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the CDATA sections
+    #[inline]
+    pub fn keep_cdata(mut self, enabled: bool) -> Self {
+        self.keep_cdata = enabled;
+        self
+    }
+
+    /// Selects if the named entity references (`&amp;`, `&nbsp;`, etc.) of the `PCData` nodes
+    /// are kept as `NodeType::EntityRef` nodes instead of being expanded in place.
+    ///
+    /// The text located around a reference becomes distinct text nodes, so the references
+    /// can be written back as-is by the serialization. The references of unknown entities
+    /// are kept as well, while the character references (`&#65;`) are still expanded.
+    /// `Node::entity_value()` returns the replacement text of the known entities. The entity
+    /// reference nodes are not part of the text of `Node::text_content()`.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the entity references as nodes
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<p>Fish &amp; Chips&copy;</p>".to_vec();
+    /// let options = ParserOptions::new().keep_entity_refs(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let nodes: Vec<_> = document.root().unwrap().children().collect();
+    ///
+    /// assert_eq!(nodes.len(), 4);
+    /// assert_eq!(nodes[0].text(), Some("Fish "));
+    /// assert_eq!(nodes[1].entity_name(), Some("amp"));
+    /// assert_eq!(nodes[1].entity_value(), Some("&"));
+    /// assert_eq!(nodes[3].entity_name(), Some("copy"));
+    /// assert_eq!(document.to_xml(), "<p>Fish &amp; Chips&copy;</p>");
+    /// ```
+    #[inline]
+    pub fn keep_entity_refs(mut self, enabled: bool) -> Self {
+        self.keep_entity_refs = enabled;
+        self
+    }
+
+    /// Selects if the original content of the text nodes is kept, to be returned by
+    /// `Node::raw_text()`.
+    ///
+    /// As the escape sequences and line endings of the text are translated in place, the
+    /// original content of the text nodes modified by the parsing is copied aside, before
+    /// their translation. The other text nodes need no copy.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the original content of the text nodes
+    #[inline]
+    pub fn keep_raw_text(mut self, enabled: bool) -> Self {
+        self.keep_raw_text = enabled;
+        self
+    }
+
+    /// Selects if the span of the elements in the XML content is recorded, to be returned by
+    /// `Node::span()` and `Node::inner_span()`.
+    ///
+    /// The span of an element goes from the `<` of its start tag to the `>` of its end tag,
+    /// or of its self-closing start tag. An element implicitly closed by another tag, as
+    /// when recovering from errors or with the `html_leniency` option, ends at that tag.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to record the span of the elements
+    #[inline]
+    pub fn keep_element_spans(mut self, enabled: bool) -> Self {
+        self.keep_element_spans = enabled;
+        self
+    }
+
+    /// Selects if the elements whose end tag is optional in HTML are implicitly closed.
+    ///
+    /// When enabled, the opening of an element closes the open element it cannot be nested
+    /// in (`<p>` or `<div>` closes a `p` element, `<li>` closes a `li` element, `<tr>`
+    /// closes a `td` element, etc.), and a closing tag also closes the elements with an
+    /// optional end tag still open inside the element it closes, instead of failing with a
+    /// mismatched closing tag error. Tag names are compared ignoring ASCII case.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to implicitly close the elements
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<body><p>One<p>Two<ul><li>A<li>B</ul></body>".to_vec();
+    /// let options = ParserOptions::new().html_leniency(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let body = document.root().unwrap();
+    ///
+    /// let names: Vec<_> = body.children().map(|n| n.tag_name().to_string()).collect();
+    /// assert_eq!(names, ["p", "p", "ul"]);
+    ///
+    /// let ul = body.children_by_name("ul").next().unwrap();
+    /// assert_eq!(ul.children().filter(|n| n.is("li")).count(), 2);
+    /// ```
+    #[inline]
+    pub fn html_leniency(mut self, enabled: bool) -> Self {
+        self.html_leniency = enabled;
+        self
+    }
+
+    /// Selects if the HTML void elements (`area`, `base`, `br`, `col`, `embed`, `hr`, `img`,
+    /// `input`, `link`, `meta`, `param`, `source`, `track` and `wbr`) are closed by the end of
+    /// their opening tag, even without the `/` of a self-closing tag.
+    ///
+    /// The end tags of these elements (`</br>`, etc.) are then ignored. Tag names are
+    /// compared ignoring ASCII case.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to close the void elements by the end of their opening tag
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<p>One<br>Two<img src='a.png'></p>".to_vec();
+    /// let options = ParserOptions::new().html_void_elements(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let p = document.root().unwrap();
+    ///
+    /// assert_eq!(p.children().count(), 4);
+    /// assert!(p.children().all(|n| !n.has_children()));
+    /// ```
+    #[inline]
+    pub fn html_void_elements(mut self, enabled: bool) -> Self {
+        self.html_void_elements = enabled;
+        self
+    }
+
+    /// Selects if the content of the `script` and `style` elements is kept as raw text.
+    ///
+    /// When enabled, the content of these elements is not parsed as markup: it is consumed
+    /// up to the matching end tag and kept as-is as a `NodeType::Text` node, without entity
+    /// translation or trimming. The `script` and `style` names are recognized ignoring ASCII
+    /// case, the end tag having to match the opening tag.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to keep the content of `script` and `style` elements as raw text
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<head><script>if (a < b && c) { x = '</p>'; }</script></head>".to_vec();
+    /// let options = ParserOptions::new().html_raw_text(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let script = document.root().unwrap().first_child().unwrap();
+    ///
+    /// assert_eq!(script.first_child().unwrap().text(), Some("if (a < b && c) { x = '</p>'; }"));
+    /// ```
+    #[inline]
+    pub fn html_raw_text(mut self, enabled: bool) -> Self {
+        self.html_raw_text = enabled;
+        self
+    }
+
+    /// Selects if the element and attribute names are folded to lowercase.
+    ///
+    /// The ASCII uppercase letters of the names are folded in the XML buffer at parse time,
+    /// so that `<BR>`, `<Body>` or `CLASS=` coming from legacy HTML sources are found by the
+    /// usual lookups (`Node::is()`, `Node::get_attribute()`, etc.) with lowercase names, at
+    /// no cost. Closing tags are folded the same way, so that `<P>...</p>` is well-formed.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to fold the names to lowercase
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<Body><P CLASS=\"Intro\">Text</p></BODY>".to_vec();
+    /// let options = ParserOptions::new().lowercase_names(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let body = document.root().unwrap();
+    ///
+    /// assert!(body.is("body"));
+    /// assert_eq!(body.first_child().unwrap().get_attribute("class"), Some("Intro"));
+    /// ```
+    #[inline]
+    pub fn lowercase_names(mut self, enabled: bool) -> Self {
+        self.lowercase_names = enabled;
+        self
+    }
+
+    /// Selects if ISO-8859-1 (Latin-1) and Windows-1252 content is decoded.
+    ///
+    /// The content declared with one of these encodings is transcoded to UTF-8 into an owned
+    /// buffer before parsing, so that accented characters are kept instead of producing
+    /// invalid UTF-8 strings. Content without byte order mark or encoding declaration that is
+    /// not valid UTF-8, as is common with older HTML files, is decoded as Windows-1252. When
+    /// disabled, parsing content declared with one of these encodings fails with
+    /// `ParseXmlError::InvalidEncoding`.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to decode the ISO-8859-1 and Windows-1252 content
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><a>caf\xE9</a>".to_vec();
+    /// let options = ParserOptions::new().legacy_encodings(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    ///
+    /// assert_eq!(document.root().unwrap().first_child().unwrap().text(), Some("café"));
+    /// ```
+    #[inline]
+    pub fn legacy_encodings(mut self, enabled: bool) -> Self {
+        self.legacy_encodings = enabled;
+        self
+    }
+
+    /// Selects if the attributes of the elements having many of them are indexed by name.
+    ///
+    /// When enabled, `Node::get_attribute()` and `Node::has_attribute()` look up the
+    /// attributes of the elements having more than 8 attributes, as is common with SVG or
+    /// data-heavy markup, by a binary search instead of comparing every name. The index
+    /// holds one attribute index per attribute. It is built on the first lookup, and rebuilt
+    /// after the document is modified.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to index the attributes by name
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let xml_data = b"<svg a=\"1\" b=\"2\" c=\"3\" d=\"4\" e=\"5\" f=\"6\" g=\"7\" h=\"8\" i=\"9\"/>".to_vec();
+    /// let options = ParserOptions::new().index_attributes(true);
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let svg = document.root().unwrap();
+    ///
+    /// assert_eq!(svg.get_attribute("h"), Some("8"));
+    /// assert!(!svg.has_attribute("z"));
+    /// ```
+    #[inline]
+    pub fn index_attributes(mut self, enabled: bool) -> Self {
+        self.index_attributes = enabled;
+        self
+    }
+
+    /// Selects how the content that is not valid UTF-8 is handled.
+    ///
+    /// By default, the invalid bytes are kept as-is and only discovered when a string accessor
+    /// returns "non valid utf-8". Rejecting the document, or replacing the invalid sequences
+    /// with U+FFFD, validates the content once before parsing, so that the string accessors
+    /// always return the actual content.
+    ///
+    /// # Arguments
+    /// * `policy` - The invalid UTF-8 policy
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::defs::ParseXmlError;
+    /// use xhtml_parser::parser::{InvalidUtf8, ParserOptions};
+    ///
+    /// let options = ParserOptions::new().invalid_utf8(InvalidUtf8::Reject);
+    ///
+    /// let result = Document::new_with_options(b"<a>caf\xE9</a>".to_vec(), options);
+    /// assert!(matches!(result, Err(ParseXmlError::InvalidEncoding(_))));
+    /// ```
+    #[inline]
+    pub fn invalid_utf8(mut self, policy: InvalidUtf8) -> Self {
+        self.invalid_utf8 = policy;
+        self
+    }
+
+    /// Limits the number of entities and character references (`&amp;`, `&#65;`, etc.)
+    /// expanded in a document.
+    ///
+    /// Parsing fails with `ParseXmlError::EntityLimitExceeded` once the limit is exceeded.
+    /// There is no limit by default.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum number of expansions, or `None` for no limit
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::defs::ParseXmlError;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let options = ParserOptions::new().parse_escapes(true).max_entity_expansions(Some(2));
+    ///
+    /// let xml_data = b"<root a=\"&lt;\">&amp;</root>".to_vec();
+    /// assert!(Document::new_with_options(xml_data, options.clone()).is_ok());
+    ///
+    /// let xml_data = b"<root a=\"&lt;\">&amp;&#65;</root>".to_vec();
+    /// let result = Document::new_with_options(xml_data, options);
+    /// assert!(matches!(result, Err(ParseXmlError::EntityLimitExceeded)));
+    /// ```
+    #[inline]
+    pub fn max_entity_expansions(mut self, limit: Option<usize>) -> Self {
+        self.max_entity_expansions = limit;
+        self
+    }
+
+    /// Limits the total size, in bytes, of the output of the entities and character
+    /// references expanded in a document.
+    ///
+    /// Parsing fails with `ParseXmlError::EntityLimitExceeded` once the limit is exceeded.
+    /// There is no limit by default.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum size of the expansions output, or `None` for no limit
+    #[inline]
+    pub fn max_entity_expansion_size(mut self, limit: Option<usize>) -> Self {
+        self.max_entity_expansion_size = limit;
+        self
+    }
+
+    /// Limits the number of nodes of a document, the head node excepted.
+    ///
+    /// Parsing fails with `ParseXmlError::LimitExceeded` once the limit is exceeded, and the
+    /// memory reserved for the nodes is bounded by the limit. The limit also applies to the
+    /// nodes later added to the document. There is no limit by default, other than the one of
+    /// the selected `xxxx_node_count` feature.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum number of nodes, or `None` for no limit
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::defs::ParseXmlError;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let options = ParserOptions::new()
+    ///     .max_nodes(Some(3))
+    ///     .max_attributes(Some(1))
+    ///     .max_input_len(Some(40));
+    ///
+    /// let xml_data = b"<root a=\"1\"><b>Text</b></root>".to_vec();
+    /// assert!(Document::new_with_options(xml_data, options.clone()).is_ok());
+    ///
+    /// let xml_data = b"<root><b/><c/><d/></root>".to_vec();
+    /// let result = Document::new_with_options(xml_data, options);
+    /// assert!(matches!(result, Err(ParseXmlError::LimitExceeded(_))));
+    /// ```
+    #[inline]
+    pub fn max_nodes(mut self, limit: Option<usize>) -> Self {
+        self.max_nodes = limit;
+        self
+    }
+
+    /// Limits the number of attributes of a document.
+    ///
+    /// Parsing fails with `ParseXmlError::LimitExceeded` once the limit is exceeded, and the
+    /// memory reserved for the attributes is bounded by the limit. The limit also applies to
+    /// the attributes later added to the document. There is no limit by default, other than
+    /// the one of the selected `xxxx_attr_count` feature.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum number of attributes, or `None` for no limit
+    #[inline]
+    pub fn max_attributes(mut self, limit: Option<usize>) -> Self {
+        self.max_attributes = limit;
+        self
+    }
+
+    /// Limits the size, in bytes, of the XML content to parse.
+    ///
+    /// Parsing fails with `ParseXmlError::LimitExceeded` before any processing if the content
+    /// is larger. There is no limit by default, other than the one of the selected
+    /// `xxxx_xml_size` feature.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum size of the XML content, or `None` for no limit
+    #[inline]
+    pub fn max_input_len(mut self, limit: Option<usize>) -> Self {
+        self.max_input_len = limit;
+        self
+    }
+
+    /// Selects which namespace prefixes are removed when the `namespace_removal` option is
+    /// enabled.
+    ///
+    /// # Arguments
+    /// * `filter` - The prefixes to remove
+    #[inline]
+    pub fn namespace_prefix_filter(mut self, filter: PrefixFilter) -> Self {
+        self.prefix_filter = filter;
+        self
+    }
+
+    /// Registers a named entity, translated by the `parse_escapes` option after the
+    /// predefined entities, such as the ones declared in a DTD or a project-specific set.
+    ///
+    /// The replacement text is inserted as-is: it is not parsed for markup or entity
+    /// references. As the translation is done in place, the replacement text cannot be
+    /// longer, in bytes, than the entity reference (`&name;`): parsing fails with
+    /// `ParseXmlError::InvalidXml` otherwise. Registering a name again replaces its
+    /// replacement text. The predefined entities cannot be redefined.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the entity, without the `&` and `;`
+    /// * `value` - The replacement text of the entity
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let options = ParserOptions::new()
+    ///     .parse_escapes(true)
+    ///     .entity("product", "Widget");
+    ///
+    /// let xml_data = b"<p title=\"&product;\">The &product; &amp; more</p>".to_vec();
+    /// let document = Document::new_with_options(xml_data, options).unwrap();
+    /// let p = document.root().unwrap();
+    ///
+    /// assert_eq!(p.get_attribute("title"), Some("Widget"));
+    /// assert_eq!(p.first_child().unwrap().text(), Some("The Widget & more"));
+    /// ```
+    #[inline]
+    pub fn entity(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.entities.insert(name.into(), value.into());
+        self
+    }
+
+    /// Registers a set of named entities, as with `entity()`.
+    ///
+    /// # Arguments
+    /// * `entities` - The `(name, replacement text)` pairs of the entities
+    #[inline]
+    pub fn entities<N, V>(mut self, entities: impl IntoIterator<Item = (N, V)>) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.entities.extend(
+            entities
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into())),
+        );
+        self
+    }
+
+    /// Registers a callback reporting the progress of the parsing of a `Document`, so that a
+    /// user interface can show a progress bar while parsing large content.
+    ///
+    /// The callback is called each time the parser reaches a tag located `interval` bytes
+    /// or more after the previous report, and once more at the end of a successful parsing,
+    /// with the offset equal to the length of the content. An `interval` of 0 reports every
+    /// tag.
+    ///
+    /// # Arguments
+    /// * `interval` - The number of bytes between two reports
+    /// * `callback` - The function receiving the progress
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::parser::ParserOptions;
+    ///
+    /// let reports = Arc::new(Mutex::new(Vec::new()));
+    /// let recorder = Arc::clone(&reports);
+    /// let options = ParserOptions::new().progress(10, move |progress| {
+    ///     recorder.lock().unwrap().push((progress.offset, progress.total));
+    /// });
+    ///
+    /// let xml_data = b"<root><child>Text</child><child/></root>".to_vec();
+    /// Document::new_with_options(xml_data, options).unwrap();
+    ///
+    /// assert_eq!(*reports.lock().unwrap(), [(17, 40), (33, 40), (40, 40)]);
+    /// ```
+    #[inline]
+    pub fn progress(
+        mut self,
+        interval: usize,
+        callback: impl Fn(ParseProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(ProgressCallback {
+            interval: interval.max(1),
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
+    /// Returns true if the namespace prefixes are removed from tag and attribute names.
+    #[inline]
+    #[must_use]
+    pub fn has_namespace_removal(&self) -> bool {
+        self.namespace_removal
+    }
+
+    /// Returns true if the escape sequences of `PCData` nodes are translated.
+    #[inline]
+    #[must_use]
+    pub fn has_parse_escapes(&self) -> bool {
+        self.parse_escapes
+    }
+
+    /// Returns true if the escape sequences are decoded at all.
+    #[inline]
+    #[must_use]
+    pub fn has_decode_entities(&self) -> bool {
+        self.decode_entities
+    }
+
+    /// Returns true if the `PCData` nodes are trimmed.
+    #[inline]
+    #[must_use]
+    pub fn has_trim_pcdata(&self) -> bool {
+        self.trim_pcdata
+    }
+
+    /// Returns true if the whitespace-only `PCData` nodes are kept.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_ws_only_pcdata(&self) -> bool {
+        self.keep_ws_only_pcdata
+    }
+
+    /// Returns the handling of the whitespace of the `PCData` nodes. When `trim_pcdata` is
+    /// enabled, this is `WhitespaceMode::Trim`, whatever the `keep_ws_only_pcdata` option.
+    #[inline]
+    #[must_use]
+    pub fn get_whitespace_mode(&self) -> WhitespaceMode {
+        if self.trim_pcdata {
+            WhitespaceMode::Trim
+        } else if self.keep_ws_only_pcdata {
+            WhitespaceMode::Preserve
+        } else {
+            WhitespaceMode::DropWsOnly
+        }
+    }
+
+    /// Returns true if the comments are kept as `NodeType::Comment` nodes.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_comments(&self) -> bool {
+        self.keep_comments
+    }
+
+    /// Returns true if the processing instructions are kept as `NodeType::ProcessingInstruction`
+    /// nodes.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_processing_instructions(&self) -> bool {
+        self.keep_processing_instructions
+    }
+
+    /// Returns true if the content of CDATA sections is kept as `NodeType::Text` nodes.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_cdata(&self) -> bool {
+        self.keep_cdata
+    }
+
+    /// Returns true if the named entity references are kept as `NodeType::EntityRef` nodes.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_entity_refs(&self) -> bool {
+        self.keep_entity_refs
+    }
+
+    /// Returns true if the original content of the text nodes is kept.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_raw_text(&self) -> bool {
+        self.keep_raw_text
+    }
+
+    /// Returns true if the span of the elements in the XML content is recorded.
+    #[inline]
+    #[must_use]
+    pub fn has_keep_element_spans(&self) -> bool {
+        self.keep_element_spans
+    }
+
+    /// Returns true if the elements whose end tag is optional in HTML are implicitly closed.
+    #[inline]
+    #[must_use]
+    pub fn has_html_leniency(&self) -> bool {
+        self.html_leniency
+    }
+
+    /// Returns true if the HTML void elements are closed by the end of their opening tag.
+    #[inline]
+    #[must_use]
+    pub fn has_html_void_elements(&self) -> bool {
+        self.html_void_elements
+    }
+
+    /// Returns true if the content of the `script` and `style` elements is kept as raw text.
+    #[inline]
+    #[must_use]
+    pub fn has_html_raw_text(&self) -> bool {
+        self.html_raw_text
+    }
+
+    /// Returns true if the element and attribute names are folded to lowercase.
+    #[inline]
+    #[must_use]
+    pub fn has_lowercase_names(&self) -> bool {
+        self.lowercase_names
+    }
+
+    /// Returns true if the ISO-8859-1 and Windows-1252 content is decoded.
+    #[inline]
+    #[must_use]
+    pub fn has_legacy_encodings(&self) -> bool {
+        self.legacy_encodings
+    }
+
+    /// Returns true if the attributes of the elements having many of them are indexed by name.
+    #[inline]
+    #[must_use]
+    pub fn has_index_attributes(&self) -> bool {
+        self.index_attributes
+    }
+
+    /// Returns the invalid UTF-8 policy.
+    #[inline]
+    #[must_use]
+    pub fn get_invalid_utf8(&self) -> InvalidUtf8 {
+        self.invalid_utf8
+    }
+
+    /// Returns the maximum number of entity expansions in a document, if limited.
+    #[inline]
+    #[must_use]
+    pub fn get_max_entity_expansions(&self) -> Option<usize> {
+        self.max_entity_expansions
+    }
+
+    /// Returns the maximum size of the entity expansions output in a document, if limited.
+    #[inline]
+    #[must_use]
+    pub fn get_max_entity_expansion_size(&self) -> Option<usize> {
+        self.max_entity_expansion_size
+    }
+
+    /// Returns the maximum number of nodes of a document, if limited.
+    #[inline]
+    #[must_use]
+    pub fn get_max_nodes(&self) -> Option<usize> {
+        self.max_nodes
+    }
+
+    /// Returns the maximum number of attributes of a document, if limited.
+    #[inline]
+    #[must_use]
+    pub fn get_max_attributes(&self) -> Option<usize> {
+        self.max_attributes
+    }
+
+    /// Returns the maximum size of the XML content to parse, if limited.
+    #[inline]
+    #[must_use]
+    pub fn get_max_input_len(&self) -> Option<usize> {
+        self.max_input_len
+    }
+
+    /// Returns the filter selecting the namespace prefixes to remove.
+    #[inline]
+    #[must_use]
+    pub fn get_namespace_prefix_filter(&self) -> &PrefixFilter {
+        &self.prefix_filter
+    }
+
+    /// Returns the elements inside which the whitespace of the `PCData` nodes is preserved.
+    #[inline]
+    #[must_use]
+    pub fn get_preserve_whitespace_tags(&self) -> &[String] {
+        &self.preserve_whitespace_tags
+    }
+
+    /// Returns the number of bytes between two progress reports, if a callback is registered
+    /// with the `progress` option.
+    #[inline]
+    #[must_use]
+    pub fn get_progress_interval(&self) -> Option<usize> {
+        self.progress.as_ref().map(|progress| progress.interval)
+    }
+
+    /// Returns the replacement text of a registered entity.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the entity, without the `&` and `;`
+    #[inline]
+    #[must_use]
+    pub fn get_entity(&self, name: &str) -> Option<&str> {
+        self.entities.get(name).map(String::as_str)
+    }
+
+    /// Returns the replacement text of a registered entity, for the entity translation.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the entity, as found in the XML content
+    #[inline]
+    pub(crate) fn custom_entity(&self, name: &[u8]) -> Option<&[u8]> {
+        if self.entities.is_empty() {
+            return None;
+        }
+        std::str::from_utf8(name)
+            .ok()
+            .and_then(|name| self.entities.get(name))
+            .map(String::as_bytes)
+    }
+
+    /// Returns true if the whitespace of the `PCData` nodes is preserved inside the given
+    /// element.
+    #[inline]
+    pub(crate) fn preserves_whitespace(&self, name: &[u8]) -> bool {
+        self.preserve_whitespace_tags
+            .iter()
+            .any(|tag| tag.as_bytes().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns true if the given namespace prefix is removed from tag and attribute names.
+    #[inline]
+    pub(crate) fn removes_prefix(&self, prefix: &[u8]) -> bool {
+        self.namespace_removal && self.prefix_filter.removes(prefix)
+    }
+
+    /// Checks the size of the XML content against the `max_input_len` limit.
+    ///
+    /// # Arguments
+    /// * `len` - The size of the XML content, in bytes
+    pub(crate) fn check_input_len(&self, len: usize) -> Result<(), ParseXmlError> {
+        match self.max_input_len {
+            Some(max) if len > max => Err(ParseXmlError::LimitExceeded(format!(
+                "XML content of {len} bytes is larger than the limit of {max} bytes"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
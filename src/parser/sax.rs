@@ -0,0 +1,190 @@
+//! SAX-style streaming parser for XML/XHTML documents.
+//!
+//! This module provides the `stream()` function, which parses XML content and reports its
+//! constructs to a user-supplied `SaxHandler`, instead of building the nodes vector of a
+//! `Document`. The memory used is limited to the XML buffer and a stack of the currently
+//! open elements, which makes it suitable for very large documents on memory-constrained targets.
+//! It is built on the `Tokenizer` pull-parser.
+//!
+//! The same in-place processing as the tree-based parser is used: entity translation in
+//! text and attribute values, attribute value normalization, closing tag validation, and
+//! the `namespace_removal`, `parse_escapes` and `trim_pcdata` parsing options, with their
+//! default values, or the ones given to `stream_with_options()`.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::parser::{stream_with_options, ParserOptions, SaxAttributes, SaxHandler};
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     elements: usize,
+//!     text: String,
+//! }
+//!
+//! impl SaxHandler for Counter {
+//!     fn start_element(&mut self, _name: &str, _attributes: SaxAttributes) {
+//!         self.elements += 1;
+//!     }
+//!
+//!     fn text(&mut self, text: &str) {
+//!         self.text.push_str(text);
+//!     }
+//! }
+//!
+//! let mut counter = Counter::default();
+//! let xml_data = b"<root><a>Fish &amp; </a><b>Chips</b></root>".to_vec();
+//! let options = ParserOptions::new().parse_escapes(true);
+//! stream_with_options(xml_data, &mut counter, options).unwrap();
+//!
+//! assert_eq!(counter.elements, 3);
+//! assert_eq!(counter.text, "Fish & Chips");
+//! ```
+
+use std::fmt;
+
+use crate::defs::ParseXmlError;
+
+use super::events::range_str;
+use super::{Event, ParserOptions, Tokenizer, XmlRange};
+
+/// Receives the events produced by `stream()`.
+///
+/// All methods have a default implementation that ignores the event, so a handler only
+/// needs to implement the events it is interested in. The string slices are only valid for
+/// the duration of the call.
+pub trait SaxHandler {
+    /// Called for each start tag, with its attributes. Self-closing elements are followed
+    /// by a call to `end_element()`.
+    fn start_element(&mut self, _name: &str, _attributes: SaxAttributes) {}
+
+    /// Called for each end tag, and after `start_element()` for self-closing elements.
+    fn end_element(&mut self, _name: &str) {}
+
+    /// Called for the text content of elements, with entities translated. The content of
+    /// CDATA sections is reported through this method, as-is.
+    fn text(&mut self, _text: &str) {}
+
+    /// Called instead of `text()` for text runs composed of whitespace only, such as the
+    /// indentation between elements.
+    fn whitespace(&mut self, _text: &str) {}
+
+    /// Called for each comment, with the content located between `<!--` and `-->`.
+    fn comment(&mut self, _comment: &str) {}
+
+    /// Called for each processing instruction (`<?target data?>`). The XML declaration is
+    /// reported as a processing instruction with the `xml` target.
+    fn pi(&mut self, _target: &str, _data: &str) {}
+}
+
+/// An iterator over the attributes of a start tag, as `(name, value)` pairs.
+///
+/// Attribute values are normalized and their entities translated.
+#[derive(Clone)]
+pub struct SaxAttributes<'a> {
+    pub(super) xml: &'a [u8],
+    pub(super) attrs: core::slice::Iter<'a, (XmlRange, XmlRange)>,
+}
+
+impl<'a> SaxAttributes<'a> {
+    /// Returns the value of the attribute with the given name, if present.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the attribute to look for
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.clone()
+            .find(|(attr_name, _)| *attr_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<'a> Iterator for SaxAttributes<'a> {
+    type Item = (&'a str, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.attrs
+            .next()
+            .map(|(name, value)| (range_str(self.xml, name), range_str(self.xml, value)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.attrs.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SaxAttributes<'_> {}
+
+impl fmt::Debug for SaxAttributes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_map().entries(self.clone()).finish()
+    }
+}
+
+/// Parses the XML content, reporting its constructs to the given handler.
+///
+/// No nodes vector is built. Elements must be properly nested and closed, as with
+/// `Document::new()`. Text and elements located outside of any element are not reported.
+///
+/// # Arguments
+/// * `xml` - A byte vector containing the XML content to be parsed. It is modified in place.
+/// * `handler` - The handler receiving the parsing events
+///
+/// # Returns
+/// `Ok(())` if the parsing succeeds, or a `ParseXmlError` if the XML is malformed
+///
+/// # Errors
+/// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or is too large.
+pub fn stream<H: SaxHandler>(xml: Vec<u8>, handler: &mut H) -> Result<(), ParseXmlError> {
+    let mut tokenizer = Tokenizer::new(xml)?;
+    report_events(&mut tokenizer, handler)
+}
+
+/// Parses the XML content with the given parser options, reporting its constructs to the
+/// given handler.
+///
+/// # Arguments
+/// * `xml` - A byte vector containing the XML content to be parsed. It is modified in place.
+/// * `handler` - The handler receiving the parsing events
+/// * `options` - The parser options to use
+///
+/// # Returns
+/// `Ok(())` if the parsing succeeds, or a `ParseXmlError` if the XML is malformed
+///
+/// # Errors
+/// - `ParseXmlError::InvalidXml`: If the XML content is not well-formed or is too large.
+pub fn stream_with_options<H: SaxHandler>(
+    xml: Vec<u8>,
+    handler: &mut H,
+    options: ParserOptions,
+) -> Result<(), ParseXmlError> {
+    let mut tokenizer = Tokenizer::new_with_options(xml, options)?;
+    report_events(&mut tokenizer, handler)
+}
+
+/// Reports the events of the tokenizer to the handler, up to `Event::Eof`.
+///
+/// # Arguments
+/// * `tokenizer` - The tokenizer producing the events
+/// * `handler` - The handler receiving the parsing events
+///
+/// # Errors
+/// Returns the errors of the tokenizer.
+pub(super) fn report_events<H: SaxHandler>(
+    tokenizer: &mut Tokenizer,
+    handler: &mut H,
+) -> Result<(), ParseXmlError> {
+    loop {
+        match tokenizer.next_event()? {
+            Event::StartTag { name, attributes } => handler.start_element(name, attributes),
+            Event::EndTag { name } => handler.end_element(name),
+            Event::Text(text) | Event::CData(text) => handler.text(text),
+            Event::Whitespace(text) => handler.whitespace(text),
+            Event::Comment(comment) => handler.comment(comment),
+            Event::ProcessingInstruction { target, data } => handler.pi(target, data),
+            Event::Eof => return Ok(()),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Accelerated scanning of the XML buffer.
+//!
+//! This module is only available when the `fast_scan` feature is enabled. It replaces the
+//! byte-at-a-time chartype table lookups of the most frequent scans with `memchr` (which uses
+//! the SIMD instructions of the target) and SWAR (SIMD within a register) loops, processing
+//! eight bytes at a time:
+//!
+//! - `Chartype::Space`: the whitespace between tags and attributes.
+//! - `Chartype::ParseCloseTag`: the end of a tag name.
+//! - `Chartype::ParsePCData`: the `&` and `\r` characters of `PCData` content.
+//! - `Chartype::ParseAtrNorm`: the characters to normalize in attribute values.
+//!
+//! The other chartypes use the table lookups.
+
+use memchr::memchr2;
+
+use super::{Chartype, CHARTYPE_TABLE};
+
+const LANE: usize = size_of::<u64>();
+const LOW_BITS: u64 = u64::from_ne_bytes([0x7F; LANE]);
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; LANE]);
+
+const SPACES: &[u8] = b" \t\n\r";
+const CLOSE_TAG: &[u8] = b" \t\n\r>";
+const ATTRIBUTE_NORM: &[u8] = b" \t\n\r&";
+
+/// Returns the position of the first byte that is not of the given chartype.
+///
+/// # Arguments
+/// * `bytes` - The bytes to scan
+/// * `chartype` - The chartype of the bytes to skip
+#[inline(always)]
+pub(crate) fn skip_chartype(bytes: &[u8], chartype: Chartype) -> Option<usize> {
+    match chartype {
+        Chartype::Space => find_in_set(bytes, SPACES, false),
+        _ => bytes
+            .iter()
+            .position(|&byte| CHARTYPE_TABLE[byte as usize] & chartype as u8 == 0),
+    }
+}
+
+/// Returns the position of the first byte of the given chartype.
+///
+/// # Arguments
+/// * `bytes` - The bytes to scan
+/// * `chartype` - The chartype of the byte to find
+#[inline(always)]
+pub(crate) fn find_chartype(bytes: &[u8], chartype: Chartype) -> Option<usize> {
+    match chartype {
+        Chartype::ParsePCData => memchr2(b'&', b'\r', bytes),
+        Chartype::ParseAtrNorm => find_in_set(bytes, ATTRIBUTE_NORM, true),
+        Chartype::ParseCloseTag => find_in_set(bytes, CLOSE_TAG, true),
+        Chartype::Space => find_in_set(bytes, SPACES, true),
+        _ => bytes
+            .iter()
+            .position(|&byte| CHARTYPE_TABLE[byte as usize] & chartype as u8 != 0),
+    }
+}
+
+/// Returns the position of the first byte that is (if `member` is true) or is not (if
+/// `member` is false) part of the set.
+#[inline(always)]
+fn find_in_set(bytes: &[u8], set: &[u8], member: bool) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(LANE);
+    let mut offset = 0;
+
+    for chunk in chunks.by_ref() {
+        let word = u64::from_le_bytes(chunk.try_into().unwrap_or([0; LANE]));
+        let members = set_members(word, set);
+        let found = if member {
+            members
+        } else {
+            !members & HIGH_BITS
+        };
+        if found != 0 {
+            // The bytes are loaded in little-endian order: the first one is the lowest
+            return Some(offset + found.trailing_zeros() as usize / 8);
+        }
+        offset += LANE;
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .position(|byte| set.contains(byte) == member)
+        .map(|pos| offset + pos)
+}
+
+/// Returns a word with the high bit of each byte set if the corresponding byte of `word` is
+/// part of the set.
+#[inline(always)]
+fn set_members(word: u64, set: &[u8]) -> u64 {
+    let not_member = set.iter().fold(HIGH_BITS, |acc, &byte| {
+        acc & non_zero_bytes(word ^ splat(byte))
+    });
+    !not_member & HIGH_BITS
+}
+
+/// Returns a word with the high bit of each byte set if the corresponding byte of `word` is
+/// not zero. Unlike the usual "has zero byte" trick, the result is exact for every byte, as
+/// no carry crosses the byte boundaries.
+#[inline(always)]
+fn non_zero_bytes(word: u64) -> u64 {
+    (((word & LOW_BITS) + LOW_BITS) | word) & HIGH_BITS
+}
+
+/// Returns a word with all bytes set to the given value.
+#[inline(always)]
+fn splat(byte: u8) -> u64 {
+    u64::from_ne_bytes([byte; LANE])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scans_match_the_chartype_table() {
+        let bytes: Vec<u8> =
+            b"  \t\r\n<a attr=\"x&amp;y\">  Some text\r\n and &lt; more   </a>  \xC3\xA9\t>"
+                .iter()
+                .copied()
+                .cycle()
+                .take(300)
+                .collect();
+
+        for chartype in [
+            Chartype::Space,
+            Chartype::ParseCloseTag,
+            Chartype::ParsePCData,
+            Chartype::ParseAtrNorm,
+            Chartype::Symbol,
+        ] {
+            let mask = chartype as u8;
+            for start in 0..bytes.len() {
+                let slice = &bytes[start..];
+                assert_eq!(
+                    find_chartype(slice, chartype),
+                    slice
+                        .iter()
+                        .position(|&b| CHARTYPE_TABLE[b as usize] & mask != 0),
+                );
+                assert_eq!(
+                    skip_chartype(slice, chartype),
+                    slice
+                        .iter()
+                        .position(|&b| CHARTYPE_TABLE[b as usize] & mask == 0),
+                );
+            }
+        }
+    }
+}
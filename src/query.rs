@@ -0,0 +1,157 @@
+//! Precompiled element queries.
+//!
+//! This module provides the `Query` type, a simple matcher of elements by tag name and
+//! attributes. A query is built once, and can then be run against many documents or nodes
+//! without processing its terms again, which suits the lookups done in tight loops. The
+//! names and values are compared as bytes, and the attribute lookups use the attribute
+//! index when the `index_attributes` parsing option is enabled.
+//!
+//! For more complex searches, `xpath::XPath` and, with the `css` feature, `css::Selector`
+//! are the precompiled forms of the XPath expressions and CSS selectors.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::Document;
+//! use xhtml_parser::query::Query;
+//!
+//! let query = Query::element("a").attribute_value("rel", "next").attribute("href");
+//! let mut next_pages = Vec::new();
+//!
+//! for xml_data in [
+//!     b"<p><a href=\"2.xhtml\" rel=\"next\">Next</a><a rel=\"next\">None</a></p>".to_vec(),
+//!     b"<p><a href=\"1.xhtml\" rel=\"prev\">Previous</a></p>".to_vec(),
+//! ] {
+//!     let document = Document::new(xml_data).unwrap();
+//!     for a in query.select(&document) {
+//!         next_pages.push(a.get_attribute("href").unwrap().to_string());
+//!     }
+//! }
+//!
+//! assert_eq!(next_pages, ["2.xhtml"]);
+//! ```
+
+use crate::document::Document;
+use crate::node::Node;
+
+/// A precompiled matcher of elements, by tag name and attributes.
+///
+/// The query is built with `Query::new()` or `Query::element()`, and the attribute
+/// conditions added with `attribute()` and `attribute_value()`. An element matches if it
+/// satisfies all the conditions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct Query {
+    name: Option<Vec<u8>>,                       // None for any element
+    attributes: Vec<(Vec<u8>, Option<Vec<u8>>)>, // Name, and value if it is tested
+}
+
+impl Query {
+    /// Creates a query matching any element.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a query matching the elements with the given tag name.
+    ///
+    /// # Arguments
+    /// * `tag_name` - The tag name of the elements, as kept by the parser
+    #[inline]
+    pub fn element(tag_name: &str) -> Self {
+        Query {
+            name: Some(tag_name.as_bytes().to_vec()),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Adds the condition that the elements have the given attribute.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the attribute
+    #[inline]
+    pub fn attribute(mut self, name: &str) -> Self {
+        self.attributes.push((name.as_bytes().to_vec(), None));
+        self
+    }
+
+    /// Adds the condition that the elements have the given attribute, with the given value.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the attribute
+    /// * `value` - The value of the attribute, as decoded by the parser
+    #[inline]
+    pub fn attribute_value(mut self, name: &str, value: &str) -> Self {
+        self.attributes
+            .push((name.as_bytes().to_vec(), Some(value.as_bytes().to_vec())));
+        self
+    }
+
+    /// Returns true if the node is an element matching the query.
+    ///
+    /// # Arguments
+    /// * `node` - The node to test
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::query::Query;
+    ///
+    /// let document = Document::new(b"<img src=\"a.png\" alt=\"\"/>".to_vec()).unwrap();
+    /// let img = document.root().unwrap();
+    ///
+    /// assert!(Query::element("img").attribute_value("alt", "").matches(&img));
+    /// assert!(!Query::new().attribute("title").matches(&img));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, node: &Node) -> bool {
+        node.is_element()
+            && self.name.as_ref().is_none_or(|name| node.is_bytes(name))
+            && self.attributes.iter().all(|(name, value)| {
+                node.find_attribute(name).is_some_and(|attr| {
+                    value
+                        .as_ref()
+                        .is_none_or(|value| attr.value_bytes() == value.as_slice())
+                })
+            })
+    }
+
+    /// Returns an iterator over the elements of a document matching the query, in document
+    /// order.
+    ///
+    /// # Arguments
+    /// * `document` - The document to search
+    #[inline]
+    pub fn select<'a, 'q>(
+        &'q self,
+        document: &'a Document<'a>,
+    ) -> impl Iterator<Item = Node<'a>> + 'q
+    where
+        'a: 'q,
+    {
+        document.all_nodes().filter(move |node| self.matches(node))
+    }
+
+    /// Returns an iterator over the descendant elements of a node matching the query, in
+    /// document order.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose descendants are searched
+    #[inline]
+    pub fn select_from<'a, 'q>(&'q self, node: &Node<'a>) -> impl Iterator<Item = Node<'a>> + 'q
+    where
+        'a: 'q,
+    {
+        node.descendants().filter(move |node| self.matches(node))
+    }
+
+    /// Returns the first element of a document matching the query.
+    ///
+    /// # Arguments
+    /// * `document` - The document to search
+    #[inline]
+    #[must_use]
+    pub fn first<'a>(&self, document: &'a Document<'a>) -> Option<Node<'a>> {
+        self.select(document).next()
+    }
+}
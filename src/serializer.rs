@@ -0,0 +1,781 @@
+//! Serialization of a document back to XML.
+//!
+//! The parser processes the XML content in place (entities are translated, attribute values
+//! normalized), so the original content cannot be recovered from the buffer. This module
+//! re-emits the node tree as XML, escaping the text and attribute values as required.
+//!
+//! As the serialization works on the parsed tree, what the parser did not keep is not emitted:
+//! the XML declaration, the DOCTYPE, the namespace prefixes removed by the `namespace_removal`
+//! option, and the comments, processing instructions, CDATA sections and whitespace-only text
+//! not retained by the parsing options.
+
+use std::io::{self, Write};
+
+use crate::defs::{XmlIdx, XmlLocation};
+use crate::document::Document;
+use crate::node::Node;
+use crate::node_type::NodeType;
+use crate::walk::{Visitor, WalkAction};
+
+impl Document<'_> {
+    /// Serializes the document to an XML string.
+    ///
+    /// # Returns
+    /// The XML content of the document.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<root a=\"&quot;x&quot;\">Fish &amp; Chips<empty></empty></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(document.to_xml(), "<root a=\"&quot;x&quot;\">Fish &amp; Chips<empty/></root>");
+    /// ```
+    #[must_use]
+    pub fn to_xml(&self) -> String {
+        self.to_xml_with(&self.serialize_options())
+    }
+
+    /// Serializes the document to an XML string, using the given options.
+    ///
+    /// # Arguments
+    /// * `options` - The serialization options
+    ///
+    /// # Returns
+    /// The XML content of the document.
+    #[must_use]
+    pub fn to_xml_with(&self, options: &SerializeOptions) -> String {
+        let mut xml = Vec::with_capacity(self.xml.len());
+        // Writing to a vector cannot fail
+        let _ = self.write_xml_with(&mut xml, options);
+        // Only valid UTF-8 strings are written
+        String::from_utf8(xml).unwrap_or_default()
+    }
+
+    /// Serializes the document as XML to the given writer.
+    ///
+    /// The comments and processing instructions located before the root element are written
+    /// first, in their order of appearance.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination of the XML content
+    ///
+    /// # Errors
+    /// Returns the errors reported by the writer.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<!-- header --><root><a>1 &lt; 2</a></root>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let mut output = Vec::new();
+    /// document.write_xml(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"<!-- header --><root><a>1 &lt; 2</a></root>");
+    /// ```
+    pub fn write_xml<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_xml_with(writer, &self.serialize_options())
+    }
+
+    /// Selects the form of the elements without children in the XML produced by `to_xml()`,
+    /// `write_xml()`, `Node::outer_xml()` and `Node::inner_xml()`, whatever their form in the
+    /// parsed content, so that the serialization is deterministic. The elements are collapsed
+    /// by default.
+    ///
+    /// The methods taking `SerializeOptions` use the self-closing policy of the options instead.
+    ///
+    /// # Arguments
+    /// * `mode` - The form of the elements without children
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::serializer::SelfClosingMode;
+    ///
+    /// let mut document = Document::new(b"<root><a/><b></b></root>".to_vec()).unwrap();
+    /// assert_eq!(document.to_xml(), "<root><a/><b/></root>");
+    ///
+    /// document.normalize_self_closing(SelfClosingMode::Expand);
+    /// assert_eq!(document.to_xml(), "<root><a></a><b></b></root>");
+    /// ```
+    pub fn normalize_self_closing(&mut self, mode: SelfClosingMode) {
+        self.self_closing = match mode {
+            SelfClosingMode::Collapse => SelfClosing::Always,
+            SelfClosingMode::Expand => SelfClosing::Never,
+        };
+    }
+
+    /// Returns the serialization options used by the methods not taking any.
+    #[inline]
+    pub(crate) fn serialize_options(&self) -> SerializeOptions {
+        SerializeOptions::new().self_closing(self.self_closing)
+    }
+
+    /// Serializes the document as XML to the given writer, using the given options.
+    ///
+    /// When pretty printing, the prolog items and the root element are written on their own
+    /// line, and the output ends with a line feed.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination of the XML content
+    /// * `options` - The serialization options
+    ///
+    /// # Errors
+    /// Returns the errors reported by the writer.
+    pub fn write_xml_with<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &SerializeOptions,
+    ) -> io::Result<()> {
+        let mut serializer = Serializer::new(writer, options);
+        self.write_prolog(&mut serializer)?;
+
+        if let Some(root) = self.root() {
+            serializer.write_subtree(&root, 0)?;
+        }
+        if options.indent.is_some() && serializer.started {
+            serializer.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the comments and processing instructions located before the root element,
+    /// merged in their order of appearance.
+    fn write_prolog<W: Write>(&self, serializer: &mut Serializer<W>) -> io::Result<()> {
+        let mut comments = self.prolog_comments.iter().peekable();
+        let mut pis = self.prolog_pis.iter().peekable();
+
+        loop {
+            let comment_first = match (comments.peek(), pis.peek()) {
+                (Some(comment), Some((target, _))) => {
+                    location_start(comment) < location_start(target)
+                }
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => return Ok(()),
+            };
+
+            serializer.new_line(0)?;
+            serializer.started = true;
+            if comment_first {
+                if let Some(comment) = comments.next() {
+                    write_comment(&mut serializer.writer, self.location_str(comment))?;
+                }
+            } else if let Some((target, data)) = pis.next() {
+                let (target, data) = (self.location_str(target), self.location_str(data));
+                write_pi(&mut serializer.writer, target, data)?;
+            }
+        }
+    }
+
+    /// Returns the string at the given location.
+    #[inline]
+    fn location_str(&self, location: &XmlLocation) -> &str {
+        #[cfg(feature = "use_cstr")]
+        {
+            self.get_str_from_location(*location)
+        }
+
+        #[cfg(not(feature = "use_cstr"))]
+        self.get_str_from_location(location.clone())
+    }
+}
+
+impl Node<'_> {
+    /// Serializes the node and its descendants to an XML string.
+    ///
+    /// # Returns
+    /// The XML content of the subtree, including the node itself.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><body class=\"c\"><p>A &amp; B</p></body></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let body = document.find_first("body").unwrap();
+    ///
+    /// assert_eq!(body.outer_xml(), "<body class=\"c\"><p>A &amp; B</p></body>");
+    /// ```
+    #[must_use]
+    pub fn outer_xml(&self) -> String {
+        self.outer_xml_with(&self.doc.serialize_options())
+    }
+
+    /// Serializes the node and its descendants to an XML string, using the given options.
+    ///
+    /// # Arguments
+    /// * `options` - The serialization options
+    ///
+    /// # Returns
+    /// The XML content of the subtree, including the node itself.
+    #[must_use]
+    pub fn outer_xml_with(&self, options: &SerializeOptions) -> String {
+        let mut xml = Vec::new();
+        let mut serializer = Serializer::new(&mut xml, options);
+        // Writing to a vector cannot fail
+        let _ = serializer.write_subtree(self, 0);
+        // Only valid UTF-8 strings are written
+        String::from_utf8(xml).unwrap_or_default()
+    }
+
+    /// Serializes the descendants of the node to an XML string.
+    ///
+    /// # Returns
+    /// The XML content of the children of the node, or an empty string if it has none.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><body>Text <b>bold</b><br/></body></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let body = document.find_first("body").unwrap();
+    ///
+    /// assert_eq!(body.inner_xml(), "Text <b>bold</b><br/>");
+    /// ```
+    #[must_use]
+    pub fn inner_xml(&self) -> String {
+        self.inner_xml_with(&self.doc.serialize_options())
+    }
+
+    /// Serializes the descendants of the node to an XML string, using the given options.
+    ///
+    /// When pretty printing an element containing no text, each child starts on its own line,
+    /// at the first indentation level.
+    ///
+    /// # Arguments
+    /// * `options` - The serialization options
+    ///
+    /// # Returns
+    /// The XML content of the children of the node, or an empty string if it has none.
+    #[must_use]
+    pub fn inner_xml_with(&self, options: &SerializeOptions) -> String {
+        let mut xml = Vec::new();
+        // Writing to a vector cannot fail
+        let _ = self.serialize_children_to_with(&mut xml, options);
+        // Only valid UTF-8 strings are written
+        String::from_utf8(xml).unwrap_or_default()
+    }
+
+    /// Serializes the descendants of the node as XML to the given writer. This is the
+    /// streaming form of `inner_xml()`, which avoids building the content of large subtrees
+    /// in memory.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination of the XML content
+    ///
+    /// # Errors
+    /// Returns the errors reported by the writer.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<html><body>Text <b>bold</b><br/></body></html>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let body = document.find_first("body").unwrap();
+    /// let mut output = Vec::new();
+    /// body.serialize_children_to(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"Text <b>bold</b><br/>");
+    /// ```
+    pub fn serialize_children_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.serialize_children_to_with(writer, &self.doc.serialize_options())
+    }
+
+    /// Serializes the descendants of the node as XML to the given writer, using the given
+    /// options.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination of the XML content
+    /// * `options` - The serialization options
+    ///
+    /// # Errors
+    /// Returns the errors reported by the writer.
+    pub fn serialize_children_to_with<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &SerializeOptions,
+    ) -> io::Result<()> {
+        // Mixed content is written as-is, as for the children of an element in outer_xml()
+        let mut options = options.clone();
+        if !self
+            .children()
+            .all(|child| !child.is_text() || is_whitespace_text(&child))
+        {
+            options.indent = None;
+        }
+
+        let mut serializer = Serializer::new(writer, &options);
+
+        for child in self.children() {
+            if options.indent.is_some() && is_whitespace_text(&child) {
+                continue;
+            }
+            serializer.write_subtree(&child, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the position of the first byte of the location.
+#[inline]
+fn location_start(location: &XmlLocation) -> XmlIdx {
+    #[cfg(feature = "use_cstr")]
+    {
+        *location
+    }
+
+    #[cfg(not(feature = "use_cstr"))]
+    location.start
+}
+
+/// The HTML void elements, which cannot have content.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Selects how the elements without children are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelfClosing {
+    /// All elements without children are written as empty-element tags (`<p/>`).
+    #[default]
+    Always,
+    /// All elements without children are written with a start and an end tag (`<p></p>`).
+    Never,
+    /// Only the HTML void elements (`br`, `img`, `meta`, ...) are written as empty-element
+    /// tags, as expected by HTML user agents reading XHTML content.
+    VoidElements,
+}
+
+/// Selects the form given to the elements without children by
+/// `Document::normalize_self_closing()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfClosingMode {
+    /// The elements are written as empty-element tags (`<a/>`).
+    Collapse,
+    /// The elements are written with a start and an end tag (`<a></a>`).
+    Expand,
+}
+
+/// Options of the XML serialization.
+///
+/// The default options produce a compact output, without added whitespace. Setting an
+/// indentation string enables the pretty printing: the children of elements that contain
+/// no text are written on their own line, indented by their depth. The content of elements
+/// containing text is written as-is, as whitespace is significant there.
+///
+/// # Example
+/// ```
+/// use xhtml_parser::Document;
+/// use xhtml_parser::serializer::{SelfClosing, SerializeOptions};
+///
+/// let xml_data = b"<html><body><p>Some <b>bold</b> text</p><br/><div></div></body></html>".to_vec();
+/// let document = Document::new(xml_data).unwrap();
+/// let options = SerializeOptions::new()
+///     .indent("  ")
+///     .self_closing(SelfClosing::VoidElements);
+///
+/// assert_eq!(
+///     document.to_xml_with(&options),
+///     "<html>\n  <body>\n    <p>Some <b>bold</b> text</p>\n    <br/>\n    <div></div>\n  </body>\n</html>\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct SerializeOptions {
+    indent: Option<String>,
+    max_line_width: Option<usize>,
+    self_closing: SelfClosing,
+}
+
+impl SerializeOptions {
+    /// Creates the options of a compact serialization.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the pretty printing, with the given string used for each indentation level.
+    ///
+    /// # Arguments
+    /// * `indent` - The indentation string, usually spaces or a tab
+    #[inline]
+    pub fn indent(mut self, indent: impl Into<String>) -> Self {
+        self.indent = Some(indent.into());
+        self
+    }
+
+    /// Sets the maximum width of the lines, when pretty printing. A start tag that would
+    /// exceed it has its attributes written on their own line. Text content is never wrapped.
+    ///
+    /// # Arguments
+    /// * `width` - The maximum line width, in bytes
+    #[inline]
+    pub fn max_line_width(mut self, width: usize) -> Self {
+        self.max_line_width = Some(width);
+        self
+    }
+
+    /// Selects how the elements without children are written.
+    ///
+    /// # Arguments
+    /// * `policy` - The self-closing policy
+    #[inline]
+    pub fn self_closing(mut self, policy: SelfClosing) -> Self {
+        self.self_closing = policy;
+        self
+    }
+
+    /// Returns the indentation string, if the pretty printing is enabled.
+    #[inline]
+    #[must_use]
+    pub fn get_indent(&self) -> Option<&str> {
+        self.indent.as_deref()
+    }
+
+    /// Returns the maximum line width, if set.
+    #[inline]
+    #[must_use]
+    pub fn get_max_line_width(&self) -> Option<usize> {
+        self.max_line_width
+    }
+
+    /// Returns the self-closing policy.
+    #[inline]
+    #[must_use]
+    pub fn get_self_closing(&self) -> SelfClosing {
+        self.self_closing
+    }
+}
+
+/// Writes nodes according to the serialization options, as the visitor of a tree walk.
+struct Serializer<'o, W: Write> {
+    writer: W,
+    options: &'o SerializeOptions,
+    started: bool,            // Something has been written
+    depth: usize,             // Indentation level of the walked subtree
+    open: Vec<(bool, bool)>,  // For the open elements: if their children are indented, if any
+    error: Option<io::Error>, // The first error reported by the writer
+}
+
+impl<'o, W: Write> Serializer<'o, W> {
+    fn new(writer: W, options: &'o SerializeOptions) -> Self {
+        Serializer {
+            writer,
+            options,
+            started: false,
+            depth: 0,
+            open: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Starts a new line indented to the given depth, when pretty printing.
+    fn new_line(&mut self, depth: usize) -> io::Result<()> {
+        if let Some(indent) = &self.options.indent {
+            if self.started {
+                self.writer.write_all(b"\n")?;
+            }
+            for _ in 0..depth {
+                self.writer.write_all(indent.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the node and its descendants, at the given indentation level. The tree walk does
+    /// not recurse, so deeply nested documents cannot overflow the stack.
+    fn write_subtree(&mut self, node: &Node, depth: usize) -> io::Result<()> {
+        self.depth = depth;
+        node.walk(self);
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    /// Returns the indentation level of the node being visited.
+    #[inline]
+    fn current_depth(&self) -> usize {
+        self.depth + self.open.len()
+    }
+
+    /// Starts the writing of a node, on a new line if the children of its parent are
+    /// indented. Returns false if the node is whitespace text to be skipped.
+    fn begin_node(&mut self, node: &Node) -> io::Result<bool> {
+        let in_block = self
+            .open
+            .last()
+            .map_or(self.options.indent.is_some(), |(block, _)| *block);
+
+        if in_block {
+            if is_whitespace_text(node) {
+                return Ok(false);
+            }
+            self.new_line(self.current_depth())?;
+        }
+        self.started = true;
+        Ok(true)
+    }
+
+    /// Writes the start tag of an element. An element without children is written completely.
+    fn write_element(&mut self, node: &Node, has_children: bool) -> io::Result<()> {
+        self.begin_node(node)?;
+        self.write_start_tag(node, self.current_depth())?;
+        if has_children {
+            self.writer.write_all(b">")
+        } else if self.is_self_closing(node) {
+            self.writer.write_all(b"/>")
+        } else {
+            write!(self.writer, "></{}>", node.tag_name())
+        }
+    }
+
+    /// Writes a node that is not an element.
+    fn write_leaf(&mut self, node: &Node) -> io::Result<()> {
+        if !self.begin_node(node)? {
+            return Ok(());
+        }
+
+        match node.get_node_type() {
+            NodeType::Text(location) => {
+                let text = node.text().unwrap_or_default();
+                if node.is_cdata() {
+                    write!(self.writer, "<![CDATA[{text}]]>")
+                } else {
+                    // Unlike the attribute values, the text is translated only with parse_escapes
+                    let options = &node.doc.options;
+                    let ampersands = if !options.decode_entities {
+                        if node.doc.is_parsed_location(location) {
+                            Ampersands::Keep
+                        } else {
+                            Ampersands::Escape
+                        }
+                    } else if options.parse_escapes {
+                        Ampersands::Escape
+                    } else {
+                        Ampersands::KeepSequences
+                    };
+                    write_escaped(&mut self.writer, text, false, ampersands)
+                }
+            }
+            NodeType::Comment(_) => {
+                write_comment(&mut self.writer, node.comment().unwrap_or_default())
+            }
+            NodeType::EntityRef(_) => {
+                write!(self.writer, "&{};", node.entity_name().unwrap_or_default())
+            }
+            NodeType::ProcessingInstruction { .. } => write_pi(
+                &mut self.writer,
+                node.pi_target().unwrap_or_default(),
+                node.pi_data().unwrap_or_default(),
+            ),
+            NodeType::Element { .. } | NodeType::Head => Ok(()),
+        }
+    }
+
+    /// Runs a write operation, unless a previous one failed, and keeps its error.
+    fn attempt(&mut self, write: impl FnOnce(&mut Self) -> io::Result<()>) {
+        if self.error.is_none() {
+            if let Err(error) = write(self) {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    /// Writes the start tag of an element, without its closing `>` or `/>`. When pretty
+    /// printing, the attributes are written on their own line if the tag would exceed the
+    /// maximum line width.
+    fn write_start_tag(&mut self, node: &Node, depth: usize) -> io::Result<()> {
+        let mut attributes: Vec<Vec<u8>> = Vec::new();
+        for attribute in node.attributes() {
+            let mut bytes =
+                Vec::with_capacity(attribute.name().len() + attribute.value().len() + 3);
+            write!(bytes, "{}=\"", attribute.name())?;
+            let ampersands = if node.doc.options.decode_entities || !attribute.is_parsed() {
+                Ampersands::Escape
+            } else {
+                Ampersands::Keep
+            };
+            write_escaped(&mut bytes, attribute.value(), true, ampersands)?;
+            bytes.push(b'"');
+            attributes.push(bytes);
+        }
+
+        let wrap = match (&self.options.indent, self.options.max_line_width) {
+            (Some(indent), Some(width)) if attributes.len() > 1 => {
+                let len = indent.len() * depth
+                    + node.tag_name().len()
+                    + attributes.iter().map(|attr| attr.len() + 1).sum::<usize>()
+                    + 3;
+                len > width
+            }
+            _ => false,
+        };
+
+        write!(self.writer, "<{}", node.tag_name())?;
+        for attribute in &attributes {
+            if wrap {
+                self.new_line(depth + 1)?;
+            } else {
+                self.writer.write_all(b" ")?;
+            }
+            self.writer.write_all(attribute)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the element without children is to be written as an empty-element tag.
+    fn is_self_closing(&self, node: &Node) -> bool {
+        match self.options.self_closing {
+            SelfClosing::Always => true,
+            SelfClosing::Never => false,
+            SelfClosing::VoidElements => {
+                let name = node.local_name();
+                VOID_ELEMENTS
+                    .iter()
+                    .any(|void| void.eq_ignore_ascii_case(name))
+            }
+        }
+    }
+}
+
+impl<'xml, W: Write> Visitor<'xml> for Serializer<'_, W> {
+    fn enter_element(&mut self, element: &Node<'xml>) -> WalkAction {
+        // The children of an element containing no text are indented, when pretty printing
+        let block = self.options.indent.is_some()
+            && element
+                .children()
+                .all(|child| !child.is_text() || is_whitespace_text(&child));
+        let has_children = if block {
+            element.children().any(|child| !is_whitespace_text(&child))
+        } else {
+            element.has_children()
+        };
+
+        self.attempt(|serializer| serializer.write_element(element, has_children));
+        self.open.push((block, has_children));
+
+        if has_children && self.error.is_none() {
+            WalkAction::Continue
+        } else {
+            WalkAction::SkipChildren
+        }
+    }
+
+    fn leave_element(&mut self, element: &Node<'xml>) {
+        if let Some((block, true)) = self.open.pop() {
+            self.attempt(|serializer| {
+                if block {
+                    serializer.new_line(serializer.current_depth())?;
+                }
+                write!(serializer.writer, "</{}>", element.tag_name())
+            });
+        }
+    }
+
+    fn text(&mut self, text: &Node<'xml>) {
+        self.attempt(|serializer| serializer.write_leaf(text));
+    }
+
+    fn comment(&mut self, comment: &Node<'xml>) {
+        self.attempt(|serializer| serializer.write_leaf(comment));
+    }
+
+    fn processing_instruction(&mut self, pi: &Node<'xml>) {
+        self.attempt(|serializer| serializer.write_leaf(pi));
+    }
+
+    fn entity_ref(&mut self, entity_ref: &Node<'xml>) {
+        self.attempt(|serializer| serializer.write_leaf(entity_ref));
+    }
+}
+
+/// Returns true if the node is a text node composed of whitespace only.
+#[inline]
+fn is_whitespace_text(node: &Node) -> bool {
+    node.is_text()
+        && !node.is_cdata()
+        && node
+            .text()
+            .is_some_and(|text| text.bytes().all(|b| b.is_ascii_whitespace()))
+}
+
+#[inline]
+fn write_comment<W: Write>(writer: &mut W, comment: &str) -> io::Result<()> {
+    write!(writer, "<!--{comment}-->")
+}
+
+#[inline]
+fn write_pi<W: Write>(writer: &mut W, target: &str, data: &str) -> io::Result<()> {
+    if data.is_empty() {
+        write!(writer, "<?{target}?>")
+    } else {
+        write!(writer, "<?{target} {data}?>")
+    }
+}
+
+/// How the `&` of a text are written, depending on the translation of its escape sequences.
+#[derive(Clone, Copy)]
+enum Ampersands {
+    /// All escaped, as the escape sequences were translated.
+    Escape,
+    /// All written as-is, as the escape sequences of the parsed content were not translated
+    /// (`decode_entities` disabled).
+    Keep,
+    /// Written as-is when starting an escape sequence left untranslated by the parser, and
+    /// escaped otherwise, such as the ones of a text set through the mutable DOM methods.
+    KeepSequences,
+}
+
+/// Writes the text, escaping the characters that cannot appear as-is in text content or, if
+/// `in_attribute` is true, in a double-quoted attribute value. The `&` are written according
+/// to `ampersands`.
+fn write_escaped<W: Write>(
+    writer: &mut W,
+    text: &str,
+    in_attribute: bool,
+    ampersands: Ampersands,
+) -> io::Result<()> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+
+    for (pos, &byte) in bytes.iter().enumerate() {
+        let escape: &[u8] = match byte {
+            b'&' => match ampersands {
+                Ampersands::Escape => b"&amp;",
+                Ampersands::KeepSequences if !is_escape_sequence(&bytes[pos..]) => b"&amp;",
+                _ => continue,
+            },
+            b'<' => b"&lt;",
+            b'>' => b"&gt;",
+            b'"' if in_attribute => b"&quot;",
+            b'\t' if in_attribute => b"&#9;",
+            b'\n' if in_attribute => b"&#10;",
+            b'\r' => b"&#13;",
+            _ => continue,
+        };
+        writer.write_all(&bytes[start..pos])?;
+        writer.write_all(escape)?;
+        start = pos + 1;
+    }
+
+    writer.write_all(&bytes[start..])
+}
+
+/// Returns true if the bytes start with an escape sequence: a character reference
+/// (`&#nnnn;` or `&#xhhhh;`) or an entity reference (`&name;`).
+fn is_escape_sequence(bytes: &[u8]) -> bool {
+    let (digits, is_valid): (&[u8], fn(&u8) -> bool) = match bytes.get(1..) {
+        Some([b'#', b'x' | b'X', rest @ ..]) => (rest, u8::is_ascii_hexdigit),
+        Some([b'#', rest @ ..]) => (rest, u8::is_ascii_digit),
+        Some(rest) => (rest, |&b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.' | b':')
+        }),
+        None => return false,
+    };
+    let len = digits.iter().take_while(|b| is_valid(b)).count();
+    len > 0 && digits.get(len) == Some(&b';')
+}
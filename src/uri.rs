@@ -0,0 +1,209 @@
+//! URI reference resolution.
+//!
+//! This module provides the resolution of relative URI references against a base URI, as
+//! described in [RFC 3986, section 5.2](https://www.rfc-editor.org/rfc/rfc3986#section-5.2).
+//! It is used by `Node::resolve_url()` to honor the `xml:base` attributes, and can be used on
+//! its own. The URIs are processed as strings: no validation or percent-encoding normalization
+//! is done.
+
+/// The components of a URI reference. An undefined component is `None`, which differs from
+/// an empty one (e.g. `http://a/b?` has an empty query).
+struct Components<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+    /// Splits a URI reference into its components.
+    fn parse(uri: &'a str) -> Self {
+        let (rest, fragment) = match uri.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (uri, None),
+        };
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (scheme, rest) = match scheme_len(rest) {
+            Some(len) => (Some(&rest[..len]), &rest[len + 1..]),
+            None => (None, rest),
+        };
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Some(&rest[..end]), &rest[end..])
+            }
+            None => (None, rest),
+        };
+
+        Components {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+}
+
+/// Returns the length of the scheme of the URI reference, if it has one.
+fn scheme_len(uri: &str) -> Option<usize> {
+    let len = uri.find(':')?;
+    let scheme = &uri.as_bytes()[..len];
+
+    if scheme.first().is_some_and(u8::is_ascii_alphabetic)
+        && scheme
+            .iter()
+            .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+    {
+        Some(len)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the URI reference has a scheme (e.g. `http:`), i.e. it is not relative.
+///
+/// # Arguments
+/// * `uri` - The URI reference to check
+///
+/// # Example
+/// ```
+/// use xhtml_parser::uri::has_scheme;
+///
+/// assert!(has_scheme("http://example.com/a"));
+/// assert!(has_scheme("urn:isbn:123"));
+/// assert!(!has_scheme("../images/a.png"));
+/// ```
+#[must_use]
+pub fn has_scheme(uri: &str) -> bool {
+    scheme_len(uri).is_some()
+}
+
+/// Resolves a URI reference against a base URI.
+///
+/// # Arguments
+/// * `base` - The base URI, normally absolute
+/// * `reference` - The URI reference to resolve, relative or absolute
+///
+/// # Returns
+/// The resolved URI
+///
+/// # Example
+/// ```
+/// use xhtml_parser::uri::resolve;
+///
+/// assert_eq!(resolve("http://a/b/c/d;p?q", "../g"), "http://a/b/g");
+/// assert_eq!(resolve("http://a/b/c/d;p?q", "//g"), "http://g");
+/// assert_eq!(resolve("http://a/b/c/d;p?q", "#s"), "http://a/b/c/d;p?q#s");
+/// ```
+#[must_use]
+pub fn resolve(base: &str, reference: &str) -> String {
+    let base = Components::parse(base);
+    let reference = Components::parse(reference);
+
+    let merged;
+    let (scheme, authority, path, query) = if reference.scheme.is_some() {
+        (
+            reference.scheme,
+            reference.authority,
+            reference.path,
+            reference.query,
+        )
+    } else if reference.authority.is_some() {
+        (
+            base.scheme,
+            reference.authority,
+            reference.path,
+            reference.query,
+        )
+    } else if reference.path.is_empty() {
+        (
+            base.scheme,
+            base.authority,
+            base.path,
+            reference.query.or(base.query),
+        )
+    } else if reference.path.starts_with('/') {
+        (base.scheme, base.authority, reference.path, reference.query)
+    } else {
+        merged = if base.authority.is_some() && base.path.is_empty() {
+            format!("/{}", reference.path)
+        } else {
+            let dir_end = base.path.rfind('/').map_or(0, |pos| pos + 1);
+            format!("{}{}", &base.path[..dir_end], reference.path)
+        };
+        (
+            base.scheme,
+            base.authority,
+            merged.as_str(),
+            reference.query,
+        )
+    };
+
+    let mut result = String::with_capacity(base.path.len() + reference.path.len() + 16);
+    if let Some(scheme) = scheme {
+        result.push_str(scheme);
+        result.push(':');
+    }
+    if let Some(authority) = authority {
+        result.push_str("//");
+        result.push_str(authority);
+    }
+    result.push_str(&remove_dot_segments(path));
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+    if let Some(fragment) = reference.fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+
+    result
+}
+
+/// Removes the `.` and `..` segments of a path (RFC 3986, section 5.2.4).
+fn remove_dot_segments(path: &str) -> String {
+    // Removes the last segment of the output, and its preceding slash
+    fn pop_segment(output: &mut String) {
+        let len = output.rfind('/').unwrap_or(0);
+        output.truncate(len);
+    }
+
+    let mut input = path;
+    let mut output = String::with_capacity(path.len());
+
+    while !input.is_empty() {
+        if let Some(rest) = input
+            .strip_prefix("../")
+            .or_else(|| input.strip_prefix("./"))
+        {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") {
+            input = &input[3..];
+            pop_segment(&mut output);
+        } else if input == "/.." {
+            input = "/";
+            pop_segment(&mut output);
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let skip = usize::from(input.starts_with('/'));
+            let end = input[skip..]
+                .find('/')
+                .map_or(input.len(), |pos| pos + skip);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
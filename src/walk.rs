@@ -0,0 +1,176 @@
+//! Depth-first traversal of a document with enter and leave events.
+//!
+//! This module provides the `Visitor` trait and the `Document::walk()` and `Node::walk()`
+//! methods, which visit the nodes of a tree in document order. Unlike the flat `Nodes`
+//! iterator, the visitor is told when each element ends, and can skip the subtree of an
+//! element, which suits the conversion of a document to another tree-shaped format, such as
+//! the rendering of an XHTML page. The `Debug` output of a document and its serialization to
+//! XML are both produced by visitors.
+//!
+//! The traversal uses a stack of the open elements instead of recursion, so deeply nested
+//! documents do not overflow the call stack.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::walk::{Visitor, WalkAction};
+//! use xhtml_parser::{Document, Node};
+//!
+//! #[derive(Default)]
+//! struct Outline(String);
+//!
+//! impl<'xml> Visitor<'xml> for Outline {
+//!     fn enter_element(&mut self, element: &Node<'xml>) -> WalkAction {
+//!         if element.is("script") {
+//!             return WalkAction::SkipChildren;
+//!         }
+//!         self.0.push_str(&format!("<{}>", element.tag_name()));
+//!         WalkAction::Continue
+//!     }
+//!
+//!     fn leave_element(&mut self, element: &Node<'xml>) {
+//!         if !element.is("script") {
+//!             self.0.push_str(&format!("</{}>", element.tag_name()));
+//!         }
+//!     }
+//!
+//!     fn text(&mut self, text: &Node<'xml>) {
+//!         self.0.push_str(text.text().unwrap_or_default());
+//!     }
+//! }
+//!
+//! let xml_data = b"<body><p>Hi <b>there</b></p><script>code</script></body>".to_vec();
+//! let document = Document::new(xml_data).unwrap();
+//! let mut outline = Outline::default();
+//! document.walk(&mut outline);
+//!
+//! assert_eq!(outline.0, "<body><p>Hi <b>there</b></p></body>");
+//! ```
+
+use crate::document::Document;
+use crate::node::{Node, NodeChildren};
+
+/// Selects how the traversal continues after an element is entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WalkAction {
+    /// The children of the element are visited.
+    #[default]
+    Continue,
+    /// The children of the element are not visited. The element is left right away.
+    SkipChildren,
+}
+
+/// Receives the nodes visited by `Document::walk()` and `Node::walk()`.
+///
+/// All methods have a default implementation that ignores the node, so a visitor only needs
+/// to implement the events it is interested in. The comments, processing instructions and
+/// entity references are only present in the tree when kept by the parser options.
+pub trait Visitor<'xml> {
+    /// Called when an element is entered, before its children. The returned action selects
+    /// if its children are visited.
+    fn enter_element(&mut self, _element: &Node<'xml>) -> WalkAction {
+        WalkAction::Continue
+    }
+
+    /// Called when an element is left, after its children. It is called for every entered
+    /// element, including the ones whose children are skipped.
+    fn leave_element(&mut self, _element: &Node<'xml>) {}
+
+    /// Called for each text node, including the CDATA sections.
+    fn text(&mut self, _text: &Node<'xml>) {}
+
+    /// Called for each comment node.
+    fn comment(&mut self, _comment: &Node<'xml>) {}
+
+    /// Called for each processing instruction node.
+    fn processing_instruction(&mut self, _pi: &Node<'xml>) {}
+
+    /// Called for each entity reference node.
+    fn entity_ref(&mut self, _entity_ref: &Node<'xml>) {}
+}
+
+impl Document<'_> {
+    /// Visits the nodes of the document, in document order, starting with the root element.
+    ///
+    /// # Arguments
+    /// * `visitor` - The visitor receiving the nodes
+    pub fn walk<'xml, V>(&'xml self, visitor: &mut V)
+    where
+        V: Visitor<'xml> + ?Sized,
+    {
+        if let Some(root) = self.root() {
+            root.walk(visitor);
+        }
+    }
+}
+
+impl<'xml> Node<'xml> {
+    /// Visits this node and its descendants, in document order.
+    ///
+    /// # Arguments
+    /// * `visitor` - The visitor receiving the nodes
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::walk::{Visitor, WalkAction};
+    /// use xhtml_parser::{Document, Node};
+    ///
+    /// struct Depth(usize, usize);
+    ///
+    /// impl<'xml> Visitor<'xml> for Depth {
+    ///     fn enter_element(&mut self, _element: &Node<'xml>) -> WalkAction {
+    ///         self.0 += 1;
+    ///         self.1 = self.1.max(self.0);
+    ///         WalkAction::Continue
+    ///     }
+    ///
+    ///     fn leave_element(&mut self, _element: &Node<'xml>) {
+    ///         self.0 -= 1;
+    ///     }
+    /// }
+    ///
+    /// let document = Document::new(b"<a><b><c/></b><d><e><f/></e></d></a>".to_vec()).unwrap();
+    /// let mut depth = Depth(0, 0);
+    /// document.find_first("d").unwrap().walk(&mut depth);
+    ///
+    /// assert_eq!(depth.1, 3);
+    /// ```
+    pub fn walk<V>(&self, visitor: &mut V)
+    where
+        V: Visitor<'xml> + ?Sized,
+    {
+        let mut open: Vec<(Node<'xml>, NodeChildren<'xml>)> = Vec::new();
+        let mut next = Some(self.clone());
+
+        loop {
+            if let Some(node) = next.take() {
+                if node.is_element() {
+                    if visitor.enter_element(&node) == WalkAction::Continue {
+                        let children = node.children();
+                        open.push((node, children));
+                    } else {
+                        visitor.leave_element(&node);
+                    }
+                } else if node.is_text() {
+                    visitor.text(&node);
+                } else if node.is_comment() {
+                    visitor.comment(&node);
+                } else if node.is_processing_instruction() {
+                    visitor.processing_instruction(&node);
+                } else if node.is_entity_ref() {
+                    visitor.entity_ref(&node);
+                }
+            }
+
+            let Some((_, children)) = open.last_mut() else {
+                break;
+            };
+            next = children.next();
+            if next.is_none() {
+                if let Some((element, _)) = open.pop() {
+                    visitor.leave_element(&element);
+                }
+            }
+        }
+    }
+}
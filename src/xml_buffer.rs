@@ -0,0 +1,123 @@
+//! Storage of the XML content of a document.
+//!
+//! This module provides the `XmlBuffer` type, which holds the XML content processed in place
+//! by the parser. The content is either owned by the document (`Document::new()`), or borrowed
+//! from the caller (`Document::new_borrowed()`), which avoids copying a buffer living in a
+//! memory-mapped file or an arena.
+
+use std::collections::TryReserveError;
+use std::ops::{Deref, DerefMut};
+
+/// The XML content of a document, owned or mutably borrowed.
+///
+/// It dereferences to the `[u8]` content. Operations that need to grow the content, such as
+/// `Document::set_text()` with a longer text, first copy a borrowed content into an owned
+/// vector.
+#[derive(Debug, PartialEq, Eq)]
+pub enum XmlBuffer<'a> {
+    /// The content is owned by the document.
+    Owned(Vec<u8>),
+    /// The content is borrowed from the caller, and is modified in place.
+    Borrowed(&'a mut [u8]),
+}
+
+impl XmlBuffer<'_> {
+    /// Returns true if the content is borrowed from the caller.
+    #[inline]
+    #[must_use]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, XmlBuffer::Borrowed(_))
+    }
+
+    /// Returns the number of bytes the content can hold without reallocation. For a borrowed
+    /// content, this is its length.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        match self {
+            XmlBuffer::Owned(xml) => xml.capacity(),
+            XmlBuffer::Borrowed(xml) => xml.len(),
+        }
+    }
+
+    /// Returns the owned content, copying a borrowed content into an owned vector first.
+    #[inline]
+    pub fn to_mut(&mut self) -> &mut Vec<u8> {
+        if let XmlBuffer::Borrowed(xml) = self {
+            *self = XmlBuffer::Owned(xml.to_vec());
+        }
+
+        match self {
+            XmlBuffer::Owned(xml) => xml,
+            XmlBuffer::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes, copying a borrowed
+    /// content into an owned vector first.
+    ///
+    /// # Errors
+    /// Returns an error if the capacity overflows or the allocation fails.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.to_mut().try_reserve(additional)
+    }
+
+    /// Appends the bytes to the content, copying a borrowed content into an owned vector first.
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.to_mut().extend_from_slice(bytes);
+    }
+
+    /// Appends a byte to the content, copying a borrowed content into an owned vector first.
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        self.to_mut().push(byte);
+    }
+
+    /// Returns the content as an owned vector, copying it if it is borrowed.
+    #[inline]
+    #[must_use]
+    pub fn into_owned(self) -> Vec<u8> {
+        match self {
+            XmlBuffer::Owned(xml) => xml,
+            XmlBuffer::Borrowed(xml) => xml.to_vec(),
+        }
+    }
+}
+
+impl Deref for XmlBuffer<'_> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        match self {
+            XmlBuffer::Owned(xml) => xml,
+            XmlBuffer::Borrowed(xml) => xml,
+        }
+    }
+}
+
+impl DerefMut for XmlBuffer<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            XmlBuffer::Owned(xml) => xml,
+            XmlBuffer::Borrowed(xml) => xml,
+        }
+    }
+}
+
+impl From<Vec<u8>> for XmlBuffer<'_> {
+    #[inline]
+    fn from(xml: Vec<u8>) -> Self {
+        XmlBuffer::Owned(xml)
+    }
+}
+
+impl<'a> From<&'a mut [u8]> for XmlBuffer<'a> {
+    #[inline]
+    fn from(xml: &'a mut [u8]) -> Self {
+        XmlBuffer::Borrowed(xml)
+    }
+}
@@ -0,0 +1,687 @@
+//! Node selection with XPath 1.0 expressions.
+//!
+//! This module evaluates a subset of the XPath 1.0 location paths against a `Document`, to
+//! reach nodes without walking the tree with `first_child()` and `next_sibling()`. The
+//! supported subset is:
+//!
+//! - Absolute (`/html/body`) and relative (`body/p`) location paths, and the `//` abbreviation.
+//! - The `child`, `descendant`, `descendant-or-self` and `self` axes, and the `.` abbreviation.
+//! - The name tests (`p`, `svg:rect`), `*`, and the `node()`, `text()`, `comment()` and
+//!   `processing-instruction()` node type tests.
+//! - Predicates testing the presence (`[@id]`) or value (`[@class='x']`, `[@class!='x']`) of
+//!   an attribute, positional predicates (`[2]`, `[last()]`, `[last()-1]`,
+//!   `[position()<3]`), combined with `and`, `or`, `not()` and parentheses.
+//!
+//! The element names are the tag names kept by the parser: when the namespace prefixes are
+//! removed, the name tests must not use them. The attribute axis can only be used in
+//! predicates, as attributes are not nodes in this crate. The document node is the context
+//! of the absolute paths, but it is never part of the selected nodes.
+//!
+//! # Example
+//!
+//! ```
+//! use xhtml_parser::Document;
+//!
+//! let xml_data = b"<html><body><p class=\"x\">A</p><div><p>B</p><p class=\"x\">C</p></div></body></html>".to_vec();
+//! let document = Document::new(xml_data).unwrap();
+//! let nodes = document.select("/html/body//p[@class='x']").unwrap();
+//!
+//! assert_eq!(nodes.len(), 2);
+//! assert_eq!(nodes[1].text_content(), "C");
+//! assert_eq!(document.select("//div/p[last()]/text()").unwrap()[0].text(), Some("C"));
+//! ```
+
+use crate::defs::ParseXmlError;
+use crate::document::Document;
+use crate::node::Node;
+
+/// A compiled XPath location path.
+///
+/// Compiling the expression once with `XPath::new()` avoids parsing it again when it is
+/// evaluated many times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XPath {
+    absolute: bool,
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Expr>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Child,
+    Descendant,
+    DescendantOrSelf,
+    SelfNode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NodeTest {
+    Name(String),
+    AnyElement,
+    Node,
+    Text,
+    Comment,
+    ProcessingInstruction,
+}
+
+/// A predicate expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    HasAttribute(Option<String>), // None for `@*`
+    AttributeEquals {
+        name: Option<String>,
+        value: String,
+        equal: bool,
+    },
+    Position(Comparison, Position),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// A position, `Last(n)` being `last() - n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Value(usize),
+    Last(usize),
+}
+
+impl XPath {
+    /// Compiles an XPath expression.
+    ///
+    /// # Arguments
+    /// * `expr` - The XPath location path, in the subset described in the module documentation
+    ///
+    /// # Returns
+    /// - `Ok(XPath)`: The compiled expression.
+    /// - `Err(ParseXmlError)`: If the expression is not valid or not supported.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXPath`: If the expression is not valid or not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::xpath::XPath;
+    ///
+    /// assert!(XPath::new("//ul/li[position() > 1]").is_ok());
+    /// assert!(XPath::new("//ul/li[").is_err());
+    /// ```
+    pub fn new(expr: &str) -> Result<Self, ParseXmlError> {
+        let mut parser = ExprParser { expr, pos: 0 };
+        let xpath = parser.location_path()?;
+        parser.skip_whitespace();
+        if parser.pos < expr.len() {
+            return Err(parser.error("Unexpected character"));
+        }
+        Ok(xpath)
+    }
+
+    /// Selects the nodes of a document, the relative paths starting at the document node.
+    ///
+    /// # Arguments
+    /// * `document` - The document to search
+    ///
+    /// # Returns
+    /// The selected nodes, in document order
+    #[must_use]
+    pub fn select<'a>(&self, document: &'a Document<'a>) -> Vec<Node<'a>> {
+        self.evaluate(document, None)
+    }
+
+    /// Selects nodes from a context node. The absolute paths start at the document node.
+    ///
+    /// # Arguments
+    /// * `node` - The context node of the relative paths
+    ///
+    /// # Returns
+    /// The selected nodes, in document order
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    /// use xhtml_parser::xpath::XPath;
+    ///
+    /// let document = Document::new(b"<r><a><b/></a><b/></r>".to_vec()).unwrap();
+    /// let a = document.find_first("a").unwrap();
+    ///
+    /// assert_eq!(XPath::new("b").unwrap().select_from(&a).len(), 1);
+    /// assert_eq!(XPath::new("//b").unwrap().select_from(&a).len(), 2);
+    /// ```
+    #[must_use]
+    pub fn select_from<'a>(&self, node: &Node<'a>) -> Vec<Node<'a>> {
+        self.evaluate(node.doc, Some(node.clone()))
+    }
+
+    /// Evaluates the steps of the path, `None` being the document node.
+    fn evaluate<'a>(&self, document: &'a Document<'a>, context: Option<Node<'a>>) -> Vec<Node<'a>> {
+        let mut contexts = if self.absolute {
+            vec![None]
+        } else {
+            vec![context]
+        };
+
+        for step in &self.steps {
+            let mut selected = Vec::new();
+            for context in &contexts {
+                let mut candidates: Vec<Option<Node<'a>>> = step
+                    .axis_nodes(document, context)
+                    .into_iter()
+                    .filter(|node| step.test.matches(node.as_ref()))
+                    .collect();
+                for predicate in &step.predicates {
+                    let last = candidates.len();
+                    candidates = candidates
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(i, node)| predicate.matches(node.as_ref(), i + 1, last))
+                        .map(|(_, node)| node)
+                        .collect();
+                }
+                selected.append(&mut candidates);
+            }
+
+            // The node indexes follow the document order, the document node being before them
+            selected.sort_by_key(|node| node.as_ref().map(Node::idx));
+            selected.dedup_by_key(|node| node.as_ref().map(Node::idx));
+            contexts = selected;
+        }
+
+        contexts.into_iter().flatten().collect()
+    }
+}
+
+impl Step {
+    /// The `descendant-or-self::node()` step, abbreviated by `//`.
+    fn descendant_or_self() -> Self {
+        Step {
+            axis: Axis::DescendantOrSelf,
+            test: NodeTest::Node,
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Returns the nodes of the step axis from the context node, in document order.
+    fn axis_nodes<'a>(
+        &self,
+        document: &'a Document<'a>,
+        context: &Option<Node<'a>>,
+    ) -> Vec<Option<Node<'a>>> {
+        let mut nodes = Vec::new();
+        if matches!(self.axis, Axis::SelfNode | Axis::DescendantOrSelf) {
+            nodes.push(context.clone());
+        }
+        match (self.axis, context) {
+            (Axis::Child, None) => nodes.extend(document.root().map(Some)),
+            (Axis::Child, Some(node)) => nodes.extend(node.children().map(Some)),
+            (Axis::Descendant | Axis::DescendantOrSelf, None) => {
+                nodes.extend(document.all_nodes().map(Some));
+            }
+            (Axis::Descendant | Axis::DescendantOrSelf, Some(node)) => {
+                nodes.extend(node.descendants().map(Some));
+            }
+            (Axis::SelfNode, _) => {}
+        }
+        nodes
+    }
+}
+
+impl NodeTest {
+    /// Returns true if the node, `None` being the document node, passes the test.
+    fn matches(&self, node: Option<&Node>) -> bool {
+        let Some(node) = node else {
+            return *self == NodeTest::Node;
+        };
+        match self {
+            NodeTest::Name(name) => node.is_element() && node.is(name),
+            NodeTest::AnyElement => node.is_element(),
+            NodeTest::Node => true,
+            NodeTest::Text => node.is_text(),
+            NodeTest::Comment => node.is_comment(),
+            NodeTest::ProcessingInstruction => node.is_processing_instruction(),
+        }
+    }
+}
+
+impl Expr {
+    /// Returns true if the node, at the given position of a node-set of size `last`, satisfies
+    /// the predicate.
+    fn matches(&self, node: Option<&Node>, position: usize, last: usize) -> bool {
+        match self {
+            Expr::Or(left, right) => {
+                left.matches(node, position, last) || right.matches(node, position, last)
+            }
+            Expr::And(left, right) => {
+                left.matches(node, position, last) && right.matches(node, position, last)
+            }
+            Expr::Not(expr) => !expr.matches(node, position, last),
+            Expr::HasAttribute(name) => node.is_some_and(|node| match name {
+                Some(name) => node.get_attribute(name).is_some(),
+                None => node.attributes().next().is_some(),
+            }),
+            // As in XPath, `@a != 'x'` is false when there is no `a` attribute
+            Expr::AttributeEquals { name, value, equal } => node.is_some_and(|node| {
+                node.attributes().any(|attribute| {
+                    name.as_ref().is_none_or(|name| attribute.is(name))
+                        && (attribute.value() == value) == *equal
+                })
+            }),
+            Expr::Position(comparison, value) => {
+                let value = match *value {
+                    Position::Value(value) => value,
+                    Position::Last(offset) => match last.checked_sub(offset) {
+                        Some(value) => value,
+                        None => return false,
+                    },
+                };
+                match comparison {
+                    Comparison::Equal => position == value,
+                    Comparison::NotEqual => position != value,
+                    Comparison::Less => position < value,
+                    Comparison::LessOrEqual => position <= value,
+                    Comparison::Greater => position > value,
+                    Comparison::GreaterOrEqual => position >= value,
+                }
+            }
+        }
+    }
+}
+
+/// A recursive descent parser of XPath expressions.
+struct ExprParser<'e> {
+    expr: &'e str,
+    pos: usize,
+}
+
+impl<'e> ExprParser<'e> {
+    fn error(&self, msg: &str) -> ParseXmlError {
+        ParseXmlError::InvalidXPath(format!("{msg} at position {} in `{}`", self.pos, self.expr))
+    }
+
+    fn rest(&self) -> &str {
+        &self.expr[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes the token if the expression continues with it, after whitespaces.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), ParseXmlError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected `{token}`")))
+        }
+    }
+
+    /// Consumes a name, stopping before an axis separator (`::`).
+    fn name(&mut self) -> Option<&'e str> {
+        self.skip_whitespace();
+        let rest = &self.expr[self.pos..];
+        let mut chars = rest.char_indices().peekable();
+        match chars.peek() {
+            Some(&(_, c)) if c.is_alphabetic() || c == '_' => {}
+            _ => return None,
+        }
+
+        let mut end = rest.len();
+        while let Some((i, c)) = chars.next() {
+            let is_axis_separator = c == ':' && chars.peek().is_some_and(|&(_, c)| c == ':');
+            if is_axis_separator || !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':')) {
+                end = i;
+                break;
+            }
+        }
+
+        self.pos += end;
+        Some(&rest[..end])
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let number = rest[..len].parse().ok()?;
+        self.pos += len;
+        Some(number)
+    }
+
+    fn location_path(&mut self) -> Result<XPath, ParseXmlError> {
+        let mut steps = Vec::new();
+        let absolute = if self.eat("//") {
+            steps.push(Step::descendant_or_self());
+            true
+        } else if self.eat("/") {
+            if self.at_path_end() {
+                return Ok(XPath {
+                    absolute: true,
+                    steps,
+                });
+            }
+            true
+        } else {
+            false
+        };
+
+        loop {
+            steps.push(self.step()?);
+            if self.eat("//") {
+                steps.push(Step::descendant_or_self());
+            } else if !self.eat("/") {
+                return Ok(XPath { absolute, steps });
+            }
+        }
+    }
+
+    fn at_path_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.expr.len()
+    }
+
+    fn step(&mut self) -> Result<Step, ParseXmlError> {
+        if self.eat("..") {
+            return Err(self.error("The parent axis is not supported"));
+        }
+        if self.eat(".") {
+            return Ok(Step {
+                axis: Axis::SelfNode,
+                test: NodeTest::Node,
+                predicates: Vec::new(),
+            });
+        }
+        if self.eat("@") {
+            return Err(self.error("Attribute steps are only supported in predicates"));
+        }
+
+        let start = self.pos;
+        let mut axis = Axis::Child;
+        if let Some(name) = self.name() {
+            if self.eat("::") {
+                axis = match name {
+                    "child" => Axis::Child,
+                    "descendant" => Axis::Descendant,
+                    "descendant-or-self" => Axis::DescendantOrSelf,
+                    "self" => Axis::SelfNode,
+                    _ => {
+                        self.pos = start;
+                        return Err(self.error(&format!("The `{name}` axis is not supported")));
+                    }
+                };
+            } else {
+                self.pos = start;
+            }
+        }
+
+        let test = self.node_test()?;
+        let mut predicates = Vec::new();
+        while self.eat("[") {
+            predicates.push(self.or_expr()?);
+            self.expect("]")?;
+        }
+
+        Ok(Step {
+            axis,
+            test,
+            predicates,
+        })
+    }
+
+    fn node_test(&mut self) -> Result<NodeTest, ParseXmlError> {
+        if self.eat("*") {
+            return Ok(NodeTest::AnyElement);
+        }
+        let start = self.pos;
+        let Some(name) = self.name() else {
+            return Err(self.error("Expected a node test"));
+        };
+        let name = name.to_string();
+        if !self.eat("(") {
+            return Ok(NodeTest::Name(name));
+        }
+
+        let test = match name.as_str() {
+            "node" => NodeTest::Node,
+            "text" => NodeTest::Text,
+            "comment" => NodeTest::Comment,
+            "processing-instruction" => NodeTest::ProcessingInstruction,
+            _ => {
+                self.pos = start;
+                return Err(self.error(&format!("Unknown node type test `{name}()`")));
+            }
+        };
+        self.expect(")")?;
+        Ok(test)
+    }
+
+    /// Consumes a keyword (`and`, `or`), which must not be the start of a longer name.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let start = self.pos;
+        if self.name() == Some(keyword) {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, ParseXmlError> {
+        let mut expr = self.and_expr()?;
+        while self.keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, ParseXmlError> {
+        let mut expr = self.primary_expr()?;
+        while self.keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.primary_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn primary_expr(&mut self) -> Result<Expr, ParseXmlError> {
+        if self.eat("(") {
+            let expr = self.or_expr()?;
+            self.expect(")")?;
+            return Ok(expr);
+        }
+        if self.eat("@") {
+            return self.attribute_expr();
+        }
+        if let Some(position) = self.position()? {
+            return Ok(Expr::Position(Comparison::Equal, position));
+        }
+
+        let start = self.pos;
+        match self.name() {
+            Some("not") if self.eat("(") => {
+                let expr = self.or_expr()?;
+                self.expect(")")?;
+                Ok(Expr::Not(Box::new(expr)))
+            }
+            Some("position") if self.eat("(") => {
+                self.expect(")")?;
+                let comparison = self.comparison()?;
+                match self.position()? {
+                    Some(position) => Ok(Expr::Position(comparison, position)),
+                    None => Err(self.error("Expected a number or `last()`")),
+                }
+            }
+            _ => {
+                self.pos = start;
+                Err(self.error("Unsupported predicate"))
+            }
+        }
+    }
+
+    fn attribute_expr(&mut self) -> Result<Expr, ParseXmlError> {
+        let name = if self.eat("*") {
+            None
+        } else {
+            match self.name() {
+                Some(name) => Some(name.to_string()),
+                None => return Err(self.error("Expected an attribute name")),
+            }
+        };
+
+        let equal = if self.eat("!=") {
+            false
+        } else if self.eat("=") {
+            true
+        } else {
+            return Ok(Expr::HasAttribute(name));
+        };
+
+        Ok(Expr::AttributeEquals {
+            name,
+            value: self.literal()?,
+            equal,
+        })
+    }
+
+    fn literal(&mut self) -> Result<String, ParseXmlError> {
+        self.skip_whitespace();
+        let Some(quote) = self
+            .rest()
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+        else {
+            return Err(self.error("Expected a string literal"));
+        };
+        let Some(len) = self.rest()[1..].find(quote) else {
+            return Err(self.error("Unterminated string literal"));
+        };
+        let literal = self.rest()[1..=len].to_string();
+        self.pos += len + 2;
+        Ok(literal)
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, ParseXmlError> {
+        // The two characters operators first
+        for (token, comparison) in [
+            ("!=", Comparison::NotEqual),
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("=", Comparison::Equal),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+        ] {
+            if self.eat(token) {
+                return Ok(comparison);
+            }
+        }
+        Err(self.error("Expected a comparison operator"))
+    }
+
+    /// Consumes a number, `last()` or `last() - number`.
+    fn position(&mut self) -> Result<Option<Position>, ParseXmlError> {
+        if let Some(number) = self.number() {
+            return Ok(Some(Position::Value(number)));
+        }
+
+        let start = self.pos;
+        if self.name() == Some("last") && self.eat("(") {
+            self.expect(")")?;
+            if !self.eat("-") {
+                return Ok(Some(Position::Last(0)));
+            }
+            match self.number() {
+                Some(offset) => Ok(Some(Position::Last(offset))),
+                None => Err(self.error("Expected a number")),
+            }
+        } else {
+            self.pos = start;
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> Document<'a> {
+    /// Selects the nodes matching an XPath expression, the relative paths starting at the
+    /// document node.
+    ///
+    /// The expression is compiled at each call: use `XPath::new()` and `XPath::select()` to
+    /// evaluate it several times.
+    ///
+    /// # Arguments
+    /// * `expr` - The XPath location path, in the subset described in the `xpath` module
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Node>)`: The selected nodes, in document order.
+    /// - `Err(ParseXmlError)`: If the expression is not valid or not supported.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXPath`: If the expression is not valid or not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<ul><li>1</li><li id=\"b\">2</li><li>3</li></ul>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    ///
+    /// assert_eq!(document.select("/ul/li[2]").unwrap()[0].get_attribute("id"), Some("b"));
+    /// assert_eq!(document.select("//li[not(@id)]").unwrap().len(), 2);
+    /// ```
+    pub fn select(&'a self, expr: &str) -> Result<Vec<Node<'a>>, ParseXmlError> {
+        Ok(XPath::new(expr)?.select(self))
+    }
+}
+
+impl<'xml> Node<'xml> {
+    /// Selects the nodes matching an XPath expression, the relative paths starting at this
+    /// node.
+    ///
+    /// # Arguments
+    /// * `expr` - The XPath location path, in the subset described in the `xpath` module
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Node>)`: The selected nodes, in document order.
+    /// - `Err(ParseXmlError)`: If the expression is not valid or not supported.
+    ///
+    /// # Errors
+    /// - `ParseXmlError::InvalidXPath`: If the expression is not valid or not supported.
+    ///
+    /// # Example
+    /// ```
+    /// use xhtml_parser::Document;
+    ///
+    /// let xml_data = b"<r><a><b>1</b><b>2</b></a><b>3</b></r>".to_vec();
+    /// let document = Document::new(xml_data).unwrap();
+    /// let a = document.find_first("a").unwrap();
+    ///
+    /// assert_eq!(a.select("b[last()]").unwrap()[0].text_content(), "2");
+    /// ```
+    pub fn select(&self, expr: &str) -> Result<Vec<Node<'xml>>, ParseXmlError> {
+        Ok(XPath::new(expr)?.select_from(self))
+    }
+}
@@ -26,9 +26,8 @@ mod negative_tests {
         let result = Document::new(invalid_utf8);
         // Note: The parser might handle this differently based on implementation
         // but it should not panic
-        if result.is_ok() {
+        if let Ok(doc) = result {
             // If it doesn't fail immediately, operations on it should handle gracefully
-            let doc = result.unwrap();
             assert!(doc.is_empty());
         }
     }
@@ -467,8 +466,7 @@ mod negative_tests {
         let xml = b"<root>Content with &invalidEntity; here</root>".to_vec();
         let result = Document::new(xml);
         // Invalid entities should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -482,8 +480,7 @@ mod negative_tests {
         let xml = b"<root>Content with &amp here</root>".to_vec();
         let result = Document::new(xml);
         // Incomplete entities should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -497,8 +494,7 @@ mod negative_tests {
         let xml = b"<root>Content with &#invalid; here</root>".to_vec();
         let result = Document::new(xml);
         // Invalid numeric entities should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -512,8 +508,7 @@ mod negative_tests {
         let xml = b"<root>Content with &; here</root>".to_vec();
         let result = Document::new(xml);
         // Empty entities should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -529,8 +524,7 @@ mod negative_tests {
         let xml = b"<root>Content\x00with\x00nulls</root>".to_vec();
         let result = Document::new(xml);
         // Null bytes should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -544,8 +538,7 @@ mod negative_tests {
         let xml = b"<root>Content\x01\x02\x03</root>".to_vec();
         let result = Document::new(xml);
         // Control characters should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
@@ -571,8 +564,7 @@ mod negative_tests {
         xml.extend_from_slice(b"<root>Content</root>");
         let result = Document::new(xml);
         // BOM should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             assert!(root.is("root"));
         }
@@ -816,8 +808,7 @@ mod negative_tests {
     fn test_namespace_with_empty_prefix() {
         let xml = b"<:root>Content</:root>".to_vec();
         let result = Document::new(xml);
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             assert_eq!(root.tag_name(), "root");
         }
@@ -829,8 +820,7 @@ mod negative_tests {
         let xml = b"<root xmlns:=\"http://example.com\">Content</root>".to_vec();
         let result = Document::new(xml);
         // This should be handled gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             assert_eq!(root.tag_name(), "root");
         }
@@ -853,8 +843,7 @@ mod negative_tests {
         let xml = b"<root>Content &#xGGG;</root>".to_vec();
         let result = Document::new(xml);
         // Should handle malformed numeric escapes gracefully
-        if result.is_ok() {
-            let document = result.unwrap();
+        if let Ok(document) = result {
             let root = document.root().unwrap();
             let text_node = root.first_child().unwrap();
             let text = text_node.text().unwrap();
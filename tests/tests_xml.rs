@@ -1,7 +1,22 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 #[cfg(test)]
 mod xhtml_parser_tests {
-    use xhtml_parser::document::Document;
-    use xhtml_parser::node::Node;
+    use xhtml_parser::builder::DocumentBuilder;
+    use xhtml_parser::defs::{NodeIdx, ParseXmlError, Violation, XmlDeclaration, XmlIdx};
+    use xhtml_parser::diff::{diff, diff_with_options, DiffOptions, Difference};
+    use xhtml_parser::document::{Document, FRAGMENT_ROOT_NAME};
+    use xhtml_parser::json::JsonOptions;
+    use xhtml_parser::node::{Node, NodeId};
+    use xhtml_parser::parser::{
+        stream, stream_with_options, Event, FeedParser, InvalidUtf8, ParserOptions, PrefixFilter,
+        SaxAttributes, SaxHandler, Tokenizer, WhitespaceMode,
+    };
+    use xhtml_parser::query::Query;
+    use xhtml_parser::serializer::{SelfClosing, SelfClosingMode, SerializeOptions};
+    use xhtml_parser::uri;
+    use xhtml_parser::walk::{Visitor, WalkAction};
+    use xhtml_parser::xpath::XPath;
 
     use test_support::unit_test::UnitTest;
     use timelapse::{profile_end_print, profile_start, TimeLapse};
@@ -44,7 +59,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -85,7 +100,7 @@ mod xhtml_parser_tests {
                 let data = format!("{:#?}", doc);
                 profile_end_print!(formatting_data);
 
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -120,7 +135,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -155,7 +170,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -190,7 +205,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -225,7 +240,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -266,7 +281,7 @@ mod xhtml_parser_tests {
                 );
 
                 let data = format!("{:#?}", document.unwrap());
-                assert!(unit_test.check_result_with_file(&data, &file_name));
+                assert!(unit_test.check_result_with_file(&data, file_name));
             }
         }
     }
@@ -282,5 +297,4339 @@ mod xhtml_parser_tests {
         assert!(descendants[0].is("child"));
         assert_eq!(descendants[1].text().unwrap(), "Text");
         assert!(descendants[2].is("totototo"));
+
+        let child_descendants: Vec<Node> = descendants[0].descendants().collect();
+        assert_eq!(child_descendants.len(), 1); // Text only, not the following sibling
+        assert_eq!(child_descendants[0].text().unwrap(), "Text");
+    }
+
+    #[test]
+    fn test_attribute_value_bytes() {
+        let xml = br#"<root name="value" other="second"/>"#.to_vec();
+        let document = Document::new(xml).unwrap();
+        let root = document.root().unwrap();
+        let values: Vec<Vec<u8>> = root
+            .attributes()
+            .map(|attr| attr.value_bytes().to_vec())
+            .collect();
+
+        assert_eq!(values, vec![b"value".to_vec(), b"second".to_vec()]);
+    }
+
+    #[test]
+    fn test_last_descendant_stops_at_following_sibling() {
+        let xml = b"<root><a><b><c/></b></a><d><e/></d></root>".to_vec();
+        let document = Document::new(xml).unwrap();
+        let a = document.root().unwrap().first_child().unwrap();
+        let b = a.first_child().unwrap();
+
+        assert_eq!(document.last_descendant(a.idx()), Some(4)); // c, not d nor e
+        assert_eq!(document.last_descendant(b.idx()), Some(4));
+        assert_eq!(document.last_descendant(5), Some(6)); // e, for d
+        assert_eq!(a.descendants().count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "forward_only")]
+    fn test_children_rev_forward_only() {
+        let xml_data = b"<root><a/>Text<b/><c/></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root_node = document.root().unwrap();
+        let children: Vec<Node> = root_node.children_rev().collect();
+
+        assert_eq!(children.len(), 4);
+        assert!(children[0].is("c"));
+        assert!(children[1].is("b"));
+        assert_eq!(children[2].text().unwrap(), "Text");
+        assert!(children[3].is("a"));
+    }
+
+    #[test]
+    fn test_validate_against_allowed() {
+        let xml_data =
+            b"<div class=\"x\"><p onclick=\"alert(1)\">Hi</p><script>evil()</script></div>"
+                .to_vec();
+        let document = Document::new(xml_data).unwrap();
+
+        let violations = document
+            .validate_against_allowed(&["div", "p"], &["class"])
+            .unwrap_err();
+
+        assert_eq!(
+            violations,
+            vec![
+                Violation::Attribute {
+                    node_idx: 2,
+                    name: "onclick".to_string()
+                },
+                Violation::Element {
+                    node_idx: 4,
+                    tag_name: "script".to_string()
+                },
+            ]
+        );
+
+        assert!(document
+            .validate_against_allowed(&["div", "p", "script"], &["class", "onclick"])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_attribute_count_matching() {
+        let xml_data =
+            b"<root id=\"r\" data-a=\"1\" class=\"c\" data-b=\"2\" data-c=\"3\"/>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root_node = document.root().unwrap();
+
+        assert_eq!(
+            root_node.attribute_count_matching(|attr| attr.name().starts_with("data-")),
+            3
+        );
+        assert_eq!(root_node.attribute_count_matching(|attr| attr.is("id")), 1);
+        assert_eq!(
+            root_node.attribute_count_matching(|attr| attr.is("none")),
+            0
+        );
+    }
+
+    #[test]
+    fn test_replace_text_globally() {
+        let xml_data =
+            b"<root><name>{{name}}</name><p>Hello</p><q>{{name}}</q><r>{{name}}!</r></root>"
+                .to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+
+        assert_eq!(
+            document.replace_text_globally("{{name}}", "Alice").unwrap(),
+            2
+        );
+        assert_eq!(
+            document
+                .replace_text_globally("Hello", "A much longer greeting")
+                .unwrap(),
+            1
+        );
+        assert_eq!(document.replace_text_globally("{{none}}", "x").unwrap(), 0);
+
+        let texts: Vec<&str> = document.all_nodes().filter_map(|n| n.text()).collect();
+        assert_eq!(
+            texts,
+            vec!["Alice", "A much longer greeting", "Alice", "{{name}}!"]
+        );
+    }
+
+    #[test]
+    fn test_to_text_events() {
+        let xml_data =
+            b"<root>A somewhat long text node, to be streamed in chunks.<e/></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let text_node = document.root().unwrap().first_child().unwrap();
+
+        for chunk_size in [1, 3, 7, 64] {
+            let reassembled: Vec<u8> = text_node
+                .to_text_events(chunk_size)
+                .flatten()
+                .copied()
+                .collect();
+            assert_eq!(reassembled, text_node.text_bytes().unwrap());
+            assert!(text_node
+                .to_text_events(chunk_size)
+                .all(|chunk| chunk.len() <= chunk_size));
+        }
+
+        let element = text_node.next_sibling().unwrap();
+        assert_eq!(element.to_text_events(4).count(), 0);
+    }
+
+    #[test]
+    fn test_get_attribute_or() {
+        let xml_data = b"<root lang=\"fr\"><child/></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root_node = document.root().unwrap();
+        let child_node = root_node.first_child().unwrap();
+
+        assert_eq!(root_node.get_attribute_or("lang", "en"), "fr");
+        assert_eq!(child_node.get_attribute_or("lang", "en"), "en");
+
+        let fallback = String::from("und");
+        let lang: &str = child_node.get_attribute_or("lang", &fallback);
+        assert_eq!(lang, "und");
+    }
+
+    #[test]
+    fn test_map_attributes() {
+        let xml_data = b"<root name=\"The root\" id=\"1\" count=\"x\"/>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root_node = document.root().unwrap();
+
+        let pairs: Vec<(String, String)> =
+            root_node.map_attributes(|attr| (attr.name().to_string(), attr.value().to_string()));
+        assert_eq!(
+            pairs,
+            vec![
+                ("name".to_string(), "The root".to_string()),
+                ("id".to_string(), "1".to_string()),
+                ("count".to_string(), "x".to_string()),
+            ]
+        );
+
+        let numbers = root_node.try_map_attributes(|attr| attr.value().parse::<u32>());
+        assert!(numbers.is_err());
+    }
+
+    #[test]
+    fn test_subtree_hash() {
+        let doc1 = Document::new(
+            b"<root><sec id=\"a\"><p>One</p><p>Two</p></sec><sec><p>Other</p></sec></root>"
+                .to_vec(),
+        )
+        .unwrap();
+        let doc2 = Document::new(b"<book><sec id=\"a\"><p>One</p><p>Two</p></sec></book>".to_vec())
+            .unwrap();
+        let doc3 = Document::new(b"<book><sec id=\"b\"><p>One</p><p>Two</p></sec></book>".to_vec())
+            .unwrap();
+        let doc4 = Document::new(b"<book><sec id=\"a\"><p>One<p>Two</p></p></sec></book>".to_vec())
+            .unwrap();
+
+        let sec1 = doc1.root().unwrap().first_child().unwrap();
+        let sec2 = doc2.root().unwrap().first_child().unwrap();
+        let sec3 = doc3.root().unwrap().first_child().unwrap();
+        let sec4 = doc4.root().unwrap().first_child().unwrap();
+
+        let hash1 = doc1.subtree_hash(sec1.idx()).unwrap();
+        assert_eq!(hash1, doc2.subtree_hash(sec2.idx()).unwrap());
+        assert_ne!(hash1, doc3.subtree_hash(sec3.idx()).unwrap()); // attribute value changed
+        assert_ne!(hash1, doc4.subtree_hash(sec4.idx()).unwrap()); // nesting changed
+
+        let other_sec = sec1.next_sibling().unwrap();
+        assert_ne!(hash1, doc1.subtree_hash(other_sec.idx()).unwrap());
+        assert!(doc1.subtree_hash(1000).is_err());
+    }
+
+    #[test]
+    fn test_text_content_len() {
+        let samples: [&[u8]; 4] = [
+            b"<root>Hello <b>World</b>!</root>",
+            b"<root><a/><b/></root>",
+            b"<root><p>caf\xC3\xA9 &amp; cr\xC3\xA8me</p><p><i>x</i>yz</p></root>",
+            b"<root>Single</root>",
+        ];
+
+        for sample in samples {
+            let document = Document::new(sample.to_vec()).unwrap();
+            for node in document.all_nodes() {
+                assert_eq!(node.text_content_len(), node.text_content().len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_first() {
+        let xml_data =
+            b"<html><body><p id=\"1\"/><div><p id=\"2\"/></div><p id=\"3\"/></body></html>"
+                .to_vec();
+        let document = Document::new(xml_data).unwrap();
+
+        let p = document.find_first("p").unwrap();
+        assert_eq!(p.get_attribute("id"), Some("1"));
+        assert!(document.find_first("div").unwrap().is("div"));
+        assert!(document.find_first("html").unwrap().is_root());
+        assert!(document.find_first("span").is_none());
+    }
+
+    #[test]
+    fn test_attribute_names_bytes() {
+        let xml_data = b"<svg width=\"10\" height=\"20\" viewBox=\"0 0 10 20\"><g/></svg>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root_node = document.root().unwrap();
+
+        let names: Vec<&str> = root_node.attribute_names().collect();
+        let names_bytes: Vec<&[u8]> = root_node.attribute_names_bytes().collect();
+
+        assert_eq!(names, vec!["width", "height", "viewBox"]);
+        assert_eq!(names_bytes.len(), names.len());
+        for (name, name_bytes) in names.iter().zip(names_bytes) {
+            assert_eq!(name.as_bytes(), name_bytes);
+        }
+
+        let g = root_node.first_child().unwrap();
+        assert_eq!(g.attribute_names_bytes().count(), 0);
+    }
+
+    #[test]
+    fn test_prolog_comments() {
+        let xml_data = b"<?xml version=\"1.0\"?>\n<!-- License: MIT -->\n<!DOCTYPE html>\n<!--second--><r><!-- inside --></r>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let comments: Vec<&str> = document.prolog_comments().collect();
+
+        assert_eq!(comments, vec![" License: MIT ", "second"]);
+        assert!(document.root().unwrap().is("r"));
+
+        let document = Document::new(b"<r/>".to_vec()).unwrap();
+        assert_eq!(document.prolog_comments().count(), 0);
+    }
+
+    #[test]
+    fn test_with_each_descendant() {
+        let xml_data = b"<root><a>one<b x=\"1\"/></a>two<c><d><e/></d></c><f/></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root = document.root().unwrap();
+
+        for node in document.all_nodes() {
+            let mut visited = Vec::new();
+            node.with_each_descendant(|n| visited.push(n.idx()));
+            let expected: Vec<_> = node.descendants().map(|n| n.idx()).collect();
+            assert_eq!(visited, expected);
+        }
+
+        let mut visited = 0;
+        root.with_each_descendant(|_| visited += 1);
+        assert_eq!(visited, 8);
+    }
+
+    #[test]
+    fn test_is_probably_html() {
+        let xml_data = b"<!DOCTYPE html>\n<html lang=\"en\"><head><title>T</title></head><body><p>Hi</p></body></html>".to_vec();
+        assert!(Document::new(xml_data).unwrap().is_probably_html());
+
+        let xml_data = b"<!DOCTYPE HTML><div>fragment</div>".to_vec();
+        assert!(Document::new(xml_data).unwrap().is_probably_html());
+
+        let xml_data = b"<page><BODY>text</BODY></page>".to_vec();
+        assert!(Document::new(xml_data).unwrap().is_probably_html());
+
+        let xml_data = b"<?xml version=\"1.0\"?><!DOCTYPE config><config><entry key=\"a\">html</entry></config>".to_vec();
+        assert!(!Document::new(xml_data).unwrap().is_probably_html());
+
+        let xml_data =
+            b"<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.1//EN\" \"xhtml11.dtd\"><div/>";
+        assert!(Document::new(xml_data.to_vec()).unwrap().is_probably_html());
+
+        for xml_data in [
+            &b"<!DOCTYPE html-config><config/>"[..],
+            b"<!DOCTYPE htmlhelp SYSTEM \"help.dtd\"><toc/>",
+            b"<!DOCTYPE html_report [<!ENTITY a \"b\">]><report/>",
+        ] {
+            assert!(!Document::new(xml_data.to_vec()).unwrap().is_probably_html());
+        }
+    }
+
+    #[test]
+    fn test_inner_text_with_separator() {
+        let xml_data =
+            b"<ul>\n<li>first</li><li>second <b>bold</b></li><li/><li>third</li></ul>".to_vec();
+        let options = ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let root = document.root().unwrap();
+
+        assert_eq!(
+            root.inner_text_with_separator("\n"),
+            "first\nsecond \nbold\nthird"
+        );
+        assert_eq!(
+            root.first_child().unwrap().inner_text_with_separator(" | "),
+            "first"
+        );
+        assert_eq!(
+            root.inner_text_with_separator("").len(),
+            root.text_content_len()
+        );
+    }
+
+    #[test]
+    fn test_inner_text_separator_boundaries() {
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .keep_comments(true)
+            .keep_processing_instructions(true)
+            .keep_entity_refs(true);
+
+        for (xml_data, expected) in [
+            (&b"<p>a<!--x-->b</p>"[..], "ab"),
+            (b"<p>a<?pi x?>b</p>", "ab"),
+            (b"<li>a&amp;b</li>", "a&b"),
+            (b"<ul><li>a</li><li>b</li></ul>", "a|b"),
+            (b"<p>a<b>b</b>c</p>", "a|b|c"),
+            (b"<p>a<br/>b<!--x--><i/>c</p>", "a|b|c"),
+        ] {
+            let document = Document::new_with_options(xml_data.to_vec(), options.clone()).unwrap();
+            let root = document.root().unwrap();
+            assert_eq!(root.inner_text_with_separator("|"), expected);
+        }
+    }
+
+    #[test]
+    fn test_ensure_single_root() {
+        for xml in [
+            &b"<a/>"[..],
+            b"<a></a>",
+            b"<a x=\"1\"/>\n",
+            b"<a></a> <!-- end --> <?pi data?>\n",
+        ] {
+            let document = Document::new(xml.to_vec()).unwrap();
+            assert!(!document.had_trailing_content());
+            assert!(document.ensure_single_root().is_ok());
+        }
+
+        for xml in [
+            &b"<a/><b/>"[..],
+            b"<a></a><b>x</b>",
+            b"<a x=\"1\"/>text",
+            b"<a></a><!-- c --> text",
+        ] {
+            let document = Document::new(xml.to_vec()).unwrap();
+            assert!(document.had_trailing_content());
+            assert!(matches!(
+                document.ensure_single_root(),
+                Err(ParseXmlError::MultipleRoots)
+            ));
+            assert!(document.root().unwrap().is("a"));
+        }
+    }
+
+    #[test]
+    fn test_attribute_decoded_into() {
+        let xml_data = b"<root a=\"&lt;one&gt;\" b=\"&#65;&#x42; &quot;two&quot;\"/>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root = document.root().unwrap();
+        let mut buf = String::from(">");
+
+        for attr in root.attributes() {
+            attr.decoded_into(&mut buf);
+        }
+
+        assert_eq!(buf, "><one>AB \"two\"");
+
+        // The references are translated when the parser kept them
+        let xml_data =
+            b"<root a=\"&lt;one&gt;\" b=\"&#65;&#x42; &quot;two&quot;\" c=\"&bad; & &#;\"/>";
+        let options = ParserOptions::new().decode_entities(false);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.get_attribute("a"), Some("&lt;one&gt;"));
+
+        let mut buf = String::new();
+        for attr in root.attributes() {
+            attr.decoded_into(&mut buf);
+        }
+        assert_eq!(buf, "<one>AB \"two\"&bad; & &#;");
+    }
+
+    #[test]
+    fn test_count_depth_histogram() {
+        let xml_data = b"<root><a><b><c>deep</c></b></a><d/><e>text<f/></e></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let histogram = document.count_depth_histogram();
+
+        assert_eq!(histogram, vec![1, 3, 3, 1, 1]);
+        assert_eq!(histogram.iter().sum::<usize>(), document.nodes.len() - 1);
+
+        let document = Document::new(b"<root/>".to_vec()).unwrap();
+        assert_eq!(document.count_depth_histogram(), vec![1]);
+    }
+
+    #[test]
+    fn test_reserve_text_store() {
+        let xml_data = b"<list><i>a</i><i>b</i><i>c</i><i>d</i><i>e</i></list>".to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+        let text_nodes: Vec<_> = document
+            .all_nodes()
+            .filter(Node::is_text)
+            .map(|node| node.idx())
+            .collect();
+
+        let replacement = "a much longer replacement text";
+        document
+            .reserve_text_store(text_nodes.len() * (replacement.len() + 1))
+            .unwrap();
+        let capacity = document.xml.capacity();
+
+        for node_idx in text_nodes {
+            document.set_text(node_idx, replacement).unwrap();
+        }
+
+        assert_eq!(document.xml.capacity(), capacity);
+        assert!(document
+            .all_nodes()
+            .filter(Node::is_text)
+            .all(|node| node.text() == Some(replacement)));
+    }
+
+    #[test]
+    fn test_strip_to_text() {
+        let xml_data =
+            b"<div><p>  Hello\n  <b>world</b> </p>\t<p>Second\r\n line</p><p>\xc2\xa0</p></div>"
+                .to_vec();
+        let options = ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let root = document.root().unwrap();
+        let first = root.first_child().unwrap();
+
+        assert_eq!(first.strip_to_text(), "Hello world");
+        assert_eq!(first.strip_to_text_with_boundaries(), "Hello world");
+        assert_eq!(root.strip_to_text(), "Hello worldSecond line\u{a0}");
+        assert_eq!(
+            root.strip_to_text_with_boundaries(),
+            "Hello world Second line \u{a0}"
+        );
+
+        let document = Document::new(b"<p>  </p>".to_vec()).unwrap();
+        assert_eq!(document.root().unwrap().strip_to_text(), "");
+    }
+
+    #[test]
+    fn test_line_offsets() {
+        let lines = [
+            "<root>",
+            "  <first a=\"1\"/>",
+            "",
+            "  <second>text</second>",
+            "</root>",
+        ];
+        let xml_data = lines.join("\n").into_bytes();
+        let options = ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly);
+        let mut document = Document::new_with_options(xml_data, options).unwrap();
+        let offsets = document.line_offsets();
+
+        assert_eq!(offsets.len(), lines.len());
+        for (line, window) in lines.iter().zip(offsets.windows(2)) {
+            assert_eq!(window[1] - window[0], (line.len() + 1) as _);
+        }
+        assert_eq!(document.xml[offsets[3] as usize..].first(), Some(&b' '));
+        assert!(document.xml[offsets[4] as usize..].starts_with(b"</root"));
+        assert!(std::ptr::eq(offsets, document.line_offsets()));
+
+        // The cache is reset when the tree is modified
+        let text = document.create_text("added\nline").unwrap();
+        document.append_child(1, text).unwrap();
+        assert_eq!(document.line_offsets().len(), lines.len() + 1);
+    }
+
+    #[test]
+    fn test_get_child_text() {
+        let xml_data =
+            b"<records><record><id>1</id><name>First &amp; Co</name><empty/></record></records>"
+                .to_vec();
+        let options = ParserOptions::new().parse_escapes(true);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let record = document.root().unwrap().get_child("record").unwrap();
+
+        assert_eq!(record.get_child_text("name").as_deref(), Some("First & Co"));
+        assert_eq!(record.get_child_text("id").as_deref(), Some("1"));
+        assert_eq!(record.get_child_text("empty").as_deref(), Some(""));
+        assert_eq!(record.get_child_text("missing"), None);
+        assert_eq!(document.root().unwrap().get_child_text("name"), None);
+    }
+
+    #[test]
+    fn test_attribute_value_index() {
+        let xml_data = b"<doc><sec id=\"intro\">Intro</sec><sec id=\"body\"><fig id=\"f1\"/></sec><ref idref=\"f1\"/><ref idref=\"intro\"/><ref idref=\"none\"/><dup id=\"f1\"/></doc>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let index = document.attribute_value_index("id");
+
+        assert_eq!(index.len(), 3);
+
+        let resolved: Vec<Option<&str>> = document
+            .all_nodes()
+            .filter(|node| node.is("ref"))
+            .map(|node| {
+                index
+                    .get(node.get_attribute("idref").unwrap())
+                    .map(|target| target.tag_name())
+            })
+            .collect();
+
+        // The later duplicate overwrites the first "f1"
+        assert_eq!(resolved, vec![Some("dup"), Some("sec"), None]);
+        assert!(document.attribute_value_index("missing").is_empty());
+    }
+
+    #[test]
+    fn test_cdata_with_inner_brackets() {
+        // `]]` not followed by `>` must not terminate the CDATA section
+        let document = Document::new(b"<r><![CDATA[a]]b]]></r>".to_vec()).unwrap();
+        assert_eq!(document.nodes.len(), 2);
+        assert!(document.root().unwrap().first_child().is_none());
+
+        let document =
+            Document::new(b"<r><![CDATA[x]]]]>tail<![CDATA[<b> ]] > ]]></r>".to_vec()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.children().count(), 1);
+        assert_eq!(root.first_child().unwrap().text(), Some("tail"));
+    }
+
+    #[test]
+    fn test_trim_document() {
+        let xml_data = b"<root>\n  <title>  My title\n  </title>\n  <list a=\"1\">\n    <item b=\"2\">One</item>\n    <item>\tTwo <b>bold</b> </item>\n  </list>\n</root>\n".to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+
+        // Turn a text node into a whitespace-only one
+        let one_idx = document
+            .all_nodes()
+            .find(|node| node.text() == Some("One"))
+            .unwrap()
+            .idx();
+        document.set_text(one_idx, " \n ").unwrap();
+        let node_count = document.nodes.len();
+
+        let removed = document.trim_document().unwrap();
+
+        assert!(removed >= 1);
+        assert_eq!(document.nodes.len(), node_count - removed);
+        assert!(document
+            .all_nodes()
+            .filter_map(|node| node.text())
+            .all(|text| !text.is_empty() && text.trim() == text));
+
+        let root = document.root().unwrap();
+        assert_eq!(root.get_child_text("title").as_deref(), Some("My title"));
+
+        let list = root.get_child("list").unwrap();
+        assert_eq!(list.get_attribute("a"), Some("1"));
+        let items: Vec<_> = list.children().collect();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].get_attribute("b"), Some("2"));
+        assert!(items[0].first_child().is_none());
+        assert_eq!(items[1].text_content(), "Twobold");
+        assert_eq!(
+            document.last_descendant(list.idx()),
+            Some(document.last_node_idx())
+        );
+
+        // Nothing left to trim
+        assert_eq!(document.trim_document().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_count_descendants_matching() {
+        let xml_data = b"<body><img src=\"0\"/><section><img src=\"1\" alt=\"one\"/><p><img src=\"2\"/>text</p><img src=\"3\"/></section></body>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root = document.root().unwrap();
+        let section = root.get_child("section").unwrap();
+        let missing_alt = |node: &Node| node.is("img") && node.get_attribute("alt").is_none();
+
+        assert_eq!(section.count_descendants_matching(missing_alt), 2);
+        assert_eq!(root.count_descendants_matching(missing_alt), 3);
+        assert_eq!(section.count_descendants_matching(Node::is_text), 1);
+        assert_eq!(
+            section.count_descendants_matching(|_| true),
+            section.descendants().count()
+        );
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    #[test]
+    fn test_namespace_uri() {
+        let document = Document::new(b"<a:b xmlns:a=\"urn:x\"/>".to_vec()).unwrap();
+        assert_eq!(document.root().unwrap().namespace_uri(), Some("urn:x"));
+
+        let xml_data = b"<feed xmlns=\"http://www.w3.org/2005/Atom\" xmlns:x=\"urn:x\"><x:a><b/><x:c xmlns:x=\"urn:y\"><x:d/></x:c><x:e/></x:a><plain xmlns=\"\"><f/>text</plain><y:g/><h/></feed>".to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+
+        // Node removal must keep the namespaces attached to their elements
+        let text_idx = document.all_nodes().find(Node::is_text).unwrap().idx();
+        document.set_text(text_idx, " ").unwrap();
+        assert_eq!(document.trim_document().unwrap(), 1);
+
+        let uris: Vec<(String, Option<&str>)> = document
+            .all_nodes()
+            .filter(Node::is_element)
+            .map(|node| (node.tag_name().to_string(), node.namespace_uri()))
+            .collect();
+        let atom = Some("http://www.w3.org/2005/Atom");
+
+        let expected: Vec<(&str, Option<&str>)> = vec![
+            ("feed", atom),
+            ("a", Some("urn:x")),
+            ("b", atom),
+            ("c", Some("urn:y")),
+            ("d", Some("urn:y")),
+            ("e", Some("urn:x")),
+            ("plain", None),
+            ("f", None),
+            ("g", None),
+            ("h", atom),
+        ];
+        for ((tag, uri), (expected_tag, expected_uri)) in uris.iter().zip(expected) {
+            assert!(tag.ends_with(expected_tag));
+            assert_eq!(*uri, expected_uri, "element {tag}");
+        }
+        assert_eq!(uris.len(), 10);
+        assert_eq!(document.all_nodes().filter(Node::is_text).count(), 0);
+    }
+
+    #[cfg(feature = "namespace_aware")]
+    #[test]
+    fn test_matches_ns() {
+        const ATOM: &str = "http://www.w3.org/2005/Atom";
+
+        for xml_data in [
+            &b"<feed xmlns=\"http://www.w3.org/2005/Atom\"><entry/><x:entry xmlns:x=\"urn:x\"/></feed>"[..],
+            b"<a:feed xmlns:a=\"http://www.w3.org/2005/Atom\"><a:entry/><entry/></a:feed>",
+            b"<rss><atom:entry xmlns:atom=\"http://www.w3.org/2005/Atom\"/><entry/></rss>",
+        ] {
+            let document = Document::new(xml_data.to_vec()).unwrap();
+            let entries: Vec<_> = document
+                .all_nodes()
+                .filter(|node| node.matches_ns(ATOM, "entry"))
+                .collect();
+
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].local_name(), "entry");
+            assert!(!entries[0].matches_ns(ATOM, "feed"));
+            assert!(!entries[0].matches_ns("urn:x", "entry"));
+        }
+    }
+
+    #[derive(Default)]
+    struct EventRecorder {
+        events: Vec<String>,
+    }
+
+    impl SaxHandler for EventRecorder {
+        fn start_element(&mut self, name: &str, attributes: SaxAttributes) {
+            let attrs: Vec<String> = attributes.map(|(n, v)| format!(" {n}={v}")).collect();
+            self.events.push(format!("start {name}{}", attrs.concat()));
+        }
+
+        fn end_element(&mut self, name: &str) {
+            self.events.push(format!("end {name}"));
+        }
+
+        fn text(&mut self, text: &str) {
+            self.events.push(format!("text {text}"));
+        }
+
+        fn whitespace(&mut self, text: &str) {
+            self.events.push(format!("ws {}", text.len()));
+        }
+
+        fn comment(&mut self, comment: &str) {
+            self.events.push(format!("comment {comment}"));
+        }
+
+        fn pi(&mut self, target: &str, data: &str) {
+            self.events.push(format!("pi {target}|{data}"));
+        }
+    }
+
+    #[test]
+    fn test_sax_stream() {
+        let xml_data = b"<?xml version=\"1.0\"?>\n<!DOCTYPE html>\n<html lang=\"en\">\n  <!-- note -->\n  <p class=\" a  b \">Fish &amp; <b>Chips</b></p>\n  <br/><![CDATA[<raw>]]><?custom?>\n</html>\n".to_vec();
+        let mut recorder = EventRecorder::default();
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::Preserve)
+            .parse_escapes(true);
+
+        stream_with_options(xml_data, &mut recorder, options).unwrap();
+
+        assert_eq!(
+            recorder.events,
+            vec![
+                "pi xml|version=\"1.0\"",
+                "start html lang=en",
+                "ws 3",
+                "comment  note ",
+                "ws 3",
+                "start p class=a b",
+                "text Fish & ",
+                "start b",
+                "text Chips",
+                "end b",
+                "end p",
+                "ws 3",
+                "start br",
+                "end br",
+                "text <raw>",
+                "pi custom|",
+                "ws 1",
+                "end html",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sax_stream_errors() {
+        for (xml_data, message) in [
+            (&b"<a><b></a>"[..], "does not match opening tag"),
+            (b"<a></a></b>", "No opening tag for closing tag"),
+            (b"<a><b>", "Unexpected end of XML document"),
+            (b"<a x=1/>", "Attribute value must be enclosed in quotes"),
+        ] {
+            let mut recorder = EventRecorder::default();
+            match stream(xml_data.to_vec(), &mut recorder) {
+                Err(ParseXmlError::InvalidXml(msg)) => assert!(msg.contains(message), "{msg}"),
+                result => panic!("Unexpected result: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sax_attributes_get() {
+        struct Finder(Vec<(usize, String)>);
+
+        impl SaxHandler for Finder {
+            fn start_element(&mut self, _name: &str, attributes: SaxAttributes) {
+                if let Some(href) = attributes.get("href") {
+                    self.0.push((attributes.len(), href.to_string()));
+                }
+            }
+        }
+
+        let mut finder = Finder(Vec::new());
+        stream(
+            b"<p><a id=\"1\" href=\"x.html\"/><a id=\"2\" name=\"y\"/></p>".to_vec(),
+            &mut finder,
+        )
+        .unwrap();
+        assert_eq!(finder.0, vec![(2, "x.html".to_string())]);
+    }
+
+    #[test]
+    fn test_tokenizer_events() {
+        let xml_data =
+            b"<?xml version=\"1.0\"?><root id=\"r\"><a>x &lt; y</a> <b/><![CDATA[<raw>]]><!--c--></root>"
+                .to_vec();
+        let options = ParserOptions::new().parse_escapes(true);
+        let mut tokenizer = Tokenizer::new_with_options(xml_data, options).unwrap();
+        let mut events = Vec::new();
+
+        loop {
+            let event = tokenizer.next_event().unwrap();
+            events.push(match event {
+                Event::StartTag { name, attributes } => {
+                    format!("start {name} {}", attributes.len())
+                }
+                Event::EndTag { name } => format!("end {name}"),
+                Event::Text(text) => format!("text {text}"),
+                Event::Whitespace(text) => format!("ws {}", text.len()),
+                Event::CData(text) => format!("cdata {text}"),
+                Event::Comment(comment) => format!("comment {comment}"),
+                Event::ProcessingInstruction { target, data } => format!("pi {target} {data}"),
+                Event::Eof => break,
+            });
+        }
+
+        assert_eq!(
+            events,
+            [
+                "pi xml version=\"1.0\"",
+                "start root 1",
+                "start a 0",
+                "text x < y",
+                "end a",
+                "ws 1",
+                "start b 0",
+                "end b",
+                "cdata <raw>",
+                "comment c",
+                "end root",
+            ]
+        );
+        assert!(matches!(tokenizer.next_event(), Ok(Event::Eof)));
+    }
+
+    #[test]
+    fn test_tokenizer_skip_subtree_and_depth() {
+        let xml_data = b"<root><skip><x><y/></x>t</skip><empty/><keep>k</keep></root>".to_vec();
+        let mut tokenizer = Tokenizer::new(xml_data).unwrap();
+        let mut seen = Vec::new();
+
+        loop {
+            match tokenizer.next_event().unwrap() {
+                Event::StartTag { name, .. } => {
+                    let name = name.to_string();
+                    let skip = name == "skip" || name == "empty";
+                    seen.push(format!("{name}@{}", tokenizer.depth()));
+                    if skip {
+                        tokenizer.skip_subtree().unwrap();
+                        assert_eq!(tokenizer.depth(), 1);
+                    }
+                }
+                Event::Text(text) => seen.push(text.to_string()),
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        assert_eq!(seen, ["root@1", "skip@2", "empty@2", "keep@2", "k"]);
+        assert_eq!(tokenizer.depth(), 0);
+    }
+
+    #[test]
+    fn test_tokenizer_errors() {
+        let mut tokenizer = Tokenizer::new(b"<root><a></b></root>".to_vec()).unwrap();
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        assert!(matches!(
+            tokenizer.next_event(),
+            Err(ParseXmlError::InvalidXml(_))
+        ));
+        assert!(matches!(tokenizer.next_event(), Ok(Event::Eof)));
+
+        let mut tokenizer = Tokenizer::new(b"<root><!foo></root>".to_vec()).unwrap();
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        let Err(ParseXmlError::InvalidXml(msg)) = tokenizer.next_event() else {
+            panic!("An unknown markup declaration must be rejected");
+        };
+        assert!(msg.starts_with("Invalid markup declaration. at line 1, column 7 (position 6)"));
+
+        let mut tokenizer = Tokenizer::new(b"<root><skip><a>".to_vec()).unwrap();
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        assert!(matches!(
+            tokenizer.skip_subtree(),
+            Err(ParseXmlError::InvalidXml(_))
+        ));
+    }
+
+    #[test]
+    fn test_parser_options() {
+        let xml = b"<x:root x:id=\"1\"><a> 1 &amp; 2 </a> <b/></x:root>";
+
+        let options = ParserOptions::new()
+            .namespace_removal(false)
+            .parse_escapes(false)
+            .trim_pcdata(false)
+            .keep_ws_only_pcdata(true);
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        assert_eq!(*document.options(), options);
+        let root = document.root().unwrap();
+        assert_eq!(root.tag_name(), "x:root");
+        assert_eq!(root.attributes().next().unwrap().name(), "x:id");
+        assert_eq!(root.children().count(), 3);
+        assert_eq!(
+            root.first_child().unwrap().first_child().unwrap().text(),
+            Some(" 1 &amp; 2 ")
+        );
+
+        let options = ParserOptions::new()
+            .namespace_removal(true)
+            .parse_escapes(true)
+            .trim_pcdata(true)
+            .keep_ws_only_pcdata(false);
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.tag_name(), "root");
+        assert_eq!(root.attributes().next().unwrap().name(), "id");
+        assert_eq!(root.children().count(), 2);
+        assert_eq!(
+            root.first_child().unwrap().first_child().unwrap().text(),
+            Some("1 & 2")
+        );
+
+        let mut tokenizer = Tokenizer::new_with_options(xml.to_vec(), options).unwrap();
+        assert!(matches!(
+            tokenizer.next_event(),
+            Ok(Event::StartTag { name: "root", .. })
+        ));
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        assert!(matches!(tokenizer.next_event(), Ok(Event::Text("1 & 2"))));
+    }
+
+    #[test]
+    fn test_borrowed_document() {
+        let mut buffer =
+            b"<p:root a=\"x &lt; y\"><p:child>Fish &amp; Chips</p:child></p:root>".to_vec();
+
+        {
+            let options = ParserOptions::new().parse_escapes(true);
+            let mut document =
+                Document::new_borrowed_with_options(buffer.as_mut_slice(), options).unwrap();
+            assert!(document.xml.is_borrowed());
+            assert_eq!(document.xml.capacity(), document.xml.len());
+
+            let root = document.root().unwrap();
+            assert_eq!(root.local_name(), "root");
+            assert_eq!(root.attributes().next().unwrap().value(), "x < y");
+            let text_idx = root.first_child().unwrap().first_child().unwrap().idx();
+            assert_eq!(
+                document.get_node(text_idx).unwrap().text(),
+                Some("Fish & Chips")
+            );
+
+            // A shorter text is still written in the borrowed buffer
+            document.set_text(text_idx, "Fish").unwrap();
+            assert!(document.xml.is_borrowed());
+
+            // A longer one requires an owned copy of the buffer
+            document.set_text(text_idx, "Fish & Chips & Peas").unwrap();
+            assert!(!document.xml.is_borrowed());
+            assert_eq!(
+                document.get_node(text_idx).unwrap().text(),
+                Some("Fish & Chips & Peas")
+            );
+        }
+
+        // The parsing was done in the caller's buffer
+        assert!(buffer.windows(4).any(|w| w == b"Fish"));
+        assert!(!buffer.windows(5).any(|w| w == b"&amp;"));
+
+        let mut buffer = *b"<root><a></b></root>";
+        assert!(Document::new_borrowed(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn test_document_from_string() {
+        let xml = "<résumé à=\"€ &#x1F600;\">naïve 日本<b>α</b></résumé>";
+
+        let document = Document::from_string(xml.to_string()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.tag_name(), "résumé");
+        let attribute = root.attributes().next().unwrap();
+        assert_eq!(attribute.name(), "à");
+        assert_eq!(attribute.value(), "€ 😀");
+        assert_eq!(root.first_child().unwrap().text(), Some("naïve 日本"));
+
+        let document: Document = xml.parse().unwrap();
+        assert_eq!(document.root().unwrap().text_content(), "naïve 日本α");
+
+        assert!(matches!(
+            "<root>".parse::<Document>(),
+            Err(ParseXmlError::InvalidXml(_))
+        ));
+    }
+
+    #[test]
+    fn test_document_from_reader() {
+        let xml = b"<root a=\"1\"><child>Text</child><child/></root>";
+
+        let document = Document::from_reader(&xml[..], None).unwrap();
+        assert_eq!(document.root().unwrap().children().count(), 2);
+
+        let document = Document::from_reader(std::io::Cursor::new(xml), Some(4)).unwrap();
+        assert_eq!(document.xml.len(), xml.len());
+        assert_eq!(
+            document.root().unwrap().first_child().unwrap().tag_name(),
+            "child"
+        );
+
+        assert!(matches!(
+            Document::from_reader(&b"<root><a></root>"[..], None),
+            Err(ParseXmlError::InvalidXml(_))
+        ));
+
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disconnected"))
+            }
+        }
+
+        let result = Document::from_reader(FailingReader, Some(100));
+        assert!(
+            matches!(result, Err(ParseXmlError::Io(ref err)) if err.to_string() == "disconnected")
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_document_from_async_reader() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            use tokio::io::AsyncWriteExt;
+
+            let (mut writer, reader) = tokio::io::duplex(64);
+            for chunk in [
+                "<root a=\"1\">",
+                "<child>Text</child>",
+                "<child/>",
+                "</root>",
+            ] {
+                writer.write_all(chunk.as_bytes()).await.unwrap();
+            }
+            drop(writer);
+
+            let document = Document::from_async_reader(reader, Some(64)).await;
+            let document = document.unwrap();
+            assert_eq!(document.root().unwrap().children().count(), 2);
+
+            let source: &[u8] = b"<root><a></root>";
+            assert!(matches!(
+                Document::from_async_reader(source, None).await,
+                Err(ParseXmlError::InvalidXml(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_keep_comments() {
+        let xml = b"<!-- prolog --><root>a<!-- one --><b><!--two--></b>c</root><!-- trailing -->";
+
+        let document = Document::new(xml.to_vec()).unwrap();
+        assert!(!document.options().has_keep_comments());
+        assert!(document.all_nodes().all(|node| !node.is_comment()));
+
+        let options = ParserOptions::new().keep_comments(true);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+
+        let comments: Vec<&str> = document
+            .all_nodes()
+            .filter_map(|node| node.comment())
+            .collect();
+        assert_eq!(comments, [" one ", "two"]);
+        assert_eq!(document.prolog_comments().count(), 1);
+
+        let children: Vec<bool> = root.children().map(|node| node.is_comment()).collect();
+        assert_eq!(children, [false, true, false, false]);
+        assert_eq!(root.text_content(), "ac");
+        assert!(root
+            .get_child("b")
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .is_comment());
+    }
+
+    #[test]
+    fn test_keep_processing_instructions() {
+        let xml = b"<?xml version=\"1.0\"?>\n<?xml-stylesheet type=\"text/css\" href=\"a.css\"?>\n<?custom?><root>a<?php echo 1; ?><b/><?empty ?></root><?after x?>";
+
+        let document = Document::new(xml.to_vec()).unwrap();
+        let pis: Vec<(&str, &str)> = document.prolog_processing_instructions().collect();
+        assert_eq!(
+            pis,
+            [
+                ("xml-stylesheet", "type=\"text/css\" href=\"a.css\""),
+                ("custom", "")
+            ]
+        );
+        assert!(document
+            .all_nodes()
+            .all(|node| !node.is_processing_instruction()));
+
+        let options = ParserOptions::new().keep_processing_instructions(true);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(document.prolog_processing_instructions().count(), 2);
+
+        let pis: Vec<(&str, &str)> = root
+            .children()
+            .filter(Node::is_processing_instruction)
+            .map(|node| (node.pi_target().unwrap(), node.pi_data().unwrap()))
+            .collect();
+        assert_eq!(pis, [("php", "echo 1;"), ("empty", "")]);
+        assert_eq!(root.children().count(), 4);
+        assert_eq!(root.first_child().unwrap().pi_target(), None);
+        assert_eq!(root.text_content(), "a");
+    }
+
+    #[test]
+    fn test_keep_cdata() {
+        let xml = b"<root>a<![CDATA[<b>&amp;</b>]]>c<![CDATA[]]><x><![CDATA[ ]] ]]></x></root>";
+
+        let document = Document::new(xml.to_vec()).unwrap();
+        assert_eq!(document.root().unwrap().text_content(), "ac");
+
+        let options = ParserOptions::new().keep_cdata(true);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.text_content(), "a<b>&amp;</b>c ]] ");
+
+        let flags: Vec<(&str, bool)> = root
+            .descendants()
+            .filter(Node::is_text)
+            .map(|node| (node.text().unwrap(), node.is_cdata()))
+            .collect();
+        assert_eq!(
+            flags,
+            [
+                ("a", false),
+                ("<b>&amp;</b>", true),
+                ("c", false),
+                (" ]] ", true)
+            ]
+        );
+
+        // The flags follow the nodes when the document is trimmed
+        let options = ParserOptions::new()
+            .keep_cdata(true)
+            .keep_ws_only_pcdata(true);
+        let xml = b"<r> <a/><![CDATA[x]]></r>".to_vec();
+        let mut document = Document::new_with_options(xml, options).unwrap();
+        assert_eq!(document.trim_document().unwrap(), 1);
+        let cdata: Vec<bool> = document.all_nodes().map(|node| node.is_cdata()).collect();
+        assert_eq!(cdata, [false, false, true]);
+    }
+
+    #[test]
+    fn test_namespace_prefix_filter() {
+        let xml = br#"<html:body xmlns:html="urn:h" xmlns:xlink="urn:x"><html:a xlink:href="x.svg" epub:type="note">T</html:a></html:body>"#;
+
+        let options = ParserOptions::new()
+            .namespace_removal(true)
+            .namespace_prefix_filter(PrefixFilter::Keep(vec!["xlink".into(), "epub".into()]));
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let body = document.root().unwrap();
+        assert_eq!(body.tag_name(), "body");
+        assert_eq!(body.get_attribute("xlink"), Some("urn:x"));
+        let a = body.first_child().unwrap();
+        assert_eq!(a.tag_name(), "a");
+        assert_eq!(a.get_attribute("xlink:href"), Some("x.svg"));
+        assert_eq!(a.get_attribute("epub:type"), Some("note"));
+
+        let options = ParserOptions::new()
+            .namespace_removal(true)
+            .namespace_prefix_filter(PrefixFilter::Only(vec!["html".into()]));
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let body = document.root().unwrap();
+        assert_eq!(body.tag_name(), "body");
+        assert_eq!(body.get_attribute("xmlns:xlink"), Some("urn:x"));
+        let a = body.first_child().unwrap();
+        assert_eq!(a.tag_name(), "a");
+        assert_eq!(a.get_attribute("xlink:href"), Some("x.svg"));
+        assert_eq!(a.get_attribute("epub:type"), Some("note"));
+
+        // The filter is ignored when the namespace removal is disabled
+        let options = ParserOptions::new()
+            .namespace_removal(false)
+            .namespace_prefix_filter(PrefixFilter::Only(vec!["html".into()]));
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        assert_eq!(document.root().unwrap().tag_name(), "html:body");
+        assert!(document
+            .options()
+            .get_namespace_prefix_filter()
+            .removes(b"html"));
+    }
+
+    #[test]
+    fn test_node_language() {
+        let xml = br#"<html xml:lang="en"><p>A<b lang="fr">B<i xml:lang="">C</i>D</b>E</p><p lang="x" xml:lang="de">F</p>G</html>"#;
+        let options = ParserOptions::new().namespace_removal(false);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+
+        let languages: Vec<(&str, Option<&str>)> = document
+            .all_nodes()
+            .filter(Node::is_text)
+            .map(|node| (node.text().unwrap(), node.language()))
+            .collect();
+        assert_eq!(
+            languages,
+            [
+                ("A", Some("en")),
+                ("B", Some("fr")),
+                ("C", None),
+                ("D", Some("fr")),
+                ("E", Some("en")),
+                ("F", Some("de")),
+                ("G", Some("en")),
+            ]
+        );
+        assert_eq!(document.root().unwrap().language(), Some("en"));
+
+        let document = Document::new(b"<a><b>T</b></a>".to_vec()).unwrap();
+        assert!(document.all_nodes().all(|node| node.language().is_none()));
+
+        // The language scopes follow the nodes when the document is trimmed
+        let options = ParserOptions::new().keep_ws_only_pcdata(true);
+        let xml = br#"<a> <b lang="it"> <c>T</c></b>U</a>"#.to_vec();
+        let mut document = Document::new_with_options(xml, options).unwrap();
+        assert_eq!(document.find_first("c").unwrap().language(), Some("it"));
+        assert_eq!(document.trim_document().unwrap(), 2);
+        let languages: Vec<Option<&str>> = document.all_nodes().map(|n| n.language()).collect();
+        assert_eq!(languages, [None, Some("it"), Some("it"), Some("it"), None]);
+    }
+
+    #[test]
+    fn test_uri_resolve() {
+        // Examples of RFC 3986, section 5.4
+        let base = "http://a/b/c/d;p?q";
+        let examples = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            (";x", "http://a/b/c/;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../g", "http://a/g"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g"),
+        ];
+
+        for (reference, expected) in examples {
+            assert_eq!(uri::resolve(base, reference), expected, "{reference}");
+        }
+        assert_eq!(uri::resolve("http://a", "b"), "http://a/b");
+        assert_eq!(
+            uri::resolve("OEBPS/text/ch1.xhtml", "../img/é.png"),
+            "OEBPS/img/é.png"
+        );
+    }
+
+    #[test]
+    fn test_node_base_url() {
+        let xml = br#"<doc xml:base="http://example.org/today/"><p><a href="new.xml">N</a></p><list xml:base="/hotpicks/"><item><a href="pick1.xml">1</a></item><sub xml:base="more/"><a href="../x.xml"/></sub></list><abs xml:base="file:///tmp/"><a href="y"/></abs></doc>"#;
+
+        for removal in [false, true] {
+            let options = ParserOptions::new().namespace_removal(removal);
+            let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+
+            let urls: Vec<String> = document
+                .all_nodes()
+                .filter(|node| node.is("a"))
+                .map(|node| node.resolve_url(node.get_attribute("href").unwrap()))
+                .collect();
+            assert_eq!(
+                urls,
+                [
+                    "http://example.org/today/new.xml",
+                    "http://example.org/hotpicks/pick1.xml",
+                    "http://example.org/hotpicks/x.xml",
+                    "file:///tmp/y",
+                ]
+            );
+
+            let text = document.find_first("a").unwrap().first_child().unwrap();
+            assert_eq!(
+                text.base_url().as_deref(),
+                Some("http://example.org/today/")
+            );
+        }
+
+        let document = Document::new(b"<a href=\"x/y\"/>".to_vec()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.base_url(), None);
+        assert_eq!(root.resolve_url("x/y"), "x/y");
+    }
+
+    #[test]
+    fn test_document_to_xml() {
+        let xml = br#"<?xml version="1.0"?><!-- c1 --><?style href="a.css"?><!--c2--><root a="1 &lt; 2 &amp; &quot;3&quot;" b='x>y'><p>Fish &amp; Chips &gt; <b>all</b></p><empty></empty><!--in--><?pi data?><s><![CDATA[a < b]]></s>tail</root>"#;
+        let options = ParserOptions::new()
+            .keep_comments(true)
+            .keep_processing_instructions(true)
+            .keep_cdata(true)
+            .trim_pcdata(false);
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+
+        let expected = r#"<!-- c1 --><?style href="a.css"?><!--c2--><root a="1 &lt; 2 &amp; &quot;3&quot;" b="x&gt;y"><p>Fish &amp; Chips &gt; <b>all</b></p><empty/><!--in--><?pi data?><s><![CDATA[a < b]]></s>tail</root>"#;
+        assert_eq!(document.to_xml(), expected);
+
+        let mut output = Vec::new();
+        document.write_xml(&mut output).unwrap();
+        assert_eq!(output, expected.as_bytes());
+
+        // The output parses back to the same tree
+        let reparsed = Document::new_with_options(output, options).unwrap();
+        assert_eq!(reparsed.to_xml(), expected);
+        assert_eq!(
+            reparsed.root().unwrap().get_attribute("a"),
+            Some("1 < 2 & \"3\"")
+        );
+
+        // Deeply nested documents are written without recursion
+        let depth = 2000;
+        let nested = "<a>".repeat(depth) + "x" + &"</a>".repeat(depth);
+        let document = Document::new(nested.clone().into_bytes()).unwrap();
+        assert_eq!(document.to_xml(), nested);
+    }
+
+    #[test]
+    fn test_document_pretty_print() {
+        let xml = br#"<?style href="a.css"?><html xmlns="http://www.w3.org/1999/xhtml">
+  <head><meta charset="utf-8"/><title>T</title></head>
+  <body class="main" id="top" data-x="123"><p>Some <b>bold</b> text</p><!--c--><img src="a.png" alt="A"/><div></div></body>
+</html>"#;
+        let options = ParserOptions::new()
+            .keep_comments(true)
+            .keep_ws_only_pcdata(true)
+            .trim_pcdata(false);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+
+        let pretty = SerializeOptions::new()
+            .indent("\t")
+            .self_closing(SelfClosing::VoidElements);
+        assert_eq!(
+            document.to_xml_with(&pretty),
+            "<?style href=\"a.css\"?>\n<html xmlns=\"http://www.w3.org/1999/xhtml\">\n\t<head>\n\t\t<meta charset=\"utf-8\"/>\n\t\t<title>T</title>\n\t</head>\n\t<body class=\"main\" id=\"top\" data-x=\"123\">\n\t\t<p>Some <b>bold</b> text</p>\n\t\t<!--c-->\n\t\t<img src=\"a.png\" alt=\"A\"/>\n\t\t<div></div>\n\t</body>\n</html>\n"
+        );
+
+        // Attributes of long start tags are written on their own line
+        let wrapped = SerializeOptions::new()
+            .indent("  ")
+            .max_line_width(30)
+            .self_closing(SelfClosing::Never);
+        let output = document.to_xml_with(&wrapped);
+        assert!(
+            output.contains("  <body\n    class=\"main\"\n    id=\"top\"\n    data-x=\"123\">\n")
+        );
+        assert!(output.contains("    <div></div>\n"));
+        assert!(output.contains("    <meta charset=\"utf-8\"></meta>\n"));
+        assert_eq!(wrapped.get_max_line_width(), Some(30));
+
+        // The pretty printed output parses back to the same content
+        let options = ParserOptions::new()
+            .keep_comments(true)
+            .keep_ws_only_pcdata(false)
+            .trim_pcdata(false);
+        let reparsed = Document::new_with_options(output.into_bytes(), options.clone()).unwrap();
+        let compact = Document::new_with_options(xml.to_vec(), options)
+            .unwrap()
+            .to_xml();
+        assert_eq!(reparsed.to_xml(), compact);
+    }
+
+    #[test]
+    fn test_node_outer_and_inner_xml() {
+        let xml = br#"<html><body id="b"><h1>Title &amp; more</h1><div><p>A</p><p>B <i>C</i></p></div><hr/></body></html>"#;
+        let options = ParserOptions::new().trim_pcdata(false);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let body = document.find_first("body").unwrap();
+
+        assert_eq!(
+            body.outer_xml(),
+            r#"<body id="b"><h1>Title &amp; more</h1><div><p>A</p><p>B <i>C</i></p></div><hr/></body>"#
+        );
+        assert_eq!(
+            body.inner_xml(),
+            r#"<h1>Title &amp; more</h1><div><p>A</p><p>B <i>C</i></p></div><hr/>"#
+        );
+
+        let hr = document.find_first("hr").unwrap();
+        assert_eq!(hr.outer_xml(), "<hr/>");
+        assert_eq!(hr.inner_xml(), "");
+
+        let text = document.find_first("h1").unwrap().first_child().unwrap();
+        assert_eq!(text.outer_xml(), "Title &amp; more");
+
+        // The outer XML of the root element is the document content
+        assert_eq!(document.root().unwrap().outer_xml(), document.to_xml());
+
+        let pretty = SerializeOptions::new().indent("  ");
+        assert_eq!(
+            body.inner_xml_with(&pretty),
+            "<h1>Title &amp; more</h1>\n<div>\n  <p>A</p>\n  <p>B <i>C</i></p>\n</div>\n<hr/>"
+        );
+        assert_eq!(
+            document.find_first("div").unwrap().outer_xml_with(&pretty),
+            "<div>\n  <p>A</p>\n  <p>B <i>C</i></p>\n</div>"
+        );
+        let div = document.find_first("div").unwrap();
+        let p = div.children().nth(1).unwrap();
+        assert_eq!(p.inner_xml_with(&pretty), "B <i>C</i>");
+    }
+
+    #[test]
+    fn test_mutable_dom() {
+        let xml = b"<html><body><div class=\"ad\">Buy</div><p>Text</p><nav/><script>x()</script></body></html>";
+        let options = ParserOptions::new().trim_pcdata(false);
+        let mut document = Document::new_with_options(xml.to_vec(), options).unwrap();
+
+        // Strip the ads and scripts
+        let unwanted: Vec<NodeIdx> = document
+            .all_nodes()
+            .filter(|node| node.is("script") || node.get_attribute("class") == Some("ad"))
+            .map(|node| node.idx())
+            .collect();
+        assert_eq!(document.remove_nodes(&unwanted).unwrap(), 4);
+        assert_eq!(
+            document.to_xml(),
+            "<html><body><p>Text</p><nav/></body></html>"
+        );
+
+        // Build a detached subtree, which is not part of the document until inserted
+        let last_idx = document.last_node_idx();
+        let link = document
+            .create_element("a", &[("href", "next.xhtml"), ("id", "n")])
+            .unwrap();
+        let text = document.create_text("Next & last").unwrap();
+        assert_eq!(document.append_child(link, text).unwrap(), text);
+        assert_eq!(document.last_node_idx(), last_idx);
+        assert_eq!(document.all_nodes().count(), last_idx as usize);
+        assert!(document
+            .root()
+            .unwrap()
+            .descendants()
+            .all(|node| !node.is("a")));
+
+        document.create_text("pending").unwrap();
+        let nav = document.find_first("nav").unwrap().idx();
+        let link = document.append_child(nav, link).unwrap();
+        let link_node = document.get_node(link).unwrap();
+        assert!(link_node.is("a"));
+        assert_eq!(link_node.get_attribute("href"), Some("next.xhtml"));
+        assert_eq!(
+            document.to_xml(),
+            "<html><body><p>Text</p><nav><a href=\"next.xhtml\" id=\"n\">Next &amp; last</a></nav></body></html>"
+        );
+
+        // The nodes are kept in document order
+        let names: Vec<String> = document
+            .all_nodes()
+            .map(|node| node.text().unwrap_or(node.tag_name()).to_string())
+            .collect();
+        assert_eq!(
+            names,
+            ["html", "body", "p", "Text", "nav", "a", "Next & last"]
+        );
+
+        // The pending node survived the renumbering, and is inserted before the paragraph
+        assert_eq!(document.pending_nodes(), [document.last_node_idx() + 1]);
+        let other = document.pending_nodes()[0];
+        let p = document.find_first("p").unwrap().idx();
+        let other = document.insert_before(p, other).unwrap();
+        assert_eq!(other, 3);
+        assert_eq!(
+            document.root().unwrap().first_child().unwrap().inner_xml(),
+            "pending<p>Text</p><nav><a href=\"next.xhtml\" id=\"n\">Next &amp; last</a></nav>"
+        );
+        let body = document.find_first("body").unwrap();
+        assert_eq!(
+            body.first_child()
+                .unwrap()
+                .next_sibling()
+                .unwrap()
+                .tag_name(),
+            "p"
+        );
+
+        // Invalid operations
+        let text_idx = document
+            .find_first("p")
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .idx();
+        let orphan = document.create_element("b", &[]).unwrap();
+        assert!(document.append_child(text_idx, orphan).is_err());
+        assert!(document.append_child(orphan, orphan).is_err());
+        assert!(document.append_child(1, text_idx).is_err());
+        assert!(document.insert_before(1, orphan).is_err());
+        assert!(document.remove_node(1).is_err());
+        assert!(document.create_element("", &[]).is_err());
+        let nodes_len = document.nodes.len();
+        for (name, attributes) in [
+            ("a b", &[][..]),
+            ("1a", &[]),
+            ("a<b", &[]),
+            ("a", &[("", "x")]),
+            ("a", &[("x y", "1")]),
+            ("a", &[("x", "1"), ("-y", "2")]),
+        ] {
+            assert!(matches!(
+                document.create_element(name, attributes),
+                Err(ParseXmlError::InvalidXml(_))
+            ));
+        }
+        assert_eq!(document.nodes.len(), nodes_len);
+        let valid = document
+            .create_element("svg:g", &[("xml:lang", "en"), ("data-x.1", "")])
+            .unwrap();
+        document.remove_node(valid).unwrap();
+        document.remove_node(orphan).unwrap();
+        assert_eq!(document.all_nodes().count() + 1, document.nodes.len());
+    }
+
+    #[test]
+    fn test_document_builder() {
+        let item = |text: &str| DocumentBuilder::element("li").text(text);
+        let builder = DocumentBuilder::element("html")
+            .attr("xml:lang", "en")
+            .child(
+                DocumentBuilder::element("head").child(DocumentBuilder::element("title").text("T")),
+            )
+            .child(
+                DocumentBuilder::element("body")
+                    .attr("class", "a \"b\"")
+                    .text("Intro & ")
+                    .child(
+                        DocumentBuilder::element("ul")
+                            .child(item("1"))
+                            .child(item("2")),
+                    )
+                    .child(DocumentBuilder::element("br")),
+            );
+        let document = builder.build().unwrap();
+
+        assert_eq!(
+            document.to_xml(),
+            "<html xml:lang=\"en\"><head><title>T</title></head><body class=\"a &quot;b&quot;\">Intro &amp; <ul><li>1</li><li>2</li></ul><br/></body></html>"
+        );
+
+        // The tree is the one of the parsed output
+        let options = ParserOptions::new()
+            .namespace_removal(false)
+            .parse_escapes(true)
+            .trim_pcdata(false);
+        let parsed = Document::new_with_options(document.to_xml().into_bytes(), options).unwrap();
+        assert_eq!(document.nodes.len(), parsed.nodes.len());
+        assert_eq!(document.attributes.len(), parsed.attributes.len());
+        for (built, parsed) in document.all_nodes().zip(parsed.all_nodes()) {
+            assert_eq!(built.idx(), parsed.idx());
+            assert_eq!(built.tag_name(), parsed.tag_name());
+            assert_eq!(built.text(), parsed.text());
+            assert_eq!(
+                built
+                    .attributes()
+                    .map(|a| (a.name(), a.value()))
+                    .collect::<Vec<_>>(),
+                parsed
+                    .attributes()
+                    .map(|a| (a.name(), a.value()))
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(document.find_first("li").unwrap().language(), Some("en"));
+        assert_eq!(
+            document
+                .find_first("ul")
+                .unwrap()
+                .next_sibling()
+                .unwrap()
+                .tag_name(),
+            "br"
+        );
+
+        // The built document can be modified
+        let mut document = builder.build().unwrap();
+        let text = document.create_text("3").unwrap();
+        let li = document.create_element("li", &[]).unwrap();
+        document.append_child(li, text).unwrap();
+        let ul = document.find_first("ul").unwrap().idx();
+        document.append_child(ul, li).unwrap();
+        assert_eq!(
+            document.find_first("ul").unwrap().inner_xml(),
+            "<li>1</li><li>2</li><li>3</li>"
+        );
+
+        assert!(DocumentBuilder::element("").build().is_err());
+        assert!(DocumentBuilder::element("a")
+            .child(DocumentBuilder::element(""))
+            .build()
+            .is_err());
+        for builder in [
+            DocumentBuilder::element("<a>"),
+            DocumentBuilder::element("a").child(DocumentBuilder::element("b c")),
+            DocumentBuilder::element("a").attr("1x", "1"),
+            DocumentBuilder::element("a").attr("x=\"1\" y", "2"),
+        ] {
+            assert!(matches!(builder.build(), Err(ParseXmlError::InvalidXml(_))));
+        }
+    }
+
+    #[test]
+    fn test_xpath_select() {
+        let xml_data = br#"<html>
+<head><title>T</title></head>
+<body>
+  <p class="x">A</p>
+  <div id="d1">
+    <p>B</p>
+    <p class="x" lang="en">C</p>
+    <div><p class="y">D</p></div>
+  </div>
+  <ul><li>1</li><li>2</li><li>3</li><li>4</li></ul>
+</body>
+</html>"#
+            .to_vec();
+        let options = ParserOptions::new()
+            .trim_pcdata(false)
+            .keep_ws_only_pcdata(false);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let texts = |expr: &str| -> Vec<String> {
+            document
+                .select(expr)
+                .unwrap()
+                .iter()
+                .map(Node::text_content)
+                .collect()
+        };
+
+        assert_eq!(texts("/html/body//p[@class='x']"), ["A", "C"]);
+        assert_eq!(texts("//p"), ["A", "B", "C", "D"]);
+        assert_eq!(texts("//p[@class]"), ["A", "C", "D"]);
+        assert_eq!(texts("//p[@class!='x']"), ["D"]);
+        assert_eq!(texts("//p[not(@class='x')]"), ["B", "D"]);
+        assert_eq!(texts("//p[@class='x' and @lang]"), ["C"]);
+        assert_eq!(texts("//p[@class='y' or @lang=\"en\"]"), ["C", "D"]);
+        assert_eq!(texts("//p[@*]"), ["A", "C", "D"]);
+        assert_eq!(texts("html/head/title"), ["T"]);
+        assert_eq!(texts("/html/*/div[@id='d1']/div/p"), ["D"]);
+        assert_eq!(texts("/descendant::div/child::p"), ["B", "C", "D"]);
+
+        // Positional predicates apply to the node-set of each context node
+        assert_eq!(texts("//p[1]"), ["A", "B", "D"]);
+        assert_eq!(texts("/descendant::p[1]"), ["A"]);
+        assert_eq!(texts("//li[2]"), ["2"]);
+        assert_eq!(texts("//li[last()]"), ["4"]);
+        assert_eq!(texts("//li[last() - 1]"), ["3"]);
+        assert_eq!(texts("//li[position() > 2]"), ["3", "4"]);
+        assert_eq!(texts("//li[position() <= 2][last()]"), ["2"]);
+        assert_eq!(texts("//li[5]").len(), 0);
+
+        let nodes = document.select("//li/text()").unwrap();
+        assert_eq!(nodes.len(), 4);
+        assert!(nodes.iter().all(Node::is_text));
+        assert!(document.select("/").unwrap().is_empty());
+        assert_eq!(
+            document.select("//ul/self::node()").unwrap()[0].tag_name(),
+            "ul"
+        );
+
+        // Relative paths from a node, the absolute ones starting at the document node
+        let div = document.select("//div[@id='d1']").unwrap().remove(0);
+        let p_texts = |expr: &str| -> Vec<String> {
+            div.select(expr)
+                .unwrap()
+                .iter()
+                .map(Node::text_content)
+                .collect()
+        };
+        assert_eq!(p_texts("p"), ["B", "C"]);
+        assert_eq!(p_texts(".//p"), ["B", "C", "D"]);
+        assert_eq!(p_texts("//p[1]"), ["A", "B", "D"]);
+
+        let xpath = XPath::new("//li[@id]").unwrap();
+        assert!(xpath.select(&document).is_empty());
+        assert_eq!(xpath, XPath::new(" // li [ @id ] ").unwrap());
+
+        for expr in [
+            "",
+            "//",
+            "p[",
+            "p[@]",
+            "p[@a=x]",
+            "p[@a='x]",
+            "../p",
+            "p/@a",
+            "ancestor::p",
+            "p[foo()]",
+            "(//li)[2]",
+            "unknown()",
+            "p]",
+        ] {
+            assert!(
+                matches!(document.select(expr), Err(ParseXmlError::InvalidXPath(_))),
+                "{expr}"
+            );
+        }
+    }
+
+    #[cfg(feature = "css")]
+    #[test]
+    fn test_css_select() {
+        use xhtml_parser::css::Selector;
+
+        let xml_data = br#"<html>
+<body>
+  <h1 id="title">T</h1>
+  <div class="note big" lang="en-US">
+    <p>A</p>
+    <span><p class="x">B</p></span>
+    <p data-href="http://a.org/x.png">C</p>
+  </div>
+  <p>D</p>
+  <ul><li>1</li><li class="sel">2</li><li>3</li><li>4</li></ul>
+</body>
+</html>"#
+            .to_vec();
+        let options = ParserOptions::new()
+            .trim_pcdata(false)
+            .keep_ws_only_pcdata(false);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let root = document.root().unwrap();
+        let texts = |selectors: &str| -> Vec<String> {
+            root.select_css(selectors)
+                .unwrap()
+                .map(|node| node.text_content())
+                .collect()
+        };
+
+        assert_eq!(texts("p"), ["A", "B", "C", "D"]);
+        assert_eq!(texts("div.note > p"), ["A", "C"]);
+        assert_eq!(texts("div p"), ["A", "B", "C"]);
+        assert_eq!(texts("body > div span > p"), ["B"]);
+        assert_eq!(texts(".note.big p.x"), ["B"]);
+        assert_eq!(texts(".note.small p"), Vec::<String>::new());
+        assert_eq!(texts("#title"), ["T"]);
+        assert_eq!(texts("div ~ p"), ["D"]);
+        assert_eq!(texts("h1 + div > p + span > p"), ["B"]);
+        assert_eq!(texts("li.sel + li"), ["3"]);
+        assert_eq!(texts("li.sel ~ li"), ["3", "4"]);
+        assert_eq!(texts("[lang|=en] > [data-href]"), ["C"]);
+        assert_eq!(texts("[data-href^='http:'][data-href$=\".png\"]"), ["C"]);
+        assert_eq!(texts("[data-href*=a\\.org]"), ["C"]);
+        assert_eq!(texts("[class~=big] > p"), ["A", "C"]);
+        assert_eq!(texts("[class=big]").len(), 0);
+        assert_eq!(texts("[class^='']").len(), 0);
+        assert_eq!(texts("h1, li.sel , p.x"), ["T", "B", "2"]);
+        assert_eq!(root.select_css("*").unwrap().count(), 13);
+        assert!(root.select_css("html").unwrap().next().is_none());
+
+        // The ancestors above the starting node are considered
+        let span = document.find_first("span").unwrap();
+        assert_eq!(span.select_css("div p").unwrap().count(), 1);
+        assert_eq!(span.select_css("body > div > span > p").unwrap().count(), 1);
+        let div = document.find_first("div").unwrap();
+        assert_eq!(div.select_css("div").unwrap().count(), 0);
+
+        let selector = Selector::new("ul > li:nth-child(2)");
+        assert!(matches!(selector, Err(ParseXmlError::InvalidSelector(_))));
+        let selector = Selector::new("div > p").unwrap();
+        assert!(selector.matches(&document.find_first("p").unwrap()));
+        assert!(!selector.matches(&document.find_first("li").unwrap()));
+        assert_eq!(selector.select(&root).count(), 2);
+
+        for selectors in [
+            "",
+            ",p",
+            "p,",
+            "p >",
+            "> p",
+            "p..x",
+            "p#",
+            "[a",
+            "[a=]",
+            "[a~b]",
+            "[a='b]",
+            "p:first-child",
+            "p)",
+        ] {
+            assert!(
+                matches!(
+                    Selector::new(selectors),
+                    Err(ParseXmlError::InvalidSelector(_))
+                ),
+                "{selectors}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_element_by_id() {
+        let xml_data = br##"<book><p>See <a href="#n1">1</a></p><aside id="n1">First</aside><aside id="n2">Second</aside><p id="n1">Duplicate</p></book>"##.to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+
+        let note = document.get_element_by_id("n1").unwrap();
+        assert!(note.is("aside"));
+        assert_eq!(note.text_content(), "First");
+        assert_eq!(
+            document.get_element_by_id("n2").unwrap().text_content(),
+            "Second"
+        );
+        assert!(document.get_element_by_id("n3").is_none());
+        assert!(document.get_element_by_id("").is_none());
+
+        // The index follows the modifications of the document
+        let first_idx = document.get_element_by_id("n1").unwrap().idx();
+        document.remove_node(first_idx).unwrap();
+        let note = document.get_element_by_id("n1").unwrap();
+        assert!(note.is("p"));
+        assert_eq!(note.text_content(), "Duplicate");
+
+        let new_note = document.create_element("aside", &[("id", "n3")]).unwrap();
+        assert!(document.get_element_by_id("n3").is_none());
+        let root_idx = document.root().unwrap().idx();
+        document.append_child(root_idx, new_note).unwrap();
+        assert_eq!(
+            document.get_element_by_id("n3").unwrap().idx(),
+            document.root().unwrap().children().last().unwrap().idx()
+        );
+    }
+
+    #[test]
+    fn test_node_ancestors() {
+        let xml_data = b"<html><body><div><p>A<b>B</b></p></div><p>C</p></body></html>".to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+        let names = |node: &Node| -> Vec<String> {
+            node.ancestors()
+                .map(|ancestor| ancestor.tag_name().to_string())
+                .collect()
+        };
+
+        let b = document.find_first("b").unwrap();
+        assert_eq!(names(&b), ["p", "div", "body", "html"]);
+        assert_eq!(
+            names(&b.first_child().unwrap()),
+            ["b", "p", "div", "body", "html"]
+        );
+        assert_eq!(
+            names(&document.select("/html/body/p").unwrap()[0]),
+            ["body", "html"]
+        );
+        assert!(names(&document.root().unwrap()).is_empty());
+        assert!(b.ancestors().any(|ancestor| ancestor.is("div")));
+        assert_eq!(b.ancestors().last(), document.root());
+
+        // Nodes not inserted in the tree have no ancestors
+        let text = document.create_text("T").unwrap();
+        let em = document.create_element("em", &[]).unwrap();
+        document.append_child(em, text).unwrap();
+        assert!(names(&document.get_node(em).unwrap()).is_empty());
+        let body_idx = document.find_first("body").unwrap().idx();
+        document.append_child(body_idx, em).unwrap();
+        let em = document.find_first("em").unwrap();
+        assert_eq!(names(&em.first_child().unwrap()), ["em", "body", "html"]);
+    }
+
+    #[test]
+    fn test_node_sibling_iterators() {
+        let xml_data = b"<body><p>A</p><h2>T</h2><p>B</p>text<p>C</p><div/></body>".to_vec();
+        let options = ParserOptions::new().trim_pcdata(false);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let h2 = document.find_first("h2").unwrap();
+
+        let after: Vec<String> = h2
+            .next_siblings()
+            .filter(|node| node.is("p"))
+            .map(|node| node.text_content())
+            .collect();
+        assert_eq!(after, ["B", "C"]);
+        assert_eq!(h2.next_siblings().count(), 4);
+        assert!(h2.next_siblings().all(|node| node != h2));
+        assert_eq!(h2.next_siblings().last().unwrap().tag_name(), "div");
+        assert!(document
+            .find_first("div")
+            .unwrap()
+            .next_siblings()
+            .next()
+            .is_none());
+        assert!(document.root().unwrap().next_siblings().next().is_none());
+
+        #[cfg(not(feature = "forward_only"))]
+        {
+            let before: Vec<String> = h2.prev_siblings().map(|node| node.text_content()).collect();
+            assert_eq!(before, ["A"]);
+
+            let div = document.find_first("div").unwrap();
+            let names: Vec<String> = div
+                .prev_siblings()
+                .map(|node| node.tag_name().to_string())
+                .collect();
+            assert_eq!(names, ["p", "", "p", "h2", "p"]);
+            assert!(document
+                .find_first("p")
+                .unwrap()
+                .prev_siblings()
+                .next()
+                .is_none());
+            assert!(document.root().unwrap().prev_siblings().next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_element_sibling_navigation() {
+        let xml_data = b"<root>\n  <a/>\n  text\n  <!-- c -->\n  <b/>\n  <c/>\n</root>".to_vec();
+        let options = ParserOptions::new()
+            .keep_ws_only_pcdata(true)
+            .trim_pcdata(false)
+            .keep_comments(true);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let a = document.find_first("a").unwrap();
+
+        assert!(a.next_sibling().unwrap().is_text());
+        let b = a.next_element_sibling().unwrap();
+        assert!(b.is("b"));
+        let c = b.next_element_sibling().unwrap();
+        assert!(c.is("c"));
+        assert!(c.next_element_sibling().is_none());
+        assert!(document.root().unwrap().next_element_sibling().is_none());
+
+        #[cfg(not(feature = "forward_only"))]
+        {
+            assert!(c.prev_element_sibling().unwrap().is("b"));
+            assert!(b.prev_sibling().unwrap().is_text());
+            assert!(b.prev_element_sibling().unwrap().is("a"));
+            assert!(a.prev_element_sibling().is_none());
+            assert!(document.root().unwrap().prev_element_sibling().is_none());
+        }
+    }
+
+    #[test]
+    fn test_children_by_name() {
+        let xml_data = b"<table><tr><td>1</td><td>2</td></tr>text<tr><td>3</td><th>H</th><td><td>nested</td></td></tr></table>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let table = document.root().unwrap();
+
+        let rows: Vec<Vec<String>> = table
+            .children_by_name("tr")
+            .map(|tr| {
+                tr.children_by_name("td")
+                    .map(|td| td.text_content())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows, [vec!["1", "2"], vec!["3", "nested"]]);
+
+        // Only the direct children elements are considered
+        assert_eq!(table.children_by_name("td").count(), 0);
+        assert_eq!(table.children_by_name("").count(), 0);
+        let name = String::from("tr");
+        let count = table.children_by_name(&name).count();
+        assert_eq!(count, 2);
+        assert!(document
+            .find_first("th")
+            .unwrap()
+            .children_by_name("td")
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_line_and_column() {
+        let xml_data = "<root>\n  <a>été</a>\r\n\t<b x=\"1\">\n    text\n  </b>\n</root>"
+            .as_bytes()
+            .to_vec();
+        let options = ParserOptions::new().trim_pcdata(false);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+
+        let a = document.find_first("a").unwrap();
+        let b = document.find_first("b").unwrap();
+        assert_eq!(document.root().unwrap().line(), 1);
+        assert_eq!(a.line(), 2);
+        assert_eq!(document.line_col(a.position()), (2, 4));
+        // The column counts characters, not bytes
+        let a_text = a.first_child().unwrap();
+        assert_eq!(document.line_col(a_text.position()), (2, 6));
+        assert_eq!(document.line_col(a_text.position() + 5), (2, 9));
+        assert_eq!(document.line_col(b.position()), (3, 3));
+        assert_eq!(b.first_child().unwrap().line(), 3);
+        assert_eq!(document.line_col(0), (1, 1));
+        assert_eq!(document.line_col(XmlIdx::MAX), (6, 8));
+
+        // Parsing errors report the line and column
+        for (xml, expected) in [
+            (
+                "<root>\n  <a></b>\n</root>",
+                "at line 2, column 8 (position 14)",
+            ),
+            (
+                "<root>\n\n  <é 1a=\"1\"/></root>",
+                "at line 3, column 6 (position 14)",
+            ),
+            (
+                "<root a=\"1\"\n  b=1></root>",
+                "at line 2, column 5 (position 16)",
+            ),
+        ] {
+            match Document::new(xml.as_bytes().to_vec()) {
+                Err(ParseXmlError::InvalidXml(msg)) => assert!(msg.contains(expected), "{msg}"),
+                result => panic!("Unexpected result: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_recovering_parse() {
+        use xhtml_parser::defs::DiagnosticKind;
+
+        // Well-formed content produces no diagnostic
+        let (document, diagnostics) =
+            Document::new_recovering(b"<root><a/></root>".to_vec(), ParserOptions::default())
+                .unwrap();
+        assert!(diagnostics.is_empty());
+        assert_eq!(document.root().unwrap().children().count(), 1);
+
+        let xml = b"<root>\n  <a x=1 y='2'>text</b></a>\n  <c><d></c>\n  <e/ >\n  < f\n</root>";
+        let (document, diagnostics) =
+            Document::new_recovering(xml.to_vec(), ParserOptions::default()).unwrap();
+
+        let kinds: Vec<_> = diagnostics.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                DiagnosticKind::InvalidAttribute,
+                DiagnosticKind::MismatchedClosingTag,
+                DiagnosticKind::MismatchedClosingTag,
+                DiagnosticKind::MalformedTag,
+                DiagnosticKind::InvalidTagName,
+                DiagnosticKind::MismatchedClosingTag,
+            ]
+        );
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 8));
+        assert_eq!(diagnostics[0].position, 14);
+        assert_eq!((diagnostics[2].line, diagnostics[2].column), (3, 11));
+        assert!(diagnostics[1].to_string().contains("line 2"));
+
+        let root = document.root().unwrap();
+        let names: Vec<_> = root
+            .children()
+            .filter(|n| n.is_element())
+            .map(|n| n.tag_name().to_string())
+            .collect();
+        assert_eq!(names, ["a", "c", "e"]);
+
+        let a = root.children_by_name("a").next().unwrap();
+        assert_eq!(a.get_attribute("x"), None);
+        assert_eq!(a.get_attribute("y"), Some("2"));
+        assert_eq!(a.first_child().unwrap().text(), Some("text"));
+
+        // <d> is implicitly closed by </c>, and <e> by </root>
+        let c = a.next_element_sibling().unwrap();
+        assert_eq!(
+            c.children()
+                .map(|n| n.tag_name().to_string())
+                .collect::<Vec<_>>(),
+            ["d"]
+        );
+
+        // Closing tag located after the end of the root element
+        let (document, diagnostics) = Document::new_recovering(
+            b"<root><a>text</root></x>".to_vec(),
+            ParserOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::MismatchedClosingTag);
+        assert_eq!(diagnostics[1].kind, DiagnosticKind::UnmatchedClosingTag);
+        let a = document.root().unwrap().first_child().unwrap();
+        assert_eq!(a.first_child().unwrap().text(), Some("text"));
+
+        // Unclosed elements at the end of the document
+        let (document, diagnostics) =
+            Document::new_recovering(b"<root><a>text".to_vec(), ParserOptions::default()).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::UnexpectedEnd);
+        assert!(diagnostics[0].message.contains("'a'"));
+        assert!(document.root().unwrap().is("root"));
+
+        // The strict entry points still fail on the first problem
+        assert!(Document::new(b"<root><a></b></root>".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_html_leniency() {
+        let xml = b"<html><body><p>One<p>Two<div><p>Three</div><ul><li>A<li>B<ol><li>C</ol></ul><table><tr><td>1<td>2<tr><th>3</table><dl><dt>T<dd>D</dl></body></html>";
+        let options = ParserOptions::new().html_leniency(true);
+        assert!(options.has_html_leniency());
+        assert!(!ParserOptions::new().has_html_leniency());
+
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let body = document.root().unwrap().first_child().unwrap();
+        let names = |node: &Node| -> Vec<String> {
+            node.children()
+                .filter(|n| n.is_element())
+                .map(|n| n.tag_name().to_string())
+                .collect()
+        };
+
+        assert_eq!(names(&body), ["p", "p", "div", "ul", "table", "dl"]);
+
+        let div = body.children_by_name("div").next().unwrap();
+        assert_eq!(names(&div), ["p"]);
+
+        let ul = body.children_by_name("ul").next().unwrap();
+        assert_eq!(names(&ul), ["li", "li"]);
+        let second_li = ul.children_by_name("li").nth(1).unwrap();
+        assert_eq!(names(&second_li), ["ol"]);
+
+        let table = body.children_by_name("table").next().unwrap();
+        assert_eq!(names(&table), ["tr", "tr"]);
+        assert_eq!(names(&table.first_child().unwrap()), ["td", "td"]);
+
+        let dl = body.children_by_name("dl").next().unwrap();
+        assert_eq!(names(&dl), ["dt", "dd"]);
+
+        // Elements without an optional end tag are still checked
+        let error = Document::new_with_options(b"<div><span>x</div>".to_vec(), options.clone());
+        assert!(error.is_err());
+
+        // The root element is never implicitly closed
+        let document =
+            Document::new_with_options(b"<p>One<p>Two</p></p>".to_vec(), options).unwrap();
+        assert_eq!(names(&document.root().unwrap()), ["p"]);
+
+        // Disabled by default
+        assert!(Document::new(b"<body><p>One<p>Two</body>".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_html_void_elements() {
+        let xml = b"<html><head><meta charset='utf-8'><LINK rel=\"a\" href=\"b\"></head><body>One<br>Two<br/>Three<br></br><hr><input name='x' value='y'><img src='a.png' /></body></html>";
+        let options = ParserOptions::new().html_void_elements(true);
+        assert!(options.has_html_void_elements());
+        assert!(!ParserOptions::new().has_html_void_elements());
+
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let html = document.root().unwrap();
+        let names = |node: &Node| -> Vec<String> {
+            node.children()
+                .map(|n| {
+                    if n.is_element() {
+                        n.tag_name().to_string()
+                    } else {
+                        n.text().unwrap_or_default().to_string()
+                    }
+                })
+                .collect()
+        };
+
+        assert_eq!(names(&html), ["head", "body"]);
+
+        let head = html.first_child().unwrap();
+        assert_eq!(names(&head), ["meta", "LINK"]);
+        assert_eq!(
+            head.children().nth(1).unwrap().get_attribute("href"),
+            Some("b")
+        );
+
+        let body = head.next_sibling().unwrap();
+        assert_eq!(
+            names(&body),
+            ["One", "br", "Two", "br", "Three", "br", "hr", "input", "img"]
+        );
+        assert!(body.children().all(|n| !n.has_children()));
+        let input = body.children_by_name("input").next().unwrap();
+        assert_eq!(input.get_attribute("value"), Some("y"));
+
+        // A void element as the root element
+        let document = Document::new_with_options(b"<br>".to_vec(), options.clone()).unwrap();
+        assert!(document.root().unwrap().is("br"));
+
+        // Other elements still need an end tag
+        assert!(Document::new_with_options(b"<p><span></p>".to_vec(), options).is_err());
+
+        // Disabled by default
+        assert!(Document::new(b"<p>One<br>Two</p>".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_html_raw_text() {
+        let xml = b"<html><head><style type=\"text/css\">a > b { content: \"&amp;\"; }</style><SCRIPT>\n  if (a < b && c) document.write('</div>');\n  // </scripts\n</SCRIPT><script src=\"x.js\"></script><script/></head><body><p>&amp;</p></body></html>";
+        let options = ParserOptions::new().html_raw_text(true).parse_escapes(true);
+        assert!(options.has_html_raw_text());
+        assert!(!ParserOptions::new().has_html_raw_text());
+
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let head = document.root().unwrap().first_child().unwrap();
+        let children: Vec<_> = head.children().collect();
+        assert_eq!(children.len(), 4);
+
+        let style = &children[0];
+        assert_eq!(style.get_attribute("type"), Some("text/css"));
+        assert_eq!(
+            style.first_child().unwrap().text(),
+            Some("a > b { content: \"&amp;\"; }")
+        );
+
+        let script = &children[1];
+        assert_eq!(script.children().count(), 1);
+        assert_eq!(
+            script.first_child().unwrap().text(),
+            Some("\n  if (a < b && c) document.write('</div>');\n  // </scripts\n")
+        );
+
+        // Empty elements
+        assert!(!children[2].has_children());
+        assert!(!children[3].has_children());
+
+        // Other elements are parsed as usual
+        let body = head.next_sibling().unwrap();
+        let p = body.first_child().unwrap();
+        assert_eq!(p.first_child().unwrap().text(), Some("&"));
+
+        // Missing end tag
+        assert!(Document::new_with_options(b"<script>a < b</scrip>".to_vec(), options).is_err());
+
+        // Disabled by default
+        assert!(Document::new(b"<script>a < b</script>".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_lowercase_names() {
+        let xml = b"<HTML><Body CLASS=\"Main\" Data-Id='X'><P>Text &NoSuchEntity; More</p><BR/><Script>A &lt; B</SCRIPT></BODY></html>";
+        let options = ParserOptions::new().lowercase_names(true);
+        assert!(options.has_lowercase_names());
+        assert!(!ParserOptions::new().has_lowercase_names());
+
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let html = document.root().unwrap();
+        assert!(html.is("html"));
+
+        let body = html.first_child().unwrap();
+        assert_eq!(body.tag_name(), "body");
+        assert_eq!(body.get_attribute("class"), Some("Main"));
+        assert_eq!(body.get_attribute("data-id"), Some("X"));
+        assert_eq!(body.get_attribute("CLASS"), None);
+
+        let p = body.first_child().unwrap();
+        assert!(p.is("p"));
+        assert_eq!(
+            p.first_child().unwrap().text(),
+            Some("Text &NoSuchEntity; More")
+        );
+        assert!(p.next_sibling().unwrap().is("br"));
+
+        // Combined with the raw text option, the end tag is matched ignoring case
+        let options = options.html_raw_text(true);
+        let xml = b"<Head><Script>A < B</SCRIPT></head>";
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let script = document
+            .root()
+            .unwrap()
+            .descendants()
+            .find(|n| n.is("script"))
+            .unwrap();
+        assert_eq!(script.first_child().unwrap().text(), Some("A < B"));
+
+        // Disabled by default
+        assert!(Document::new(b"<P>Text</p>".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_entity_expansion_limits() {
+        let xml = b"<root title=\"&lt;&gt;\">&amp;&#x20AC;&#65;&unknown;</root>";
+
+        // No limit by default
+        let options = ParserOptions::new().parse_escapes(true);
+        assert_eq!(options.get_max_entity_expansions(), None);
+        assert_eq!(options.get_max_entity_expansion_size(), None);
+        let document = Document::new_with_options(xml.to_vec(), options.clone()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.get_attribute("title"), Some("<>"));
+        assert_eq!(root.first_child().unwrap().text(), Some("&€A&unknown;"));
+
+        // 5 expansions producing 7 bytes, invalid sequences not being counted
+        let limited = options.clone().max_entity_expansions(Some(5));
+        assert_eq!(limited.get_max_entity_expansions(), Some(5));
+        assert!(Document::new_with_options(xml.to_vec(), limited).is_ok());
+
+        let limited = options.clone().max_entity_expansions(Some(4));
+        let result = Document::new_with_options(xml.to_vec(), limited);
+        assert!(matches!(result, Err(ParseXmlError::EntityLimitExceeded)));
+
+        let limited = options.clone().max_entity_expansion_size(Some(7));
+        assert_eq!(limited.get_max_entity_expansion_size(), Some(7));
+        assert!(Document::new_with_options(xml.to_vec(), limited).is_ok());
+
+        let limited = options.clone().max_entity_expansion_size(Some(6));
+        let result = Document::new_with_options(xml.to_vec(), limited);
+        let error = result.unwrap_err();
+        assert!(matches!(error, ParseXmlError::EntityLimitExceeded));
+        assert_eq!(error.to_string(), "Entity expansion limits exceeded");
+
+        // Attribute values are limited, even without escape parsing
+        let limited = ParserOptions::new()
+            .parse_escapes(false)
+            .max_entity_expansions(Some(1));
+        let result = Document::new_with_options(xml.to_vec(), limited.clone());
+        assert!(matches!(result, Err(ParseXmlError::EntityLimitExceeded)));
+
+        // The limits also apply to the tokenizer
+        let mut tokenizer = Tokenizer::new_with_options(xml.to_vec(), limited).unwrap();
+        let result = loop {
+            match tokenizer.next_event() {
+                Ok(Event::Eof) => break Ok(()),
+                Ok(_) => {}
+                Err(err) => break Err(err),
+            }
+        };
+        assert!(matches!(result, Err(ParseXmlError::EntityLimitExceeded)));
+    }
+
+    #[test]
+    fn test_runtime_limits() {
+        let xml = b"<root a=\"1\" b=\"2\"><c d=\"3\">Text</c><e/></root>";
+
+        let options = ParserOptions::new();
+        assert_eq!(options.get_max_nodes(), None);
+        assert_eq!(options.get_max_attributes(), None);
+        assert_eq!(options.get_max_input_len(), None);
+
+        // 4 nodes, 3 attributes, 44 bytes
+        let options = ParserOptions::new()
+            .max_nodes(Some(4))
+            .max_attributes(Some(3))
+            .max_input_len(Some(xml.len()));
+        assert_eq!(options.get_max_nodes(), Some(4));
+        assert_eq!(options.get_max_attributes(), Some(3));
+        assert_eq!(options.get_max_input_len(), Some(xml.len()));
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        assert_eq!(document.nodes.len(), 5); // With the head node
+
+        let limited =
+            |options: ParserOptions| match Document::new_with_options(xml.to_vec(), options) {
+                Err(ParseXmlError::LimitExceeded(msg)) => msg,
+                other => panic!("Unexpected result: {other:?}"),
+            };
+
+        let msg = limited(ParserOptions::new().max_nodes(Some(3)));
+        assert_eq!(msg, "Document has more than 3 nodes");
+        let msg = limited(ParserOptions::new().max_attributes(Some(2)));
+        assert_eq!(msg, "Document has more than 2 attributes");
+        let msg = limited(ParserOptions::new().max_input_len(Some(10)));
+        assert!(msg.contains("limit of 10 bytes"));
+
+        let error = ParseXmlError::LimitExceeded(msg);
+        assert!(error.to_string().starts_with("Parsing limit exceeded: "));
+
+        // The input size limit also applies to the tokenizer
+        let options = ParserOptions::new().max_input_len(Some(10));
+        assert!(matches!(
+            Tokenizer::new_with_options(xml.to_vec(), options),
+            Err(ParseXmlError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_encoding_detection() {
+        use xhtml_parser::encoding::{detect, Encoding};
+
+        let utf16 = |text: &str, little_endian: bool, bom: bool| -> Vec<u8> {
+            let mut bytes = Vec::new();
+            if bom {
+                bytes.extend_from_slice(if little_endian {
+                    b"\xFF\xFE"
+                } else {
+                    b"\xFE\xFF"
+                });
+            }
+            for unit in text.encode_utf16() {
+                if little_endian {
+                    bytes.extend_from_slice(&unit.to_le_bytes());
+                } else {
+                    bytes.extend_from_slice(&unit.to_be_bytes());
+                }
+            }
+            bytes
+        };
+
+        let text = "<?xml version=\"1.0\" encoding=\"UTF-16\"?><root a=\"ü\">Ça 😀</root>";
+        for (little_endian, bom, encoding) in [
+            (true, true, Encoding::Utf16Le),
+            (false, true, Encoding::Utf16Be),
+            (true, false, Encoding::Utf16Le),
+            (false, false, Encoding::Utf16Be),
+        ] {
+            let xml = utf16(text, little_endian, bom);
+            assert_eq!(detect(&xml).unwrap(), encoding);
+
+            let document = Document::new(xml).unwrap();
+            let root = document.root().unwrap();
+            assert_eq!(root.get_attribute("a"), Some("ü"));
+            assert_eq!(root.first_child().unwrap().text(), Some("Ça 😀"));
+        }
+
+        // UTF-8, with or without byte order mark or declaration
+        for xml in [
+            &b"<root>Text</root>"[..],
+            b"\xEF\xBB\xBF<root>Text</root>",
+            b"<?xml version='1.0' encoding='utf-8'?><root>Text</root>",
+            b"<?xml version=\"1.0\" encoding = \"US-ASCII\" ?><root>Text</root>",
+            b"<?xml version=\"1.0\"?><root>Text</root>",
+        ] {
+            assert_eq!(detect(xml).unwrap(), Encoding::Utf8);
+            let document = Document::new(xml.to_vec()).unwrap();
+            assert_eq!(
+                document.root().unwrap().first_child().unwrap().text(),
+                Some("Text")
+            );
+        }
+
+        // Unsupported encodings are reported
+        let xml = b"<?xml version=\"1.0\" encoding=\"EBCDIC\"?><root/>";
+        match Document::new(xml.to_vec()) {
+            Err(ParseXmlError::InvalidEncoding(msg)) => {
+                assert_eq!(msg, "Unsupported encoding 'EBCDIC'");
+            }
+            other => panic!("Unexpected result: {other:?}"),
+        }
+        assert!(matches!(
+            Tokenizer::new(xml.to_vec()),
+            Err(ParseXmlError::InvalidEncoding(_))
+        ));
+
+        // Malformed UTF-16 content
+        let mut xml = utf16("<root>", true, true);
+        xml.extend_from_slice(&0xD800u16.to_le_bytes());
+        assert!(matches!(
+            Document::new(xml.clone()),
+            Err(ParseXmlError::InvalidEncoding(_))
+        ));
+        xml.pop();
+        let error = Document::new(xml).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid encoding: UTF-16 content with an odd number of bytes"
+        );
+
+        // String content is already UTF-8, whatever its declaration says
+        let document = Document::from_string(
+            "<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><root>é</root>".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            document.root().unwrap().first_child().unwrap().text(),
+            Some("é")
+        );
+    }
+
+    #[test]
+    fn test_legacy_encodings() {
+        let latin1 =
+            b"<?xml version=\"1.0\" encoding=\"iso-8859-1\"?><a t=\"\xC0\">\xE9t\xE9 \x80</a>";
+        let cp1252 = b"<?xml version='1.0' encoding='Windows-1252'?><a>\x93caf\xE9\x94 \x80</a>";
+        let undeclared = b"<a>na\xEFve \x85</a>";
+
+        assert_eq!(
+            xhtml_parser::encoding::detect(latin1).unwrap(),
+            xhtml_parser::encoding::Encoding::Latin1
+        );
+        assert_eq!(
+            xhtml_parser::encoding::detect(cp1252).unwrap(),
+            xhtml_parser::encoding::Encoding::Windows1252
+        );
+
+        // Not decoded by default
+        let result = Document::new(latin1.to_vec());
+        assert!(matches!(result, Err(ParseXmlError::InvalidEncoding(_))));
+
+        let options = ParserOptions::new().legacy_encodings(true);
+
+        let document = Document::new_with_options(latin1.to_vec(), options.clone()).unwrap();
+        let a = document.root().unwrap();
+        assert_eq!(a.get_attribute("t"), Some("À"));
+        assert_eq!(a.first_child().unwrap().text(), Some("été \u{80}"));
+
+        let document = Document::new_with_options(cp1252.to_vec(), options.clone()).unwrap();
+        let a = document.root().unwrap();
+        assert_eq!(a.first_child().unwrap().text(), Some("“café” €"));
+
+        let document = Document::new_with_options(undeclared.to_vec(), options.clone()).unwrap();
+        let a = document.root().unwrap();
+        assert_eq!(a.first_child().unwrap().text(), Some("naïve …"));
+
+        // Valid UTF-8 content without declaration is kept as-is
+        let document =
+            Document::new_with_options("<a>naïve</a>".as_bytes().to_vec(), options).unwrap();
+        let a = document.root().unwrap();
+        assert_eq!(a.first_child().unwrap().text(), Some("naïve"));
+    }
+
+    #[test]
+    fn test_invalid_utf8_policy() {
+        let xml_data = b"<root a=\"x\xFFy\">caf\xE9 \xF0\x9F\x98</root>";
+
+        // Passed through by default, with the byte slice accessors returning the actual bytes
+        let document = Document::new(xml_data.to_vec()).unwrap();
+        let root = document.root().unwrap();
+        let attr = root.attributes().next().unwrap();
+        assert_eq!(attr.value(), "non valid utf-8");
+        assert_eq!(attr.value_bytes(), b"x\xFFy");
+        assert_eq!(
+            root.first_child().unwrap().text_bytes(),
+            Some(&b"caf\xE9 \xF0\x9F\x98"[..])
+        );
+
+        let options = ParserOptions::new().invalid_utf8(InvalidUtf8::Reject);
+        let result = Document::new_with_options(xml_data.to_vec(), options.clone());
+        assert!(matches!(
+            result,
+            Err(ParseXmlError::InvalidEncoding(msg)) if msg == "Invalid UTF-8 sequence at position 10"
+        ));
+        assert!(Document::new_with_options(b"<root>caf\xC3\xA9</root>".to_vec(), options).is_ok());
+
+        let options = ParserOptions::new().invalid_utf8(InvalidUtf8::Replace);
+        let document = Document::new_with_options(xml_data.to_vec(), options.clone()).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.get_attribute("a"), Some("x\u{FFFD}y"));
+        assert_eq!(
+            root.first_child().unwrap().text(),
+            Some("caf\u{FFFD} \u{FFFD}")
+        );
+
+        // The same policy applies to the tokenizer
+        let mut tokenizer = Tokenizer::new_with_options(xml_data.to_vec(), options).unwrap();
+        let mut texts = Vec::new();
+        loop {
+            match tokenizer.next_event().unwrap() {
+                Event::Text(text) => texts.push(text.to_string()),
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(texts, vec!["caf\u{FFFD} \u{FFFD}"]);
+
+        // The legacy encodings option takes precedence for undeclared content
+        let options = ParserOptions::new()
+            .invalid_utf8(InvalidUtf8::Reject)
+            .legacy_encodings(true);
+        let document =
+            Document::new_with_options(b"<root>caf\xE9</root>".to_vec(), options).unwrap();
+        assert_eq!(
+            document.root().unwrap().first_child().unwrap().text(),
+            Some("café")
+        );
+    }
+
+    #[test]
+    fn test_json_export() {
+        let xml_data = b"<root id=\"r\" q='say \"hi\"'><!-- note --><a>Line 1&#10;\tback\\slash</a><?pi some data?><b x=\"1\" x2=\"2\"/>Tail<![CDATA[ & more]]></root>";
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .keep_comments(true)
+            .keep_processing_instructions(true)
+            .keep_cdata(true);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+
+        assert_eq!(
+            document.to_json(),
+            r#"{"name":"root","attributes":{"id":"r","q":"say \"hi\""},"children":[{"comment":" note "},{"name":"a","children":["Line 1\n\tback\\slash"]},{"target":"pi","data":"some data"},{"name":"b","attributes":{"x":"1","x2":"2"}},"Tail & more"]}"#
+        );
+
+        let options = JsonOptions::new()
+            .attributes_as_object(false)
+            .merge_text(false);
+        let b = document.find_first("b").unwrap();
+        assert_eq!(
+            b.to_json_with(&options),
+            r#"{"name":"b","attributes":[["x","1"],["x2","2"]]}"#
+        );
+        assert!(document
+            .to_json_with(&options)
+            .ends_with(r#""Tail"," & more"]}"#));
+
+        // A text node alone is exported as a string
+        let a_text = document.find_first("a").unwrap().first_child().unwrap();
+        assert_eq!(a_text.to_json(), r#""Line 1\n\tback\\slash""#);
+
+        // Deeply nested documents do not overflow the stack
+        let depth = 10_000;
+        let xml_data = format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        let document = Document::from_string(xml_data).unwrap();
+        let json = document.to_json();
+        assert!(json.starts_with(r#"{"name":"a","children":[{"name":"a","#));
+        assert!(json.ends_with(&"]}".repeat(depth - 1)));
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn test_custom_allocator() {
+        use std::alloc::{AllocError, Allocator, Global, Layout};
+        use std::ptr::NonNull;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Keeps track of the bytes currently allocated through it
+        struct Tracking(AtomicUsize);
+
+        unsafe impl Allocator for Tracking {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                self.0.fetch_add(layout.size(), Ordering::Relaxed);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.fetch_sub(layout.size(), Ordering::Relaxed);
+                unsafe { Global.deallocate(ptr, layout) }
+            }
+        }
+
+        let allocator = Tracking(AtomicUsize::new(0));
+        let mut xml_data = b"<root a=\"1\"><b>Text</b><c/></root>".to_vec();
+        {
+            let mut document =
+                Document::new_in(&mut xml_data, ParserOptions::default(), &allocator).unwrap();
+            assert!(allocator.0.load(Ordering::Relaxed) > 0);
+            assert_eq!(document.to_xml(), "<root a=\"1\"><b>Text</b><c/></root>");
+
+            // The rebuilt nodes vector keeps using the allocator
+            let b = document.find_first("b").unwrap().idx();
+            document.remove_node(b).unwrap();
+            assert_eq!(document.to_xml(), "<root a=\"1\"><c/></root>");
+            assert_eq!(
+                allocator.0.load(Ordering::Relaxed),
+                document.nodes.capacity() * size_of::<xhtml_parser::node_info::NodeInfo>()
+                    + document.attributes.capacity()
+                        * size_of::<xhtml_parser::attribute::AttributeInfo>()
+            );
+        }
+        assert_eq!(allocator.0.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_clear_and_reparse() {
+        // Declared first, as it must outlive the document
+        let mut xml_data = b"<root><a/><b/><c/><d/><e/><f/><g/></root>".to_vec();
+        let options = ParserOptions::new().keep_comments(true);
+        let mut document = Document::new_with_options(
+            b"<!-- one --><root id=\"a\" xml:lang=\"en\"><p>One</p><p>Two</p><p>Three</p></root>"
+                .to_vec(),
+            options.clone(),
+        )
+        .unwrap();
+        assert!(document.get_element_by_id("a").is_some());
+        let (node_capacity, attr_capacity) =
+            (document.nodes.capacity(), document.attributes.capacity());
+
+        document.clear();
+        assert!(document.root().is_none());
+        assert_eq!(document.nodes.len(), 1);
+        assert!(document.attributes.is_empty());
+        assert_eq!(document.to_xml(), "");
+        assert_eq!(document.nodes.capacity(), node_capacity);
+
+        // A smaller document reuses the vectors, and they are not shrunk
+        document
+            .reparse(b"<!-- two --><root id=\"b\"><p>Four</p></root>".to_vec())
+            .unwrap();
+        assert_eq!(
+            document.to_xml(),
+            "<!-- two --><root id=\"b\"><p>Four</p></root>"
+        );
+        assert_eq!(document.nodes.capacity(), node_capacity);
+        assert_eq!(document.attributes.capacity(), attr_capacity);
+        assert!(document.get_element_by_id("a").is_none());
+        assert!(document.get_element_by_id("b").is_some());
+        assert_eq!(document.options(), &options);
+
+        // A borrowed buffer can be parsed as well
+        document.reparse(&mut xml_data[..]).unwrap();
+        assert!(document.xml.is_borrowed());
+        assert_eq!(document.root().unwrap().children().count(), 7);
+
+        // A failed parsing leaves the document cleared
+        assert!(document.reparse(b"<root><a></root>".to_vec()).is_err());
+        assert!(document.root().is_none());
+        document.reparse(b"<root/>".to_vec()).unwrap();
+        assert_eq!(document.to_xml(), "<root/>");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_iteration() {
+        use rayon::prelude::*;
+
+        let mut xml = String::from("<book>");
+        for chapter in 0..50 {
+            xml.push_str(&format!("<chapter n=\"{chapter}\">"));
+            for paragraph in 0..20 {
+                xml.push_str(&format!("<p>Chapter {chapter} paragraph {paragraph}</p>"));
+            }
+            xml.push_str("</chapter>");
+        }
+        xml.push_str("</book>");
+        let document = Document::from_string(xml).unwrap();
+
+        // Same nodes, in the same order, as the sequential iterators
+        let sequential: Vec<NodeIdx> = document.all_nodes().map(|node| node.idx()).collect();
+        let parallel: Vec<NodeIdx> = document.par_all_nodes().map(|node| node.idx()).collect();
+        assert_eq!(parallel, sequential);
+        assert_eq!(document.par_all_nodes().len(), 1 + 50 * 41);
+
+        let chapter = document.find_first("chapter").unwrap();
+        let sequential: Vec<String> = chapter
+            .descendants()
+            .filter_map(|node| node.text().map(str::to_string))
+            .collect();
+        let parallel: Vec<String> = chapter
+            .par_descendants()
+            .filter_map(|node| node.text().map(str::to_string))
+            .collect();
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.len(), 20);
+
+        let words: usize = document
+            .par_all_nodes()
+            .filter_map(|node| node.text())
+            .map(|text| text.split_whitespace().count())
+            .sum();
+        assert_eq!(words, 50 * 20 * 4);
+
+        // No descendants
+        let p = document.find_first("p").unwrap().first_child().unwrap();
+        assert_eq!(p.par_descendants().count(), 0);
+    }
+
+    #[test]
+    fn test_feed_parser_chunks() {
+        let xml_data = b"<?xml version=\"1.0\"?>\n<!DOCTYPE html [<!ENTITY x \"y\">]>\n<html lang=\"en\">\n  <!-- note -->\n  <p title=\"a > b\">Caf\xC3\xA9 &amp; <b>Chips</b></p>\n  <br/><![CDATA[<raw>]]><?custom?>\n</html>\n";
+        let mut expected = EventRecorder::default();
+        stream(xml_data.to_vec(), &mut expected).unwrap();
+
+        for size in 1..=xml_data.len() {
+            let mut parser = FeedParser::new(EventRecorder::default());
+            for chunk in xml_data.chunks(size) {
+                parser.feed(chunk).unwrap();
+            }
+            assert_eq!(
+                parser.finish().unwrap().events,
+                expected.events,
+                "size {size}"
+            );
+        }
+
+        let mut parser = FeedParser::new(EventRecorder::default());
+        parser.feed(b"<root><a>Some te").unwrap();
+        assert_eq!(parser.handler().events, ["start root", "start a"]);
+        assert_eq!(parser.buffered_len(), 7);
+        parser.feed(b"xt</a><!-- unfinished").unwrap();
+        assert_eq!(parser.handler().events.len(), 4);
+        assert_eq!(parser.buffered_len(), 15);
+        parser.feed(b" --></root>").unwrap();
+        assert_eq!(parser.buffered_len(), 0);
+        assert_eq!(
+            parser.finish().unwrap().events,
+            [
+                "start root",
+                "start a",
+                "text Some text",
+                "end a",
+                "comment  unfinished ",
+                "end root"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_feed_parser_errors() {
+        let mut parser = FeedParser::new(EventRecorder::default());
+        parser.feed(b"<a><b>").unwrap();
+        match parser.finish() {
+            Err(ParseXmlError::InvalidXml(msg)) => assert!(msg.contains("Unexpected end"), "{msg}"),
+            result => panic!("Unexpected result: {:?}", result.map(|r| r.events)),
+        }
+
+        let mut parser = FeedParser::new(EventRecorder::default());
+        parser.feed(b"<a><b>").unwrap();
+        match parser.feed(b"</a>") {
+            Err(ParseXmlError::InvalidXml(msg)) => assert!(msg.contains("does not match"), "{msg}"),
+            result => panic!("Unexpected result: {result:?}"),
+        }
+        assert!(matches!(
+            parser.feed(b"</b></a>"),
+            Err(ParseXmlError::InvalidXml(msg)) if msg.contains("already failed")
+        ));
+
+        let mut parser = FeedParser::new_with_options(
+            EventRecorder::default(),
+            ParserOptions::new().max_input_len(Some(10)),
+        );
+        parser.feed(b"<root>").unwrap();
+        assert!(matches!(
+            parser.feed(b"<child/>"),
+            Err(ParseXmlError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_custom_entities() {
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .entities([("cr", "\u{A9}"), ("pname", "Acme")])
+            .entity("amp", "and");
+
+        assert_eq!(options.get_entity("pname"), Some("Acme"));
+        assert_eq!(options.get_entity("unknown"), None);
+
+        let xml_data =
+            b"<doc a=\"&pname; &cr;\">&cr; &pname;, &amp; &unknown; &#65;</doc>".to_vec();
+        let document = Document::new_with_options(xml_data, options.clone()).unwrap();
+        let root = document.root().unwrap();
+
+        assert_eq!(root.get_attribute("a"), Some("Acme \u{A9}"));
+        assert_eq!(
+            root.first_child().unwrap().text(),
+            Some("\u{A9} Acme, & &unknown; A")
+        );
+
+        let mut tokenizer =
+            Tokenizer::new_with_options(b"<a>&pname;</a>".to_vec(), options.clone()).unwrap();
+        let mut texts = Vec::new();
+        loop {
+            match tokenizer.next_event().unwrap() {
+                Event::Text(text) => texts.push(text.to_string()),
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(texts, ["Acme"]);
+
+        // Values longer than their reference are expanded out of place
+        let options = options.entity("long", "a much longer value");
+        let xml_data =
+            b"<doc a=\" &long;  &long; \">x &long; &#65; &long;!<b>&long;</b>&long;</doc>";
+        let document = Document::new_with_options(xml_data.to_vec(), options.clone()).unwrap();
+        let root = document.root().unwrap();
+        let long = "a much longer value";
+
+        assert_eq!(
+            root.get_attribute("a"),
+            Some(format!("{long} {long}").as_str())
+        );
+        assert_eq!(
+            root.first_child().unwrap().text(),
+            Some(format!("x {long} A {long}!").as_str())
+        );
+        assert_eq!(root.get_child_text("b").as_deref(), Some(long));
+        assert_eq!(root.children().last().unwrap().text(), Some(long));
+
+        let mut tokenizer = Tokenizer::new_with_options(xml_data.to_vec(), options).unwrap();
+        let mut texts = Vec::new();
+        loop {
+            match tokenizer.next_event().unwrap() {
+                Event::StartTag {
+                    name: "doc",
+                    attributes,
+                } => {
+                    texts.extend(attributes.map(|(_, value)| value.to_string()));
+                }
+                Event::Text(text) => texts.push(text.to_string()),
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(
+            texts,
+            [
+                format!("{long} {long}"),
+                format!("x {long} A {long}!"),
+                long.to_string(),
+                long.to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html5_entities() {
+        let options = ParserOptions::new().parse_escapes(true);
+        let xml_data = b"<p>&euro;5 &hellip; &trade;</p>".to_vec();
+        let document = Document::new_with_options(xml_data, options.clone()).unwrap();
+        let text = document.root().unwrap().first_child().unwrap();
+
+        #[cfg(feature = "html5_entities")]
+        assert_eq!(text.text(), Some("\u{20AC}5 \u{2026} \u{2122}"));
+        #[cfg(not(feature = "html5_entities"))]
+        assert_eq!(text.text(), Some("\u{20AC}5 &hellip; &trade;"));
+
+        #[cfg(feature = "html5_entities")]
+        {
+            let xml_data =
+                b"<p title=\"&rarr;\">&bull; &alpha;&Omega; &fjlig; &NotNestedGreaterGreater; &nGt;</p>"
+                    .to_vec();
+            let document = Document::new_with_options(xml_data, options).unwrap();
+            let p = document.root().unwrap();
+
+            assert_eq!(p.get_attribute("title"), Some("\u{2192}"));
+            assert_eq!(
+                p.first_child().unwrap().text(),
+                Some("\u{2022} \u{3B1}\u{3A9} fj \u{2AA2}\u{338} &nGt;")
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_entities_toggle() {
+        let xml_data =
+            b"<a x=\"1 &amp; 2\" y=\"a&b\" z=\"&unknown;\">&lt;p&gt; &amp;amp; &#65;</a>";
+
+        let raw = ParserOptions::new().decode_entities(false);
+        assert!(!raw.has_decode_entities());
+        assert!(ParserOptions::new().has_decode_entities());
+
+        for options in [raw.clone(), raw.clone().parse_escapes(true)] {
+            let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+            let a = document.root().unwrap();
+            assert_eq!(a.get_attribute("x"), Some("1 &amp; 2"));
+            assert_eq!(a.get_attribute("y"), Some("a&b"));
+            assert_eq!(a.get_attribute("z"), Some("&unknown;"));
+            assert_eq!(
+                a.first_child().unwrap().text(),
+                Some("&lt;p&gt; &amp;amp; &#65;")
+            );
+            assert_eq!(document.to_xml().as_bytes(), xml_data);
+        }
+
+        let options = ParserOptions::new().parse_escapes(true);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let a = document.root().unwrap();
+        assert_eq!(a.get_attribute("x"), Some("1 & 2"));
+        assert_eq!(a.get_attribute("y"), Some("a&b"));
+        assert_eq!(a.get_attribute("z"), Some("&unknown;"));
+        assert_eq!(a.first_child().unwrap().text(), Some("<p> &amp; A"));
+        assert_eq!(
+            document.to_xml(),
+            "<a x=\"1 &amp; 2\" y=\"a&amp;b\" z=\"&amp;unknown;\">&lt;p&gt; &amp;amp; A</a>"
+        );
+
+        let mut tokenizer = Tokenizer::new_with_options(xml_data.to_vec(), raw).unwrap();
+        match tokenizer.next_event().unwrap() {
+            Event::StartTag { attributes, .. } => {
+                assert_eq!(attributes.get("x"), Some("1 &amp; 2"));
+            }
+            event => panic!("Unexpected event: {event:?}"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_created_nodes_without_decoding() {
+        let options = ParserOptions::new().decode_entities(false);
+        let xml_data = b"<r a=\"x&amp;y\"><p>a&amp;b</p></r>".to_vec();
+        let mut document = Document::new_with_options(xml_data, options).unwrap();
+
+        // The parsed text keeps its escape sequences, the stored text is escaped
+        let text = document
+            .find_first("p")
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .idx();
+        document.set_text(text, "c & d").unwrap();
+        let element = document.create_element("e", &[("k", "p&q")]).unwrap();
+        document.append_child(1, element).unwrap();
+        let text = document.create_text("1 &amp; 2").unwrap();
+        document.append_child(1, text).unwrap();
+
+        assert_eq!(
+            document.to_xml(),
+            "<r a=\"x&amp;y\"><p>c &amp; d</p><e k=\"p&amp;q\"/>1 &amp;amp; 2</r>"
+        );
+    }
+
+    #[test]
+    fn test_raw_text() {
+        let xml_data =
+            b"<root>  A &lt;b&gt; &#65;\r\n <i>plain</i><![CDATA[&amp;]]><u>&unknown;</u></root>";
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .trim_pcdata(true)
+            .keep_cdata(true)
+            .keep_raw_text(true);
+        assert!(options.has_keep_raw_text());
+
+        let document = Document::new_with_options(xml_data.to_vec(), options.clone()).unwrap();
+        let root = document.root().unwrap();
+        let texts: Vec<(Option<&str>, Option<&str>)> = root
+            .descendants()
+            .filter(Node::is_text)
+            .map(|node| (node.text(), node.raw_text()))
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                (Some("A <b> A"), Some("A &lt;b&gt; &#65;")),
+                (Some("plain"), Some("plain")),
+                (Some("&amp;"), Some("&amp;")),
+                (Some("&unknown;"), Some("&unknown;")),
+            ]
+        );
+        assert_eq!(root.raw_text(), None);
+
+        let options = options.trim_pcdata(false);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let first = document.root().unwrap().first_child().unwrap();
+        assert_eq!(first.text(), Some("  A <b> A\n "));
+        assert_eq!(first.raw_text(), Some("  A &lt;b&gt; &#65;\r\n "));
+
+        // Not kept by default
+        let document = Document::new(xml_data.to_vec()).unwrap();
+        assert_eq!(
+            document.root().unwrap().first_child().unwrap().raw_text(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_node_id() {
+        let xml_data = b"<root><a>One</a><b>Two</b></root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root = document.root().unwrap();
+        let ids: Vec<NodeId> = root.children().map(|child| child.id()).collect();
+        assert_eq!(ids, [NodeId(2), NodeId(4)]);
+        assert_eq!(ids[1].idx(), 4);
+        assert_eq!(document.node(root.id()).unwrap().tag_name(), "root");
+        assert!(document.node(NodeId(100)).is_none());
+
+        let document = std::sync::Arc::new(document);
+        let handles: Vec<_> = ids
+            .into_iter()
+            .map(|id| {
+                let document = std::sync::Arc::clone(&document);
+                std::thread::spawn(move || {
+                    let node = document.node(id).unwrap();
+                    format!(
+                        "{}:{}",
+                        node.tag_name(),
+                        node.first_child().unwrap().text().unwrap()
+                    )
+                })
+            })
+            .collect();
+        let results: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results, ["a:One", "b:Two"]);
+    }
+
+    #[test]
+    fn test_has_attribute_and_count() {
+        let xml_data = b"<root a=\"1\" b=\"\" c='3'><child/>Text</root>".to_vec();
+        let document = Document::new(xml_data).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.attribute_count(), 3);
+        assert!(root.has_attribute("a"));
+        assert!(root.has_attribute("b"));
+        assert!(root.has_attribute("c"));
+        assert!(!root.has_attribute("d"));
+        assert!(!root.has_attribute("A"));
+
+        let child = root.first_child().unwrap();
+        assert_eq!(child.attribute_count(), 0);
+        assert!(!child.has_attribute("a"));
+
+        let text = child.next_sibling().unwrap();
+        assert_eq!(text.attribute_count(), 0);
+        assert!(!text.has_attribute("a"));
+    }
+
+    #[test]
+    fn test_index_attributes() {
+        let names = [
+            "zeta", "alpha", "mu", "beta", "omega", "kappa", "delta", "pi", "gamma", "epsilon",
+        ];
+        let mut xml = String::from("<root><svg");
+        for (i, name) in names.iter().enumerate() {
+            xml.push_str(&format!(" {name}=\"{i}\""));
+        }
+        xml.push_str("/><p b=\"1\" a=\"2\"/></root>");
+
+        for enabled in [false, true] {
+            let options = ParserOptions::new().index_attributes(enabled);
+            assert_eq!(options.has_index_attributes(), enabled);
+            let mut document =
+                Document::new_with_options(xml.as_bytes().to_vec(), options).unwrap();
+            let svg = document.root().unwrap().first_child().unwrap();
+            for (i, name) in names.iter().enumerate() {
+                let value = i.to_string();
+                assert_eq!(svg.get_attribute(name), Some(value.as_str()));
+                assert!(svg.has_attribute(name));
+            }
+            assert_eq!(svg.get_attribute("eta"), None);
+            assert_eq!(svg.get_attribute("zz"), None);
+            assert!(!svg.has_attribute(""));
+            let names_in_order: Vec<_> = svg.attribute_names().collect();
+            assert_eq!(names_in_order, names);
+
+            let p = svg.next_sibling().unwrap();
+            assert_eq!(p.get_attribute("a"), Some("2"));
+            assert_eq!(p.get_attribute("c"), None);
+
+            // The index is rebuilt after the document is modified
+            let attributes: Vec<(String, String)> = (0..12)
+                .map(|i| (format!("n{}", 11 - i), format!("v{i}")))
+                .collect();
+            let attributes: Vec<(&str, &str)> = attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect();
+            let created = document.create_element("g", &attributes).unwrap();
+            let g = document.get_node(created).unwrap();
+            assert_eq!(g.get_attribute("n3"), Some("v8"));
+            document.append_child(1, created).unwrap();
+            let g = document.get_node(created).unwrap();
+            assert_eq!(g.get_attribute("n0"), Some("v11"));
+            assert_eq!(g.get_attribute("n11"), Some("v0"));
+            assert_eq!(g.get_attribute("n12"), None);
+            let svg = document.root().unwrap().first_child().unwrap();
+            assert_eq!(svg.get_attribute("pi"), Some("7"));
+        }
+    }
+
+    #[cfg(not(feature = "use_cstr"))]
+    #[test]
+    fn test_attribute_ranges() {
+        let xml_data = b"<root>\n  <a x='1' long-name=\"a &amp; b\"/>\n</root>";
+        let options = ParserOptions::new().parse_escapes(true);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let a = document.find_first("a").unwrap();
+        let ranges: Vec<_> = a
+            .attributes()
+            .map(|attr| (attr.name_range(), attr.value_range()))
+            .collect();
+        assert_eq!(ranges, [(12..13, 15..16), (18..27, 29..34)]);
+
+        let name = ranges[1].0.clone();
+        assert_eq!(
+            &xml_data[name.start as usize..name.end as usize],
+            b"long-name"
+        );
+        assert_eq!(document.line_col(ranges[1].1.start), (2, 23));
+        assert_eq!(a.attributes().nth(1).unwrap().value(), "a & b");
+    }
+
+    #[test]
+    fn test_element_spans() {
+        fn spans(document: &Document) -> Vec<&'static [u8]> {
+            document
+                .all_nodes()
+                .filter(Node::is_element)
+                .map(|node| node.span().unwrap())
+                .map(|span| &SOURCE[span.start as usize..span.end as usize])
+                .collect()
+        }
+        const SOURCE: &[u8] =
+            b"<root>\n <a x=\"1 &amp; 2\">T &lt; U</a >\n <b/><c><d y='z' /></c></root>";
+
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .keep_element_spans(true);
+        assert!(options.has_keep_element_spans());
+        let mut document = Document::new_with_options(SOURCE.to_vec(), options.clone()).unwrap();
+        assert_eq!(
+            spans(&document),
+            [
+                SOURCE,
+                b"<a x=\"1 &amp; 2\">T &lt; U</a >".as_slice(),
+                b"<b/>".as_slice(),
+                b"<c><d y='z' /></c>".as_slice(),
+                b"<d y='z' />".as_slice(),
+            ]
+        );
+        let text = document.find_first("a").unwrap().first_child().unwrap();
+        assert_eq!(text.span(), None);
+
+        // The spans follow the nodes when the document is rebuilt
+        let a = document.find_first("a").unwrap().idx();
+        document.remove_node(a).unwrap();
+        assert_eq!(
+            document
+                .find_first("c")
+                .unwrap()
+                .span()
+                .map(|span| span.start),
+            Some(44)
+        );
+
+        // Implicitly closed elements end at the closing tag
+        const HTML: &[u8] = b"<ul><li>One<li>Two<br>x</ul>";
+        let options = ParserOptions::new()
+            .html_leniency(true)
+            .html_void_elements(true)
+            .keep_element_spans(true);
+        let document = Document::new_with_options(HTML.to_vec(), options).unwrap();
+        let html_spans: Vec<_> = document
+            .all_nodes()
+            .filter(Node::is_element)
+            .map(|node| node.span().unwrap())
+            .collect();
+        assert_eq!(html_spans, [0..28, 4..11, 11..23, 18..22]);
+
+        // Unclosed elements extend to the end of the content
+        let options = ParserOptions::new().keep_element_spans(true);
+        let (document, _) =
+            Document::new_recovering(b"<root><a>Text</b>".to_vec(), options).unwrap();
+        assert_eq!(document.find_first("a").unwrap().span(), Some(6..17));
+
+        // Not recorded by default
+        let document = Document::new(SOURCE.to_vec()).unwrap();
+        assert_eq!(document.root().unwrap().span(), None);
+    }
+
+    #[test]
+    fn test_element_inner_spans() {
+        fn inner_spans(document: &Document, source: &'static [u8]) -> Vec<&'static [u8]> {
+            document
+                .all_nodes()
+                .filter(Node::is_element)
+                .map(|node| node.inner_span().unwrap())
+                .map(|span| &source[span.start as usize..span.end as usize])
+                .collect()
+        }
+
+        const SOURCE: &[u8] = b"<root><a x=\"1>2\">T &amp; <i>U</i></a><b/><c y='z' ></c ></root>";
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .keep_element_spans(true);
+        let document = Document::new_with_options(SOURCE.to_vec(), options).unwrap();
+        assert_eq!(
+            inner_spans(&document, SOURCE),
+            [
+                b"<a x=\"1>2\">T &amp; <i>U</i></a><b/><c y='z' ></c >".as_slice(),
+                b"T &amp; <i>U</i>".as_slice(),
+                b"U".as_slice(),
+                b"".as_slice(),
+                b"".as_slice(),
+            ]
+        );
+        let b = document.find_first("b").unwrap();
+        assert_eq!(b.inner_span(), Some(41..41));
+        assert_eq!(b.span(), Some(37..41));
+
+        const HTML: &[u8] = b"<ul><li>One<li>Two<br>x<script>a</b></script></ul>";
+        let options = ParserOptions::new()
+            .html_leniency(true)
+            .html_void_elements(true)
+            .html_raw_text(true)
+            .keep_element_spans(true);
+        let document = Document::new_with_options(HTML.to_vec(), options).unwrap();
+        assert_eq!(
+            inner_spans(&document, HTML),
+            [
+                b"<li>One<li>Two<br>x<script>a</b></script>".as_slice(),
+                b"One".as_slice(),
+                b"Two<br>x<script>a</b></script>".as_slice(),
+                b"".as_slice(),
+                b"a</b>".as_slice(),
+            ]
+        );
+
+        let options = ParserOptions::new().keep_element_spans(true);
+        let (document, _) =
+            Document::new_recovering(b"<root><a>Text</b>".to_vec(), options).unwrap();
+        assert_eq!(document.find_first("a").unwrap().inner_span(), Some(9..17));
+        assert_eq!(document.root().unwrap().inner_span(), Some(6..17));
+    }
+
+    #[test]
+    fn test_walk() {
+        #[derive(Default)]
+        struct Log(Vec<String>);
+
+        impl<'xml> Visitor<'xml> for Log {
+            fn enter_element(&mut self, element: &Node<'xml>) -> WalkAction {
+                self.0.push(format!("+{}", element.tag_name()));
+                if element.has_attribute("skip") {
+                    WalkAction::SkipChildren
+                } else {
+                    WalkAction::Continue
+                }
+            }
+
+            fn leave_element(&mut self, element: &Node<'xml>) {
+                self.0.push(format!("-{}", element.tag_name()));
+            }
+
+            fn text(&mut self, text: &Node<'xml>) {
+                self.0.push(text.text().unwrap().to_string());
+            }
+        }
+
+        let xml_data =
+            b"<root>A<b>B<c/></b><d skip=\"\"><e>E</e></d><!--x--><f><g><h>H</h></g></f>Z</root>";
+        let options = ParserOptions::new().keep_comments(true);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+
+        let mut log = Log::default();
+        document.walk(&mut log);
+        assert_eq!(
+            log.0,
+            [
+                "+root", "A", "+b", "B", "+c", "-c", "-b", "+d", "-d", "+f", "+g", "+h", "H", "-h",
+                "-g", "-f", "Z", "-root"
+            ]
+        );
+
+        let mut log = Log::default();
+        document.find_first("b").unwrap().walk(&mut log);
+        assert_eq!(log.0, ["+b", "B", "+c", "-c", "-b"]);
+
+        let mut log = Log::default();
+        let text = document.root().unwrap().first_child().unwrap();
+        text.walk(&mut log);
+        assert_eq!(log.0, ["A"]);
+
+        // A deeply nested document does not overflow the stack
+        let depth = 10_000;
+        let mut xml = "<a>".repeat(depth);
+        xml.push_str(&"</a>".repeat(depth));
+        let document = Document::new(xml.into_bytes()).unwrap();
+        let mut log = Log::default();
+        document.walk(&mut log);
+        assert_eq!(log.0.len(), 2 * depth);
+    }
+
+    #[test]
+    fn test_descendants_named() {
+        let xml_data =
+            b"<root><p>1</p><div><p>2</p>p<span><p>3</p></span></div><p/><pre>4</pre></root>";
+        let document = Document::new(xml_data.to_vec()).unwrap();
+
+        let all: Vec<_> = document
+            .elements_named("p")
+            .map(|p| p.text_content())
+            .collect();
+        assert_eq!(all, ["1", "2", "3", ""]);
+        assert_eq!(document.elements_named("root").count(), 1);
+        assert_eq!(document.elements_named("q").count(), 0);
+
+        let div = document.find_first("div").unwrap();
+        let nested: Vec<_> = div.descendants_named("p").map(|p| p.idx()).collect();
+        assert_eq!(nested.len(), 2);
+        assert!(nested
+            .iter()
+            .all(|&idx| document.get_node(idx).unwrap().is("p")));
+        assert_eq!(div.descendants_named("div").count(), 0);
+
+        let span = div.descendants_named("span").next().unwrap();
+        assert_eq!(span.descendants_named("p").count(), 1);
+        assert_eq!(
+            document
+                .find_first("pre")
+                .unwrap()
+                .descendants_named("p")
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_query() {
+        let xml_data = b"<body><div class=\"note\" id=\"a\"><p class=\"note\">1</p></div>\
+            <p>2</p><p class=\"other\">3</p><p class=\"note\" hidden=\"\">4</p>Text</body>";
+        let document = Document::new(xml_data.to_vec()).unwrap();
+
+        let texts = |query: &Query| -> Vec<String> {
+            query
+                .select(&document)
+                .map(|node| node.text_content())
+                .collect()
+        };
+        assert_eq!(texts(&Query::element("p")), ["1", "2", "3", "4"]);
+        assert_eq!(
+            texts(&Query::element("p").attribute_value("class", "note")),
+            ["1", "4"]
+        );
+        assert_eq!(
+            texts(
+                &Query::element("p")
+                    .attribute_value("class", "note")
+                    .attribute("hidden")
+            ),
+            ["4"]
+        );
+        assert_eq!(
+            texts(&Query::new().attribute_value("class", "note")),
+            ["1", "1", "4"]
+        );
+        assert_eq!(Query::new().select(&document).count(), 6);
+        assert!(texts(&Query::element("span")).is_empty());
+
+        let div = Query::element("div").first(&document).unwrap();
+        assert_eq!(div.get_attribute("id"), Some("a"));
+        let query = Query::element("p");
+        assert_eq!(query.select_from(&div).count(), 1);
+        assert!(!query.matches(&div));
+        let text = document.root().unwrap().children().last().unwrap();
+        assert!(!Query::new().matches(&text));
+
+        // The same query runs against other documents
+        let other = Document::new(b"<p class=\"note\"><p class=\"note\"/></p>".to_vec()).unwrap();
+        let query = Query::element("p").attribute_value("class", "note");
+        assert_eq!(query.select(&other).count(), 2);
+        assert_eq!(query.first(&document).unwrap().text_content(), "1");
+    }
+
+    #[test]
+    fn test_diff() {
+        let golden = Document::new(
+            b"<html><body><h1 id=\"t\">Title</h1><p>First</p><p class=\"a\" lang=\"en\">Second  text</p><div>Old</div></body></html>"
+                .to_vec(),
+        )
+        .unwrap();
+
+        let same = Document::new_with_options(
+            b"<html>\n  <body>\n    <h1 id='t'>Title</h1>\n    <p>First</p>\n    <p lang=\"en\" class=\"a\">Second\n text</p>\n    <div>Old</div>\n  </body>\n</html>"
+                .to_vec(),
+            ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly),
+        )
+        .unwrap();
+        assert!(diff(&golden, &same).is_empty());
+
+        let changed = Document::new(
+            b"<html><body><h1>Title</h1><nav/><p>First</p><p class=\"b\" dir=\"ltr\">Second text!</p><section>Old</section></body></html>"
+                .to_vec(),
+        )
+        .unwrap();
+        let differences = diff(&golden, &changed);
+        assert_eq!(
+            differences,
+            [
+                Difference::AttributeRemoved {
+                    path: "/html[1]/body[1]/h1[1]".to_string(),
+                    name: "id".to_string(),
+                    value: "t".to_string(),
+                },
+                Difference::Added {
+                    path: "/html[1]/body[1]/nav[1]".to_string(),
+                },
+                Difference::AttributeChanged {
+                    path: "/html[1]/body[1]/p[2]".to_string(),
+                    name: "class".to_string(),
+                    from: "a".to_string(),
+                    to: "b".to_string(),
+                },
+                Difference::AttributeRemoved {
+                    path: "/html[1]/body[1]/p[2]".to_string(),
+                    name: "lang".to_string(),
+                    value: "en".to_string(),
+                },
+                Difference::AttributeAdded {
+                    path: "/html[1]/body[1]/p[2]".to_string(),
+                    name: "dir".to_string(),
+                    value: "ltr".to_string(),
+                },
+                Difference::TextChanged {
+                    path: "/html[1]/body[1]/p[2]/text()[1]".to_string(),
+                    from: "Second text".to_string(),
+                    to: "Second text!".to_string(),
+                },
+                Difference::Renamed {
+                    path: "/html[1]/body[1]/div[1]".to_string(),
+                    from: "div".to_string(),
+                    to: "section".to_string(),
+                },
+            ]
+        );
+        assert_eq!(differences[1].path(), "/html[1]/body[1]/nav[1]");
+        assert_eq!(
+            differences[6].to_string(),
+            "~ /html[1]/body[1]/div[1]: <div> -> <section>"
+        );
+
+        let removed = Document::new(b"<html><body><p>First</p></body></html>".to_vec()).unwrap();
+        let lines: Vec<String> = diff(&golden, &removed)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "- /html[1]/body[1]/h1[1]",
+                "- /html[1]/body[1]/p[2]",
+                "- /html[1]/body[1]/div[1]",
+            ]
+        );
+
+        let options = DiffOptions::new().normalize_whitespace(false);
+        assert!(!options.has_normalize_whitespace());
+        assert!(diff_with_options(&golden, &golden, &options).is_empty());
+        assert_eq!(diff_with_options(&golden, &same, &options).len(), 1);
+    }
+
+    #[test]
+    fn test_content_eq() {
+        let document = Document::new(
+            b"<html><body><p class=\"a\" id=\"b\">Some text</p><!-- note --><div/></body></html>"
+                .to_vec(),
+        )
+        .unwrap();
+        let formatted = Document::new_with_options(
+            b"<html>\n  <body>\n    <p id='b'  class='a'>\n      Some text\n    </p>\n    <div></div>\n  </body>\n</html>"
+                .to_vec(),
+            ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly),
+        )
+        .unwrap();
+
+        assert!(document != formatted);
+        assert!(document.content_eq(&formatted));
+        assert!(formatted.content_eq(&document));
+        assert!(document.content_eq(&document));
+
+        let ordered = DiffOptions::new().attribute_order(true);
+        assert!(ordered.has_attribute_order());
+        assert!(!document.content_eq_with_options(&formatted, &ordered));
+        assert_eq!(
+            diff_with_options(&document, &formatted, &ordered),
+            [
+                Difference::AttributeRemoved {
+                    path: "/html[1]/body[1]/p[1]".to_string(),
+                    name: "class".to_string(),
+                    value: "a".to_string(),
+                },
+                Difference::AttributeAdded {
+                    path: "/html[1]/body[1]/p[1]".to_string(),
+                    name: "id".to_string(),
+                    value: "b".to_string(),
+                },
+                Difference::AttributeRemoved {
+                    path: "/html[1]/body[1]/p[1]".to_string(),
+                    name: "id".to_string(),
+                    value: "b".to_string(),
+                },
+                Difference::AttributeAdded {
+                    path: "/html[1]/body[1]/p[1]".to_string(),
+                    name: "class".to_string(),
+                    value: "a".to_string(),
+                },
+            ]
+        );
+
+        let raw = DiffOptions::new().normalize_whitespace(false);
+        assert!(!document.content_eq_with_options(&formatted, &raw));
+        assert!(document.content_eq_with_options(&document, &raw));
+
+        for other in [
+            &b"<html><body><p class=\"a\" id=\"b\">Other text</p><div/></body></html>"[..],
+            b"<html><body><p class=\"a\" id=\"c\">Some text</p><div/></body></html>",
+            b"<html><body><p class=\"a\">Some text</p><div/></body></html>",
+            b"<html><body><p class=\"a\" id=\"b\">Some text</p><div/><hr/></body></html>",
+            b"<html><body><p class=\"a\" id=\"b\">Some text</p><span/></body></html>",
+            b"<html><body><p class=\"a\" id=\"b\">Some text</p><div>x</div></body></html>",
+        ] {
+            let other = Document::new(other.to_vec()).unwrap();
+            assert!(!document.content_eq(&other));
+            assert!(!other.content_eq(&document));
+            assert!(!diff(&document, &other).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_whitespace_mode() {
+        let xml_data = b"<doc>\n  <code>  a\n b  </code>\n  <meta> value </meta>\n</doc>";
+        let parse = |mode: WhitespaceMode| {
+            let options = ParserOptions::new().whitespace_mode(mode);
+            assert_eq!(options.get_whitespace_mode(), mode);
+            Document::new_with_options(xml_data.to_vec(), options).unwrap()
+        };
+        let texts = |document: &Document| -> Vec<String> {
+            document
+                .root()
+                .unwrap()
+                .descendants()
+                .filter(|node| node.is_text())
+                .map(|node| node.text().unwrap().to_string())
+                .collect()
+        };
+
+        let document = parse(WhitespaceMode::Preserve);
+        assert_eq!(
+            texts(&document),
+            ["\n  ", "  a\n b  ", "\n  ", " value ", "\n"]
+        );
+
+        let document = parse(WhitespaceMode::DropWsOnly);
+        assert_eq!(texts(&document), ["  a\n b  ", " value "]);
+
+        let document = parse(WhitespaceMode::Trim);
+        assert_eq!(texts(&document), ["a\n b", "value"]);
+
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::Preserve)
+            .trim_pcdata(true);
+        assert!(options.has_keep_ws_only_pcdata());
+        assert_eq!(options.get_whitespace_mode(), WhitespaceMode::Trim);
+
+        let options = ParserOptions::new()
+            .trim_pcdata(false)
+            .keep_ws_only_pcdata(false);
+        assert_eq!(options.get_whitespace_mode(), WhitespaceMode::DropWsOnly);
+    }
+
+    #[test]
+    fn test_preserve_whitespace_tags() {
+        assert_eq!(
+            ParserOptions::new().get_preserve_whitespace_tags(),
+            ["pre", "textarea", "script", "style"]
+        );
+
+        let xml_data = b"<body>\n  <p>  Some text  </p>\n  <PRE>\n  fn main() {\n    <b> run(); </b>\n  }\n</PRE>\n  <textarea>   </textarea>\n</body>";
+        let texts = |document: &Document| -> Vec<String> {
+            document
+                .root()
+                .unwrap()
+                .descendants()
+                .filter(|node| node.is_text())
+                .map(|node| node.text().unwrap().to_string())
+                .collect()
+        };
+
+        let options = ParserOptions::new().whitespace_mode(WhitespaceMode::Trim);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        assert_eq!(
+            texts(&document),
+            [
+                "Some text",
+                "\n  fn main() {\n    ",
+                " run(); ",
+                "\n  }\n",
+                "   "
+            ]
+        );
+
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::Trim)
+            .preserve_whitespace_tags(Vec::<String>::new());
+        assert!(options.get_preserve_whitespace_tags().is_empty());
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        assert_eq!(
+            texts(&document),
+            ["Some text", "fn main() {", "run();", "}"]
+        );
+
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::DropWsOnly)
+            .preserve_whitespace_tags(["b"]);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        assert_eq!(
+            texts(&document),
+            [
+                "  Some text  ",
+                "\n  fn main() {\n    ",
+                " run(); ",
+                "\n  }\n"
+            ]
+        );
+
+        let options = ParserOptions::new()
+            .html_raw_text(true)
+            .whitespace_mode(WhitespaceMode::DropWsOnly);
+        let document =
+            Document::new_with_options(b"<html><script>  </script></html>".to_vec(), options)
+                .unwrap();
+        assert_eq!(texts(&document), ["  "]);
+    }
+
+    #[test]
+    fn test_xml_declaration() {
+        let declaration = |xml: &[u8]| Document::new(xml.to_vec()).unwrap().declaration().cloned();
+
+        assert_eq!(
+            declaration(b"<?xml version = '1.1'  encoding=\"UTF-8\"\n standalone='no' ?><root/>"),
+            Some(XmlDeclaration {
+                version: Some("1.1".to_string()),
+                encoding: Some("UTF-8".to_string()),
+                standalone: Some("no".to_string()),
+            })
+        );
+        assert_eq!(
+            declaration(b"<?xml version=\"1.0\"?>\n<?xml-stylesheet href=\"a.css\"?><root/>"),
+            Some(XmlDeclaration {
+                version: Some("1.0".to_string()),
+                ..XmlDeclaration::default()
+            })
+        );
+        assert_eq!(declaration(b"<root/>"), None);
+        assert_eq!(
+            declaration(b"<?xml?><root/>"),
+            Some(XmlDeclaration::default())
+        );
+
+        // The values are not verified, and the reading stops at a malformed pseudo-attribute
+        let invalid =
+            declaration(b"<?xml standalone=\"maybe\" version=1.0 encoding=\"UTF-8\"?><root/>")
+                .unwrap();
+        assert_eq!(invalid.standalone.as_deref(), Some("maybe"));
+        assert!(!invalid.is_standalone());
+        assert_eq!(invalid.version, None);
+        assert_eq!(invalid.encoding, None);
+
+        // The declared encoding of transcoded content is kept
+        let mut xml = b"\xFF\xFE".to_vec();
+        for unit in "<?xml version=\"1.0\" encoding=\"UTF-16\"?><root/>".encode_utf16() {
+            xml.extend_from_slice(&unit.to_le_bytes());
+        }
+        let document = Document::new(xml).unwrap();
+        assert_eq!(
+            document.declaration().unwrap().encoding.as_deref(),
+            Some("UTF-16")
+        );
+        assert_eq!(document.prolog_processing_instructions().count(), 0);
+
+        let mut document =
+            Document::new(b"<?xml version=\"1.0\" standalone=\"yes\"?><root/>".to_vec()).unwrap();
+        assert!(document.declaration().unwrap().is_standalone());
+        document.clear();
+        assert!(document.declaration().is_none());
+    }
+
+    #[test]
+    fn test_parse_fragment() {
+        let xml_data = b"Hello <b>you</b>, <!-- note --><p class=\"x\">a<br/>b</p>\n  end ";
+        let plain = ParserOptions::new().whitespace_mode(WhitespaceMode::DropWsOnly);
+        let document =
+            Document::parse_fragment_with_options(xml_data.to_vec(), plain.clone()).unwrap();
+        let fragment = document.root().unwrap();
+        assert_eq!(fragment.tag_name(), FRAGMENT_ROOT_NAME);
+        assert!(!document.had_trailing_content());
+
+        let children: Vec<String> = fragment
+            .children()
+            .map(|node| {
+                if node.is_element() {
+                    format!("<{}>", node.tag_name())
+                } else {
+                    node.text().unwrap().to_string()
+                }
+            })
+            .collect();
+        assert_eq!(children, ["Hello ", "<b>", ", ", "<p>", "\n  end "]);
+        assert_eq!(fragment.text_content(), "Hello you, ab\n  end ");
+        assert_eq!(
+            document.find_first("p").unwrap().get_attribute("class"),
+            Some("x")
+        );
+        assert_eq!(document.find_first("b").unwrap().position(), 7);
+        assert_eq!(
+            document.to_xml(),
+            "<fragment>Hello <b>you</b>, <p class=\"x\">a<br/>b</p>\n  end </fragment>"
+        );
+
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::DropWsOnly)
+            .keep_element_spans(true)
+            .keep_comments(true);
+        let document = Document::parse_fragment_with_options(xml_data.to_vec(), options).unwrap();
+        let fragment = document.root().unwrap();
+        assert_eq!(fragment.children().count(), 6);
+        let len = xml_data.len() as XmlIdx;
+        assert_eq!(fragment.span(), Some(0..len));
+        assert_eq!(fragment.inner_span(), Some(0..len));
+        assert_eq!(document.find_first("b").unwrap().span(), Some(6..16));
+
+        for (xml, count) in [(&b""[..], 0), (b"  \n ", 0), (b"text", 1), (b"<a/><b/>", 2)] {
+            let document =
+                Document::parse_fragment_with_options(xml.to_vec(), plain.clone()).unwrap();
+            assert_eq!(document.root().unwrap().children().count(), count);
+        }
+
+        assert!(Document::parse_fragment(b"<p>a</p><p>b".to_vec()).is_err());
+        assert!(Document::parse_fragment(b"<p>a</b>".to_vec()).is_err());
+        assert!(Document::new(b"<p>a</p><p>b</p>text".to_vec())
+            .unwrap()
+            .had_trailing_content());
+    }
+
+    #[test]
+    fn test_append_fragment() {
+        let xml_data = b"<html><body><p>Intro</p></body><footer>End</footer></html>";
+        let options = ParserOptions::new()
+            .whitespace_mode(WhitespaceMode::DropWsOnly)
+            .parse_escapes(true)
+            .keep_comments(true);
+        let mut document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let body = document.find_first("body").unwrap().idx();
+        document.create_text("pending").unwrap();
+
+        let inserted = document
+            .append_fragment(
+                body,
+                b"<h2 id=\"s\">Section</h2>text <!-- c --><div><p>a<br/>b</p><p>c &lt; d</p></div>",
+            )
+            .unwrap();
+        assert_eq!(inserted.len(), 4);
+        assert_eq!(document.get_node(inserted[0]).unwrap().tag_name(), "h2");
+        assert_eq!(
+            document.get_node(inserted[1]).unwrap().text(),
+            Some("text ")
+        );
+        assert_eq!(
+            document.get_node(inserted[2]).unwrap().comment(),
+            Some(" c ")
+        );
+        assert_eq!(
+            document.get_node(inserted[3]).unwrap().text_content(),
+            "abc < d"
+        );
+        assert_eq!(
+            document.to_xml(),
+            "<html><body><p>Intro</p><h2 id=\"s\">Section</h2>text <!-- c --><div><p>a<br/>b</p><p>c &lt; d</p></div></body><footer>End</footer></html>"
+        );
+        assert_eq!(document.find_first("footer").unwrap().text_content(), "End");
+        assert_eq!(
+            document.get_element_by_id("s").unwrap().text_content(),
+            "Section"
+        );
+
+        // The other created nodes are still waiting for insertion
+        assert_eq!(document.pending_nodes().len(), 1);
+        let pending = document.pending_nodes()[0];
+        assert_ne!(pending, 0);
+        let footer = document.find_first("footer").unwrap().idx();
+        document.append_child(footer, pending).unwrap();
+        assert_eq!(
+            document.find_first("footer").unwrap().text_content(),
+            "Endpending"
+        );
+
+        let before = document.to_xml();
+        let body = document.find_first("body").unwrap().idx();
+        assert!(document.append_fragment(body, b"<p>unclosed").is_err());
+        let text = document
+            .get_node(body)
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .idx();
+        assert!(document.append_fragment(text, b"<p/>").is_err());
+        assert_eq!(document.to_xml(), before);
+        assert!(document.append_fragment(body, b"").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_keep_entity_refs() {
+        let xml_data =
+            b"<doc a=\"x &amp; y\"><p>&lt;tag&gt; &amp;&amp; &#65;&#x42; &nbsp;</p><q>& a;&;&amp</q><r>&unknown; text</r></doc>";
+        let options = ParserOptions::new()
+            .keep_entity_refs(true)
+            .parse_escapes(true);
+        assert!(options.has_keep_entity_refs());
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.get_attribute("a"), Some("x & y"));
+
+        let describe = |node: &Node| -> String {
+            match node.entity_name() {
+                Some(name) => format!("&{name}"),
+                None => node.text().unwrap().to_string(),
+            }
+        };
+        let p: Vec<String> = root
+            .first_child()
+            .unwrap()
+            .children()
+            .map(|n| describe(&n))
+            .collect();
+        assert_eq!(
+            p,
+            ["&lt", "tag", "&gt", " ", "&amp", "&amp", " AB ", "&nbsp"]
+        );
+
+        let q = document.find_first("q").unwrap();
+        assert_eq!(q.children().count(), 1);
+        assert_eq!(q.text_content(), "& a;&;&amp");
+
+        let r = document.find_first("r").unwrap();
+        let unknown = r.first_child().unwrap();
+        assert!(unknown.is_entity_ref());
+        assert!(!unknown.is_text());
+        assert_eq!(unknown.entity_name(), Some("unknown"));
+        assert_eq!(unknown.entity_value(), None);
+        assert_eq!(unknown.position(), 88);
+        assert_eq!(r.text_content(), "&unknown; text");
+
+        assert_eq!(
+            document.to_xml(),
+            "<doc a=\"x &amp; y\"><p>&lt;tag&gt; &amp;&amp; AB &nbsp;</p><q>&amp; a;&amp;;&amp;amp</q><r>&unknown; text</r></doc>"
+        );
+        assert_eq!(
+            document.find_first("p").unwrap().to_json(),
+            r#"{"name":"p","children":[{"entity":"lt"},"tag",{"entity":"gt"}," ",{"entity":"amp"},{"entity":"amp"}," AB ",{"entity":"nbsp"}]}"#
+        );
+
+        // Without the option, the references are expanded
+        let document = Document::new(xml_data.to_vec()).unwrap();
+        assert!(document
+            .root()
+            .unwrap()
+            .descendants()
+            .all(|n| !n.is_entity_ref()));
+
+        // The entity references are copied by append_fragment()
+        let options = ParserOptions::new().keep_entity_refs(true);
+        let mut document = Document::new_with_options(b"<p/>".to_vec(), options).unwrap();
+        document.append_fragment(1, b"a &copy; b").unwrap();
+        assert_eq!(document.to_xml(), "<p>a &copy; b</p>");
+        assert_eq!(
+            document
+                .root()
+                .unwrap()
+                .children()
+                .nth(1)
+                .unwrap()
+                .entity_value(),
+            Some("\u{a9}")
+        );
+    }
+
+    #[test]
+    fn test_text_accessors_expand_entity_refs() {
+        let xml_data = b"<ul><li>a&amp;b</li><li> c&unknown; </li></ul>".to_vec();
+        let options = ParserOptions::new()
+            .parse_escapes(true)
+            .whitespace_mode(WhitespaceMode::Preserve)
+            .keep_entity_refs(true);
+        let document = Document::new_with_options(xml_data, options).unwrap();
+        let ul = document.root().unwrap();
+        let li = ul.first_child().unwrap();
+
+        assert!(li.children().nth(1).unwrap().is_entity_ref());
+        assert_eq!(li.children().nth(1).unwrap().text_content(), "&");
+        assert_eq!(li.text_content(), "a&b");
+        assert_eq!(ul.text_content(), "a&b c&unknown; ");
+        assert_eq!(ul.text_content_len(), ul.text_content().len());
+        assert_eq!(li.text_content_len(), 3);
+        assert_eq!(ul.inner_text_with_separator("|"), "a&b| c&unknown; ");
+        assert_eq!(ul.strip_to_text(), "a&b c&unknown;");
+        assert_eq!(ul.strip_to_text_with_boundaries(), "a&b c&unknown;");
+        assert_eq!(li.strip_to_text_with_boundaries(), "a&b");
+    }
+
+    #[test]
+    fn test_internal_subset_entities() {
+        let xml_data = br#"<?xml version="1.0"?>
+<!DOCTYPE doc [
+  <!-- The company ] name -->
+  <!ELEMENT doc (#PCDATA | p)*>
+  <!ATTLIST p title CDATA "a > b">
+  <!ENTITY % common SYSTEM "common.ent">
+  %common;
+  <?dtd-processor keep?>
+  <!ENTITY co "ACME">
+  <!ENTITY co "Other">
+  <!ENTITY cr '&#169;&#xAE;'>
+  <!ENTITY amp "and">
+  <!ENTITY ext SYSTEM "ext.xml">
+  <!ENTITY br "]&gt;">
+]><doc><p title="&co; &cr;">&co;&amp;&cr; &ext; &br;</p></doc>"#;
+        let escapes = ParserOptions::new().parse_escapes(true);
+        let document = Document::new_with_options(xml_data.to_vec(), escapes.clone()).unwrap();
+
+        assert_eq!(document.declared_entity("co"), Some("ACME"));
+        assert_eq!(document.declared_entity("cr"), Some("\u{a9}\u{ae}"));
+        assert_eq!(document.declared_entity("br"), Some("]>"));
+        assert_eq!(document.declared_entity("amp"), None);
+        assert_eq!(document.declared_entity("ext"), None);
+        assert_eq!(document.declared_entity("common"), None);
+
+        let p = document.root().unwrap().first_child().unwrap();
+        assert_eq!(p.get_attribute("title"), Some("ACME \u{a9}\u{ae}"));
+        assert_eq!(p.text_content(), "ACME&\u{a9}\u{ae} &ext; ]>");
+
+        // Kept entity references know the declared values
+        let options = escapes.clone().keep_entity_refs(true);
+        let document = Document::new_with_options(xml_data.to_vec(), options).unwrap();
+        let p = document.root().unwrap().first_child().unwrap();
+        assert_eq!(p.first_child().unwrap().entity_value(), Some("ACME"));
+
+        // A declared value longer than its reference is expanded out of place
+        let xml_data = b"<!DOCTYPE doc [<!ENTITY co \"ACME Corporation\">]><doc>&co;</doc>";
+        let document = Document::new_with_options(xml_data.to_vec(), escapes.clone()).unwrap();
+        assert_eq!(document.root().unwrap().text_content(), "ACME Corporation");
+
+        // The tokenizer, stream() and the FeedParser read the internal subset as well
+        let xml_data = br#"<!DOCTYPE r [
+  <!-- ] -->
+  <!ENTITY foo "barbaz">
+  <!ENTITY e 'x]y'>
+]><r a="&e;">&foo;&e;</r>"#;
+        let expected = ["start r a=x]y", "text barbazx]y", "end r"];
+
+        let mut recorder = EventRecorder::default();
+        stream_with_options(xml_data.to_vec(), &mut recorder, escapes.clone()).unwrap();
+        assert_eq!(recorder.events, expected);
+
+        let mut parser = FeedParser::new_with_options(EventRecorder::default(), escapes);
+        for chunk in xml_data.chunks(5) {
+            parser.feed(chunk).unwrap();
+        }
+        assert_eq!(parser.finish().unwrap().events, expected);
+
+        let mut document =
+            Document::new(b"<!DOCTYPE doc [<!ENTITY a \"b\">]><doc/>".to_vec()).unwrap();
+        document.clear();
+        assert_eq!(document.declared_entity("a"), None);
+    }
+
+    #[test]
+    fn test_long_and_unterminated_constructs() {
+        use xhtml_parser::defs::DiagnosticKind;
+
+        // Comments, CDATA sections and processing instructions of any length
+        let long = "x".repeat(20_000);
+        let xml_data =
+            format!("<!--{long}--><root><!--{long}--><![CDATA[{long}]]><?pi {long}?>end</root>");
+        let options = ParserOptions::new()
+            .keep_comments(true)
+            .keep_cdata(true)
+            .keep_processing_instructions(true);
+        let document = Document::new_with_options(xml_data.into_bytes(), options).unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.children().count(), 4);
+        assert_eq!(root.first_child().unwrap().comment(), Some(long.as_str()));
+        assert_eq!(root.children().nth(3).unwrap().text(), Some("end"));
+
+        for (xml_data, message) in [
+            (&b"<root>text<!-- no end</root>"[..], "Unterminated comment"),
+            (
+                b"<root><![CDATA[ no end</root>",
+                "Unterminated CDATA section",
+            ),
+            (
+                b"<root><?pi no end</root>",
+                "Unterminated processing instruction",
+            ),
+            (
+                b"<!DOCTYPE root [<!ENTITY a \"b\">",
+                "Unterminated DOCTYPE declaration",
+            ),
+        ] {
+            match Document::new(xml_data.to_vec()) {
+                Err(ParseXmlError::InvalidXml(msg)) => assert!(msg.contains(message), "{msg}"),
+                result => panic!("Unexpected result: {:?}", result.map(|_| ())),
+            }
+        }
+
+        let mut tokenizer = Tokenizer::new(b"<root><!-- no end</root>".to_vec()).unwrap();
+        assert!(matches!(tokenizer.next_event(), Ok(Event::StartTag { .. })));
+        match tokenizer.next_event() {
+            Err(ParseXmlError::InvalidXml(msg)) => {
+                assert!(
+                    msg.contains("Unterminated comment. at line 1, column 7"),
+                    "{msg}"
+                );
+            }
+            result => panic!("Unexpected result: {result:?}"),
+        }
+
+        let (_, diagnostics) = Document::new_recovering(
+            b"<root>text<?pi no end</root>".to_vec(),
+            ParserOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::UnexpectedEnd);
+        assert_eq!(diagnostics[0].position, 10);
+        assert_eq!(
+            diagnostics[0].message,
+            "Unterminated processing instruction"
+        );
+    }
+
+    #[test]
+    fn test_parse_progress() {
+        use std::sync::{Arc, Mutex};
+        use xhtml_parser::parser::ParseProgress;
+
+        let reports = Arc::new(Mutex::new(Vec::<ParseProgress>::new()));
+        let recorder = Arc::clone(&reports);
+        let options = ParserOptions::new().progress(1000, move |progress| {
+            recorder.lock().unwrap().push(progress);
+        });
+        assert_eq!(options.get_progress_interval(), Some(1000));
+        assert_eq!(options.clone(), options);
+        assert_ne!(options, ParserOptions::new());
+        assert_eq!(ParserOptions::new().get_progress_interval(), None);
+
+        let xml_data = format!("<root>{}</root>", "<item>text</item>".repeat(1000));
+        let size = xml_data.len();
+        let mut document = Document::new_with_options(xml_data.into_bytes(), options).unwrap();
+
+        {
+            let reports = reports.lock().unwrap();
+            assert!(reports.len() > 10 && reports.len() <= size / 1000 + 1);
+            // The last report is the one of the end of the parsing
+            assert!(reports[..reports.len() - 1]
+                .windows(2)
+                .all(|w| w[1].offset >= w[0].offset + 1000 && w[1].nodes > w[0].nodes));
+            assert!(reports.iter().all(|r| r.total == size));
+            let last = reports.last().unwrap();
+            assert_eq!(last.offset, size);
+            assert_eq!(last.nodes, document.nodes.len() - 1);
+            assert_eq!(last.fraction(), 1.0);
+            assert!(reports[0].fraction() < 0.1);
+        }
+
+        // The option is kept when the document is parsed again
+        reports.lock().unwrap().clear();
+        document.reparse(b"<root/>".to_vec()).unwrap();
+        assert_eq!(
+            *reports.lock().unwrap(),
+            [ParseProgress {
+                offset: 7,
+                total: 7,
+                nodes: 1
+            }]
+        );
+
+        // No final report when the parsing fails
+        reports.lock().unwrap().clear();
+        assert!(document.reparse(b"<root>".to_vec()).is_err());
+        assert!(reports.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_normalize_self_closing() {
+        for xml in ["<r><a></a><b>x</b></r>", "<r><a/><b>x</b></r>"] {
+            let mut document = Document::new(xml.as_bytes().to_vec()).unwrap();
+
+            document.normalize_self_closing(SelfClosingMode::Collapse);
+            assert_eq!(document.to_xml(), "<r><a/><b>x</b></r>");
+            assert_eq!(document.root().unwrap().inner_xml(), "<a/><b>x</b>");
+
+            document.normalize_self_closing(SelfClosingMode::Expand);
+            assert_eq!(document.to_xml(), "<r><a></a><b>x</b></r>");
+            let a = document.root().unwrap().first_child().unwrap();
+            assert_eq!(a.outer_xml(), "<a></a>");
+        }
+    }
+
+    #[test]
+    fn test_iter_processing_instructions() {
+        let xml = br#"<doc><?xml-stylesheet type="text/xsl" href="style.xsl"?><p>x</p></doc>"#;
+        let options = ParserOptions::new().keep_processing_instructions(true);
+        let document = Document::new_with_options(xml.to_vec(), options).unwrap();
+        let pis: Vec<_> = document.iter_processing_instructions().collect();
+        assert_eq!(
+            pis,
+            vec![("xml-stylesheet", r#"type="text/xsl" href="style.xsl""#)]
+        );
+
+        let document = Document::new(xml.to_vec()).unwrap();
+        assert_eq!(document.iter_processing_instructions().count(), 0);
+    }
+
+    #[test]
+    fn test_debug_and_serializer_render_comments() {
+        let xml = b"<root><a>x</a><!-- note --><b/></root>".to_vec();
+        let options = ParserOptions::new().keep_comments(true);
+        let document = Document::new_with_options(xml, options).unwrap();
+
+        let debug = format!("{document:?}");
+        assert_eq!(debug.matches("Comment {").count(), 1);
+        assert_eq!(debug.matches("\" note \"").count(), 1);
+        assert!(!debug.contains("Unknown Node!"));
+
+        let xml = document.to_xml();
+        assert_eq!(xml, "<root><a>x</a><!-- note --><b/></root>");
+        assert_eq!(xml.matches("<!-- note -->").count(), 1);
+    }
+
+    #[test]
+    fn test_trailing_content_is_kept() {
+        let document = Document::new(b"<a>x</a><b>y</b>".to_vec()).unwrap();
+        let root = document.root().unwrap();
+
+        assert!(document.had_trailing_content());
+        let nodes: Vec<String> = document
+            .all_nodes()
+            .map(|node| {
+                if node.is_element() {
+                    format!("<{}>", node.tag_name())
+                } else {
+                    node.text().unwrap().to_string()
+                }
+            })
+            .collect();
+        assert_eq!(nodes, ["<a>", "x", "<b>", "y"]);
+        assert_eq!(root.next_sibling().unwrap().text_content(), "y");
+        assert_eq!(document.find_first("b").unwrap().text_content(), "y");
+        assert_eq!(document.to_xml(), "<a>x</a>");
+
+        // The text located outside of any element is ignored
+        let document = Document::new(b"<a><c/></a>text<b>x</b><d/>".to_vec()).unwrap();
+        let siblings: Vec<String> = document
+            .root()
+            .unwrap()
+            .next_siblings()
+            .map(|node| node.tag_name().to_string())
+            .collect();
+        assert_eq!(siblings, ["b", "d"]);
+        assert_eq!(document.all_nodes().count(), 5);
+
+        // A stray closing tag after the root is still an error
+        assert!(Document::new(b"<a/></x>".to_vec()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "css")]
+    fn test_matches_selector() {
+        use xhtml_parser::css::Selector;
+
+        let xml = br#"<body><div class="note">A</div><p class="note">B</p></body>"#.to_vec();
+        let document = Document::new(xml).unwrap();
+        let div = document.find_first("div").unwrap();
+
+        assert!(div.matches_selector("div.note"));
+        assert!(!div.matches_selector("p.note"));
+        assert!(div.matches_selector("body > .note"));
+        assert!(!div.matches_selector("div.note["));
+
+        let selector = Selector::new("div.note").unwrap();
+        let matched: Vec<_> = document
+            .all_nodes()
+            .filter(|node| node.matches_compiled_selector(&selector))
+            .collect();
+        assert_eq!(matched, vec![div]);
+    }
+
+    #[test]
+    fn test_pcdata_carriage_returns() {
+        let xml = b"<r>one\r\ntwo\rthree &amp; four\r\nfive<b>x</b></r>".to_vec();
+        let document = Document::new(xml).unwrap();
+        let text = document.root().unwrap().first_child().unwrap();
+
+        #[cfg(feature = "parse_escapes")]
+        assert_eq!(text.text(), Some("one\ntwo\nthree & four\nfive"));
+        #[cfg(not(feature = "parse_escapes"))]
+        assert_eq!(text.text(), Some("one\ntwo\nthree &amp; four\nfive"));
+    }
+
+    #[test]
+    fn test_serialize_children_to() {
+        let xml = br#"<html><body class="c">Fish &amp; <b>Chips</b><br/><ul><li>1</li></ul></body></html>"#;
+        let document = Document::new(xml.to_vec()).unwrap();
+
+        for node in document.all_nodes() {
+            let mut output = Vec::new();
+            node.serialize_children_to(&mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), node.inner_xml());
+        }
+
+        let options = SerializeOptions::new().indent("  ");
+        let ul = document.find_first("ul").unwrap();
+        let mut output = Vec::new();
+        ul.serialize_children_to_with(&mut output, &options)
+            .unwrap();
+        assert_eq!(output, ul.inner_xml_with(&options).as_bytes());
+    }
+
+    #[test]
+    fn test_trim_document_preserves_pre() {
+        let xml_data =
+            b"<root><p>  a  </p><pre>  code\n  <b> x </b>\n</pre><p> b </p></root>".to_vec();
+        let mut document = Document::new(xml_data).unwrap();
+        let pre_content = document.find_first("pre").unwrap().text_content();
+        assert_eq!(pre_content, "  code\n   x \n");
+
+        document.trim_document().unwrap();
+
+        let root = document.root().unwrap();
+        let texts: Vec<_> = root.children().map(|child| child.text_content()).collect();
+        assert_eq!(texts, vec!["a", pre_content.as_str(), "b"]);
+    }
+
+    #[test]
+    fn test_round_trip_without_parse_escapes() {
+        let xml = r#"<menu title="Fish &amp; Chips &lt;3"><item>Fish &amp; Chips &#169; &lt;b&gt;</item></menu>"#;
+        let options = ParserOptions::new().parse_escapes(false);
+        let document = Document::new_with_options(xml.as_bytes().to_vec(), options).unwrap();
+        let root = document.root().unwrap();
+
+        assert_eq!(root.get_attribute("title"), Some("Fish & Chips <3"));
+        assert_eq!(
+            root.first_child().unwrap().first_child().unwrap().text(),
+            Some("Fish &amp; Chips &#169; &lt;b&gt;")
+        );
+        assert_eq!(document.to_xml(), xml);
+
+        // A text set through the DOM has its bare `&` escaped
+        let mut document = document;
+        let text_idx = document
+            .find_first("item")
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .idx();
+        document.set_text(text_idx, "A & B &amp; C").unwrap();
+        assert_eq!(
+            document.find_first("item").unwrap().inner_xml(),
+            "A &amp; B &amp; C"
+        );
     }
 }